@@ -2,8 +2,12 @@
 use crate::Result;
 use clap::Args;
 use std::path::{Path, PathBuf};
-use std::{env, fs, io};
-use thot_local::project::{container, project, resources::Container};
+use std::{env, fs};
+use thot_core::project::metadata::{inherit_from, InheritedMetadata};
+use thot_core::project::{MetadataSchemas, ResolvedMetadata};
+use thot_local::project::migration::{self, FormatStatus, FormatVersion};
+use thot_local::project::resources::{Container, Project};
+use thot_local::project::{container, project};
 
 #[derive(Debug, Args)]
 pub struct CheckArgs {
@@ -14,7 +18,8 @@ pub struct CheckArgs {
     recurse: bool,
 }
 
-/// Check a path to ensure no files are corrupted.
+/// Check a path to ensure no files are corrupted,
+/// and that metadata matches the project's schemas.
 pub fn main(args: CheckArgs, verbose: bool) -> Result {
     let path = match args.path {
         Some(path) => path.clone(),
@@ -24,23 +29,96 @@ pub fn main(args: CheckArgs, verbose: bool) -> Result {
         },
     };
 
-    if check_path(path, args.recurse)? {
+    let mut is_healthy = true;
+    let schemas = match project::project_root_path(&path) {
+        Ok(root) => {
+            if check_format(&root)? {
+                Some(Project::load_from(root)?.settings().schemas.clone())
+            } else {
+                is_healthy = false;
+                None
+            }
+        }
+
+        Err(_) => None,
+    };
+
+    let inherited = match path.parent() {
+        Some(parent) => inherited_metadata(parent),
+        None => InheritedMetadata::new(),
+    };
+
+    if !check_path(path, args.recurse, schemas.as_ref(), &inherited)? {
+        is_healthy = false;
+    }
+
+    if is_healthy {
         println!("All ok");
     }
 
     Ok(())
 }
 
+/// Checks the `Project` is in the current format.
+/// Outdated `Project`s are reported, but not migrated.
+///
+/// # Returns
+/// `true` if the `Project` is in the current format, `false` otherwise.
+fn check_format(root: &Path) -> Result<bool> {
+    match migration::format_status(root)? {
+        FormatStatus::Current => Ok(true),
+        FormatStatus::Outdated(version) => {
+            println!(
+                "{root:?}: Project format `{version}` is outdated, \
+                it will be migrated to `{}` when next opened",
+                FormatVersion::current()
+            );
+
+            Ok(false)
+        }
+
+        FormatStatus::Newer(version) => {
+            println!(
+                "{root:?}: Project format `{version}` is newer than the supported format `{}`",
+                FormatVersion::current()
+            );
+
+            Ok(false)
+        }
+    }
+}
+
 /// Check the health of the path.
 ///
+/// # Arguments
+/// 1. Path to check.
+/// 2. Whether to check children.
+/// 3. Schemas to validate metadata against.
+/// 4. Metadata inherited from ancestors.
+///
 /// # Returns
 /// + `true` if healthy, `false` otherwise.
-fn check_path(path: impl AsRef<Path>, recurse: bool) -> Result<bool> {
+fn check_path(
+    path: impl AsRef<Path>,
+    recurse: bool,
+    schemas: Option<&MetadataSchemas>,
+    inherited: &InheritedMetadata,
+) -> Result<bool> {
     let path = path.as_ref();
     let mut is_healthy = true;
+    let mut passed = inherited.clone();
     if container::path_is_container(path) {
         match Container::load_from(path) {
-            Ok(_) => {}
+            Ok(container) => {
+                if let Some(schemas) = schemas {
+                    if !check_container_schema(path, &container, schemas, inherited) {
+                        is_healthy = false;
+                    }
+                }
+
+                inherit_from(&mut passed, &container);
+            }
+
             Err(err) => {
                 is_healthy = false;
                 println!("Could not load {path:?}: {err:?}");
@@ -52,7 +130,7 @@ fn check_path(path: impl AsRef<Path>, recurse: bool) -> Result<bool> {
         for entry in fs::read_dir(path)? {
             let entry = entry?;
             if entry.path().is_dir() {
-                if check_path(entry.path(), recurse)? {
+                if !check_path(entry.path(), recurse, schemas, &passed)? {
                     is_healthy = false;
                 }
             }
@@ -61,3 +139,60 @@ fn check_path(path: impl AsRef<Path>, recurse: bool) -> Result<bool> {
 
     Ok(is_healthy)
 }
+
+/// Validates the metadata of a `Container` and its `Asset`s against the schemas.
+/// Violations are printed.
+///
+/// # Arguments
+/// 1. Path to the `Container`.
+/// 2. `Container`.
+/// 3. Schemas.
+/// 4. Metadata inherited by the `Container`.
+///
+/// # Returns
+/// `true` if there are no violations, `false` otherwise.
+fn check_container_schema(
+    path: &Path,
+    container: &Container,
+    schemas: &MetadataSchemas,
+    inherited: &InheritedMetadata,
+) -> bool {
+    let mut is_valid = true;
+    let metadata = ResolvedMetadata::for_container(&container.properties, inherited);
+    let violations = schemas.validate(container.properties.kind.as_ref(), &metadata);
+
+    for violation in violations {
+        is_valid = false;
+        println!("{path:?}: {violation}");
+    }
+
+    let mut inherited = inherited.clone();
+    inherit_from(&mut inherited, container);
+    for asset in container.assets.values() {
+        let metadata = ResolvedMetadata::for_asset(&asset.properties, &inherited);
+        let violations = schemas.validate(asset.properties.kind.as_ref(), &metadata);
+        for violation in violations {
+            is_valid = false;
+            println!("{:?}: {violation}", path.join(&asset.path));
+        }
+    }
+
+    is_valid
+}
+
+/// Collects the metadata passed down to resources at the path by its ancestor `Container`s.
+fn inherited_metadata(path: &Path) -> InheritedMetadata {
+    let containers = path
+        .ancestors()
+        .take_while(|path| container::path_is_container(path))
+        .collect::<Vec<_>>();
+
+    let mut inherited = InheritedMetadata::new();
+    for path in containers.into_iter().rev() {
+        if let Ok(container) = Container::load_from(path) {
+            inherit_from(&mut inherited, &container);
+        }
+    }
+
+    inherited
+}
@@ -0,0 +1,140 @@
+//! Compare `Container` trees.
+use crate::Result;
+use clap::Args;
+use std::path::PathBuf;
+use thot_core::graph::diff::{AssetChange, ContainerTreeDiff, PropertyChange};
+use thot_core::graph::ResourceTree;
+use thot_core::project::Container;
+use thot_core::types::ResourceId;
+use thot_local::graph::{ContainerTreeLoader, ContainerTreeTransformer};
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// Root `Container` of the old tree.
+    old: PathBuf,
+
+    /// Root `Container` of the new tree.
+    new: PathBuf,
+
+    /// Output the diff as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+/// Print the changes from one `Container` tree to another,
+/// e.g. from a backup to the current project.
+pub fn main(args: DiffArgs, verbose: bool) -> Result {
+    let old = ContainerTreeLoader::load(&args.old)?;
+    let old = ContainerTreeTransformer::local_to_core(&old);
+    let new = ContainerTreeLoader::load(&args.new)?;
+    let new = ContainerTreeTransformer::local_to_core(&new);
+    let diff = ContainerTreeDiff::new(&old, &new)?;
+
+    if args.json {
+        let diff = serde_json::to_string_pretty(&diff).expect("could not serialize diff");
+        println!("{diff}");
+        return Ok(());
+    }
+
+    if diff.is_empty() {
+        println!("No changes");
+        return Ok(());
+    }
+
+    for added in diff.added.iter() {
+        let root = added.tree.get(added.tree.root()).unwrap();
+        println!(
+            "+ {} ({} containers) in {}",
+            root.properties.name,
+            added.tree.nodes().len(),
+            name(&new, &added.parent)
+        );
+    }
+
+    for removed in diff.removed.iter() {
+        println!("- {}", name(&old, removed.root()));
+    }
+
+    for moved in diff.moved.iter() {
+        println!(
+            "> {}: {} -> {}",
+            name(&new, &moved.container),
+            name(&old, &moved.from),
+            name(&new, &moved.to)
+        );
+    }
+
+    for modified in diff.modified.iter() {
+        println!("~ {}", name(&new, &modified.container));
+        if !verbose {
+            continue;
+        }
+
+        for change in modified.properties.iter() {
+            println!("    {}", property_display(change));
+        }
+
+        for change in modified.metadata.iter() {
+            println!(
+                "    metadata.{}: {:?} -> {:?}",
+                change.key, change.old, change.new
+            );
+        }
+
+        for change in modified.assets.iter() {
+            match change {
+                AssetChange::Added(asset) => println!("    + asset {:?}", asset.path),
+                AssetChange::Removed(asset) => println!("    - asset {:?}", asset.path),
+                AssetChange::Modified(asset) => println!("    ~ asset {}", asset.asset),
+            }
+        }
+
+        for change in modified.scripts.iter() {
+            println!(
+                "    script {}: {:?} -> {:?}",
+                change.script, change.old, change.new
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Name of a `Container`, or its id if it is not in the tree.
+fn name(tree: &ResourceTree<Container>, rid: &ResourceId) -> String {
+    match tree.get(rid) {
+        Some(container) => container.properties.name.clone(),
+        None => rid.to_string(),
+    }
+}
+
+fn property_display(change: &PropertyChange) -> String {
+    match change {
+        PropertyChange::Name(change) => format!("name: {:?} -> {:?}", change.old, change.new),
+        PropertyChange::Kind(change) => format!("kind: {:?} -> {:?}", change.old, change.new),
+        PropertyChange::Description(change) => {
+            format!("description: {:?} -> {:?}", change.old, change.new)
+        }
+
+        PropertyChange::Tags { added, removed } => {
+            format!("tags: +{added:?} -{removed:?}")
+        }
+
+        PropertyChange::Links(change) => format!("links: {:?} -> {:?}", change.old, change.new),
+        PropertyChange::Inheritance(change) => {
+            format!("inheritance: {:?} -> {:?}", change.old, change.new)
+        }
+
+        PropertyChange::Derived(change) => {
+            format!("derived: {:?} -> {:?}", change.old, change.new)
+        }
+
+        PropertyChange::Modified(change) => {
+            format!("modified: {:?} -> {:?}", change.old, change.new)
+        }
+
+        PropertyChange::ModifiedBy(change) => {
+            format!("modified_by: {:?} -> {:?}", change.old, change.new)
+        }
+    }
+}
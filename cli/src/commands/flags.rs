@@ -0,0 +1,144 @@
+//! List and resolve analysis flags.
+use crate::Result;
+use clap::Args;
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+use thot_core::project::Flag;
+use thot_core::types::{Creator, ResourceId, UserId};
+use thot_local::project::{container, flags};
+use thot_local::system::users;
+
+#[derive(Debug, Args)]
+pub struct FlagsArgs {
+    #[clap(long)]
+    path: Option<PathBuf>,
+
+    /// Include resolved flags.
+    #[clap(long)]
+    all: bool,
+
+    /// Resolve the flag as the active user.
+    #[clap(long, value_name = "FLAG")]
+    resolve: Option<ResourceId>,
+
+    /// Remove resolved flags.
+    /// With `--all`, remove all flags.
+    #[clap(long, conflicts_with = "resolve")]
+    clear: bool,
+}
+
+/// List, resolve, or clear the flags of the `Container`s at and below a path.
+pub fn main(args: FlagsArgs, verbose: bool) -> Result {
+    let path = match args.path {
+        Some(path) => path.clone(),
+        None => match env::current_dir() {
+            Ok(dir) => dir,
+            Err(err) => return Err(err.into()),
+        },
+    };
+
+    let mut containers = Vec::new();
+    collect_containers(&path, &mut containers)?;
+
+    if let Some(flag) = args.resolve {
+        let resolver = users::get_active_user_id()?.map(UserId::Id);
+        for container in containers {
+            let mut container_flags = flags::load(&container)?;
+            if let Some(resolved) = container_flags.iter_mut().find(|f| f.rid == flag) {
+                resolved.resolve(Creator::User(resolver));
+                flags::save(&container, &container_flags)?;
+                println!("Resolved {flag}");
+                return Ok(());
+            }
+        }
+
+        println!("Flag {flag} not found");
+        return Ok(());
+    }
+
+    if args.clear {
+        let mut removed = 0;
+        for container in containers {
+            let (clear, keep): (Vec<_>, Vec<_>) = flags::load(&container)?
+                .into_iter()
+                .partition(|flag| args.all || flag.is_resolved());
+
+            if !clear.is_empty() {
+                flags::save(&container, &keep)?;
+                removed += clear.len();
+            }
+        }
+
+        println!("Removed {removed} flags");
+        return Ok(());
+    }
+
+    let mut found = 0;
+    for container in containers {
+        for flag in flags::load(&container)? {
+            if args.all || !flag.is_resolved() {
+                found += 1;
+                print_flag(&container, &flag, verbose);
+            }
+        }
+    }
+
+    if found == 0 {
+        println!("No flags");
+    }
+
+    Ok(())
+}
+
+/// Collects the paths of the `Container`s at and below a path.
+fn collect_containers(path: &Path, containers: &mut Vec<PathBuf>) -> Result {
+    if container::path_is_container(path) {
+        containers.push(path.to_path_buf());
+    }
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.path().is_dir() {
+            collect_containers(&entry.path(), containers)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints a flag.
+///
+/// # Arguments
+/// 1. Path to the `Container` holding the flag.
+/// 2. Flag.
+/// 3. Whether to print the flag's details.
+fn print_flag(container: &Path, flag: &Flag, verbose: bool) {
+    let status = if flag.is_resolved() {
+        " (resolved)"
+    } else {
+        ""
+    };
+    println!(
+        "{} [{:?}] {container:?}: {}{status}",
+        flag.rid, flag.severity, flag.message
+    );
+
+    if verbose {
+        println!("  resource: {}", flag.resource);
+        println!("  created: {}", flag.created());
+        if let Some(script) = flag.script.as_ref() {
+            println!("  script: {script}");
+        }
+
+        if let Some(run) = flag.run.as_ref() {
+            println!("  run: {run}");
+        }
+
+        if let Some(resolution) = flag.resolved.as_ref() {
+            println!(
+                "  resolved: {} by {:?}",
+                resolution.resolved, resolution.resolved_by
+            );
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Merge changes between `Container` trees.
+use crate::Result;
+use clap::Args;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thot_core::graph::diff::{self, ConflictReason, ContainerTreeDiff, MergeConflict};
+use thot_core::graph::ResourceTree;
+use thot_core::project::Container as CoreContainer;
+use thot_core::types::ResourcePath;
+use thot_local::common;
+use thot_local::graph::{ContainerTreeLoader, ContainerTreeTransformer};
+use thot_local::project::resources::Container;
+
+type ContainerTree = ResourceTree<Container>;
+type CoreContainerTree = ResourceTree<CoreContainer>;
+
+#[derive(Debug, Args)]
+pub struct MergeArgs {
+    /// Root `Container` of the tree both trees were copied from.
+    base: PathBuf,
+
+    /// Root `Container` of the tree with the changes to merge.
+    other: PathBuf,
+
+    /// Root `Container` of the tree to merge the changes into.
+    target: PathBuf,
+
+    /// Report conflicts without modifying the target.
+    #[clap(long)]
+    dry_run: bool,
+
+    /// Output the conflicts as JSON.
+    #[clap(long)]
+    json: bool,
+}
+
+/// Apply the changes from one `Container` tree to another to a third,
+/// e.g. to sync changes made to a copy of a project back into it.
+/// Conflicting changes are skipped and reported.
+pub fn main(args: MergeArgs, verbose: bool) -> Result {
+    let base = ContainerTreeLoader::load(&args.base)?;
+    let other = ContainerTreeLoader::load(&args.other)?;
+    let target = ContainerTreeLoader::load(&args.target)?;
+    let diff = ContainerTreeDiff::new(
+        &ContainerTreeTransformer::local_to_core(&base),
+        &ContainerTreeTransformer::local_to_core(&other),
+    )?;
+
+    let mut merged = ContainerTreeTransformer::local_to_core(&target);
+    let conflicts = diff::merge(&mut merged, &diff);
+    if !args.dry_run {
+        write(&target, &merged, &other)?;
+    }
+
+    if args.json {
+        let conflicts =
+            serde_json::to_string_pretty(&conflicts).expect("could not serialize conflicts");
+        println!("{conflicts}");
+        return Ok(());
+    }
+
+    if verbose {
+        println!("{} changes", diff_len(&diff));
+    }
+
+    if conflicts.is_empty() {
+        println!("No conflicts");
+        return Ok(());
+    }
+
+    for conflict in conflicts.iter() {
+        println!("! {}", conflict_display(&merged, &target, conflict));
+    }
+
+    Ok(())
+}
+
+/// Writes a merged tree over the tree it was merged from.
+///
+/// # Arguments
+/// 1. Tree the changes were merged into.
+/// 2. Merged tree.
+/// 3. Tree the changes were made in.
+///
+/// # Notes
+/// + Folders of added `Container`s are named after the `Container`.
+/// + Folders of removed `Container`s are moved to the trash.
+/// + Missing files of `Asset`s are copied from `other`.
+/// + Files of removed `Asset`s are kept.
+fn write(target: &ContainerTree, merged: &CoreContainerTree, other: &ContainerTree) -> Result {
+    // current path of each `Container`, updated as folders are moved
+    let mut paths = target
+        .nodes()
+        .iter()
+        .map(|(rid, node)| (rid.clone(), node.base_path().to_path_buf()))
+        .collect::<HashMap<_, _>>();
+
+    // create and move folders, parents first
+    let nodes = merged
+        .iter_pre_order(merged.root())
+        .expect("root not found");
+    for node in nodes.skip(1) {
+        let rid = &node.rid;
+        let parent = merged
+            .parent(rid)
+            .expect("`Node` not found")
+            .expect("`Node` below root has a parent");
+
+        let parent_path = paths
+            .get(parent)
+            .expect("parent is written before its children")
+            .clone();
+
+        match target.parent(rid) {
+            Ok(from) if from == Some(parent) => {}
+            Ok(_) => {
+                let from = paths.get(rid).expect("`Container` path not found").clone();
+                let name = from.file_name().expect("invalid `Container` path");
+                let to = common::unique_file_name(parent_path.join(name))?;
+                fs::rename(&from, &to)?;
+                for path in paths.values_mut() {
+                    if let Ok(rel_path) = path.strip_prefix(&from) {
+                        *path = to.join(rel_path);
+                    }
+                }
+            }
+
+            Err(_) => {
+                let name = common::sanitize_file_path(node.properties.name.clone());
+                let path = common::unique_file_name(parent_path.join(name))?;
+                fs::create_dir(&path)?;
+                paths.insert(rid.clone(), path);
+            }
+        }
+    }
+
+    // remove folders
+    for rid in target.nodes().keys() {
+        if merged.get(rid).is_some() {
+            continue;
+        }
+
+        let parent = target.parent(rid).expect("`Node` not found");
+        if parent.is_some_and(|parent| merged.get(parent).is_some()) {
+            // folder may have been moved with an ancestor
+            let path = paths.get(rid).expect("`Container` path not found");
+            trash::delete(path)?;
+        }
+    }
+
+    // save data
+    for (rid, node) in merged.nodes() {
+        let path = paths.get(rid).expect("`Container` path not found");
+        let children = merged
+            .children(rid)
+            .expect("`Node` not found")
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut container = match target.get(rid) {
+            None => Container::new(path),
+            Some(current) => {
+                let current_core: &CoreContainer = current.data();
+                if current_core == node.data()
+                    && current.base_path() == path
+                    && current.settings().children == children
+                {
+                    continue;
+                }
+
+                Container::load_from(path)?
+            }
+        };
+
+        let core: &mut CoreContainer = &mut container;
+        *core = node.data().clone();
+        container.settings_mut().children = children;
+        container.save()?;
+
+        copy_missing_asset_files(&container, other)?;
+    }
+
+    Ok(())
+}
+
+/// Copies the files of a `Container`'s `Asset`s that are missing
+/// from the same `Asset` in another tree.
+fn copy_missing_asset_files(container: &Container, other: &ContainerTree) -> Result {
+    let Some(other_container) = other.get(&container.rid) else {
+        return Ok(());
+    };
+
+    for asset in container.assets.values() {
+        let ResourcePath::Relative(rel_path) = &asset.path else {
+            continue;
+        };
+
+        let path = container.base_path().join(rel_path);
+        if path.exists() {
+            continue;
+        }
+
+        let Some(other_asset) = other_container.assets.get(&asset.rid) else {
+            continue;
+        };
+
+        let ResourcePath::Relative(other_rel_path) = &other_asset.path else {
+            continue;
+        };
+
+        let from = other_container.base_path().join(other_rel_path);
+        if from.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+
+            fs::copy(from, &path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Number of changes in a diff.
+fn diff_len(diff: &ContainerTreeDiff) -> usize {
+    diff.added.len() + diff.removed.len() + diff.moved.len() + diff.modified.len()
+}
+
+fn conflict_display(
+    merged: &CoreContainerTree,
+    target: &ContainerTree,
+    conflict: &MergeConflict,
+) -> String {
+    let resource = match (
+        merged.get(&conflict.resource),
+        target.get(&conflict.resource),
+    ) {
+        (Some(container), _) => container.properties.name.clone(),
+        (None, Some(container)) => path_display(container.base_path()),
+        (None, None) => conflict.resource.to_string(),
+    };
+
+    let reason = match &conflict.reason {
+        ConflictReason::NotFound => "not found".to_string(),
+        ConflictReason::ParentNotFound => "parent not found".to_string(),
+        ConflictReason::AlreadyExists => "already exists".to_string(),
+        ConflictReason::ParentChanged => "parent changed".to_string(),
+        ConflictReason::Cycle => "would be moved into itself".to_string(),
+        ConflictReason::SubtreeChanged => "removed subtree has changes".to_string(),
+        ConflictReason::Changed(field) => format!("{field} changed"),
+    };
+
+    format!("{resource}: {reason}")
+}
+
+fn path_display(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+#[path = "./merge_test.rs"]
+mod merge_test;
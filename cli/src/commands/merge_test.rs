@@ -0,0 +1,51 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use thot_local::project::container;
+
+#[test]
+fn write_should_remove_children_of_moved_containers() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let builder = container::InitOptions::init();
+    builder
+        .build(dir.path())
+        .expect("could not init root `Container`");
+
+    let build = |path: PathBuf| {
+        fs::create_dir(&path).expect("could not create dir");
+        builder.build(&path).expect("could not init `Container`")
+    };
+
+    let parent_path = dir.path().join("parent");
+    let to_path = dir.path().join("to");
+    let parent = build(parent_path.clone());
+    let to = build(to_path.clone());
+    let kept = build(parent_path.join("kept"));
+    let removed = build(parent_path.join("removed"));
+
+    let target = ContainerTreeLoader::load(dir.path()).expect("could not load tree");
+    let mut merged = ContainerTreeTransformer::local_to_core(&target);
+    merged.mv(&parent, &to).expect("could not move `Container`");
+    merged
+        .remove(&removed)
+        .expect("could not remove `Container`");
+
+    // test
+    write(&target, &merged, &target).expect("write should work");
+
+    let moved_path = to_path.join("parent");
+    assert!(!parent_path.exists(), "`Container` should be moved");
+    assert!(
+        moved_path.join("kept").exists(),
+        "child should be moved with its parent"
+    );
+    assert!(
+        !moved_path.join("removed").exists(),
+        "removed child should be trashed from its moved location"
+    );
+
+    let written = ContainerTreeLoader::load(dir.path()).expect("could not load tree");
+    assert_eq!(Some(&to), written.parent(&parent).unwrap());
+    assert_eq!(Some(&parent), written.parent(&kept).unwrap());
+    assert!(written.get(&removed).is_none());
+}
@@ -1,6 +1,9 @@
 pub mod check;
 pub mod config;
 pub mod container;
+pub mod diff;
+pub mod flags;
+pub mod merge;
 pub mod project;
 pub mod run;
 pub mod user;
@@ -1,8 +1,9 @@
-use super::AddScriptArgs;
+use super::{AddScriptArgs, SaveTemplateArgs};
 use crate::Result;
 use std::env;
 use thot_core::error::{Error as CoreError, ResourceError};
-use thot_local::project::script;
+use thot_local::project::{project, script};
+use thot_local::system::template::Project as ProjectTemplate;
 
 pub fn add_script(args: AddScriptArgs, verbose: bool) -> Result {
     // format project
@@ -34,3 +35,19 @@ pub fn add_script(args: AddScriptArgs, verbose: bool) -> Result {
 
     Ok(())
 }
+
+pub fn save_template(args: SaveTemplateArgs, verbose: bool) -> Result {
+    let path = match args.project {
+        None => env::current_dir()?,
+        Some(p) => p,
+    };
+
+    let root = project::project_root_path(&path)?;
+    let template = ProjectTemplate::from_project(&root, args.name, !args.no_assets)?;
+    template.save()?;
+    if verbose {
+        println!("Saved template {}.", template.rid);
+    }
+
+    Ok(())
+}
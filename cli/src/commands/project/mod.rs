@@ -17,6 +17,7 @@ pub mod new;
 pub fn main(args: ProjectArgs, verbose: bool) -> Result {
     match args.command {
         Command::AddScript(add_args) => commands::add_script(add_args, verbose),
+        Command::SaveTemplate(template_args) => commands::save_template(template_args, verbose),
     }
 }
 
@@ -29,6 +30,7 @@ pub struct ProjectArgs {
 #[derive(Debug, Subcommand)]
 enum Command {
     AddScript(AddScriptArgs),
+    SaveTemplate(SaveTemplateArgs),
 }
 
 #[derive(Debug, Args)]
@@ -41,3 +43,16 @@ pub struct AddScriptArgs {
     // #[clap(long, value_enum)]
     // path_type: Option<ResourcePathType>,
 }
+
+#[derive(Debug, Args)]
+pub struct SaveTemplateArgs {
+    /// Name of the template.
+    name: String,
+
+    #[clap(short, long)]
+    project: Option<PathBuf>,
+
+    /// Do not include `Asset`s in the template.
+    #[clap(long)]
+    no_assets: bool,
+}
@@ -2,7 +2,9 @@ use crate::Result;
 use clap::Args;
 use std::env;
 use std::path::{Path, PathBuf};
+use thot_core::types::ResourceId;
 use thot_local::project::project;
+use thot_local::system::template::Project as ProjectTemplate;
 
 #[derive(Debug, Args)]
 pub struct NewArgs {
@@ -10,6 +12,10 @@ pub struct NewArgs {
 
     #[clap(short, long)]
     root: Option<PathBuf>,
+
+    /// Id of the template to create the project from.
+    #[clap(short, long)]
+    template: Option<ResourceId>,
 }
 
 /// Creates a new Thot project.
@@ -23,7 +29,10 @@ pub fn main(args: NewArgs, verbose: bool) -> Result {
     let root = root.join(Path::new(&args.name));
 
     // create project
-    if let Err(err) = project::new(root.as_path()) {
+    if let Some(template) = args.template.as_ref() {
+        let mut template = ProjectTemplate::load(template)?;
+        template.create_project(root.as_path())?;
+    } else if let Err(err) = project::new(root.as_path()) {
         return Err(err.into());
     };
 
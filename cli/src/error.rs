@@ -14,6 +14,7 @@ pub enum Error {
     Core(CoreError),
     Io(io::Error),
     Local(LocalError),
+    Trash(trash::Error),
 }
 
 impl From<ClapError> for Error {
@@ -40,4 +41,10 @@ impl From<LocalError> for Error {
     }
 }
 
+impl From<trash::Error> for Error {
+    fn from(err: trash::Error) -> Self {
+        Error::Trash(err)
+    }
+}
+
 pub type Result<T = ()> = StdResult<T, Error>;
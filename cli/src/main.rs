@@ -1,5 +1,5 @@
 use clap::{Parser, Subcommand};
-use thot_cli::commands::{check, config, container, project, run, user};
+use thot_cli::commands::{check, config, container, diff, flags, merge, project, run, user};
 
 fn main() {
     let cli = Cli::parse();
@@ -11,6 +11,9 @@ fn main() {
         Command::Move(args) => project::r#move::main(args, cli.verbose),
         Command::Run(args) => run::main(args, cli.verbose),
         Command::Check(args) => check::main(args, cli.verbose),
+        Command::Flags(args) => flags::main(args, cli.verbose),
+        Command::Diff(args) => diff::main(args, cli.verbose),
+        Command::Merge(args) => merge::main(args, cli.verbose),
 
         // subcommands
         Command::Config(args) => config::main(args, cli.verbose),
@@ -44,6 +47,9 @@ enum Command {
     Run(run::RunArgs),
     Move(project::r#move::MoveArgs),
     Check(check::CheckArgs),
+    Flags(flags::FlagsArgs),
+    Diff(diff::DiffArgs),
+    Merge(merge::MergeArgs),
 
     // subcommands
     Config(config::ConfigArgs),
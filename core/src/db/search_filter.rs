@@ -1,6 +1,11 @@
 //! Search filter functionality.
-use crate::project::{Asset, Container, Metadata};
-use crate::types::ResourceId;
+use crate::project::{
+    tag, Asset, Container, DateTimeRange, DateTimeValue, InheritMetadata, Metadata, Quantity,
+    WithMetadata,
+};
+use crate::types::{Creator, ResourceId};
+use chrono::prelude::*;
+use serde_json::Value as JsValue;
 use std::collections::HashSet;
 
 #[cfg(feature = "serde")]
@@ -35,9 +40,14 @@ where
 }
 
 /// Search filter for all properties.
+///
+/// # Notes
+/// + Tags match resources with the tag or any of its descendants.
+/// + Date-time metadata can be searched by range.
+/// See [`typed_value`](crate::project::typed_value) for the conventions.
 #[cfg_attr(feature = "pyo3", pyo3::pyclass)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Default, Debug, Clone)]
+#[derive(PartialEq, Default, Debug, Clone)]
 pub struct StandardSearchFilter {
     pub rid: Option<ResourceId>,
 
@@ -54,6 +64,45 @@ pub struct StandardSearchFilter {
     pub kind: Option<Option<String>>,
     pub tags: Option<HashSet<String>>,
     pub metadata: Option<Metadata>,
+
+    /// Resources modified at or after the time.
+    pub modified_after: Option<DateTime<Utc>>,
+
+    /// Resources modified before the time.
+    pub modified_before: Option<DateTime<Utc>>,
+
+    /// Resources last modified by the user or script.
+    pub modified_by: Option<Creator>,
+}
+
+impl StandardSearchFilter {
+    /// Returns whether a resource's modification matches the filter.
+    /// Resources that were never modified only match if no modification criteria are set.
+    fn modification_matches(
+        &self,
+        modified: Option<&DateTime<Utc>>,
+        modified_by: Option<&Creator>,
+    ) -> bool {
+        if let Some(after) = self.modified_after.as_ref() {
+            if !modified.is_some_and(|modified| modified >= after) {
+                return false;
+            }
+        }
+
+        if let Some(before) = self.modified_before.as_ref() {
+            if !modified.is_some_and(|modified| modified < before) {
+                return false;
+            }
+        }
+
+        if let Some(s_modified_by) = self.modified_by.as_ref() {
+            if modified_by != Some(s_modified_by) {
+                return false;
+            }
+        }
+
+        true
+    }
 }
 
 // ************************
@@ -62,6 +111,22 @@ pub struct StandardSearchFilter {
 
 impl SearchFilter<Container> for StandardSearchFilter {
     fn matches(&self, container: &Container) -> bool {
+        self.container_matches(container, &container.properties.metadata)
+    }
+}
+
+impl SearchFilter<WithMetadata<Container>> for StandardSearchFilter {
+    /// Matches metadata against the `Container`'s resolved metadata.
+    fn matches(&self, container: &WithMetadata<Container>) -> bool {
+        self.container_matches(&container.resource, container.metadata_all())
+    }
+}
+
+impl StandardSearchFilter {
+    /// # Arguments
+    /// 1. `Container` to match.
+    /// 2. `Metadata` to match against.
+    fn container_matches(&self, container: &Container, metadata: &Metadata) -> bool {
         if let Some(s_rid) = self.rid.as_ref() {
             if s_rid != &container.rid {
                 return false;
@@ -87,27 +152,24 @@ impl SearchFilter<Container> for StandardSearchFilter {
 
         if let Some(s_tags) = self.tags.as_ref() {
             for s_tag in s_tags {
-                if !props.tags.contains(s_tag) {
+                if !tag::matches(&props.tags, s_tag) {
                     return false;
                 }
             }
         }
 
+        if !self.modification_matches(props.modified(), props.modified_by.as_ref()) {
+            return false;
+        }
+
         if let Some(s_md) = self.metadata.as_ref() {
             for (s_key, s_val) in s_md {
-                let Some(f_val) = props.metadata.get(s_key) else {
+                let Some(f_val) = metadata.get(s_key) else {
                     return false;
                 };
 
-                // only compare number values, not types
-                if f_val.is_number() && s_val.is_number() {
-                    if f_val.as_f64() != s_val.as_f64() {
-                        return false;
-                    }
-                } else {
-                    if f_val != s_val {
-                        return false;
-                    }
+                if !metadatum_value_matches(s_val, f_val) {
+                    return false;
                 }
             }
         }
@@ -123,6 +185,22 @@ impl SearchFilter<Container> for StandardSearchFilter {
 
 impl SearchFilter<Asset> for StandardSearchFilter {
     fn matches(&self, asset: &Asset) -> bool {
+        self.asset_matches(asset, &asset.properties.metadata)
+    }
+}
+
+impl SearchFilter<WithMetadata<Asset>> for StandardSearchFilter {
+    /// Matches metadata against the `Asset`'s resolved metadata.
+    fn matches(&self, asset: &WithMetadata<Asset>) -> bool {
+        self.asset_matches(&asset.resource, asset.metadata_all())
+    }
+}
+
+impl StandardSearchFilter {
+    /// # Arguments
+    /// 1. `Asset` to match.
+    /// 2. `Metadata` to match against.
+    fn asset_matches(&self, asset: &Asset, metadata: &Metadata) -> bool {
         if let Some(s_rid) = self.rid.as_ref() {
             if s_rid != &asset.rid {
                 return false;
@@ -144,27 +222,24 @@ impl SearchFilter<Asset> for StandardSearchFilter {
 
         if let Some(s_tags) = self.tags.as_ref() {
             for s_tag in s_tags {
-                if !props.tags.contains(s_tag) {
+                if !tag::matches(&props.tags, s_tag) {
                     return false;
                 }
             }
         }
 
+        if !self.modification_matches(props.modified(), props.modified_by.as_ref()) {
+            return false;
+        }
+
         if let Some(s_md) = self.metadata.as_ref() {
             for (s_key, s_val) in s_md {
-                let Some(f_val) = props.metadata.get(s_key) else {
+                let Some(f_val) = metadata.get(s_key) else {
                     return false;
                 };
 
-                // only compare number values, not types
-                if f_val.is_number() && s_val.is_number() {
-                    if f_val.as_f64() != s_val.as_f64() {
-                        return false;
-                    }
-                } else {
-                    if f_val != s_val {
-                        return false;
-                    }
+                if !metadatum_value_matches(s_val, f_val) {
+                    return false;
                 }
             }
         }
@@ -174,6 +249,39 @@ impl SearchFilter<Asset> for StandardSearchFilter {
     }
 }
 
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Returns whether a metadatum value matches the searched value.
+///
+/// + Numbers are compared by value, not type.
+/// + Quantities are compared across compatible units.
+/// + Date-times are compared by instant,
+/// and match a searched [`DateTimeRange`] containing them.
+fn metadatum_value_matches(search: &JsValue, value: &JsValue) -> bool {
+    if search.is_number() && value.is_number() {
+        return search.as_f64() == value.as_f64();
+    }
+
+    if let Some(value) = DateTimeValue::from_value(value) {
+        if let Some(range) = DateTimeRange::from_value(search) {
+            return range.contains(&value);
+        }
+
+        if let Some(search) = DateTimeValue::from_value(search) {
+            return search == value;
+        }
+    }
+
+    if let (Some(search), Some(value)) = (Quantity::from_value(search), Quantity::from_value(value))
+    {
+        return search.equivalent(&value);
+    }
+
+    search == value
+}
+
 #[cfg(test)]
 #[path = "./search_filter_test.rs"]
 mod search_filter_test;
@@ -1,5 +1,6 @@
 use super::super::dev_utils;
 use super::*;
+use crate::types::UserId;
 use rand::Rng;
 use std::collections::HashMap;
 
@@ -243,3 +244,143 @@ fn standard_search_filter_asset_matches_should_work() {
 
     // TODO Check empty filters. Specifically for `tags` and `metadata`.
 }
+
+#[test]
+fn standard_search_filter_should_match_quantities_across_units() {
+    // setup
+    let mut container = Container::new("sample");
+    container
+        .properties
+        .metadata
+        .insert("length".to_string(), Quantity::new(5.0, "mm").into());
+
+    let mut filter = StandardSearchFilter::default();
+    let mut md = HashMap::new();
+    md.insert("length".to_string(), Quantity::new(0.5, "cm").into());
+    filter.metadata = Some(md);
+
+    // test
+    assert!(
+        filter.matches(&container),
+        "compatible units should be converted"
+    );
+
+    let mut md = HashMap::new();
+    md.insert("length".to_string(), Quantity::new(5.0, "m").into());
+    filter.metadata = Some(md);
+    assert!(!filter.matches(&container), "magnitudes should differ");
+
+    let mut md = HashMap::new();
+    md.insert("length".to_string(), Quantity::new(5.0, "mg").into());
+    filter.metadata = Some(md);
+    assert!(
+        !filter.matches(&container),
+        "incompatible units should not match"
+    );
+}
+
+#[test]
+fn standard_search_filter_should_match_datetime_ranges() {
+    // setup
+    let mut container = Container::new("sample");
+    container.properties.metadata.insert(
+        "recorded".to_string(),
+        "2023-04-01T12:00:00Z"
+            .parse::<DateTimeValue>()
+            .unwrap()
+            .into(),
+    );
+
+    let mut filter = StandardSearchFilter::default();
+    let range = |after: Option<&str>, before: Option<&str>| {
+        let range = DateTimeRange {
+            after: after.map(|after| after.parse().unwrap()),
+            before: before.map(|before| before.parse().unwrap()),
+        };
+
+        HashMap::from([("recorded".to_string(), range.into())])
+    };
+
+    // test
+    filter.metadata = Some(HashMap::from([(
+        "recorded".to_string(),
+        "2023-04-01T14:00:00+02:00"
+            .parse::<DateTimeValue>()
+            .unwrap()
+            .into(),
+    )]));
+    assert!(
+        filter.matches(&container),
+        "date-times should be compared by instant"
+    );
+
+    filter.metadata = Some(range(Some("2023-04-01"), Some("2023-04-02")));
+    assert!(filter.matches(&container), "date-time should be in range");
+
+    filter.metadata = Some(range(Some("2023-04-01T12:00:00Z"), None));
+    assert!(filter.matches(&container), "start should be inclusive");
+
+    filter.metadata = Some(range(None, Some("2023-04-01T12:00:00Z")));
+    assert!(!filter.matches(&container), "end should be exclusive");
+}
+
+#[test]
+fn standard_search_filter_should_match_tag_descendants() {
+    // setup
+    let mut container = Container::new("sample");
+    container.properties.tags = vec!["stimulus/visual/grating".to_string()];
+
+    let mut asset = dev_utils::mock_asset(None, None);
+    asset.properties.tags = vec!["stimulus/visual".to_string()];
+
+    let mut filter = StandardSearchFilter::default();
+
+    // test
+    filter.tags = Some(HashSet::from(["stimulus".to_string()]));
+    assert!(filter.matches(&container), "descendants should match");
+    assert!(filter.matches(&asset), "descendants should match");
+
+    filter.tags = Some(HashSet::from(["stimulus/visual/grating".to_string()]));
+    assert!(filter.matches(&container), "tag should match itself");
+    assert!(!filter.matches(&asset), "ancestors should not match");
+
+    filter.tags = Some(HashSet::from(["stim".to_string()]));
+    assert!(
+        !filter.matches(&container),
+        "partial levels should not match"
+    );
+}
+
+#[test]
+fn standard_search_filter_should_match_modification() {
+    // setup
+    let mut container = Container::new("sample");
+    let mut filter = StandardSearchFilter::default();
+    filter.modified_after = Some(Utc::now() - chrono::Duration::hours(1));
+    assert!(
+        !filter.matches(&container),
+        "unmodified resources should not match"
+    );
+
+    let user = Creator::User(Some(UserId::Email("user@example.com".to_string())));
+    container.properties.mark_modified(user.clone());
+
+    // test
+    assert!(filter.matches(&container), "modified after should match");
+
+    filter.modified_before = Some(Utc::now() - chrono::Duration::minutes(30));
+    assert!(
+        !filter.matches(&container),
+        "modified before should not match"
+    );
+
+    let mut filter = StandardSearchFilter::default();
+    filter.modified_by = Some(user);
+    assert!(filter.matches(&container), "modifier should match");
+
+    filter.modified_by = Some(Creator::User(None));
+    assert!(
+        !filter.matches(&container),
+        "other modifier should not match"
+    );
+}
@@ -0,0 +1,1006 @@
+//! Structural diff and merge of `Container` trees.
+//!
+//! Resources are matched between trees by id.
+use super::ResourceTree;
+use crate::error::GraphError;
+use crate::project::{
+    Asset, AssetProperties, Container, ContainerProperties, DerivedMetadata, Links, Metadata,
+    MetadataInheritance, RunParameters,
+};
+use crate::types::{Creator, ResourceId, ResourcePath};
+use crate::Result;
+use chrono::prelude::*;
+use has_id::HasId;
+use serde_json::Value as JsValue;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+type ContainerTree = ResourceTree<Container>;
+
+// ************
+// *** Diff ***
+// ************
+
+/// Changes from one `Container` tree to another.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ContainerTreeDiff {
+    /// Subtrees only in the new tree.
+    /// Parents are listed before their descendants.
+    pub added: Vec<AddedSubtree>,
+
+    /// Subtrees only in the old tree.
+    pub removed: Vec<RemovedSubtree>,
+
+    /// `Container`s in both trees whose parent changed.
+    pub moved: Vec<MovedContainer>,
+
+    /// Changes to `Container`s in both trees.
+    pub modified: Vec<ContainerDiff>,
+}
+
+impl ContainerTreeDiff {
+    /// Computes the changes from `old` to `new`.
+    ///
+    /// # Errors
+    /// + If the trees do not share a root.
+    pub fn new(old: &ContainerTree, new: &ContainerTree) -> Result<Self> {
+        if old.root() != new.root() {
+            return Err(GraphError::invalid_graph("trees do not share a root").into());
+        }
+
+        let mut diff = Self::default();
+        for node in new.iter_pre_order(new.root()).expect("root not found") {
+            let rid = node.id();
+            let parent = new.parent(rid).expect("`Node` not found");
+            let Some(old_node) = old.get(rid) else {
+                let parent = parent.expect("root is in both trees");
+                if old.get(parent).is_some() {
+                    diff.added.push(AddedSubtree {
+                        parent: parent.clone(),
+                        tree: subtree_excluding(new, rid, old),
+                    });
+                }
+
+                continue;
+            };
+
+            let old_parent = old.parent(rid).expect("`Node` not found");
+            if let (Some(from), Some(to)) = (old_parent, parent) {
+                if from != to {
+                    diff.moved.push(MovedContainer {
+                        container: rid.clone(),
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
+                }
+            }
+
+            let container_diff = ContainerDiff::new(old_node, node);
+            if !container_diff.is_empty() {
+                diff.modified.push(container_diff);
+            }
+        }
+
+        for node in old.iter_pre_order(old.root()).expect("root not found") {
+            let rid = node.id();
+            if new.get(rid).is_some() {
+                continue;
+            }
+
+            let parent = old
+                .parent(rid)
+                .expect("`Node` not found")
+                .expect("root is in both trees");
+
+            if new.get(parent).is_some() {
+                diff.removed.push(RemovedSubtree {
+                    parent: parent.clone(),
+                    tree: subtree_excluding(old, rid, new),
+                });
+            }
+        }
+
+        Ok(diff)
+    }
+
+    /// Returns whether the trees are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.modified.is_empty()
+    }
+}
+
+/// A subtree only in the new tree.
+///
+/// # Notes
+/// + `Container`s of the subtree that are in the old tree are not included,
+///   they are [moved](MovedContainer) into it instead.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct AddedSubtree {
+    pub parent: ResourceId,
+    pub tree: ContainerTree,
+}
+
+/// A subtree only in the old tree.
+///
+/// # Notes
+/// + `Container`s of the subtree that are in the new tree are not included,
+///   they are [moved](MovedContainer) out of it instead.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct RemovedSubtree {
+    pub parent: ResourceId,
+
+    /// The subtree as it was in the old tree.
+    pub tree: ContainerTree,
+}
+
+impl RemovedSubtree {
+    /// Id of the subtree's root.
+    pub fn root(&self) -> &ResourceId {
+        self.tree.root()
+    }
+}
+
+/// A `Container` whose parent changed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct MovedContainer {
+    pub container: ResourceId,
+    pub from: ResourceId,
+    pub to: ResourceId,
+}
+
+/// Changes to a single `Container`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct ContainerDiff {
+    pub container: ResourceId,
+    pub properties: Vec<PropertyChange>,
+    pub metadata: Vec<MetadatumChange>,
+    pub assets: Vec<AssetChange>,
+    pub scripts: Vec<ScriptAssociationChange>,
+}
+
+impl ContainerDiff {
+    pub fn new(old: &Container, new: &Container) -> Self {
+        let mut assets = Vec::new();
+        for (rid, old_asset) in old.assets.iter() {
+            match new.assets.get(rid) {
+                None => assets.push(AssetChange::Removed(old_asset.clone())),
+                Some(new_asset) => {
+                    let asset_diff = AssetDiff::new(old_asset, new_asset);
+                    if !asset_diff.is_empty() {
+                        assets.push(AssetChange::Modified(asset_diff));
+                    }
+                }
+            }
+        }
+
+        for (rid, new_asset) in new.assets.iter() {
+            if !old.assets.contains_key(rid) {
+                assets.push(AssetChange::Added(new_asset.clone()));
+            }
+        }
+
+        let mut scripts = old
+            .scripts
+            .keys()
+            .chain(new.scripts.keys())
+            .filter(|script| old.scripts.get(*script) != new.scripts.get(*script))
+            .collect::<Vec<_>>();
+
+        scripts.sort_by_key(|script| script.to_string());
+        scripts.dedup();
+        let scripts = scripts
+            .into_iter()
+            .map(|script| ScriptAssociationChange {
+                script: script.clone(),
+                old: old.scripts.get(script).cloned(),
+                new: new.scripts.get(script).cloned(),
+            })
+            .collect();
+
+        Self {
+            container: new.rid.clone(),
+            properties: PropertyChange::diff((&old.properties).into(), (&new.properties).into()),
+            metadata: MetadatumChange::diff(&old.properties.metadata, &new.properties.metadata),
+            assets,
+            scripts,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+            && self.metadata.is_empty()
+            && self.assets.is_empty()
+            && self.scripts.is_empty()
+    }
+}
+
+/// A change to an `Asset` of a `Container`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum AssetChange {
+    Added(Asset),
+    Removed(Asset),
+    Modified(AssetDiff),
+}
+
+/// Changes to a single `Asset`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct AssetDiff {
+    pub asset: ResourceId,
+    pub path: Option<ValueChange<ResourcePath>>,
+    pub properties: Vec<PropertyChange>,
+    pub metadata: Vec<MetadatumChange>,
+}
+
+impl AssetDiff {
+    pub fn new(old: &Asset, new: &Asset) -> Self {
+        let path = if old.path == new.path {
+            None
+        } else {
+            Some(ValueChange {
+                old: old.path.clone(),
+                new: new.path.clone(),
+            })
+        };
+
+        Self {
+            asset: new.rid.clone(),
+            path,
+            properties: PropertyChange::diff((&old.properties).into(), (&new.properties).into()),
+            metadata: MetadatumChange::diff(&old.properties.metadata, &new.properties.metadata),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_none() && self.properties.is_empty() && self.metadata.is_empty()
+    }
+}
+
+/// A value before and after a change.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct ValueChange<T> {
+    pub old: T,
+    pub new: T,
+}
+
+/// A change to a standard property of a resource.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum PropertyChange {
+    Name(ValueChange<Option<String>>),
+    Kind(ValueChange<Option<String>>),
+    Description(ValueChange<Option<String>>),
+    Tags {
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    Links(ValueChange<Links>),
+
+    /// Only for `Container`s.
+    Inheritance(ValueChange<MetadataInheritance>),
+    Derived(ValueChange<DerivedMetadata>),
+    Modified(ValueChange<Option<DateTime<Utc>>>),
+    ModifiedBy(ValueChange<Option<Creator>>),
+}
+
+impl PropertyChange {
+    fn diff(old: Properties, new: Properties) -> Vec<Self> {
+        let mut changes = Vec::new();
+        if old.name != new.name {
+            changes.push(Self::Name(ValueChange {
+                old: old.name.cloned(),
+                new: new.name.cloned(),
+            }));
+        }
+
+        if old.kind != new.kind {
+            changes.push(Self::Kind(ValueChange {
+                old: old.kind.clone(),
+                new: new.kind.clone(),
+            }));
+        }
+
+        if old.description != new.description {
+            changes.push(Self::Description(ValueChange {
+                old: old.description.clone(),
+                new: new.description.clone(),
+            }));
+        }
+
+        let added = new
+            .tags
+            .iter()
+            .filter(|tag| !old.tags.contains(tag))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let removed = old
+            .tags
+            .iter()
+            .filter(|tag| !new.tags.contains(tag))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !added.is_empty() || !removed.is_empty() {
+            changes.push(Self::Tags { added, removed });
+        }
+
+        if old.links != new.links {
+            changes.push(Self::Links(ValueChange {
+                old: old.links.clone(),
+                new: new.links.clone(),
+            }));
+        }
+
+        if let (Some(old_inheritance), Some(new_inheritance)) = (old.inheritance, new.inheritance) {
+            if old_inheritance != new_inheritance {
+                changes.push(Self::Inheritance(ValueChange {
+                    old: old_inheritance.clone(),
+                    new: new_inheritance.clone(),
+                }));
+            }
+        }
+
+        if old.derived != new.derived {
+            changes.push(Self::Derived(ValueChange {
+                old: old.derived.clone(),
+                new: new.derived.clone(),
+            }));
+        }
+
+        if old.modified != new.modified {
+            changes.push(Self::Modified(ValueChange {
+                old: old.modified.cloned(),
+                new: new.modified.cloned(),
+            }));
+        }
+
+        if old.modified_by != new.modified_by {
+            changes.push(Self::ModifiedBy(ValueChange {
+                old: old.modified_by.clone(),
+                new: new.modified_by.clone(),
+            }));
+        }
+
+        changes
+    }
+}
+
+/// A change to the value of a single metadatum.
+///
+/// # Fields
+/// + `old`: `None` if the metadatum was added.
+/// + `new`: `None` if the metadatum was removed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct MetadatumChange {
+    pub key: String,
+    pub old: Option<JsValue>,
+    pub new: Option<JsValue>,
+}
+
+impl MetadatumChange {
+    /// Changes between two versions of metadata, sorted by key.
+    fn diff(old: &Metadata, new: &Metadata) -> Vec<Self> {
+        let mut keys = old
+            .keys()
+            .chain(new.keys())
+            .filter(|key| old.get(*key) != new.get(*key))
+            .collect::<Vec<_>>();
+
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .map(|key| Self {
+                key: key.clone(),
+                old: old.get(key).cloned(),
+                new: new.get(key).cloned(),
+            })
+            .collect()
+    }
+}
+
+/// A change to the association of a `Script` with a `Container`.
+///
+/// # Fields
+/// + `old`: `None` if the association was added.
+/// + `new`: `None` if the association was removed.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct ScriptAssociationChange {
+    pub script: ResourceId,
+    pub old: Option<RunParameters>,
+    pub new: Option<RunParameters>,
+}
+
+// *************
+// *** Merge ***
+// *************
+
+/// A change of a diff that could not be applied.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct MergeConflict {
+    /// Resource the change applies to.
+    pub resource: ResourceId,
+    pub reason: ConflictReason,
+}
+
+impl MergeConflict {
+    fn new(resource: ResourceId, reason: ConflictReason) -> Self {
+        Self { resource, reason }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum ConflictReason {
+    /// The resource does not exist.
+    NotFound,
+
+    /// The parent of an added or moved `Container` does not exist.
+    ParentNotFound,
+
+    /// An added resource already exists with different values.
+    AlreadyExists,
+
+    /// The `Container`'s parent is not the one the change expects.
+    ParentChanged,
+
+    /// The `Container` would be moved into its own subtree.
+    Cycle,
+
+    /// A removed subtree has changes not in the diff,
+    /// e.g. `Container`s or `Asset`s that were added to it.
+    SubtreeChanged,
+
+    /// The value no longer matches the one the change was made from.
+    ///
+    /// # Fields
+    /// 1. Name of the changed field, e.g. `name` or `metadata.temperature`.
+    Changed(String),
+}
+
+/// Applies a diff to a tree.
+///
+/// Changes are applied only where the tree still matches the diff's old values,
+/// or already has its new values.
+/// Other changes are skipped and reported as conflicts.
+///
+/// # Returns
+/// Conflicts of changes that were not applied.
+pub fn merge(tree: &mut ContainerTree, diff: &ContainerTreeDiff) -> Vec<MergeConflict> {
+    let mut conflicts = Vec::new();
+    for added in diff.added.iter() {
+        let root = added.tree.root();
+        if tree.get(&added.parent).is_none() {
+            conflicts.push(MergeConflict::new(
+                root.clone(),
+                ConflictReason::ParentNotFound,
+            ));
+            continue;
+        }
+
+        if let Some(existing) = tree.get(root) {
+            if existing.data() != added.tree.get(root).unwrap().data() {
+                conflicts.push(MergeConflict::new(
+                    root.clone(),
+                    ConflictReason::AlreadyExists,
+                ));
+            }
+
+            continue;
+        }
+
+        if let Some(node) = added
+            .tree
+            .iter_pre_order(root)
+            .unwrap()
+            .find(|node| tree.get(node.id()).is_some())
+        {
+            conflicts.push(MergeConflict::new(
+                node.id().clone(),
+                ConflictReason::AlreadyExists,
+            ));
+            continue;
+        }
+
+        tree.insert_tree(&added.parent, added.tree.clone())
+            .expect("parent exists");
+    }
+
+    for moved in diff.moved.iter() {
+        let Ok(parent) = tree.parent(&moved.container) else {
+            conflicts.push(MergeConflict::new(
+                moved.container.clone(),
+                ConflictReason::NotFound,
+            ));
+            continue;
+        };
+
+        if parent == Some(&moved.to) {
+            continue;
+        }
+
+        if parent != Some(&moved.from) {
+            conflicts.push(MergeConflict::new(
+                moved.container.clone(),
+                ConflictReason::ParentChanged,
+            ));
+            continue;
+        }
+
+        if tree.get(&moved.to).is_none() {
+            conflicts.push(MergeConflict::new(
+                moved.container.clone(),
+                ConflictReason::ParentNotFound,
+            ));
+            continue;
+        }
+
+        if tree.ancestors(&moved.to).contains(&moved.container) {
+            conflicts.push(MergeConflict::new(
+                moved.container.clone(),
+                ConflictReason::Cycle,
+            ));
+            continue;
+        }
+
+        tree.mv(&moved.container, &moved.to)
+            .expect("`Container` and parent exist");
+    }
+
+    for removed in diff.removed.iter() {
+        let root = removed.root();
+        let Ok(parent) = tree.parent(root) else {
+            continue;
+        };
+
+        if parent != Some(&removed.parent) {
+            conflicts.push(MergeConflict::new(
+                root.clone(),
+                ConflictReason::ParentChanged,
+            ));
+            continue;
+        }
+
+        if !subtree_matches(tree, &removed.tree) {
+            conflicts.push(MergeConflict::new(
+                root.clone(),
+                ConflictReason::SubtreeChanged,
+            ));
+            continue;
+        }
+
+        tree.remove(root).expect("subtree exists");
+    }
+
+    for container_diff in diff.modified.iter() {
+        let Some(container) = tree.get_mut(&container_diff.container) else {
+            conflicts.push(MergeConflict::new(
+                container_diff.container.clone(),
+                ConflictReason::NotFound,
+            ));
+            continue;
+        };
+
+        merge_container(container, container_diff, &mut conflicts);
+    }
+
+    conflicts
+}
+
+/// Applies the changes of a single `Container`.
+fn merge_container(
+    container: &mut Container,
+    diff: &ContainerDiff,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    let rid = container.rid.clone();
+    for field in merge_properties((&mut container.properties).into(), &diff.properties) {
+        conflicts.push(MergeConflict::new(
+            rid.clone(),
+            ConflictReason::Changed(field),
+        ));
+    }
+
+    for field in merge_metadata(&mut container.properties.metadata, &diff.metadata) {
+        conflicts.push(MergeConflict::new(
+            rid.clone(),
+            ConflictReason::Changed(field),
+        ));
+    }
+
+    for change in diff.scripts.iter() {
+        let current = container.scripts.get(&change.script);
+        if current == change.new.as_ref() {
+            continue;
+        }
+
+        if current != change.old.as_ref() {
+            conflicts.push(MergeConflict::new(
+                rid.clone(),
+                ConflictReason::Changed(format!("scripts.{}", change.script)),
+            ));
+            continue;
+        }
+
+        match change.new.clone() {
+            Some(params) => container.scripts.insert(change.script.clone(), params),
+            None => container.scripts.remove(&change.script),
+        };
+    }
+
+    for change in diff.assets.iter() {
+        match change {
+            AssetChange::Added(asset) => match container.assets.get(&asset.rid) {
+                None => {
+                    container.insert_asset(asset.clone());
+                }
+
+                Some(current) if current == asset => {}
+                Some(_) => conflicts.push(MergeConflict::new(
+                    asset.rid.clone(),
+                    ConflictReason::AlreadyExists,
+                )),
+            },
+
+            AssetChange::Removed(asset) => match container.assets.get(&asset.rid) {
+                None => {}
+                Some(current) if current == asset => {
+                    container.remove_asset(&asset.rid);
+                }
+
+                Some(_) => conflicts.push(MergeConflict::new(
+                    asset.rid.clone(),
+                    ConflictReason::Changed("asset".to_string()),
+                )),
+            },
+
+            AssetChange::Modified(asset_diff) => {
+                let Some(asset) = container.assets.get_mut(&asset_diff.asset) else {
+                    conflicts.push(MergeConflict::new(
+                        asset_diff.asset.clone(),
+                        ConflictReason::NotFound,
+                    ));
+                    continue;
+                };
+
+                let mut changed = Vec::new();
+                if let Some(path) = asset_diff.path.as_ref() {
+                    if asset.path == path.old {
+                        asset.path = path.new.clone();
+                    } else if asset.path != path.new {
+                        changed.push("path".to_string());
+                    }
+                }
+
+                changed.extend(merge_properties(
+                    (&mut asset.properties).into(),
+                    &asset_diff.properties,
+                ));
+
+                changed.extend(merge_metadata(
+                    &mut asset.properties.metadata,
+                    &asset_diff.metadata,
+                ));
+
+                for field in changed {
+                    conflicts.push(MergeConflict::new(
+                        asset_diff.asset.clone(),
+                        ConflictReason::Changed(field),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Applies property changes.
+///
+/// # Returns
+/// Names of the conflicting fields.
+fn merge_properties(mut properties: PropertiesMut, changes: &[PropertyChange]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for change in changes {
+        match change {
+            PropertyChange::Name(change) => {
+                let current = properties.name.get();
+                if current == change.new {
+                    continue;
+                }
+
+                if current != change.old {
+                    conflicts.push("name".to_string());
+                    continue;
+                }
+
+                if !properties.name.set(change.new.clone()) {
+                    conflicts.push("name".to_string());
+                }
+            }
+
+            PropertyChange::Kind(change) => {
+                if !merge_value(&mut *properties.kind, change) {
+                    conflicts.push("kind".to_string());
+                }
+            }
+
+            PropertyChange::Description(change) => {
+                if !merge_value(&mut *properties.description, change) {
+                    conflicts.push("description".to_string());
+                }
+            }
+
+            PropertyChange::Tags { added, removed } => {
+                properties.tags.retain(|tag| !removed.contains(tag));
+                for tag in added {
+                    if !properties.tags.contains(tag) {
+                        properties.tags.push(tag.clone());
+                    }
+                }
+            }
+
+            PropertyChange::Links(change) => {
+                if !merge_value(&mut *properties.links, change) {
+                    conflicts.push("links".to_string());
+                }
+            }
+
+            PropertyChange::Inheritance(change) => {
+                let merged = match properties.inheritance.as_deref_mut() {
+                    Some(inheritance) => merge_value(inheritance, change),
+                    None => false,
+                };
+
+                if !merged {
+                    conflicts.push("inheritance".to_string());
+                }
+            }
+
+            PropertyChange::Derived(change) => {
+                if !merge_value(&mut *properties.derived, change) {
+                    conflicts.push("derived".to_string());
+                }
+            }
+
+            PropertyChange::Modified(change) => {
+                if !merge_value(&mut *properties.modified, change) {
+                    conflicts.push("modified".to_string());
+                }
+            }
+
+            PropertyChange::ModifiedBy(change) => {
+                if !merge_value(&mut *properties.modified_by, change) {
+                    conflicts.push("modified_by".to_string());
+                }
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Applies metadata changes.
+///
+/// # Returns
+/// Names of the conflicting fields.
+fn merge_metadata(metadata: &mut Metadata, changes: &[MetadatumChange]) -> Vec<String> {
+    let mut conflicts = Vec::new();
+    for change in changes {
+        let current = metadata.get(&change.key);
+        if current == change.new.as_ref() {
+            continue;
+        }
+
+        if current != change.old.as_ref() {
+            conflicts.push(format!("metadata.{}", change.key));
+            continue;
+        }
+
+        match change.new.clone() {
+            Some(value) => metadata.insert(change.key.clone(), value),
+            None => metadata.remove(&change.key),
+        };
+    }
+
+    conflicts
+}
+
+/// Sets a value if it still has its old value.
+///
+/// # Returns
+/// `false` if the value was changed to something else.
+fn merge_value<T: PartialEq + Clone>(value: &mut T, change: &ValueChange<T>) -> bool {
+    if *value == change.new {
+        return true;
+    }
+
+    if *value != change.old {
+        return false;
+    }
+
+    *value = change.new.clone();
+    true
+}
+
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Standard properties shared by `Container`s and `Asset`s.
+struct Properties<'a> {
+    name: Option<&'a String>,
+    kind: &'a Option<String>,
+    description: &'a Option<String>,
+    tags: &'a Vec<String>,
+    links: &'a Links,
+
+    /// `None` for `Asset`s.
+    inheritance: Option<&'a MetadataInheritance>,
+    derived: &'a DerivedMetadata,
+    modified: Option<&'a DateTime<Utc>>,
+    modified_by: &'a Option<Creator>,
+}
+
+impl<'a> From<&'a ContainerProperties> for Properties<'a> {
+    fn from(properties: &'a ContainerProperties) -> Self {
+        Self {
+            name: Some(&properties.name),
+            kind: &properties.kind,
+            description: &properties.description,
+            tags: &properties.tags,
+            links: &properties.links,
+            inheritance: Some(&properties.inheritance),
+            derived: &properties.derived,
+            modified: properties.modified(),
+            modified_by: &properties.modified_by,
+        }
+    }
+}
+
+impl<'a> From<&'a AssetProperties> for Properties<'a> {
+    fn from(properties: &'a AssetProperties) -> Self {
+        Self {
+            name: properties.name.as_ref(),
+            kind: &properties.kind,
+            description: &properties.description,
+            tags: &properties.tags,
+            links: &properties.links,
+            inheritance: None,
+            derived: &properties.derived,
+            modified: properties.modified(),
+            modified_by: &properties.modified_by,
+        }
+    }
+}
+
+/// `mut`able standard properties shared by `Container`s and `Asset`s.
+struct PropertiesMut<'a> {
+    name: NameMut<'a>,
+    kind: &'a mut Option<String>,
+    description: &'a mut Option<String>,
+    tags: &'a mut Vec<String>,
+    links: &'a mut Links,
+
+    /// `None` for `Asset`s.
+    inheritance: Option<&'a mut MetadataInheritance>,
+    derived: &'a mut DerivedMetadata,
+    modified: &'a mut Option<DateTime<Utc>>,
+    modified_by: &'a mut Option<Creator>,
+}
+
+impl<'a> From<&'a mut ContainerProperties> for PropertiesMut<'a> {
+    fn from(properties: &'a mut ContainerProperties) -> Self {
+        Self {
+            name: NameMut::Required(&mut properties.name),
+            kind: &mut properties.kind,
+            description: &mut properties.description,
+            tags: &mut properties.tags,
+            links: &mut properties.links,
+            inheritance: Some(&mut properties.inheritance),
+            derived: &mut properties.derived,
+            modified: &mut properties.modified,
+            modified_by: &mut properties.modified_by,
+        }
+    }
+}
+
+impl<'a> From<&'a mut AssetProperties> for PropertiesMut<'a> {
+    fn from(properties: &'a mut AssetProperties) -> Self {
+        Self {
+            name: NameMut::Optional(&mut properties.name),
+            kind: &mut properties.kind,
+            description: &mut properties.description,
+            tags: &mut properties.tags,
+            links: &mut properties.links,
+            inheritance: None,
+            derived: &mut properties.derived,
+            modified: &mut properties.modified,
+            modified_by: &mut properties.modified_by,
+        }
+    }
+}
+
+/// `Container`s require a name, `Asset`s do not.
+enum NameMut<'a> {
+    Required(&'a mut String),
+    Optional(&'a mut Option<String>),
+}
+
+impl<'a> NameMut<'a> {
+    fn get(&self) -> Option<String> {
+        match self {
+            Self::Required(name) => Some((*name).clone()),
+            Self::Optional(name) => (*name).clone(),
+        }
+    }
+
+    /// # Returns
+    /// `false` if a required name would be removed.
+    fn set(&mut self, value: Option<String>) -> bool {
+        match (self, value) {
+            (Self::Required(name), Some(value)) => **name = value,
+            (Self::Required(_), None) => return false,
+            (Self::Optional(name), value) => **name = value,
+        }
+
+        true
+    }
+}
+
+/// Clones a subtree, leaving out the subtrees of `Node`s in `exclude`.
+fn subtree_excluding(
+    tree: &ContainerTree,
+    root: &ResourceId,
+    exclude: &ContainerTree,
+) -> ContainerTree {
+    let mut subtree = ContainerTree::new(tree.get(root).expect("root not found").data().clone());
+    let nodes = tree
+        .iter_pre_order(root)
+        .expect("root not found")
+        .prune(|node| exclude.get(node.id()).is_none())
+        .skip(1);
+
+    for node in nodes {
+        let parent = tree
+            .parent(node.id())
+            .expect("`Node` not found")
+            .expect("`Node` below root has a parent");
+
+        subtree
+            .insert(parent.clone(), node.data().clone())
+            .expect("parent is inserted before its children");
+    }
+
+    subtree
+}
+
+/// Returns whether the subtree of `tree` rooted at the root of `subtree`
+/// has the same `Container`s, with the same values, as `subtree`.
+fn subtree_matches(tree: &ContainerTree, subtree: &ContainerTree) -> bool {
+    let Some(descendants) = tree.descendants(subtree.root()) else {
+        return false;
+    };
+
+    descendants.len() == subtree.nodes().len()
+        && descendants.iter().all(|rid| match subtree.get(rid) {
+            Some(node) => tree.get(rid).expect("descendant not in tree").data() == node.data(),
+            None => false,
+        })
+}
+
+#[cfg(test)]
+#[path = "./diff_test.rs"]
+mod diff_test;
@@ -0,0 +1,352 @@
+use super::*;
+use crate::graph::traversal::Visit;
+use std::path::PathBuf;
+
+#[test]
+fn container_tree_diff_should_work() {
+    // setup
+    let (old, ids) = create_tree();
+    let new = modified_tree(&old, &ids);
+
+    // test
+    let diff = ContainerTreeDiff::new(&old, &new).expect("could not diff trees");
+
+    assert_eq!(1, diff.added.len(), "added subtree should be reported once");
+    let added = &diff.added[0];
+    assert_eq!(ids.b, added.parent);
+    assert_eq!(
+        2,
+        added.tree.nodes().len(),
+        "added subtree should be complete"
+    );
+
+    assert_eq!(
+        1,
+        diff.removed.len(),
+        "removed subtree should be reported once"
+    );
+    let removed = &diff.removed[0];
+    assert_eq!(&ids.d, removed.root());
+    assert_eq!(ids.root, removed.parent);
+    assert_eq!(1, removed.tree.nodes().len());
+
+    assert_eq!(
+        vec![MovedContainer {
+            container: ids.a1.clone(),
+            from: ids.a.clone(),
+            to: ids.b.clone(),
+        }],
+        diff.moved
+    );
+
+    assert_eq!(1, diff.modified.len());
+    let a_diff = &diff.modified[0];
+    assert_eq!(ids.a, a_diff.container);
+    assert_eq!(
+        vec![PropertyChange::Name(ValueChange {
+            old: Some("a".to_string()),
+            new: Some("a renamed".to_string()),
+        })],
+        a_diff.properties
+    );
+
+    assert_eq!(
+        vec![MetadatumChange {
+            key: "temp".to_string(),
+            old: None,
+            new: Some(21.5.into()),
+        }],
+        a_diff.metadata
+    );
+
+    assert!(matches!(a_diff.assets[..], [AssetChange::Added(_)]));
+    assert!(ContainerTreeDiff::new(&old, &old).unwrap().is_empty());
+}
+
+#[test]
+fn merge_should_apply_diff() {
+    // setup
+    let (old, ids) = create_tree();
+    let new = modified_tree(&old, &ids);
+    let diff = ContainerTreeDiff::new(&old, &new).expect("could not diff trees");
+
+    // test
+    let mut merged = old.clone();
+    let conflicts = merge(&mut merged, &diff);
+    assert!(conflicts.is_empty(), "merge should not conflict");
+
+    let remaining = ContainerTreeDiff::new(&merged, &new).expect("could not diff trees");
+    assert!(remaining.is_empty(), "merged tree should match new tree");
+
+    let conflicts = merge(&mut merged, &diff);
+    assert!(conflicts.is_empty(), "merge should be idempotent");
+}
+
+#[test]
+fn merge_should_report_conflicts() {
+    // setup
+    let (old, ids) = create_tree();
+    let new = modified_tree(&old, &ids);
+    let diff = ContainerTreeDiff::new(&old, &new).expect("could not diff trees");
+
+    let mut target = old.clone();
+    target.get_mut(&ids.a).unwrap().properties.name = "a elsewhere".to_string();
+    target.mv(&ids.d, &ids.a).unwrap();
+
+    // test
+    let conflicts = merge(&mut target, &diff);
+    assert!(conflicts.contains(&MergeConflict::new(
+        ids.a.clone(),
+        ConflictReason::Changed("name".to_string()),
+    )));
+
+    assert!(conflicts.contains(&MergeConflict::new(
+        ids.d.clone(),
+        ConflictReason::ParentChanged,
+    )));
+
+    assert_eq!(2, conflicts.len());
+    assert_eq!(
+        "a elsewhere",
+        target.get(&ids.a).unwrap().properties.name,
+        "conflicting change should not be applied"
+    );
+
+    assert!(target.get(&ids.d).is_some(), "moved subtree should be kept");
+    assert_eq!(
+        Some(&21.5.into()),
+        target.get(&ids.a).unwrap().properties.metadata.get("temp"),
+        "non-conflicting changes should be applied"
+    );
+}
+
+#[test]
+fn merge_should_not_remove_changed_subtrees() {
+    // setup
+    let (old, ids) = create_tree();
+    let new = modified_tree(&old, &ids);
+    let diff = ContainerTreeDiff::new(&old, &new).expect("could not diff trees");
+
+    let mut target = old.clone();
+    let local = Container::new("local");
+    let local_id = local.rid.clone();
+    target.insert(ids.d.clone(), local).unwrap();
+
+    let mut asset_target = old.clone();
+    asset_target
+        .get_mut(&ids.d)
+        .unwrap()
+        .insert_asset(Asset::new(
+            ResourcePath::new(PathBuf::from("local.csv")).unwrap(),
+        ));
+
+    // test
+    let conflicts = merge(&mut target, &diff);
+    assert_eq!(
+        vec![MergeConflict::new(
+            ids.d.clone(),
+            ConflictReason::SubtreeChanged,
+        )],
+        conflicts
+    );
+
+    assert!(
+        target.get(&local_id).is_some(),
+        "local `Container`s should be kept"
+    );
+
+    let conflicts = merge(&mut asset_target, &diff);
+    assert_eq!(
+        vec![MergeConflict::new(
+            ids.d.clone(),
+            ConflictReason::SubtreeChanged,
+        )],
+        conflicts
+    );
+
+    assert!(
+        asset_target.get(&ids.d).is_some(),
+        "local `Asset`s should be kept"
+    );
+}
+
+#[test]
+fn diff_and_merge_should_include_all_properties() {
+    // setup
+    let (old, ids) = create_tree();
+    let mut new = old.clone();
+    let mut asset = Asset::new(ResourcePath::new(PathBuf::from("data.csv")).unwrap());
+    let aid = asset.rid.clone();
+    asset.properties.links.insert("source", ids.a.clone());
+    new.get_mut(&ids.b).unwrap().insert_asset(asset.clone());
+
+    let mut old = old;
+    old.get_mut(&ids.b).unwrap().insert_asset(asset);
+
+    let b = new.get_mut(&ids.b).unwrap();
+    b.properties.links.insert("control", ids.a.clone());
+    b.properties.inheritance.seal("temp");
+    b.properties
+        .derived
+        .insert("double".to_string(), "temp * 2".to_string());
+
+    b.properties.mark_modified(Creator::User(None));
+
+    let asset = b.assets.get_mut(&aid).unwrap();
+    asset.properties.links.remove("source", &ids.a);
+    asset
+        .properties
+        .derived
+        .insert("half".to_string(), "temp / 2".to_string());
+
+    asset.properties.mark_modified(Creator::User(None));
+
+    // test
+    let diff = ContainerTreeDiff::new(&old, &new).expect("could not diff trees");
+    assert_eq!(1, diff.modified.len());
+    let b_diff = &diff.modified[0];
+    let kinds = b_diff
+        .properties
+        .iter()
+        .map(std::mem::discriminant)
+        .collect::<Vec<_>>();
+
+    for change in [
+        PropertyChange::Links(ValueChange {
+            old: Links::new(),
+            new: Links::new(),
+        }),
+        PropertyChange::Inheritance(ValueChange {
+            old: MetadataInheritance::default(),
+            new: MetadataInheritance::default(),
+        }),
+        PropertyChange::Derived(ValueChange {
+            old: DerivedMetadata::new(),
+            new: DerivedMetadata::new(),
+        }),
+        PropertyChange::Modified(ValueChange {
+            old: None,
+            new: None,
+        }),
+        PropertyChange::ModifiedBy(ValueChange {
+            old: None,
+            new: None,
+        }),
+    ] {
+        assert!(
+            kinds.contains(&std::mem::discriminant(&change)),
+            "{change:?} should be reported"
+        );
+    }
+
+    let [AssetChange::Modified(asset_diff)] = &b_diff.assets[..] else {
+        panic!("`Asset` change should be reported");
+    };
+
+    assert_eq!(
+        4,
+        asset_diff.properties.len(),
+        "`Asset` links, derived, and modification should be reported"
+    );
+
+    let mut merged = old.clone();
+    let conflicts = merge(&mut merged, &diff);
+    assert!(conflicts.is_empty(), "merge should not conflict");
+    assert_eq!(
+        new.get(&ids.b).unwrap().data(),
+        merged.get(&ids.b).unwrap().data(),
+        "all properties should be merged"
+    );
+
+    let mut target = old.clone();
+    target
+        .get_mut(&ids.b)
+        .unwrap()
+        .properties
+        .links
+        .insert("control", ids.d.clone());
+
+    let conflicts = merge(&mut target, &diff);
+    assert_eq!(
+        vec![MergeConflict::new(
+            ids.b.clone(),
+            ConflictReason::Changed("links".to_string()),
+        )],
+        conflicts
+    );
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+struct Ids {
+    root: ResourceId,
+    a: ResourceId,
+    a1: ResourceId,
+    b: ResourceId,
+    d: ResourceId,
+}
+
+/// Creates the tree
+/// root
+/// + a
+///   + a1
+/// + b
+/// + d
+fn create_tree() -> (ContainerTree, Ids) {
+    let root = Container::new("root");
+    let a = Container::new("a");
+    let a1 = Container::new("a1");
+    let b = Container::new("b");
+    let d = Container::new("d");
+    let ids = Ids {
+        root: root.rid.clone(),
+        a: a.rid.clone(),
+        a1: a1.rid.clone(),
+        b: b.rid.clone(),
+        d: d.rid.clone(),
+    };
+
+    let mut tree = ContainerTree::new(root);
+    tree.insert(ids.root.clone(), a).unwrap();
+    tree.insert(ids.a.clone(), a1).unwrap();
+    tree.insert(ids.root.clone(), b).unwrap();
+    tree.insert(ids.root.clone(), d).unwrap();
+
+    (tree, ids)
+}
+
+/// Modifies the tree to
+/// root
+/// + a (renamed, with metadata and an asset)
+/// + b
+///   + a1
+///   + c
+///     + c1
+fn modified_tree(tree: &ContainerTree, ids: &Ids) -> ContainerTree {
+    let mut tree = tree.clone();
+    tree.remove(&ids.d).unwrap();
+    tree.mv(&ids.a1, &ids.b).unwrap();
+
+    let c = Container::new("c");
+    let c_id = c.rid.clone();
+    tree.insert(ids.b.clone(), c).unwrap();
+    tree.insert(c_id, Container::new("c1")).unwrap();
+
+    tree.visit_mut(&ids.a, |node| {
+        node.properties.name = "a renamed".to_string();
+        node.properties
+            .metadata
+            .insert("temp".to_string(), 21.5.into());
+
+        node.insert_asset(Asset::new(
+            ResourcePath::new(PathBuf::from("data.csv")).unwrap(),
+        ));
+
+        Visit::SkipChildren
+    })
+    .unwrap();
+
+    tree
+}
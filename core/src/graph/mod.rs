@@ -1,9 +1,13 @@
 //! Graph structures.
+pub mod diff;
 // pub mod edge;
 pub mod node;
+pub mod traversal;
 pub mod tree;
 
 // Re-exports
+pub use diff::{merge, ContainerTreeDiff, MergeConflict};
 // pub use edge::{DirectedEdge, DirectedEdgeBuilder};
 pub use node::ResourceNode;
+pub use traversal::Visit;
 pub use tree::ResourceTree;
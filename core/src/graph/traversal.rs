@@ -0,0 +1,248 @@
+//! Ordered traversal of a [`ResourceTree`].
+use super::{ResourceNode, ResourceTree};
+use crate::types::ResourceId;
+use has_id::HasId;
+use std::collections::VecDeque;
+
+/// Predicate deciding whether a `Node` and its subtree are traversed.
+type Prune<'a, D> = Box<dyn Fn(&ResourceNode<D>) -> bool + 'a>;
+
+/// Control flow returned by a visitor.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Visit {
+    /// Continue to the `Node`'s children.
+    Continue,
+
+    /// Do not visit the `Node`'s descendants.
+    SkipChildren,
+
+    /// End the traversal.
+    Stop,
+}
+
+/// Options shared by the traversal iterators.
+struct Options<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    max_depth: Option<usize>,
+    prune: Option<Prune<'a, D>>,
+}
+
+impl<'a, D> Options<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    fn new() -> Self {
+        Self {
+            max_depth: None,
+            prune: None,
+        }
+    }
+
+    /// Whether the `Node` should be traversed.
+    fn includes(&self, node: &ResourceNode<D>) -> bool {
+        self.prune.as_ref().map_or(true, |keep| keep(node))
+    }
+
+    /// Whether the children of a `Node` at the given depth should be traversed.
+    fn descends(&self, depth: usize) -> bool {
+        self.max_depth.map_or(true, |max_depth| depth < max_depth)
+    }
+}
+
+/// Implements the builder methods setting the [`Options`] of a traversal iterator.
+/// The iterator must store its `Options` in an `options` field.
+macro_rules! impl_options {
+    ($traversal:ident) => {
+        impl<'a, D> $traversal<'a, D>
+        where
+            D: HasId<Id = ResourceId>,
+        {
+            /// Only visit `Node`s up to the given depth below the root.
+            /// The root is at depth `0`.
+            pub fn max_depth(mut self, depth: usize) -> Self {
+                self.options.max_depth = Some(depth);
+                self
+            }
+
+            /// Only visit `Node`s for which the predicate is `true`.
+            /// Descendants of excluded `Node`s are not visited.
+            pub fn prune(mut self, keep: impl Fn(&ResourceNode<D>) -> bool + 'a) -> Self {
+                self.options.prune = Some(Box::new(keep));
+                self
+            }
+        }
+    };
+}
+
+impl_options!(PreOrder);
+impl_options!(PostOrder);
+impl_options!(BreadthFirst);
+
+// *****************
+// *** pre-order ***
+// *****************
+
+/// Depth-first iterator visiting parents before their children.
+/// Children are visited in order.
+pub struct PreOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    tree: &'a ResourceTree<D>,
+    stack: Vec<(&'a ResourceId, usize)>,
+    options: Options<'a, D>,
+}
+
+impl<'a, D> PreOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    pub(super) fn new(tree: &'a ResourceTree<D>, root: &'a ResourceId) -> Self {
+        Self {
+            tree,
+            stack: vec![(root, 0)],
+            options: Options::new(),
+        }
+    }
+}
+
+impl<'a, D> Iterator for PreOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    type Item = &'a ResourceNode<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((id, depth)) = self.stack.pop() {
+            let node = self.tree.get(id).expect("`Node` not found in tree");
+            if !self.options.includes(node) {
+                continue;
+            }
+
+            if self.options.descends(depth) {
+                let children = self.tree.children(id).expect("`Node` edges not found");
+                self.stack
+                    .extend(children.iter().rev().map(|child| (child, depth + 1)));
+            }
+
+            return Some(node);
+        }
+
+        None
+    }
+}
+
+// ******************
+// *** post-order ***
+// ******************
+
+/// Depth-first iterator visiting children before their parents.
+/// Children are visited in order.
+pub struct PostOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    tree: &'a ResourceTree<D>,
+
+    /// `Node`s to visit, with their depth and whether their children were already queued.
+    stack: Vec<(&'a ResourceId, usize, bool)>,
+    options: Options<'a, D>,
+}
+
+impl<'a, D> PostOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    pub(super) fn new(tree: &'a ResourceTree<D>, root: &'a ResourceId) -> Self {
+        Self {
+            tree,
+            stack: vec![(root, 0, false)],
+            options: Options::new(),
+        }
+    }
+}
+
+impl<'a, D> Iterator for PostOrder<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    type Item = &'a ResourceNode<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((id, depth, expanded)) = self.stack.pop() {
+            let node = self.tree.get(id).expect("`Node` not found in tree");
+            if expanded {
+                return Some(node);
+            }
+
+            if !self.options.includes(node) {
+                continue;
+            }
+
+            self.stack.push((id, depth, true));
+            if self.options.descends(depth) {
+                let children = self.tree.children(id).expect("`Node` edges not found");
+                self.stack
+                    .extend(children.iter().rev().map(|child| (child, depth + 1, false)));
+            }
+        }
+
+        None
+    }
+}
+
+// *********************
+// *** breadth-first ***
+// *********************
+
+/// Iterator visiting `Node`s level by level.
+/// Children are visited in order.
+pub struct BreadthFirst<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    tree: &'a ResourceTree<D>,
+    queue: VecDeque<(&'a ResourceId, usize)>,
+    options: Options<'a, D>,
+}
+
+impl<'a, D> BreadthFirst<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    pub(super) fn new(tree: &'a ResourceTree<D>, root: &'a ResourceId) -> Self {
+        Self {
+            tree,
+            queue: VecDeque::from([(root, 0)]),
+            options: Options::new(),
+        }
+    }
+}
+
+impl<'a, D> Iterator for BreadthFirst<'a, D>
+where
+    D: HasId<Id = ResourceId>,
+{
+    type Item = &'a ResourceNode<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((id, depth)) = self.queue.pop_front() {
+            let node = self.tree.get(id).expect("`Node` not found in tree");
+            if !self.options.includes(node) {
+                continue;
+            }
+
+            if self.options.descends(depth) {
+                let children = self.tree.children(id).expect("`Node` edges not found");
+                self.queue
+                    .extend(children.iter().map(|child| (child, depth + 1)));
+            }
+
+            return Some(node);
+        }
+
+        None
+    }
+}
@@ -1,4 +1,5 @@
 //! A tree graph
+use super::traversal::{BreadthFirst, PostOrder, PreOrder, Visit};
 use super::ResourceNode;
 use crate::error::{GraphError, ResourceError};
 use crate::types::{ResourceId, ResourceMap};
@@ -238,26 +239,110 @@ where
     /// + `None` if the root `Node` does not exist.
     /// + Descendant ids, including `root`, otherwise.
     pub fn descendants(&self, root: &ResourceId) -> Option<HashSet<ResourceId>> {
-        let mut descendants = HashSet::new();
-        if !self.get(&root).is_some() {
+        Some(
+            self.iter_pre_order(root)?
+                .map(|node| node.id().clone())
+                .collect(),
+        )
+    }
+
+    /// Returns the leaves of a subtree, in pre-order.
+    ///
+    /// # Returns
+    /// + `None` if the root `Node` does not exist.
+    /// + `[root]` if the root has no children.
+    pub fn leaves(&self, root: &ResourceId) -> Option<Vec<ResourceId>> {
+        Some(
+            self.iter_pre_order(root)?
+                .filter(|node| self.edges[node.id()].is_empty())
+                .map(|node| node.id().clone())
+                .collect(),
+        )
+    }
+
+    /// Returns the depth of a `Node`.
+    /// The tree root is at depth `0`.
+    ///
+    /// # Returns
+    /// `None` if the `Node` does not exist.
+    pub fn depth(&self, node: &ResourceId) -> Option<usize> {
+        match self.ancestors(node).len() {
+            0 => None,
+            len => Some(len - 1),
+        }
+    }
+
+    /// Returns the path from the tree root to a `Node`.
+    /// Begins with the root and ends with the `Node`.
+    ///
+    /// # Returns
+    /// `None` if the `Node` does not exist.
+    pub fn path_to(&self, node: &ResourceId) -> Option<Vec<ResourceId>> {
+        let mut path = self.ancestors(node);
+        if path.is_empty() {
             return None;
         }
 
-        descendants.insert(root.clone());
+        path.reverse();
+        Some(path)
+    }
 
-        let Some(children) = self.children(root) else {
-            return Some(descendants);
-        };
+    /// Iterates over a subtree depth-first, visiting parents before their children.
+    ///
+    /// # Returns
+    /// `None` if the root `Node` does not exist.
+    pub fn iter_pre_order(&self, root: &ResourceId) -> Option<PreOrder<D>> {
+        let (root, _) = self.nodes.get_key_value(root)?;
+        Some(PreOrder::new(self, root))
+    }
+
+    /// Iterates over a subtree depth-first, visiting children before their parents.
+    ///
+    /// # Returns
+    /// `None` if the root `Node` does not exist.
+    pub fn iter_post_order(&self, root: &ResourceId) -> Option<PostOrder<D>> {
+        let (root, _) = self.nodes.get_key_value(root)?;
+        Some(PostOrder::new(self, root))
+    }
+
+    /// Iterates over a subtree level by level.
+    ///
+    /// # Returns
+    /// `None` if the root `Node` does not exist.
+    pub fn iter_breadth_first(&self, root: &ResourceId) -> Option<BreadthFirst<D>> {
+        let (root, _) = self.nodes.get_key_value(root)?;
+        Some(BreadthFirst::new(self, root))
+    }
 
-        for child in children {
-            if let Some(c_descendants) = self.descendants(child) {
-                for did in c_descendants {
-                    descendants.insert(did);
+    /// Visits a subtree in pre-order, allowing each `Node` to be modified.
+    /// The visitor's return value controls whether the traversal continues.
+    ///
+    /// # Errors
+    /// + If the root `Node` does not exist.
+    pub fn visit_mut(
+        &mut self,
+        root: &ResourceId,
+        mut visitor: impl FnMut(&mut ResourceNode<D>) -> Visit,
+    ) -> Result {
+        if !self.nodes.contains_key(root) {
+            return Err(ResourceError::does_not_exist("root `Node` not found").into());
+        }
+
+        let mut stack = vec![root.clone()];
+        while let Some(id) = stack.pop() {
+            let node = self.nodes.get_mut(&id).expect("`Node` not found in tree");
+            match visitor(node) {
+                Visit::Continue => {
+                    let children = self.edges.get(&id).expect("`Node` edges not found");
+                    stack.extend(children.iter().rev().cloned());
                 }
+
+                Visit::SkipChildren => {}
+                Visit::Stop => break,
             }
         }
 
-        return Some(descendants);
+        Ok(())
     }
 
     /// Inserts a [`Tree`] as a subtree.
@@ -354,6 +439,9 @@ where
 
     /// Sets the index of a `Node` amongst its parent's children.
     ///
+    /// # Errors
+    /// + [`GraphError::InvalidGraph`] if the index is out of bounds.
+    ///
     /// # See also
     /// + Follows the rules of [`indexset::IndexSet::move_index`].
     pub fn move_index(&mut self, node: &ResourceId, index: usize) -> Result {
@@ -369,6 +457,10 @@ where
             return Err(ResourceError::does_not_exist("`Node` edges do not exist").into());
         };
 
+        if index >= edges.len() {
+            return Err(GraphError::invalid_graph("index out of bounds").into());
+        }
+
         edges.move_index(curr_index, index);
         Ok(())
     }
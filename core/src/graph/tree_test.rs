@@ -1,4 +1,5 @@
 use super::*;
+use crate::graph::Visit;
 use crate::types::ResourceId;
 use has_id::HasId;
 use rand::Rng;
@@ -512,6 +513,130 @@ fn move_index_should_work() {
         children.get_index(1).expect("child `Node` not found"),
         "`Node` in incorrect position"
     );
+
+    let len = children.len();
+    assert!(
+        tree.move_index(&c1, len).is_err(),
+        "out of bounds index should error"
+    );
+}
+
+#[test]
+fn traversal_orders_should_work() {
+    // setup
+    let tree = create_tree();
+    let root = tree.root().clone();
+    let c1 = tree.children(&root).unwrap()[0].clone();
+    let c2 = tree.children(&root).unwrap()[1].clone();
+    let c11 = tree.children(&c1).unwrap()[0].clone();
+    let ids = |nodes: Vec<&ResourceNode<Data>>| {
+        nodes
+            .into_iter()
+            .map(|node| node.id().clone())
+            .collect::<Vec<_>>()
+    };
+
+    // test
+    let pre_order = tree.iter_pre_order(&root).unwrap().collect();
+    assert_eq!(
+        vec![root.clone(), c1.clone(), c11.clone(), c2.clone()],
+        ids(pre_order)
+    );
+
+    let post_order = tree.iter_post_order(&root).unwrap().collect();
+    assert_eq!(
+        vec![c11.clone(), c1.clone(), c2.clone(), root.clone()],
+        ids(post_order)
+    );
+
+    let breadth_first = tree.iter_breadth_first(&root).unwrap().collect();
+    assert_eq!(
+        vec![root.clone(), c1.clone(), c2.clone(), c11.clone()],
+        ids(breadth_first)
+    );
+
+    let limited = tree.iter_pre_order(&root).unwrap().max_depth(1).collect();
+    assert_eq!(
+        vec![root.clone(), c1.clone(), c2.clone()],
+        ids(limited),
+        "depth limit should exclude grandchildren"
+    );
+
+    let pruned = tree
+        .iter_post_order(&root)
+        .unwrap()
+        .prune(|node| node.id() != &c1)
+        .collect();
+
+    assert_eq!(
+        vec![c2.clone(), root.clone()],
+        ids(pruned),
+        "pruned `Node` should exclude its subtree"
+    );
+
+    assert!(tree.iter_breadth_first(&ResourceId::new()).is_none());
+}
+
+#[test]
+fn visit_mut_should_work() {
+    // setup
+    let mut tree = create_tree();
+    let root = tree.root().clone();
+    let c1 = tree.children(&root).unwrap()[0].clone();
+    let c11 = tree.children(&c1).unwrap()[0].clone();
+
+    // test
+    let mut visited = Vec::new();
+    tree.visit_mut(&root, |node| {
+        node.inner = 0;
+        visited.push(node.id().clone());
+        if node.id() == &c1 {
+            Visit::SkipChildren
+        } else {
+            Visit::Continue
+        }
+    })
+    .expect("visit should work");
+
+    assert_eq!(3, visited.len(), "skipped children should not be visited");
+    assert!(!visited.contains(&c11));
+    assert_eq!(&0, tree.get(&c1).unwrap().inner());
+
+    let mut count = 0;
+    tree.visit_mut(&root, |_| {
+        count += 1;
+        Visit::Stop
+    })
+    .expect("visit should work");
+
+    assert_eq!(1, count, "traversal should stop");
+    assert!(tree
+        .visit_mut(&ResourceId::new(), |_| Visit::Continue)
+        .is_err());
+}
+
+#[test]
+fn depth_path_to_and_leaves_should_work() {
+    // setup
+    let tree = create_tree();
+    let root = tree.root().clone();
+    let c1 = tree.children(&root).unwrap()[0].clone();
+    let c2 = tree.children(&root).unwrap()[1].clone();
+    let c11 = tree.children(&c1).unwrap()[0].clone();
+
+    // test
+    assert_eq!(Some(0), tree.depth(&root));
+    assert_eq!(Some(2), tree.depth(&c11));
+    assert_eq!(None, tree.depth(&ResourceId::new()));
+
+    assert_eq!(
+        Some(vec![root.clone(), c1.clone(), c11.clone()]),
+        tree.path_to(&c11)
+    );
+
+    assert_eq!(None, tree.path_to(&ResourceId::new()));
+    assert_eq!(Some(vec![c11.clone(), c2.clone()]), tree.leaves(&root));
+    assert_eq!(Some(vec![c2.clone()]), tree.leaves(&c2));
 }
 
 // *****************
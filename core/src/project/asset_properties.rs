@@ -1,5 +1,5 @@
 //! Standard properties associated with other resources.
-use super::Metadata;
+use super::{DerivedMetadata, Links, Metadata};
 use crate::types::Creator;
 use chrono::prelude::*;
 use serde_json::Value as JsValue;
@@ -20,11 +20,27 @@ pub struct AssetProperties {
     created: DateTime<Utc>,
     pub creator: Creator,
 
+    /// When the properties were last modified.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    /// Who last modified the properties.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub modified_by: Option<Creator>,
+
     pub name: Option<String>,
     pub kind: Option<String>,
     pub description: Option<String>,
     pub tags: Vec<String>,
     pub metadata: Metadata,
+
+    /// Expressions of derived metadata.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub derived: DerivedMetadata,
+
+    /// Links to other resources, by relation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub links: Links,
 }
 
 impl AssetProperties {
@@ -32,18 +48,32 @@ impl AssetProperties {
         Self {
             created: Utc::now(),
             creator: Creator::User(None),
+            modified: None,
+            modified_by: None,
 
             name: None,
             kind: None,
             description: None,
             tags: Vec::new(),
             metadata: HashMap::new(),
+            derived: HashMap::new(),
+            links: Links::new(),
         }
     }
 
     pub fn created(&self) -> &DateTime<Utc> {
         &self.created
     }
+
+    pub fn modified(&self) -> Option<&DateTime<Utc>> {
+        self.modified.as_ref()
+    }
+
+    /// Records a modification of the properties, now.
+    pub fn mark_modified(&mut self, by: Creator) {
+        self.modified = Some(Utc::now());
+        self.modified_by = Some(by);
+    }
 }
 
 // ***************
@@ -58,6 +88,7 @@ pub struct Builder {
     description: Option<String>,
     tags: Vec<String>,
     metadata: Metadata,
+    derived: DerivedMetadata,
 }
 
 impl Builder {
@@ -154,6 +185,25 @@ impl Builder {
         self.metadata.remove(&key.into());
         self
     }
+
+    pub fn set_derived(&mut self, value: DerivedMetadata) -> &mut Self {
+        self.derived = value;
+        self
+    }
+
+    pub fn set_derived_metadatum(
+        &mut self,
+        key: impl Into<String>,
+        expression: impl Into<String>,
+    ) -> &mut Self {
+        self.derived.insert(key.into(), expression.into());
+        self
+    }
+
+    pub fn remove_derived_metadatum(&mut self, key: impl Into<String>) -> &mut Self {
+        self.derived.remove(&key.into());
+        self
+    }
 }
 
 impl Builder {
@@ -172,6 +222,7 @@ impl Default for Builder {
             description: None,
             tags: Vec::default(),
             metadata: Metadata::default(),
+            derived: DerivedMetadata::default(),
         }
     }
 }
@@ -181,11 +232,15 @@ impl Into<AssetProperties> for Builder {
         AssetProperties {
             created: self.created.unwrap_or_else(|| Utc::now()),
             creator: self.creator,
+            modified: None,
+            modified_by: None,
             name: self.name,
             kind: self.kind,
             description: self.description,
             tags: self.tags,
             metadata: self.metadata,
+            derived: self.derived,
+            links: Links::new(),
         }
     }
 }
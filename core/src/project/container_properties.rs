@@ -1,5 +1,6 @@
 //! Container properties.
-use super::Metadata;
+use super::metadata::MetadataInheritance;
+use super::{DerivedMetadata, Links, Metadata};
 use crate::types::Creator;
 use chrono::prelude::*;
 use serde_json::Value as JsValue;
@@ -15,11 +16,31 @@ pub struct ContainerProperties {
     created: DateTime<Utc>,
     pub creator: Creator,
 
+    /// When the properties were last modified.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) modified: Option<DateTime<Utc>>,
+
+    /// Who last modified the properties.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub modified_by: Option<Creator>,
+
     pub name: String,
     pub kind: Option<String>,
     pub description: Option<String>,
     pub tags: Vec<String>,
     pub metadata: Metadata,
+
+    /// How `metadata` is inherited by descendants.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub inheritance: MetadataInheritance,
+
+    /// Expressions of derived metadata.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub derived: DerivedMetadata,
+
+    /// Links to other resources, by relation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub links: Links,
 }
 
 impl ContainerProperties {
@@ -27,18 +48,33 @@ impl ContainerProperties {
         Self {
             created: Utc::now(),
             creator: Creator::User(None),
+            modified: None,
+            modified_by: None,
 
             name: name.into(),
             kind: None,
             description: None,
             tags: Vec::new(),
             metadata: HashMap::new(),
+            inheritance: MetadataInheritance::default(),
+            derived: HashMap::new(),
+            links: Links::new(),
         }
     }
 
     pub fn created(&self) -> &DateTime<Utc> {
         &self.created
     }
+
+    pub fn modified(&self) -> Option<&DateTime<Utc>> {
+        self.modified.as_ref()
+    }
+
+    /// Records a modification of the properties, now.
+    pub fn mark_modified(&mut self, by: Creator) {
+        self.modified = Some(Utc::now());
+        self.modified_by = Some(by);
+    }
 }
 
 // ***************
@@ -54,6 +90,8 @@ pub struct Builder {
     description: Option<String>,
     tags: Vec<String>,
     metadata: Metadata,
+    inheritance: MetadataInheritance,
+    derived: DerivedMetadata,
 }
 
 impl Builder {
@@ -145,6 +183,30 @@ impl Builder {
         self.metadata.remove(&key.into());
         self
     }
+
+    pub fn set_inheritance(&mut self, value: MetadataInheritance) -> &mut Self {
+        self.inheritance = value;
+        self
+    }
+
+    pub fn set_derived(&mut self, value: DerivedMetadata) -> &mut Self {
+        self.derived = value;
+        self
+    }
+
+    pub fn set_derived_metadatum(
+        &mut self,
+        key: impl Into<String>,
+        expression: impl Into<String>,
+    ) -> &mut Self {
+        self.derived.insert(key.into(), expression.into());
+        self
+    }
+
+    pub fn remove_derived_metadatum(&mut self, key: impl Into<String>) -> &mut Self {
+        self.derived.remove(&key.into());
+        self
+    }
 }
 
 impl Builder {
@@ -157,6 +219,8 @@ impl Builder {
             description: None,
             tags: Vec::default(),
             metadata: Metadata::default(),
+            inheritance: MetadataInheritance::default(),
+            derived: DerivedMetadata::default(),
         }
     }
 }
@@ -166,11 +230,16 @@ impl Into<ContainerProperties> for Builder {
         ContainerProperties {
             created: self.created.unwrap_or_else(|| Utc::now()),
             creator: self.creator,
+            modified: None,
+            modified_by: None,
             name: self.name,
             kind: self.kind,
             description: self.description,
             tags: self.tags,
             metadata: self.metadata,
+            inheritance: self.inheritance,
+            derived: self.derived,
+            links: Links::new(),
         }
     }
 }
@@ -0,0 +1,857 @@
+//! Expressions for derived metadata.
+//!
+//! Expressions compute a metadatum's value from the other metadata of a resource.
+//! e.g. `mass / volume`, or `join($ancestors, " / ")`.
+//!
+//! # Syntax
+//! + Literals: numbers, strings in single or double quotes, `true`, `false`, and `null`.
+//! + Metadata keys: identifiers, or any key in backticks. e.g. `` `sample mass` ``.
+//! + Variables: `$name` is the name of the resource,
+//! `$ancestors` the names of its ancestor `Container`s ordered from the root.
+//! + Operators: `+`, `-`, `*`, `/`, `^`, and parentheses.
+//! `+` concatenates if either side is a string.
+//! + Functions: `concat`, `join`, `round`, `abs`, `sqrt`, `min`, `max`, and `convert`.
+//!
+//! Arithmetic on [`Quantity`]s converts units where needed.
+use super::{Metadata, Quantity};
+use serde_json::{Number as JsNumber, Value as JsValue};
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Largest magnitude at which every integer is represented exactly by an `f64`.
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+const FUNCTIONS: [&str; 8] = [
+    "concat", "join", "round", "abs", "sqrt", "min", "max", "convert",
+];
+
+const VARIABLES: [&str; 2] = ["name", "ancestors"];
+
+// *************
+// *** Error ***
+// *************
+
+#[derive(Error, PartialEq, Clone, Debug)]
+pub enum ExpressionError {
+    #[error("syntax error at {position}: {message}")]
+    Syntax { position: usize, message: String },
+
+    #[error("unknown key `{0}`")]
+    UnknownKey(String),
+
+    #[error("invalid arguments to `{0}`")]
+    InvalidArguments(String),
+
+    #[error("can not apply `{operator}` to `{left}` and `{right}`")]
+    InvalidOperands {
+        operator: char,
+        left: JsValue,
+        right: JsValue,
+    },
+
+    #[error("can not negate `{0}`")]
+    InvalidNegation(JsValue),
+
+    #[error("units `{0}` and `{1}` are not compatible")]
+    IncompatibleUnits(String, String),
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("result is not a finite number")]
+    NotFinite,
+
+    #[error("cyclic reference")]
+    Cycle,
+}
+
+type Result<T = JsValue> = std::result::Result<T, ExpressionError>;
+
+// ***************
+// *** Context ***
+// ***************
+
+/// Values available to an [`Expression`].
+pub struct Context<'a> {
+    pub metadata: &'a Metadata,
+
+    /// Name of the resource.
+    pub name: Option<&'a str>,
+
+    /// Names of the resource's ancestor `Container`s, from the root.
+    pub ancestors: &'a [String],
+}
+
+// ******************
+// *** Expression ***
+// ******************
+
+/// A parsed expression.
+#[derive(PartialEq, Clone, Debug)]
+pub struct Expression {
+    source: String,
+    root: Node,
+}
+
+impl Expression {
+    pub fn parse(source: &str) -> Result<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            position: 0,
+            end: source.len(),
+        };
+
+        let root = parser.expression()?;
+        if let Some((position, token)) = parser.tokens.get(parser.position) {
+            return Err(ExpressionError::Syntax {
+                position: *position,
+                message: format!("unexpected `{token}`"),
+            });
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            root,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Metadata keys the expression uses.
+    pub fn keys(&self) -> HashSet<&str> {
+        let mut keys = HashSet::new();
+        self.root.keys(&mut keys);
+        keys
+    }
+
+    pub fn evaluate(&self, context: &Context) -> Result {
+        self.root.evaluate(context)
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.source)
+    }
+}
+
+// *************
+// *** Nodes ***
+// *************
+
+#[derive(PartialEq, Clone, Debug)]
+enum Node {
+    Literal(JsValue),
+    Key(String),
+    Variable(String),
+    Negate(Box<Node>),
+    Binary(char, Box<Node>, Box<Node>),
+    Call(String, Vec<Node>),
+}
+
+impl Node {
+    fn keys<'a>(&'a self, keys: &mut HashSet<&'a str>) {
+        match self {
+            Self::Literal(_) | Self::Variable(_) => {}
+            Self::Key(key) => {
+                keys.insert(key);
+            }
+            Self::Negate(node) => node.keys(keys),
+            Self::Binary(_, left, right) => {
+                left.keys(keys);
+                right.keys(keys);
+            }
+            Self::Call(_, args) => {
+                for arg in args {
+                    arg.keys(keys);
+                }
+            }
+        }
+    }
+
+    fn evaluate(&self, context: &Context) -> Result {
+        match self {
+            Self::Literal(value) => Ok(value.clone()),
+            Self::Key(key) => context
+                .metadata
+                .get(key)
+                .cloned()
+                .ok_or_else(|| ExpressionError::UnknownKey(key.clone())),
+
+            Self::Variable(name) => match name.as_str() {
+                "name" => Ok(context.name.into()),
+                "ancestors" => Ok(context.ancestors.to_vec().into()),
+                _ => unreachable!("unknown variables are rejected when parsing"),
+            },
+
+            Self::Negate(node) => negate(node.evaluate(context)?),
+            Self::Binary(operator, left, right) => {
+                let left = left.evaluate(context)?;
+                let right = right.evaluate(context)?;
+                match operator {
+                    '+' => add(left, right),
+                    '-' => subtract(left, right),
+                    '*' => multiply(left, right),
+                    '/' => divide(left, right),
+                    '^' => power(left, right),
+                    _ => unreachable!("unknown operators are rejected when parsing"),
+                }
+            }
+
+            Self::Call(function, args) => {
+                let args = args
+                    .iter()
+                    .map(|arg| arg.evaluate(context))
+                    .collect::<Result<Vec<_>>>()?;
+
+                call(function, args)
+            }
+        }
+    }
+}
+
+// *****************
+// *** Tokenizer ***
+// *****************
+
+#[derive(PartialEq, Clone, Debug)]
+enum Token {
+    Number(f64),
+    String(String),
+    Identifier(String),
+
+    /// Metadata key in backticks.
+    Key(String),
+    Variable(String),
+    Symbol(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write!(f, "{value:?}"),
+            Self::Identifier(value) => write!(f, "{value}"),
+            Self::Key(value) => write!(f, "`{value}`"),
+            Self::Variable(value) => write!(f, "${value}"),
+            Self::Symbol(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Splits an expression into tokens, with their position.
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' | '^' | '(' | ')' | ',' => Token::Symbol(c),
+            '"' | '\'' | '`' => {
+                let mut value = String::new();
+                let mut closed = false;
+                while let Some((_, next)) = chars.next() {
+                    if next == c {
+                        closed = true;
+                        break;
+                    }
+
+                    if next == '\\' && c != '`' {
+                        if let Some((_, escaped)) = chars.next() {
+                            value.push(escaped);
+                        }
+
+                        continue;
+                    }
+
+                    value.push(next);
+                }
+
+                if !closed {
+                    return Err(ExpressionError::Syntax {
+                        position,
+                        message: format!("unclosed `{c}`"),
+                    });
+                }
+
+                if c == '`' {
+                    Token::Key(value)
+                } else {
+                    Token::String(value)
+                }
+            }
+
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut end = position + c.len_utf8();
+                let mut previous = c;
+                while let Some((index, next)) = chars.peek().copied() {
+                    let is_sign = (next == '-' || next == '+') && matches!(previous, 'e' | 'E');
+                    if !(next.is_ascii_digit()
+                        || next == '.'
+                        || next == 'e'
+                        || next == 'E'
+                        || is_sign)
+                    {
+                        break;
+                    }
+
+                    chars.next();
+                    end = index + next.len_utf8();
+                    previous = next;
+                }
+
+                let number = &source[position..end];
+                let number = number.parse().map_err(|_| ExpressionError::Syntax {
+                    position,
+                    message: format!("invalid number `{number}`"),
+                })?;
+
+                Token::Number(number)
+            }
+
+            '$' => {
+                let name = take_identifier(source, &mut chars, position + 1);
+                if !VARIABLES.contains(&name.as_str()) {
+                    return Err(ExpressionError::Syntax {
+                        position,
+                        message: format!("unknown variable `${name}`"),
+                    });
+                }
+
+                Token::Variable(name)
+            }
+
+            c if c.is_alphabetic() || c == '_' => {
+                Token::Identifier(take_identifier(source, &mut chars, position))
+            }
+
+            c => {
+                return Err(ExpressionError::Syntax {
+                    position,
+                    message: format!("unexpected `{c}`"),
+                })
+            }
+        };
+
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+/// Consumes the rest of an identifier starting at `start`.
+fn take_identifier(
+    source: &str,
+    chars: &mut std::iter::Peekable<std::str::CharIndices>,
+    start: usize,
+) -> String {
+    let mut end = chars
+        .peek()
+        .map(|(index, _)| *index)
+        .unwrap_or(source.len());
+    while let Some((index, next)) = chars.peek().copied() {
+        if !(next.is_alphanumeric() || next == '_') {
+            break;
+        }
+
+        chars.next();
+        end = index + next.len_utf8();
+    }
+
+    source[start..end.max(start)].to_string()
+}
+
+// **************
+// *** Parser ***
+// **************
+
+/// Recursive descent parser.
+///
+/// # Grammar
+/// ```text
+/// expression := term (('+' | '-') term)*
+/// term := unary (('*' | '/') unary)*
+/// unary := '-' unary | power
+/// power := atom ('^' unary)?
+/// atom := number | string | key | variable | call | '(' expression ')'
+/// call := identifier '(' (expression (',' expression)*)? ')'
+/// ```
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+
+    /// Length of the source, for errors at its end.
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn next(&mut self) -> Option<(usize, Token)> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    /// Consumes the next token if it is the symbol.
+    fn consume(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<()> {
+        if self.consume(symbol) {
+            return Ok(());
+        }
+
+        Err(self.error(format!("expected `{symbol}`")))
+    }
+
+    /// Error at the current token.
+    fn error(&self, message: String) -> ExpressionError {
+        let position = self
+            .tokens
+            .get(self.position)
+            .map(|(position, _)| *position)
+            .unwrap_or(self.end);
+
+        ExpressionError::Syntax { position, message }
+    }
+
+    fn expression(&mut self) -> Result<Node> {
+        let mut node = self.term()?;
+        loop {
+            let operator = match self.peek() {
+                Some(Token::Symbol(c @ ('+' | '-'))) => *c,
+                _ => break,
+            };
+
+            self.position += 1;
+            node = Node::Binary(operator, Box::new(node), Box::new(self.term()?));
+        }
+
+        Ok(node)
+    }
+
+    fn term(&mut self) -> Result<Node> {
+        let mut node = self.unary()?;
+        loop {
+            let operator = match self.peek() {
+                Some(Token::Symbol(c @ ('*' | '/'))) => *c,
+                _ => break,
+            };
+
+            self.position += 1;
+            node = Node::Binary(operator, Box::new(node), Box::new(self.unary()?));
+        }
+
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node> {
+        if self.consume('-') {
+            return Ok(Node::Negate(Box::new(self.unary()?)));
+        }
+
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Node> {
+        let node = self.atom()?;
+        if self.consume('^') {
+            return Ok(Node::Binary('^', Box::new(node), Box::new(self.unary()?)));
+        }
+
+        Ok(node)
+    }
+
+    fn atom(&mut self) -> Result<Node> {
+        let Some((position, token)) = self.next() else {
+            return Err(self.error("unexpected end of expression".to_string()));
+        };
+
+        let node = match token {
+            Token::Number(value) => Node::Literal(number(value)?),
+            Token::String(value) => Node::Literal(value.into()),
+            Token::Key(key) => Node::Key(key),
+            Token::Variable(name) => Node::Variable(name),
+            Token::Identifier(name) => match name.as_str() {
+                "true" => Node::Literal(true.into()),
+                "false" => Node::Literal(false.into()),
+                "null" => Node::Literal(JsValue::Null),
+                _ if self.peek() == Some(&Token::Symbol('(')) => {
+                    if !FUNCTIONS.contains(&name.as_str()) {
+                        return Err(ExpressionError::Syntax {
+                            position,
+                            message: format!("unknown function `{name}`"),
+                        });
+                    }
+
+                    self.position += 1;
+                    let mut args = Vec::new();
+                    if !self.consume(')') {
+                        loop {
+                            args.push(self.expression()?);
+                            if self.consume(')') {
+                                break;
+                            }
+
+                            self.expect(',')?;
+                        }
+                    }
+
+                    Node::Call(name, args)
+                }
+
+                _ => Node::Key(name),
+            },
+
+            Token::Symbol('(') => {
+                let node = self.expression()?;
+                self.expect(')')?;
+                node
+            }
+
+            token => {
+                return Err(ExpressionError::Syntax {
+                    position,
+                    message: format!("unexpected `{token}`"),
+                })
+            }
+        };
+
+        Ok(node)
+    }
+}
+
+// ******************
+// *** Operations ***
+// ******************
+
+/// Creates a number value.
+/// Integral values are stored as integers.
+///
+/// # Errors
+/// + If the value is not finite.
+fn number(value: f64) -> Result {
+    if !value.is_finite() {
+        return Err(ExpressionError::NotFinite);
+    }
+
+    if value.fract() == 0.0 && value.abs() < MAX_EXACT_INTEGER {
+        return Ok((value as i64).into());
+    }
+
+    Ok(JsNumber::from_f64(value).expect("value is finite").into())
+}
+
+fn quantity(magnitude: f64, unit: impl Into<String>) -> Result {
+    if !magnitude.is_finite() {
+        return Err(ExpressionError::NotFinite);
+    }
+
+    Ok(Quantity::new(magnitude, unit).into())
+}
+
+/// Operand of an arithmetic operation.
+enum Operand {
+    Number(f64),
+    Quantity(Quantity),
+}
+
+impl Operand {
+    fn from_value(value: &JsValue) -> Option<Self> {
+        if let Some(value) = value.as_f64() {
+            return Some(Self::Number(value));
+        }
+
+        Quantity::from_value(value).map(Self::Quantity)
+    }
+}
+
+/// Converts a value to text for concatenation.
+fn text(value: &JsValue) -> Option<String> {
+    match value {
+        JsValue::String(value) => Some(value.clone()),
+        JsValue::Number(_) | JsValue::Bool(_) => Some(value.to_string()),
+        JsValue::Null => Some(String::new()),
+        JsValue::Object(_) => Quantity::from_value(value).map(|value| value.to_string()),
+        JsValue::Array(_) => None,
+    }
+}
+
+/// Combines two units.
+fn compound_unit(left: &str, operator: char, right: &str) -> String {
+    if right.is_empty() {
+        return left.to_string();
+    }
+
+    let right = if right.contains(['*', '/']) {
+        format!("({right})")
+    } else {
+        right.to_string()
+    };
+
+    if left.is_empty() {
+        return match operator {
+            '/' => format!("1/{right}"),
+            _ => right,
+        };
+    }
+
+    format!("{left}{operator}{right}")
+}
+
+fn invalid_operands(operator: char, left: JsValue, right: JsValue) -> ExpressionError {
+    ExpressionError::InvalidOperands {
+        operator,
+        left,
+        right,
+    }
+}
+
+/// Converts the right quantity to the unit of the left.
+fn convert_right(left: &Quantity, right: &Quantity) -> Result<Quantity> {
+    right
+        .convert_to(&left.unit)
+        .ok_or_else(|| ExpressionError::IncompatibleUnits(left.unit.clone(), right.unit.clone()))
+}
+
+fn negate(value: JsValue) -> Result {
+    match Operand::from_value(&value) {
+        Some(Operand::Number(value)) => number(-value),
+        Some(Operand::Quantity(value)) => quantity(-value.magnitude, value.unit),
+        None => Err(ExpressionError::InvalidNegation(value)),
+    }
+}
+
+fn add(left: JsValue, right: JsValue) -> Result {
+    if left.is_string() || right.is_string() {
+        let (Some(l), Some(r)) = (text(&left), text(&right)) else {
+            return Err(invalid_operands('+', left, right));
+        };
+
+        return Ok(format!("{l}{r}").into());
+    }
+
+    match (Operand::from_value(&left), Operand::from_value(&right)) {
+        (Some(Operand::Number(l)), Some(Operand::Number(r))) => number(l + r),
+        (Some(Operand::Quantity(l)), Some(Operand::Quantity(r))) => {
+            let r = convert_right(&l, &r)?;
+            quantity(l.magnitude + r.magnitude, l.unit)
+        }
+
+        _ => Err(invalid_operands('+', left, right)),
+    }
+}
+
+fn subtract(left: JsValue, right: JsValue) -> Result {
+    match (Operand::from_value(&left), Operand::from_value(&right)) {
+        (Some(Operand::Number(l)), Some(Operand::Number(r))) => number(l - r),
+        (Some(Operand::Quantity(l)), Some(Operand::Quantity(r))) => {
+            let r = convert_right(&l, &r)?;
+            quantity(l.magnitude - r.magnitude, l.unit)
+        }
+
+        _ => Err(invalid_operands('-', left, right)),
+    }
+}
+
+fn multiply(left: JsValue, right: JsValue) -> Result {
+    match (Operand::from_value(&left), Operand::from_value(&right)) {
+        (Some(Operand::Number(l)), Some(Operand::Number(r))) => number(l * r),
+        (Some(Operand::Quantity(l)), Some(Operand::Number(r))) => quantity(l.magnitude * r, l.unit),
+
+        (Some(Operand::Number(l)), Some(Operand::Quantity(r))) => quantity(l * r.magnitude, r.unit),
+
+        (Some(Operand::Quantity(l)), Some(Operand::Quantity(r))) => quantity(
+            l.magnitude * r.magnitude,
+            compound_unit(&l.unit, '*', &r.unit),
+        ),
+
+        _ => Err(invalid_operands('*', left, right)),
+    }
+}
+
+/// Divides values.
+/// Dividing quantities of compatible units gives a number.
+fn divide(left: JsValue, right: JsValue) -> Result {
+    let (Some(l), Some(r)) = (Operand::from_value(&left), Operand::from_value(&right)) else {
+        return Err(invalid_operands('/', left, right));
+    };
+
+    let divisor = match &r {
+        Operand::Number(r) => *r,
+        Operand::Quantity(r) => r.magnitude,
+    };
+
+    if divisor == 0.0 {
+        return Err(ExpressionError::DivisionByZero);
+    }
+
+    match (l, r) {
+        (Operand::Number(l), Operand::Number(r)) => number(l / r),
+        (Operand::Quantity(l), Operand::Number(r)) => quantity(l.magnitude / r, l.unit),
+        (Operand::Number(l), Operand::Quantity(r)) => {
+            quantity(l / r.magnitude, compound_unit("", '/', &r.unit))
+        }
+
+        (Operand::Quantity(l), Operand::Quantity(r)) => match r.convert_to(&l.unit) {
+            Some(r) => number(l.magnitude / r.magnitude),
+            None => quantity(
+                l.magnitude / r.magnitude,
+                compound_unit(&l.unit, '/', &r.unit),
+            ),
+        },
+    }
+}
+
+fn power(left: JsValue, right: JsValue) -> Result {
+    let Some(exponent) = right.as_f64() else {
+        return Err(invalid_operands('^', left, right));
+    };
+
+    match Operand::from_value(&left) {
+        Some(Operand::Number(base)) => number(base.powf(exponent)),
+        Some(Operand::Quantity(base)) => {
+            let unit = if base.unit.is_empty() {
+                String::new()
+            } else if base.unit.contains(['*', '/']) {
+                format!("({})^{exponent}", base.unit)
+            } else {
+                format!("{}^{exponent}", base.unit)
+            };
+
+            quantity(base.magnitude.powf(exponent), unit)
+        }
+
+        None => Err(invalid_operands('^', left, right)),
+    }
+}
+
+// *****************
+// *** Functions ***
+// *****************
+
+fn call(function: &str, args: Vec<JsValue>) -> Result {
+    let invalid = || ExpressionError::InvalidArguments(function.to_string());
+    match (function, args.as_slice()) {
+        ("concat", args) => {
+            let mut value = String::new();
+            for arg in args {
+                value.push_str(&text(arg).ok_or_else(invalid)?);
+            }
+
+            Ok(value.into())
+        }
+
+        ("join", [JsValue::Array(values), rest @ ..]) if rest.len() <= 1 => {
+            let separator = match rest {
+                [] => String::new(),
+                [separator] => separator.as_str().ok_or_else(invalid)?.to_string(),
+                _ => unreachable!(),
+            };
+
+            let values = values
+                .iter()
+                .map(|value| text(value).ok_or_else(invalid))
+                .collect::<Result<Vec<_>>>()?;
+
+            Ok(values.join(&separator).into())
+        }
+
+        ("round", [value, rest @ ..]) if rest.len() <= 1 => {
+            let digits = match rest {
+                [] => 0,
+                [digits] => digits.as_i64().ok_or_else(invalid)? as i32,
+                _ => unreachable!(),
+            };
+
+            let factor = 10_f64.powi(digits);
+            let round = |value: f64| (value * factor).round() / factor;
+            match Operand::from_value(value).ok_or_else(invalid)? {
+                Operand::Number(value) => number(round(value)),
+                Operand::Quantity(value) => quantity(round(value.magnitude), value.unit),
+            }
+        }
+
+        ("abs", [value]) => match Operand::from_value(value).ok_or_else(invalid)? {
+            Operand::Number(value) => number(value.abs()),
+            Operand::Quantity(value) => quantity(value.magnitude.abs(), value.unit),
+        },
+
+        ("sqrt", [value]) => {
+            let value = value.as_f64().ok_or_else(invalid)?;
+            if value < 0.0 {
+                return Err(invalid());
+            }
+
+            number(value.sqrt())
+        }
+
+        ("min" | "max", [first, rest @ ..]) => {
+            let mut found = first.clone();
+            for value in rest {
+                let ordering = compare(&found, value).ok_or_else(invalid)?;
+                let replace = match function {
+                    "min" => ordering.is_gt(),
+                    _ => ordering.is_lt(),
+                };
+
+                if replace {
+                    found = value.clone();
+                }
+            }
+
+            Operand::from_value(&found).ok_or_else(invalid)?;
+            Ok(found)
+        }
+
+        ("convert", [value, JsValue::String(unit)]) => {
+            let value = Quantity::from_value(value).ok_or_else(invalid)?;
+            let converted = value
+                .convert_to(unit)
+                .ok_or_else(|| ExpressionError::IncompatibleUnits(value.unit, unit.clone()))?;
+
+            Ok(converted.into())
+        }
+
+        _ => Err(invalid()),
+    }
+}
+
+/// Compares numbers, or quantities of compatible units.
+fn compare(left: &JsValue, right: &JsValue) -> Option<std::cmp::Ordering> {
+    match (Operand::from_value(left)?, Operand::from_value(right)?) {
+        (Operand::Number(l), Operand::Number(r)) => l.partial_cmp(&r),
+        (Operand::Quantity(l), Operand::Quantity(r)) => {
+            let r = r.convert_to(&l.unit)?;
+            l.magnitude.partial_cmp(&r.magnitude)
+        }
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "./expression_test.rs"]
+mod expression_test;
@@ -0,0 +1,141 @@
+use super::*;
+use serde_json::json;
+
+fn evaluate(source: &str, metadata: &Metadata) -> Result {
+    let ancestors = vec!["root".to_string(), "sample".to_string()];
+    let context = Context {
+        metadata,
+        name: Some("child"),
+        ancestors: &ancestors,
+    };
+
+    Expression::parse(source)
+        .expect("expression should parse")
+        .evaluate(&context)
+}
+
+#[test]
+fn expression_parse_should_work() {
+    let expr = Expression::parse("a + `b c` * (2 - d) ^ 2").expect("expression should parse");
+    assert_eq!(
+        HashSet::from(["a", "b c", "d"]),
+        expr.keys(),
+        "incorrect keys"
+    );
+
+    let expr = Expression::parse("join($ancestors, '/')").expect("expression should parse");
+    assert!(expr.keys().is_empty(), "variables are not keys");
+
+    assert!(
+        matches!(
+            Expression::parse("a +"),
+            Err(ExpressionError::Syntax { position: 3, .. })
+        ),
+        "incomplete expression should error"
+    );
+
+    assert!(
+        Expression::parse("(a").is_err(),
+        "unclosed parenthesis should error"
+    );
+
+    assert!(
+        Expression::parse("a b").is_err(),
+        "trailing tokens should error"
+    );
+
+    assert!(
+        Expression::parse("unknown(a)").is_err(),
+        "unknown functions should error"
+    );
+
+    assert!(
+        Expression::parse("$unknown").is_err(),
+        "unknown variables should error"
+    );
+}
+
+#[test]
+fn expression_evaluate_should_work() {
+    let metadata = Metadata::from([
+        ("mass".to_string(), json!(10)),
+        ("volume".to_string(), json!(4)),
+        ("label".to_string(), json!("S")),
+    ]);
+
+    assert_eq!(Ok(json!(2.5)), evaluate("mass / volume", &metadata));
+    assert_eq!(Ok(json!(-14)), evaluate("-mass - volume", &metadata));
+    assert_eq!(Ok(json!(20)), evaluate("2 * mass", &metadata));
+    assert_eq!(Ok(json!(256)), evaluate("2 ^ 2 ^ 3", &metadata));
+    assert_eq!(Ok(json!(0.001)), evaluate("1e-3", &metadata));
+    assert_eq!(Ok(json!("S-10")), evaluate("label + '-' + mass", &metadata));
+    assert_eq!(Ok(json!("child")), evaluate("$name", &metadata));
+    assert_eq!(
+        Ok(json!("root/sample/child")),
+        evaluate("concat(join($ancestors, '/'), '/', $name)", &metadata)
+    );
+
+    assert_eq!(Ok(json!(3.33)), evaluate("round(mass / 3, 2)", &metadata));
+    assert_eq!(Ok(json!(4)), evaluate("min(mass, volume)", &metadata));
+
+    assert_eq!(
+        Err(ExpressionError::UnknownKey("other".to_string())),
+        evaluate("other * 2", &metadata),
+        "missing keys should error"
+    );
+
+    assert_eq!(
+        Err(ExpressionError::DivisionByZero),
+        evaluate("mass / (volume - 4)", &metadata),
+        "division by zero should error"
+    );
+}
+
+#[test]
+fn expression_evaluate_should_handle_quantities() {
+    let metadata = Metadata::from([
+        ("mass".to_string(), Quantity::new(5.0, "g").into()),
+        ("volume".to_string(), Quantity::new(2.0, "mL").into()),
+        ("other_mass".to_string(), Quantity::new(500.0, "mg").into()),
+    ]);
+
+    assert_eq!(
+        Ok(Quantity::new(2.5, "g/mL").into()),
+        evaluate("mass / volume", &metadata)
+    );
+
+    let sum = evaluate("mass + other_mass", &metadata).expect("sum should evaluate");
+    let sum = Quantity::from_value(&sum).expect("sum should be a quantity");
+    assert_eq!("g", sum.unit, "unit of left operand should be used");
+    assert!(
+        sum.equivalent(&Quantity::new(5.5, "g")),
+        "units should be converted"
+    );
+
+    let ratio = evaluate("mass / other_mass", &metadata).expect("ratio should evaluate");
+    let ratio = ratio
+        .as_f64()
+        .expect("ratio of compatible units should be a number");
+    assert!((ratio - 10.0).abs() < 1e-9, "incorrect ratio");
+
+    let converted = evaluate("convert(mass, 'mg')", &metadata).expect("should convert");
+    let converted = Quantity::from_value(&converted).expect("should be a quantity");
+    assert_eq!("mg", converted.unit, "incorrect unit");
+    assert!(
+        converted.equivalent(&Quantity::new(5.0, "g")),
+        "incorrect magnitude"
+    );
+
+    assert_eq!(
+        Ok(Quantity::new(500.0, "mg").into()),
+        evaluate("min(mass, other_mass)", &metadata)
+    );
+
+    assert!(
+        matches!(
+            evaluate("mass + volume", &metadata),
+            Err(ExpressionError::IncompatibleUnits(_, _))
+        ),
+        "incompatible units should error"
+    );
+}
@@ -0,0 +1,94 @@
+//! Analysis flags.
+use crate::types::{Creator, ResourceId};
+use chrono::prelude::*;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How serious a [`Flag`] is.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, Default, Hash)]
+pub enum FlagSeverity {
+    Info,
+
+    #[default]
+    Warning,
+
+    Error,
+}
+
+/// A message attached to a resource, usually raised by an analysis script.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct Flag {
+    pub rid: ResourceId,
+
+    /// Resource the flag is attached to.
+    pub resource: ResourceId,
+    pub message: String,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: FlagSeverity,
+
+    /// Script that raised the flag.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub script: Option<ResourceId>,
+
+    /// Analysis run that raised the flag.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub run: Option<ResourceId>,
+
+    created: DateTime<Utc>,
+
+    /// `None` while the flag is open.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub resolved: Option<FlagResolution>,
+}
+
+impl Flag {
+    pub fn new(resource: ResourceId, message: impl Into<String>) -> Self {
+        Self {
+            rid: ResourceId::new(),
+            resource,
+            message: message.into(),
+            severity: FlagSeverity::default(),
+            script: None,
+            run: None,
+            created: Utc::now(),
+            resolved: None,
+        }
+    }
+
+    pub fn created(&self) -> &DateTime<Utc> {
+        &self.created
+    }
+
+    pub fn is_resolved(&self) -> bool {
+        self.resolved.is_some()
+    }
+
+    /// Marks the flag as resolved.
+    ///
+    /// # Notes
+    /// + Resolving an already resolved flag keeps the original resolution.
+    pub fn resolve(&mut self, by: Creator) {
+        if self.resolved.is_none() {
+            self.resolved = Some(FlagResolution {
+                resolved_by: by,
+                resolved: Utc::now(),
+            });
+        }
+    }
+}
+
+/// Who resolved a [`Flag`] and when.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct FlagResolution {
+    pub resolved_by: Creator,
+    pub resolved: DateTime<Utc>,
+}
+
+#[cfg(test)]
+#[path = "./flag_test.rs"]
+mod flag_test;
@@ -0,0 +1,19 @@
+use super::*;
+use crate::types::UserId;
+
+#[test]
+fn flag_resolve_should_keep_first_resolution() {
+    // setup
+    let mut flag = Flag::new(ResourceId::new(), "check calibration");
+    assert!(!flag.is_resolved(), "new flag should be open");
+    assert_eq!(FlagSeverity::Warning, flag.severity);
+
+    // test
+    let first = Creator::User(Some(UserId::Email("first@thot.so".to_string())));
+    flag.resolve(first.clone());
+    let resolution = flag.resolved.clone().expect("flag should be resolved");
+
+    flag.resolve(Creator::User(None));
+    assert_eq!(Some(resolution), flag.resolved);
+    assert_eq!(first, flag.resolved.unwrap().resolved_by);
+}
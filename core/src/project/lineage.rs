@@ -0,0 +1,116 @@
+//! Lineage of `Asset`s produced by analysis.
+//!
+//! Each record states how an `Asset` was produced,
+//! forming a graph from input `Asset`s to the `Asset`s derived from them.
+use crate::types::ResourceId;
+use chrono::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ***************
+// *** Lineage ***
+// ***************
+
+/// How an `Asset` was produced.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lineage {
+    /// Produced `Asset`.
+    pub asset: ResourceId,
+
+    /// `Script` that produced the `Asset`.
+    pub script: ResourceId,
+
+    /// Hash of the `Script`'s file when it was run.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub script_hash: Option<String>,
+
+    /// Analysis run the `Script` was executed in.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub run: Option<ResourceId>,
+
+    /// `Container` the `Script` was executed on.
+    pub container: ResourceId,
+
+    /// `Asset`s used to produce the `Asset`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub inputs: Vec<ResourceId>,
+
+    created: DateTime<Utc>,
+}
+
+impl Lineage {
+    pub fn new(asset: ResourceId, script: ResourceId, container: ResourceId) -> Self {
+        Self {
+            asset,
+            script,
+            script_hash: None,
+            run: None,
+            container,
+            inputs: Vec::new(),
+            created: Utc::now(),
+        }
+    }
+
+    pub fn created(&self) -> &DateTime<Utc> {
+        &self.created
+    }
+}
+
+// ***************
+// *** Queries ***
+// ***************
+
+/// Returns the `Asset`s an `Asset` was derived from, directly or transitively.
+///
+/// # Returns
+/// `Asset`s ordered by distance, nearest first.
+pub fn upstream(records: &[Lineage], asset: &ResourceId) -> Vec<ResourceId> {
+    traverse(asset, |current| {
+        records
+            .iter()
+            .filter(|record| &record.asset == current)
+            .flat_map(|record| record.inputs.iter().cloned())
+            .collect()
+    })
+}
+
+/// Returns the `Asset`s derived from an `Asset`, directly or transitively.
+///
+/// # Returns
+/// `Asset`s ordered by distance, nearest first.
+pub fn downstream(records: &[Lineage], asset: &ResourceId) -> Vec<ResourceId> {
+    traverse(asset, |current| {
+        records
+            .iter()
+            .filter(|record| record.inputs.contains(current))
+            .map(|record| record.asset.clone())
+            .collect()
+    })
+}
+
+/// Breadth first traversal from `start`, excluding it.
+fn traverse(
+    start: &ResourceId,
+    neighbors: impl Fn(&ResourceId) -> Vec<ResourceId>,
+) -> Vec<ResourceId> {
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([start.clone()]);
+    let mut found = Vec::new();
+    while let Some(current) = queue.pop_front() {
+        for next in neighbors(&current) {
+            if visited.insert(next.clone()) {
+                found.push(next.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+#[path = "./lineage_test.rs"]
+mod lineage_test;
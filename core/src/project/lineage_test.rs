@@ -0,0 +1,37 @@
+use super::*;
+
+#[test]
+fn lineage_queries_should_work() {
+    // setup
+    // raw -> filtered -> stats
+    //          calib -/
+    let raw = ResourceId::new();
+    let calib = ResourceId::new();
+    let filtered = ResourceId::new();
+    let stats = ResourceId::new();
+    let script = ResourceId::new();
+    let container = ResourceId::new();
+
+    let mut filter = Lineage::new(filtered.clone(), script.clone(), container.clone());
+    filter.inputs = vec![raw.clone()];
+
+    let mut summarize = Lineage::new(stats.clone(), script, container);
+    summarize.inputs = vec![filtered.clone(), calib.clone()];
+    let records = vec![filter, summarize];
+
+    // test
+    assert_eq!(
+        vec![filtered.clone(), stats.clone()],
+        downstream(&records, &raw)
+    );
+
+    assert_eq!(vec![stats.clone()], downstream(&records, &calib));
+    assert!(downstream(&records, &stats).is_empty());
+
+    assert_eq!(
+        vec![filtered.clone(), calib.clone(), raw.clone()],
+        upstream(&records, &stats)
+    );
+
+    assert!(upstream(&records, &raw).is_empty());
+}
@@ -0,0 +1,133 @@
+//! Typed links between resources.
+//!
+//! Links cross-reference `Container`s and `Asset`s beyond the `Container` tree,
+//! e.g. a calibration `Asset` used by several sessions.
+//! Links are stored on the source resource by relation name.
+use crate::types::ResourceId;
+use std::collections::HashMap;
+use std::ops::Deref;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// *************
+// *** Links ***
+// *************
+
+/// Links of a resource, by relation name.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Links(HashMap<String, Vec<ResourceId>>);
+
+impl Links {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Links a target under a relation.
+    ///
+    /// # Returns
+    /// `false` if the link already existed.
+    pub fn insert(&mut self, relation: impl Into<String>, target: ResourceId) -> bool {
+        let targets = self.0.entry(relation.into()).or_default();
+        if targets.contains(&target) {
+            return false;
+        }
+
+        targets.push(target);
+        true
+    }
+
+    /// Unlinks a target from a relation.
+    /// Relations without targets are removed.
+    ///
+    /// # Returns
+    /// `false` if the link did not exist.
+    pub fn remove(&mut self, relation: &str, target: &ResourceId) -> bool {
+        let Some(targets) = self.0.get_mut(relation) else {
+            return false;
+        };
+
+        let len = targets.len();
+        targets.retain(|rid| rid != target);
+        let removed = targets.len() != len;
+        if targets.is_empty() {
+            self.0.remove(relation);
+        }
+
+        removed
+    }
+
+    /// Unlinks a target from all relations.
+    ///
+    /// # Returns
+    /// `true` if any link was removed.
+    pub fn remove_target(&mut self, target: &ResourceId) -> bool {
+        let relations = self.relations_to(target);
+        for relation in relations.iter() {
+            self.remove(relation, target);
+        }
+
+        !relations.is_empty()
+    }
+
+    /// Returns the targets of a relation.
+    pub fn targets(&self, relation: &str) -> &[ResourceId] {
+        self.0
+            .get(relation)
+            .map_or(&[], |targets| targets.as_slice())
+    }
+
+    /// Returns the relations linking to a target.
+    pub fn relations_to(&self, target: &ResourceId) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|(_, targets)| targets.contains(target))
+            .map(|(relation, _)| relation.clone())
+            .collect()
+    }
+
+    /// Removes all links.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns an iterator over all linked targets.
+    pub fn all_targets(&self) -> impl Iterator<Item = &ResourceId> {
+        self.0.values().flatten()
+    }
+}
+
+impl Deref for Links {
+    type Target = HashMap<String, Vec<ResourceId>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<HashMap<String, Vec<ResourceId>>> for Links {
+    fn from(links: HashMap<String, Vec<ResourceId>>) -> Self {
+        Self(links)
+    }
+}
+
+// ****************
+// *** Backlink ***
+// ****************
+
+/// A link from another resource.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backlink {
+    /// Resource holding the link.
+    pub source: ResourceId,
+
+    /// Relation name of the link.
+    pub relation: String,
+}
+
+#[cfg(test)]
+#[path = "./links_test.rs"]
+mod links_test;
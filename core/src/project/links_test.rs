@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn links_insert_and_remove_should_work() {
+    let mut links = Links::new();
+    let target = ResourceId::new();
+    let other = ResourceId::new();
+
+    assert!(links.insert("calibration", target.clone()));
+    assert!(
+        !links.insert("calibration", target.clone()),
+        "links should be unique"
+    );
+    assert!(links.insert("reference", target.clone()));
+    assert!(links.insert("reference", other.clone()));
+    assert_eq!(&[target.clone()], links.targets("calibration"));
+
+    let mut relations = links.relations_to(&target);
+    relations.sort();
+    assert_eq!(vec!["calibration", "reference"], relations);
+
+    assert!(links.remove("calibration", &target));
+    assert!(!links.remove("calibration", &target));
+    assert!(
+        !links.contains_key("calibration"),
+        "empty relations should be removed"
+    );
+
+    assert!(links.remove_target(&target));
+    assert!(!links.remove_target(&target));
+    assert_eq!(&[other], links.targets("reference"));
+}
@@ -1,8 +1,25 @@
 //! Metadata.
-use std::collections::HashMap;
+use super::expression::{Context, Expression, ExpressionError};
+use super::{Asset, AssetProperties, Container, ContainerProperties};
+use crate::types::ResourceId;
+use serde_json::{Map as JsMap, Value as JsValue};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 pub type Metadata = HashMap<String, serde_json::Value>;
 
+/// Expressions computing the value of derived metadata, by key.
+///
+/// # See also
+/// + [`expression`](super::expression)
+pub type DerivedMetadata = HashMap<String, String>;
+
 pub trait InheritMetadata {
     /// Returns owned and inherited [`Metadata`].
     fn metadata_all(&self) -> &Metadata;
@@ -13,3 +30,808 @@ pub trait InheritMetadata {
     /// Returns inherited [`Metadata`].
     fn metadata_inherited(&self) -> &Metadata;
 }
+
+// *******************
+// *** Inheritance ***
+// *******************
+
+/// Controls how a `Container`'s [`Metadata`] is inherited by its descendants.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct MetadataInheritance {
+    /// Keys not passed to descendants.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub non_inheritable: HashSet<String>,
+
+    /// Keys whose value can not be overridden by descendants.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sealed: HashSet<String>,
+}
+
+impl MetadataInheritance {
+    /// Returns whether the key is passed to descendants.
+    pub fn is_inheritable(&self, key: &str) -> bool {
+        !self.non_inheritable.contains(key)
+    }
+
+    /// Returns whether the key is sealed.
+    pub fn is_sealed(&self, key: &str) -> bool {
+        self.sealed.contains(key)
+    }
+
+    /// Returns whether the defaults are used for all keys.
+    pub fn is_empty(&self) -> bool {
+        self.non_inheritable.is_empty() && self.sealed.is_empty()
+    }
+
+    /// Resets the key to be inherited normally.
+    pub fn reset(&mut self, key: &str) {
+        self.non_inheritable.remove(key);
+        self.sealed.remove(key);
+    }
+
+    /// Marks the key as not inheritable.
+    /// Unseals the key if it was sealed.
+    pub fn set_non_inheritable(&mut self, key: impl Into<String>) {
+        let key = key.into();
+        self.sealed.remove(&key);
+        self.non_inheritable.insert(key);
+    }
+
+    /// Seals the key.
+    /// Makes the key inheritable if it was not.
+    pub fn seal(&mut self, key: impl Into<String>) {
+        let key = key.into();
+        self.non_inheritable.remove(&key);
+        self.sealed.insert(key);
+    }
+
+    /// Removes keys that are not in the metadata.
+    pub fn retain_keys(&mut self, metadata: &Metadata) {
+        self.non_inheritable
+            .retain(|key| metadata.contains_key(key));
+        self.sealed.retain(|key| metadata.contains_key(key));
+    }
+}
+
+/// A metadatum passed down from an ancestor `Container`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct InheritedMetadatum {
+    pub value: JsValue,
+
+    /// `Container` the value was inherited from.
+    pub source: ResourceId,
+
+    /// Whether the value was sealed by its source.
+    pub sealed: bool,
+}
+
+/// Metadata passed down to descendants, by key.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct InheritedMetadata {
+    metadata: HashMap<String, InheritedMetadatum>,
+
+    /// Names of the `Container`s the metadata was passed down by, from the root.
+    pub ancestors: Vec<String>,
+}
+
+impl InheritedMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Deref for InheritedMetadata {
+    type Target = HashMap<String, InheritedMetadatum>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.metadata
+    }
+}
+
+impl DerefMut for InheritedMetadata {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.metadata
+    }
+}
+
+/// Compiles the metadata passed down by a line of `Container`s.
+///
+/// # Arguments
+/// 1. `Container`s, ordered from the root to the nearest ancestor.
+///
+/// # Notes
+/// + Values of nearer ancestors take precedence, unless sealed by a farther ancestor.
+/// + Non-inheritable keys are not passed down.
+pub fn inherit_metadata<'a>(
+    ancestors: impl IntoIterator<Item = &'a Container>,
+) -> InheritedMetadata {
+    let mut inherited = InheritedMetadata::new();
+    for container in ancestors {
+        inherit_from(&mut inherited, container);
+    }
+
+    inherited
+}
+
+/// Adds the metadata a `Container` passes down to that passed down by its ancestors.
+/// Derived metadata is passed down with its value.
+///
+/// # See also
+/// + [`inherit_metadata`]
+pub fn inherit_from(inherited: &mut InheritedMetadata, container: &Container) {
+    let properties = &container.properties;
+    let resolved = ResolvedMetadata::for_container(properties, inherited);
+    let keys = properties.metadata.keys().chain(
+        resolved
+            .derived
+            .iter()
+            .filter(|key| !properties.metadata.contains_key(*key)),
+    );
+
+    for key in keys {
+        if !properties.inheritance.is_inheritable(key) {
+            continue;
+        }
+
+        if inherited.get(key).is_some_and(|metadatum| metadatum.sealed) {
+            continue;
+        }
+
+        let Some(value) = resolved.all.get(key) else {
+            // derivation failed
+            continue;
+        };
+
+        inherited.insert(
+            key.clone(),
+            InheritedMetadatum {
+                value: value.clone(),
+                source: container.rid.clone(),
+                sealed: properties.inheritance.is_sealed(key),
+            },
+        );
+    }
+
+    inherited.ancestors.push(properties.name.clone());
+}
+
+/// [`Metadata`] of a resource, resolved with that inherited from its ancestors.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct ResolvedMetadata {
+    owned: Metadata,
+    inherited: Metadata,
+    all: Metadata,
+
+    /// `Container` each inherited key came from.
+    sources: HashMap<String, ResourceId>,
+
+    /// Inherited keys sealed by an ancestor.
+    sealed: HashSet<String>,
+
+    /// Keys whose value is derived from an expression.
+    #[cfg_attr(feature = "serde", serde(default))]
+    derived: HashSet<String>,
+
+    /// Errors evaluating derived keys, by key.
+    #[cfg_attr(feature = "serde", serde(default))]
+    derive_errors: HashMap<String, String>,
+}
+
+impl ResolvedMetadata {
+    /// # Arguments
+    /// 1. Owned metadata.
+    /// 2. Metadata inherited from ancestors.
+    ///
+    /// # Notes
+    /// + Owned values take precedence, unless the key is sealed by an ancestor.
+    pub fn new(owned: Metadata, inherited: &InheritedMetadata) -> Self {
+        let mut all = owned.clone();
+        let mut sources = HashMap::with_capacity(inherited.len());
+        let mut sealed = HashSet::new();
+        for (key, metadatum) in inherited.iter() {
+            if metadatum.sealed {
+                sealed.insert(key.clone());
+            } else if owned.contains_key(key) {
+                continue;
+            }
+
+            all.insert(key.clone(), metadatum.value.clone());
+            sources.insert(key.clone(), metadatum.source.clone());
+        }
+
+        let inherited = inherited
+            .iter()
+            .map(|(key, metadatum)| (key.clone(), metadatum.value.clone()))
+            .collect();
+
+        Self {
+            owned,
+            inherited,
+            all,
+            sources,
+            sealed,
+            derived: HashSet::new(),
+            derive_errors: HashMap::new(),
+        }
+    }
+
+    /// Resolves a `Container`'s metadata, including its derived metadata.
+    ///
+    /// # Arguments
+    /// 1. `Container`'s properties.
+    /// 2. Metadata inherited from its ancestors.
+    pub fn for_container(properties: &ContainerProperties, inherited: &InheritedMetadata) -> Self {
+        let mut resolved = Self::new(properties.metadata.clone(), inherited);
+        resolved.derive(
+            &properties.derived,
+            Some(&properties.name),
+            &inherited.ancestors,
+        );
+
+        resolved
+    }
+
+    /// Resolves an `Asset`'s metadata, including its derived metadata.
+    ///
+    /// # Arguments
+    /// 1. `Asset`'s properties.
+    /// 2. Metadata inherited from its `Container`.
+    pub fn for_asset(properties: &AssetProperties, inherited: &InheritedMetadata) -> Self {
+        let mut resolved = Self::new(properties.metadata.clone(), inherited);
+        resolved.derive(
+            &properties.derived,
+            properties.name.as_deref(),
+            &inherited.ancestors,
+        );
+
+        resolved
+    }
+
+    /// Evaluates derived metadata, adding the values to all metadata.
+    ///
+    /// # Arguments
+    /// 1. Expressions, by key.
+    /// 2. Name of the resource.
+    /// 3. Names of the resource's ancestor `Container`s, from the root.
+    ///
+    /// # Notes
+    /// + Derived values take precedence over owned and inherited values,
+    /// unless the key is sealed by an ancestor.
+    /// + Expressions may use other derived keys.
+    /// + Keys whose expression can not be evaluated are removed.
+    pub fn derive(&mut self, derived: &DerivedMetadata, name: Option<&str>, ancestors: &[String]) {
+        let mut pending = HashMap::with_capacity(derived.len());
+        for (key, source) in derived.iter() {
+            if self.is_sealed(key) {
+                self.derive_errors
+                    .insert(key.clone(), "key is sealed by an ancestor".to_string());
+                continue;
+            }
+
+            match Expression::parse(source) {
+                Ok(expr) => {
+                    pending.insert(key.clone(), expr);
+                }
+
+                Err(err) => self.derive_failed(key, err),
+            }
+        }
+
+        // evaluate expressions once the derived keys they use are evaluated
+        loop {
+            let ready = pending
+                .iter()
+                .filter(|(_, expr)| expr.keys().iter().all(|key| !pending.contains_key(*key)))
+                .map(|(key, _)| key.clone())
+                .collect::<Vec<_>>();
+
+            if ready.is_empty() {
+                break;
+            }
+
+            for key in ready {
+                let expr = pending.remove(&key).expect("key is pending");
+                let context = Context {
+                    metadata: &self.all,
+                    name,
+                    ancestors,
+                };
+
+                match expr.evaluate(&context) {
+                    Ok(value) => {
+                        self.all.insert(key.clone(), value);
+                        self.sources.remove(&key);
+                        self.derived.insert(key);
+                    }
+
+                    Err(err) => self.derive_failed(&key, err),
+                }
+            }
+        }
+
+        for key in pending.into_keys() {
+            self.derive_failed(&key, ExpressionError::Cycle);
+        }
+    }
+
+    fn derive_failed(&mut self, key: &str, err: ExpressionError) {
+        self.all.remove(key);
+        self.sources.remove(key);
+        self.derive_errors.insert(key.to_string(), err.to_string());
+    }
+
+    /// Returns the `Container` the key's value was inherited from.
+    ///
+    /// # Returns
+    /// `None` if the value is owned, or the key is not present.
+    pub fn source(&self, key: &str) -> Option<&ResourceId> {
+        self.sources.get(key)
+    }
+
+    /// Returns whether the key's value is sealed by an ancestor.
+    pub fn is_sealed(&self, key: &str) -> bool {
+        self.sealed.contains(key)
+    }
+
+    /// Returns whether the owned value of the key is overridden by an ancestor.
+    pub fn is_overridden(&self, key: &str) -> bool {
+        self.is_sealed(key) && self.owned.contains_key(key)
+    }
+
+    /// Returns whether the key's value is derived from an expression.
+    pub fn is_derived(&self, key: &str) -> bool {
+        self.derived.contains(key)
+    }
+
+    /// Returns the error evaluating a derived key.
+    pub fn derive_error(&self, key: &str) -> Option<&String> {
+        self.derive_errors.get(key)
+    }
+
+    /// Consumes self, returning all metadata.
+    pub fn into_all(self) -> Metadata {
+        self.all
+    }
+}
+
+impl InheritMetadata for ResolvedMetadata {
+    fn metadata_all(&self) -> &Metadata {
+        &self.all
+    }
+
+    fn metadata_owned(&self) -> &Metadata {
+        &self.owned
+    }
+
+    /// Returns inherited [`Metadata`].
+    /// Includes values overridden by owned metadata.
+    fn metadata_inherited(&self) -> &Metadata {
+        &self.inherited
+    }
+}
+
+/// A resource with its [`ResolvedMetadata`].
+///
+/// # Notes
+/// + The resource's own properties hold only its owned [`Metadata`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct WithMetadata<T> {
+    pub resource: T,
+    pub metadata: ResolvedMetadata,
+}
+
+impl<T> WithMetadata<T> {
+    pub fn new(resource: T, metadata: ResolvedMetadata) -> Self {
+        Self { resource, metadata }
+    }
+}
+
+impl WithMetadata<Container> {
+    /// Consumes self, returning the `Container` with all its [`Metadata`] as its own.
+    pub fn into_resolved(self) -> Container {
+        let mut container = self.resource;
+        container.properties.metadata = self.metadata.into_all();
+        container
+    }
+}
+
+impl WithMetadata<Asset> {
+    /// Consumes self, returning the `Asset` with all its [`Metadata`] as its own.
+    pub fn into_resolved(self) -> Asset {
+        let mut asset = self.resource;
+        asset.properties.metadata = self.metadata.into_all();
+        asset
+    }
+}
+
+impl<T> InheritMetadata for WithMetadata<T> {
+    fn metadata_all(&self) -> &Metadata {
+        self.metadata.metadata_all()
+    }
+
+    fn metadata_owned(&self) -> &Metadata {
+        self.metadata.metadata_owned()
+    }
+
+    fn metadata_inherited(&self) -> &Metadata {
+        self.metadata.metadata_inherited()
+    }
+}
+
+// ****************
+// *** Quantity ***
+// ****************
+
+/// Relative tolerance used when comparing converted magnitudes.
+const QUANTITY_TOLERANCE: f64 = 1e-9;
+
+/// A magnitude with a unit.
+///
+/// Quantities are stored in [`Metadata`] as an object with exactly
+/// a numeric `magnitude` and a string `unit`.
+/// e.g. `{"magnitude": 5, "unit": "mm"}`
+#[derive(PartialEq, Clone, Debug)]
+pub struct Quantity {
+    pub magnitude: f64,
+    pub unit: String,
+}
+
+impl Quantity {
+    pub const MAGNITUDE_KEY: &'static str = "magnitude";
+    pub const UNIT_KEY: &'static str = "unit";
+
+    pub fn new(magnitude: f64, unit: impl Into<String>) -> Self {
+        Self {
+            magnitude,
+            unit: unit.into(),
+        }
+    }
+
+    /// Interprets a metadatum value as a quantity.
+    ///
+    /// # Returns
+    /// `None` if the value does not have the shape of a quantity.
+    pub fn from_value(value: &JsValue) -> Option<Self> {
+        let JsValue::Object(value) = value else {
+            return None;
+        };
+
+        if value.len() != 2 {
+            return None;
+        }
+
+        let magnitude = value.get(Self::MAGNITUDE_KEY)?.as_f64()?;
+        let unit = value.get(Self::UNIT_KEY)?.as_str()?;
+        Some(Self::new(magnitude, unit))
+    }
+
+    /// Returns whether the metadatum value is a quantity.
+    pub fn is_quantity(value: &JsValue) -> bool {
+        Self::from_value(value).is_some()
+    }
+
+    /// Returns whether the quantity can be converted to the other's unit.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        if self.unit == other.unit {
+            return true;
+        }
+
+        match (Unit::parse(&self.unit), Unit::parse(&other.unit)) {
+            (Some(unit), Some(other)) => unit.dimension == other.dimension,
+            _ => false,
+        }
+    }
+
+    /// Converts the quantity to another unit.
+    ///
+    /// # Returns
+    /// `None` if either unit is unknown or the units are incompatible.
+    pub fn convert_to(&self, unit: &str) -> Option<Self> {
+        if self.unit == unit {
+            return Some(self.clone());
+        }
+
+        let from = Unit::parse(&self.unit)?;
+        let to = Unit::parse(unit)?;
+        if from.dimension != to.dimension {
+            return None;
+        }
+
+        let base = self.magnitude * from.scale + from.offset;
+        Some(Self::new((base - to.offset) / to.scale, unit))
+    }
+
+    /// Returns whether the quantities are equal, up to unit conversion.
+    pub fn equivalent(&self, other: &Self) -> bool {
+        let Some(other) = other.convert_to(&self.unit) else {
+            return false;
+        };
+
+        let scale = self.magnitude.abs().max(other.magnitude.abs());
+        (self.magnitude - other.magnitude).abs() <= scale * QUANTITY_TOLERANCE
+    }
+}
+
+impl From<Quantity> for JsValue {
+    fn from(quantity: Quantity) -> Self {
+        let mut value = JsMap::with_capacity(2);
+        value.insert(
+            Quantity::MAGNITUDE_KEY.to_string(),
+            quantity.magnitude.into(),
+        );
+        value.insert(Quantity::UNIT_KEY.to_string(), quantity.unit.into());
+        JsValue::Object(value)
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.unit.is_empty() {
+            write!(f, "{}", self.magnitude)
+        } else {
+            write!(f, "{} {}", self.magnitude, self.unit)
+        }
+    }
+}
+
+impl FromStr for Quantity {
+    type Err = ParseQuantityError;
+
+    /// Parses a quantity of the form `<magnitude> <unit>`.
+    /// Whitespace between the magnitude and unit is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s
+            .char_indices()
+            .find(|(index, c)| {
+                !(c.is_ascii_digit()
+                    || *c == '.'
+                    || ((*c == '-' || *c == '+')
+                        && (*index == 0 || s[..*index].ends_with(['e', 'E'])))
+                    || ((*c == 'e' || *c == 'E') && is_exponent(s, *index)))
+            })
+            .map(|(index, _)| index)
+            .unwrap_or(s.len());
+
+        let (magnitude, unit) = s.split_at(split);
+        let magnitude = magnitude
+            .parse::<f64>()
+            .map_err(|_| ParseQuantityError::InvalidMagnitude)?;
+
+        if !magnitude.is_finite() {
+            return Err(ParseQuantityError::InvalidMagnitude);
+        }
+
+        Ok(Self::new(magnitude, unit.trim()))
+    }
+}
+
+/// Returns whether the `e` at `index` is part of a number's exponent.
+fn is_exponent(s: &str, index: usize) -> bool {
+    let mut rest = s[index + 1..].chars();
+    match rest.next() {
+        Some(c) if c.is_ascii_digit() => true,
+        Some('-' | '+') => rest.next().is_some_and(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+#[derive(Error, PartialEq, Debug)]
+pub enum ParseQuantityError {
+    #[error("invalid magnitude")]
+    InvalidMagnitude,
+}
+
+// ************
+// *** Unit ***
+// ************
+
+/// Physical dimension of a unit.
+/// Units of the same dimension can be converted between.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Dimension {
+    Length,
+    Area,
+    Volume,
+    Mass,
+    Time,
+    Frequency,
+    Temperature,
+    Current,
+    Voltage,
+    Resistance,
+    Amount,
+    Force,
+    Pressure,
+    Energy,
+    Power,
+    Angle,
+}
+
+/// A known unit.
+///
+/// Values are converted to the base unit of their dimension by
+/// `value * scale + offset`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Unit {
+    pub dimension: Dimension,
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl Unit {
+    /// Parses a unit symbol.
+    /// SI prefixes are supported on SI units.
+    ///
+    /// # Returns
+    /// `None` if the unit is unknown.
+    pub fn parse(symbol: &str) -> Option<Self> {
+        let symbol = symbol.trim();
+        if let Some(unit) = find_unit(symbol) {
+            return Some(unit.unit());
+        }
+
+        for (prefix, factor) in SI_PREFIXES {
+            let Some(base) = symbol.strip_prefix(prefix) else {
+                continue;
+            };
+
+            let Some(unit) = find_unit(base) else {
+                continue;
+            };
+
+            if unit.prefixable {
+                let mut unit = unit.unit();
+                unit.scale *= factor;
+                return Some(unit);
+            }
+        }
+
+        None
+    }
+}
+
+/// Entry of the unit table.
+struct UnitDef {
+    symbols: &'static [&'static str],
+    dimension: Dimension,
+    scale: f64,
+    offset: f64,
+
+    /// Whether SI prefixes may be applied.
+    prefixable: bool,
+}
+
+impl UnitDef {
+    const fn new(
+        symbols: &'static [&'static str],
+        dimension: Dimension,
+        scale: f64,
+        prefixable: bool,
+    ) -> Self {
+        Self {
+            symbols,
+            dimension,
+            scale,
+            offset: 0.0,
+            prefixable,
+        }
+    }
+
+    fn unit(&self) -> Unit {
+        Unit {
+            dimension: self.dimension,
+            scale: self.scale,
+            offset: self.offset,
+        }
+    }
+}
+
+fn find_unit(symbol: &str) -> Option<&'static UnitDef> {
+    UNITS.iter().find(|unit| unit.symbols.contains(&symbol))
+}
+
+const SI_PREFIXES: [(&str, f64); 10] = [
+    ("T", 1e12),
+    ("G", 1e9),
+    ("M", 1e6),
+    ("k", 1e3),
+    ("c", 1e-2),
+    ("m", 1e-3),
+    ("u", 1e-6),
+    ("µ", 1e-6),
+    ("n", 1e-9),
+    ("p", 1e-12),
+];
+
+/// Known units, relative to the SI base unit of their dimension.
+const UNITS: &[UnitDef] = &[
+    // length
+    UnitDef::new(&["m"], Dimension::Length, 1.0, true),
+    UnitDef::new(&["in"], Dimension::Length, 0.0254, false),
+    UnitDef::new(&["ft"], Dimension::Length, 0.3048, false),
+    UnitDef::new(&["mi"], Dimension::Length, 1609.344, false),
+    UnitDef::new(&["Å"], Dimension::Length, 1e-10, false),
+    // area
+    UnitDef::new(&["m2", "m^2", "m²"], Dimension::Area, 1.0, false),
+    UnitDef::new(&["mm2", "mm^2", "mm²"], Dimension::Area, 1e-6, false),
+    UnitDef::new(&["cm2", "cm^2", "cm²"], Dimension::Area, 1e-4, false),
+    UnitDef::new(&["km2", "km^2", "km²"], Dimension::Area, 1e6, false),
+    // volume
+    UnitDef::new(&["m3", "m^3", "m³"], Dimension::Volume, 1.0, false),
+    UnitDef::new(
+        &["cm3", "cm^3", "cm³", "cc"],
+        Dimension::Volume,
+        1e-6,
+        false,
+    ),
+    UnitDef::new(&["mm3", "mm^3", "mm³"], Dimension::Volume, 1e-9, false),
+    UnitDef::new(&["L", "l"], Dimension::Volume, 1e-3, true),
+    // mass
+    UnitDef::new(&["g"], Dimension::Mass, 1e-3, true),
+    UnitDef::new(&["t"], Dimension::Mass, 1e3, false),
+    UnitDef::new(&["lb"], Dimension::Mass, 0.45359237, false),
+    UnitDef::new(&["oz"], Dimension::Mass, 0.028349523125, false),
+    // time
+    UnitDef::new(&["s"], Dimension::Time, 1.0, true),
+    UnitDef::new(&["min"], Dimension::Time, 60.0, false),
+    UnitDef::new(&["h", "hr"], Dimension::Time, 3600.0, false),
+    UnitDef::new(&["d", "day"], Dimension::Time, 86400.0, false),
+    // frequency
+    UnitDef::new(&["Hz"], Dimension::Frequency, 1.0, true),
+    UnitDef::new(&["rpm"], Dimension::Frequency, 1.0 / 60.0, false),
+    // temperature
+    UnitDef::new(&["K"], Dimension::Temperature, 1.0, true),
+    UnitDef {
+        symbols: &["°C", "degC"],
+        dimension: Dimension::Temperature,
+        scale: 1.0,
+        offset: 273.15,
+        prefixable: false,
+    },
+    UnitDef {
+        symbols: &["°F", "degF"],
+        dimension: Dimension::Temperature,
+        scale: 5.0 / 9.0,
+        offset: 459.67 * 5.0 / 9.0,
+        prefixable: false,
+    },
+    // electricity
+    UnitDef::new(&["A"], Dimension::Current, 1.0, true),
+    UnitDef::new(&["V"], Dimension::Voltage, 1.0, true),
+    UnitDef::new(&["Ω", "Ohm", "ohm"], Dimension::Resistance, 1.0, true),
+    // amount
+    UnitDef::new(&["mol"], Dimension::Amount, 1.0, true),
+    // force
+    UnitDef::new(&["N"], Dimension::Force, 1.0, true),
+    UnitDef::new(&["lbf"], Dimension::Force, 4.4482216152605, false),
+    // pressure
+    UnitDef::new(&["Pa"], Dimension::Pressure, 1.0, true),
+    UnitDef::new(&["bar"], Dimension::Pressure, 1e5, true),
+    UnitDef::new(&["atm"], Dimension::Pressure, 101325.0, false),
+    UnitDef::new(&["psi"], Dimension::Pressure, 6894.757293168, false),
+    UnitDef::new(&["ksi"], Dimension::Pressure, 6894757.293168, false),
+    // energy
+    UnitDef::new(&["J"], Dimension::Energy, 1.0, true),
+    UnitDef::new(&["eV"], Dimension::Energy, 1.602176634e-19, true),
+    UnitDef::new(&["cal"], Dimension::Energy, 4.184, true),
+    UnitDef::new(&["Wh"], Dimension::Energy, 3600.0, true),
+    // power
+    UnitDef::new(&["W"], Dimension::Power, 1.0, true),
+    // angle
+    UnitDef::new(&["rad"], Dimension::Angle, 1.0, true),
+    UnitDef::new(
+        &["°", "deg"],
+        Dimension::Angle,
+        std::f64::consts::PI / 180.0,
+        false,
+    ),
+];
+
+#[cfg(test)]
+#[path = "./metadata_test.rs"]
+mod metadata_test;
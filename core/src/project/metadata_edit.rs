@@ -0,0 +1,232 @@
+//! Bulk edits of metadata.
+//!
+//! Edits apply to the owned [`Metadata`] of many resources at once,
+//! e.g. when a key is renamed across a project.
+use super::schema::ValueType;
+use super::{DateTimeValue, Link, Metadata, Quantity};
+use crate::types::ResourceId;
+use serde_json::Value as JsValue;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ************
+// *** Edit ***
+// ************
+
+/// An edit of [`Metadata`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum MetadataEdit {
+    /// Renames a key, keeping its value.
+    RenameKey { from: String, to: String },
+
+    /// Replaces values equal to `find` with `replace`.
+    ///
+    /// # Fields
+    /// + `key`: Key to replace values of.
+    /// If `None`, values of all keys are replaced.
+    ReplaceValue {
+        key: Option<String>,
+        find: JsValue,
+        replace: JsValue,
+    },
+
+    /// Converts the value of a key to another type.
+    ///
+    /// # See also
+    /// + [`convert_value`]
+    ConvertType { key: String, to: ValueType },
+}
+
+impl MetadataEdit {
+    /// Applies the edit.
+    ///
+    /// # Returns
+    /// Whether the metadata was changed.
+    ///
+    /// # Errors
+    /// + [`MetadataEditError::KeyExists`]: If a key is renamed to an existing key.
+    /// + [`MetadataEditError::InvalidConversion`]: If a value can not be converted.
+    /// The metadata is left unchanged.
+    pub fn apply(&self, metadata: &mut Metadata) -> Result<bool, MetadataEditError> {
+        match self {
+            Self::RenameKey { from, to } => {
+                if from == to || !metadata.contains_key(from) {
+                    return Ok(false);
+                }
+
+                if metadata.contains_key(to) {
+                    return Err(MetadataEditError::KeyExists(to.clone()));
+                }
+
+                let value = metadata.remove(from).expect("key exists");
+                metadata.insert(to.clone(), value);
+                Ok(true)
+            }
+
+            Self::ReplaceValue { key, find, replace } => {
+                let mut changed = false;
+                for (k, value) in metadata.iter_mut() {
+                    if key.as_ref().is_some_and(|key| key != k) {
+                        continue;
+                    }
+
+                    if value == find && value != replace {
+                        *value = replace.clone();
+                        changed = true;
+                    }
+                }
+
+                Ok(changed)
+            }
+
+            Self::ConvertType { key, to } => {
+                let Some(value) = metadata.get_mut(key) else {
+                    return Ok(false);
+                };
+
+                let Some(converted) = convert_value(value, *to) else {
+                    return Err(MetadataEditError::InvalidConversion {
+                        key: key.clone(),
+                        value: value.clone(),
+                        to: *to,
+                    });
+                };
+
+                let changed = *value != converted;
+                *value = converted;
+                Ok(changed)
+            }
+        }
+    }
+}
+
+// ******************
+// *** Conversion ***
+// ******************
+
+/// Converts a value to another type.
+///
+/// # Returns
+/// `None` if the value can not be represented as the type.
+///
+/// # Notes
+/// + `null` values are kept.
+/// + Strings are parsed as the target type.
+/// + Numbers convert to and from booleans as `1` and `0`.
+/// + Quantities convert to numbers by their magnitude.
+/// + Date-times and links convert to and from strings.
+pub fn convert_value(value: &JsValue, to: ValueType) -> Option<JsValue> {
+    if value.is_null() || to.matches(value) {
+        return Some(value.clone());
+    }
+
+    match to {
+        ValueType::String => match value {
+            JsValue::String(_) => Some(value.clone()),
+            JsValue::Number(n) => Some(n.to_string().into()),
+            JsValue::Bool(b) => Some(b.to_string().into()),
+            _ => {
+                if let Some(quantity) = Quantity::from_value(value) {
+                    Some(quantity.to_string().into())
+                } else if let Some(datetime) = DateTimeValue::from_value(value) {
+                    Some(datetime.to_string().into())
+                } else if let Some(link) = Link::from_value(value) {
+                    Some(link.to_string().into())
+                } else {
+                    Some(value.to_string().into())
+                }
+            }
+        },
+
+        ValueType::Number => match value {
+            JsValue::String(s) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(|n| serde_json::Number::from_f64(n))
+                .map(JsValue::Number),
+
+            JsValue::Bool(b) => Some((*b as u8).into()),
+            _ => Quantity::from_value(value).map(|quantity| quantity.magnitude.into()),
+        },
+
+        ValueType::Integer => {
+            let n = match value {
+                JsValue::String(s) => s.trim().parse::<f64>().ok()?,
+                JsValue::Number(n) => n.as_f64()?,
+                JsValue::Bool(b) => return Some((*b as u8).into()),
+                _ => return None,
+            };
+
+            (n.fract() == 0.0 && n.is_finite()).then(|| (n as i64).into())
+        }
+
+        ValueType::Bool => match value {
+            JsValue::String(s) => match s.trim().to_lowercase().as_str() {
+                "true" => Some(true.into()),
+                "false" => Some(false.into()),
+                _ => None,
+            },
+
+            JsValue::Number(n) => match n.as_f64()? {
+                n if n == 1.0 => Some(true.into()),
+                n if n == 0.0 => Some(false.into()),
+                _ => None,
+            },
+
+            _ => None,
+        },
+
+        ValueType::Quantity => match value {
+            JsValue::String(s) => s.parse::<Quantity>().ok().map(JsValue::from),
+            _ => None,
+        },
+
+        ValueType::DateTime => match value {
+            JsValue::String(s) => s.parse::<DateTimeValue>().ok().map(JsValue::from),
+            _ => None,
+        },
+
+        ValueType::Link => match value {
+            JsValue::String(s) => s
+                .trim()
+                .parse::<ResourceId>()
+                .ok()
+                .map(|rid| Link(rid).into()),
+            _ => None,
+        },
+
+        ValueType::Array | ValueType::Object => match value {
+            JsValue::String(s) => serde_json::from_str::<JsValue>(s)
+                .ok()
+                .filter(|parsed| to.matches(parsed)),
+
+            _ => None,
+        },
+    }
+}
+
+// *************
+// *** Error ***
+// *************
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Error, PartialEq, Clone, Debug)]
+pub enum MetadataEditError {
+    #[error("metadata key `{0}` already exists")]
+    KeyExists(String),
+
+    #[error("value `{value}` of `{key}` can not be converted to {to:?}")]
+    InvalidConversion {
+        key: String,
+        value: JsValue,
+        to: ValueType,
+    },
+}
+
+#[cfg(test)]
+#[path = "./metadata_edit_test.rs"]
+mod metadata_edit_test;
@@ -0,0 +1,131 @@
+use super::*;
+use serde_json::json;
+
+fn metadata() -> Metadata {
+    Metadata::from([
+        ("temp".to_string(), json!("21.5")),
+        ("state".to_string(), json!("on")),
+        ("mode".to_string(), json!("on")),
+    ])
+}
+
+#[test]
+fn rename_key_should_work() {
+    let mut md = metadata();
+    let edit = MetadataEdit::RenameKey {
+        from: "temp".to_string(),
+        to: "temperature_c".to_string(),
+    };
+
+    assert_eq!(Ok(true), edit.apply(&mut md));
+    assert!(!md.contains_key("temp"), "old key should be removed");
+    assert_eq!(Some(&json!("21.5")), md.get("temperature_c"));
+    assert_eq!(Ok(false), edit.apply(&mut md), "missing keys are skipped");
+
+    let edit = MetadataEdit::RenameKey {
+        from: "state".to_string(),
+        to: "mode".to_string(),
+    };
+
+    assert_eq!(
+        Err(MetadataEditError::KeyExists("mode".to_string())),
+        edit.apply(&mut md)
+    );
+    assert!(md.contains_key("state"), "metadata should be unchanged");
+}
+
+#[test]
+fn replace_value_should_work() {
+    let mut md = metadata();
+    let edit = MetadataEdit::ReplaceValue {
+        key: Some("state".to_string()),
+        find: json!("on"),
+        replace: json!(true),
+    };
+
+    assert_eq!(Ok(true), edit.apply(&mut md));
+    assert_eq!(Some(&json!(true)), md.get("state"));
+    assert_eq!(Some(&json!("on")), md.get("mode"), "other keys are kept");
+
+    let edit = MetadataEdit::ReplaceValue {
+        key: None,
+        find: json!("on"),
+        replace: json!(false),
+    };
+
+    assert_eq!(Ok(true), edit.apply(&mut md));
+    assert_eq!(Some(&json!(false)), md.get("mode"));
+    assert_eq!(Ok(false), edit.apply(&mut md));
+}
+
+#[test]
+fn convert_type_should_work() {
+    let mut md = metadata();
+    let edit = MetadataEdit::ConvertType {
+        key: "temp".to_string(),
+        to: ValueType::Number,
+    };
+
+    assert_eq!(Ok(true), edit.apply(&mut md));
+    assert_eq!(Some(&json!(21.5)), md.get("temp"));
+
+    let edit = MetadataEdit::ConvertType {
+        key: "state".to_string(),
+        to: ValueType::Number,
+    };
+
+    assert!(matches!(
+        edit.apply(&mut md),
+        Err(MetadataEditError::InvalidConversion { .. })
+    ));
+    assert_eq!(Some(&json!("on")), md.get("state"), "value should be kept");
+}
+
+#[test]
+fn convert_value_should_work() {
+    assert_eq!(
+        Some(json!(3)),
+        convert_value(&json!("3"), ValueType::Integer)
+    );
+    assert_eq!(None, convert_value(&json!(3.5), ValueType::Integer));
+    assert_eq!(
+        Some(json!("3.5")),
+        convert_value(&json!(3.5), ValueType::String)
+    );
+    assert_eq!(
+        Some(json!(true)),
+        convert_value(&json!("True"), ValueType::Bool)
+    );
+    assert_eq!(
+        Some(json!(false)),
+        convert_value(&json!(0), ValueType::Bool)
+    );
+    assert_eq!(
+        Some(Quantity::new(5.0, "mm").into()),
+        convert_value(&json!("5 mm"), ValueType::Quantity)
+    );
+    assert_eq!(
+        Some(json!(5.0)),
+        convert_value(&Quantity::new(5.0, "mm").into(), ValueType::Number)
+    );
+    assert_eq!(
+        Some(json!([1, 2])),
+        convert_value(&json!("[1, 2]"), ValueType::Array)
+    );
+    assert_eq!(
+        Some(JsValue::Null),
+        convert_value(&JsValue::Null, ValueType::Number)
+    );
+
+    let datetime = "2023-04-01T12:00:00Z".parse::<DateTimeValue>().unwrap();
+    assert_eq!(
+        Some(datetime.clone().into()),
+        convert_value(&json!("2023-04-01T12:00"), ValueType::DateTime)
+    );
+    assert_eq!(
+        Some(json!("2023-04-01T12:00:00Z")),
+        convert_value(&datetime.into(), ValueType::String)
+    );
+    assert_eq!(None, convert_value(&json!("soon"), ValueType::DateTime));
+    assert_eq!(None, convert_value(&json!("not an id"), ValueType::Link));
+}
@@ -0,0 +1,75 @@
+//! History of metadata changes.
+use super::Metadata;
+use crate::types::{Creator, ResourceId};
+use chrono::prelude::*;
+use serde_json::Value as JsValue;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A change to the value of a single metadatum.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct MetadataChange {
+    /// Resource whose metadata changed.
+    pub resource: ResourceId,
+    pub key: String,
+
+    /// Value before the change.
+    /// `None` if the metadatum was added.
+    pub old: Option<JsValue>,
+
+    /// Value after the change.
+    /// `None` if the metadatum was removed.
+    pub new: Option<JsValue>,
+
+    pub modified_by: Creator,
+    pub modified: DateTime<Utc>,
+
+    /// Command that made the change.
+    pub command: String,
+}
+
+impl MetadataChange {
+    /// Changes between two versions of a resource's metadata, sorted by key.
+    ///
+    /// # Arguments
+    /// 1. Resource.
+    /// 2. Metadata before the changes.
+    /// 3. Metadata after the changes.
+    /// 4. Who made the changes.
+    /// 5. Command that made the changes.
+    pub fn diff(
+        resource: &ResourceId,
+        old: &Metadata,
+        new: &Metadata,
+        modified_by: &Creator,
+        command: &str,
+    ) -> Vec<Self> {
+        let mut keys = old
+            .keys()
+            .chain(new.keys())
+            .filter(|key| old.get(*key) != new.get(*key))
+            .collect::<Vec<_>>();
+
+        keys.sort();
+        keys.dedup();
+
+        let modified = Utc::now();
+        keys.into_iter()
+            .map(|key| Self {
+                resource: resource.clone(),
+                key: key.clone(),
+                old: old.get(key).cloned(),
+                new: new.get(key).cloned(),
+                modified_by: modified_by.clone(),
+                modified,
+                command: command.to_string(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "./metadata_history_test.rs"]
+mod metadata_history_test;
@@ -0,0 +1,33 @@
+use super::*;
+use serde_json::json;
+
+#[test]
+fn metadata_change_diff_should_work() {
+    // setup
+    let rid = ResourceId::new();
+    let old = Metadata::from([
+        ("kept".to_string(), json!(1)),
+        ("changed".to_string(), json!("a")),
+        ("removed".to_string(), json!(true)),
+    ]);
+
+    let new = Metadata::from([
+        ("kept".to_string(), json!(1)),
+        ("changed".to_string(), json!("b")),
+        ("added".to_string(), json!(2.5)),
+    ]);
+
+    // test
+    let changes = MetadataChange::diff(&rid, &old, &new, &Creator::User(None), "update");
+    let keys = changes
+        .iter()
+        .map(|change| change.key.as_str())
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec!["added", "changed", "removed"], keys);
+    assert_eq!(None, changes[0].old, "added metadatum has no old value");
+    assert_eq!(Some(json!("a")), changes[1].old);
+    assert_eq!(Some(json!("b")), changes[1].new);
+    assert_eq!(None, changes[2].new, "removed metadatum has no new value");
+    assert!(changes.iter().all(|change| change.resource == rid));
+}
@@ -0,0 +1,260 @@
+use super::*;
+use serde_json::json;
+
+#[test]
+fn quantity_from_value_should_work() {
+    let q = Quantity::from_value(&json!({"magnitude": 5, "unit": "mm"}))
+        .expect("value should be a quantity");
+
+    assert_eq!(Quantity::new(5.0, "mm"), q, "incorrect quantity");
+
+    assert!(
+        Quantity::from_value(&json!({"magnitude": "5", "unit": "mm"})).is_none(),
+        "magnitude should be a number"
+    );
+
+    assert!(
+        Quantity::from_value(&json!({"magnitude": 5, "unit": "mm", "other": 0})).is_none(),
+        "other keys should not be allowed"
+    );
+
+    assert!(
+        Quantity::from_value(&json!(5)).is_none(),
+        "numbers are not quantities"
+    );
+
+    let value: JsValue = q.clone().into();
+    assert_eq!(
+        Some(q),
+        Quantity::from_value(&value),
+        "quantity should round trip"
+    );
+}
+
+#[test]
+fn quantity_from_str_should_work() {
+    assert_eq!(Ok(Quantity::new(5.0, "mm")), "5 mm".parse());
+    assert_eq!(Ok(Quantity::new(-2.5, "kPa")), "-2.5kPa".parse());
+    assert_eq!(Ok(Quantity::new(1e-3, "m")), "1e-3 m".parse());
+    assert_eq!(Ok(Quantity::new(3.0, "eV")), "3 eV".parse());
+    assert_eq!(Ok(Quantity::new(3.0, "")), "3".parse());
+    assert_eq!(
+        Err(ParseQuantityError::InvalidMagnitude),
+        "mm".parse::<Quantity>()
+    );
+}
+
+#[test]
+fn quantity_convert_to_should_work() {
+    let q = Quantity::new(5.0, "mm");
+    let m = q.convert_to("m").expect("units should be compatible");
+    assert!((m.magnitude - 0.005).abs() < 1e-12, "incorrect conversion");
+    assert_eq!("m", m.unit, "incorrect unit");
+
+    assert!(q.convert_to("kg").is_none(), "units should be incompatible");
+    assert!(q.convert_to("furlong").is_none(), "unit should be unknown");
+
+    let t = Quantity::new(100.0, "°C")
+        .convert_to("degF")
+        .expect("units should be compatible");
+
+    assert!(
+        (t.magnitude - 212.0).abs() < 1e-9,
+        "incorrect offset conversion"
+    );
+}
+
+#[test]
+fn quantity_equivalent_should_work() {
+    let mm = Quantity::new(5.0, "mm");
+    assert!(mm.equivalent(&Quantity::new(0.005, "m")));
+    assert!(mm.equivalent(&Quantity::new(0.5, "cm")));
+    assert!(!mm.equivalent(&Quantity::new(5.0, "m")));
+    assert!(!mm.equivalent(&Quantity::new(5.0, "mg")));
+
+    let unknown = Quantity::new(1.0, "widget");
+    assert!(unknown.equivalent(&Quantity::new(1.0, "widget")));
+    assert!(!unknown.equivalent(&Quantity::new(2.0, "widget")));
+
+    assert!(Quantity::new(1.0, "min").equivalent(&Quantity::new(60.0, "s")));
+    assert!(Quantity::new(1.0, "kg").equivalent(&Quantity::new(1000.0, "g")));
+}
+
+#[test]
+fn inherit_metadata_should_respect_inheritance() {
+    // setup
+    let mut root = Container::new("root");
+    root.properties.metadata.insert("a".to_string(), json!(0));
+    root.properties
+        .metadata
+        .insert("sealed".to_string(), json!(0));
+    root.properties
+        .metadata
+        .insert("private".to_string(), json!(0));
+    root.properties.inheritance.seal("sealed");
+    root.properties.inheritance.set_non_inheritable("private");
+
+    let mut parent = Container::new("parent");
+    parent.properties.metadata.insert("a".to_string(), json!(1));
+    parent
+        .properties
+        .metadata
+        .insert("sealed".to_string(), json!(1));
+
+    // test
+    let inherited = inherit_metadata([&root, &parent]);
+    assert_eq!(2, inherited.len(), "incorrect number of keys inherited");
+    assert!(
+        !inherited.contains_key("private"),
+        "non-inheritable key should not be inherited"
+    );
+
+    let a = inherited.get("a").expect("key should be inherited");
+    assert_eq!(json!(1), a.value, "nearer ancestor should take precedence");
+    assert_eq!(parent.rid, a.source, "incorrect source");
+
+    let sealed = inherited.get("sealed").expect("key should be inherited");
+    assert_eq!(
+        json!(0),
+        sealed.value,
+        "sealed value should not be overridden"
+    );
+    assert_eq!(root.rid, sealed.source, "incorrect source");
+    assert!(sealed.sealed, "value should be sealed");
+}
+
+#[test]
+fn resolved_metadata_should_work() {
+    // setup
+    let mut root = Container::new("root");
+    root.properties.metadata.insert("a".to_string(), json!(0));
+    root.properties.metadata.insert("b".to_string(), json!(0));
+    root.properties
+        .metadata
+        .insert("sealed".to_string(), json!(0));
+    root.properties.inheritance.seal("sealed");
+
+    let inherited = inherit_metadata([&root]);
+    let owned = Metadata::from([
+        ("a".to_string(), json!(1)),
+        ("sealed".to_string(), json!(1)),
+        ("c".to_string(), json!(1)),
+    ]);
+
+    // test
+    let resolved = ResolvedMetadata::new(owned.clone(), &inherited);
+    assert_eq!(
+        &owned,
+        resolved.metadata_owned(),
+        "owned should be unchanged"
+    );
+    assert_eq!(
+        3,
+        resolved.metadata_inherited().len(),
+        "incorrect inherited"
+    );
+
+    let all = resolved.metadata_all();
+    assert_eq!(4, all.len(), "incorrect number of keys");
+    assert_eq!(
+        Some(&json!(1)),
+        all.get("a"),
+        "owned should take precedence"
+    );
+    assert_eq!(Some(&json!(0)), all.get("b"), "key should be inherited");
+    assert_eq!(Some(&json!(1)), all.get("c"), "owned key should exist");
+    assert_eq!(
+        Some(&json!(0)),
+        all.get("sealed"),
+        "sealed should take precedence"
+    );
+
+    assert_eq!(None, resolved.source("a"), "owned value has no source");
+    assert_eq!(Some(&root.rid), resolved.source("b"), "incorrect source");
+    assert_eq!(
+        Some(&root.rid),
+        resolved.source("sealed"),
+        "incorrect source"
+    );
+    assert!(
+        resolved.is_overridden("sealed"),
+        "owned value should be overridden"
+    );
+    assert!(
+        !resolved.is_overridden("a"),
+        "owned value should not be overridden"
+    );
+}
+
+#[test]
+fn resolved_metadata_derive_should_work() {
+    // setup
+    let mut root = Container::new("root");
+    root.properties.metadata.insert("a".to_string(), json!(2));
+    root.properties
+        .metadata
+        .insert("sealed".to_string(), json!(0));
+    root.properties.inheritance.seal("sealed");
+    root.properties
+        .derived
+        .insert("double".to_string(), "a * 2".to_string());
+
+    let inherited = inherit_metadata([&root]);
+    assert_eq!(
+        Some(&json!(4)),
+        inherited.get("double").map(|metadatum| &metadatum.value),
+        "derived value should be inherited"
+    );
+    assert_eq!(vec!["root".to_string()], inherited.ancestors);
+
+    let mut properties = AssetProperties::new();
+    properties.name = Some("asset".to_string());
+    properties.metadata.insert("b".to_string(), json!(3));
+    properties
+        .derived
+        .insert("sum".to_string(), "a + b".to_string());
+    properties
+        .derived
+        .insert("total".to_string(), "sum + double".to_string());
+    properties.derived.insert(
+        "label".to_string(),
+        "join($ancestors, '/') + '/' + $name".to_string(),
+    );
+    properties
+        .derived
+        .insert("sealed".to_string(), "1".to_string());
+    properties
+        .derived
+        .insert("cycle".to_string(), "cycle + 1".to_string());
+    properties
+        .derived
+        .insert("invalid".to_string(), "a +".to_string());
+
+    // test
+    let resolved = ResolvedMetadata::for_asset(&properties, &inherited);
+    let all = resolved.metadata_all();
+    assert_eq!(Some(&json!(5)), all.get("sum"), "incorrect derived value");
+    assert_eq!(
+        Some(&json!(9)),
+        all.get("total"),
+        "derived keys should be usable"
+    );
+    assert_eq!(Some(&json!("root/asset")), all.get("label"));
+    assert!(resolved.is_derived("total"));
+
+    assert_eq!(
+        Some(&json!(0)),
+        all.get("sealed"),
+        "sealed keys should not be derived"
+    );
+    assert!(resolved.derive_error("sealed").is_some());
+
+    assert!(!all.contains_key("cycle"), "cyclic key should be removed");
+    assert!(resolved.derive_error("cycle").is_some());
+
+    assert!(
+        !all.contains_key("invalid"),
+        "invalid key should be removed"
+    );
+    assert!(resolved.derive_error("invalid").is_some());
+}
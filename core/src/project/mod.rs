@@ -3,19 +3,40 @@ pub mod asset;
 pub mod asset_properties;
 pub mod container;
 pub mod container_properties;
+pub mod expression;
+pub mod flag;
+pub mod lineage;
+pub mod links;
 pub mod metadata;
+pub mod metadata_edit;
+pub mod metadata_history;
 pub mod project;
 pub mod resources;
+pub mod schema;
 pub mod script;
 pub mod script_association;
+pub mod tag;
+pub mod typed_value;
+pub mod vocabulary;
 
 // Reexports
 pub use asset::Asset;
 pub use asset_properties::AssetProperties;
 pub use container::Container;
 pub use container_properties::ContainerProperties;
-pub use metadata::Metadata;
+pub use flag::{Flag, FlagResolution, FlagSeverity};
+pub use lineage::Lineage;
+pub use links::{Backlink, Links};
+pub use metadata::{
+    DerivedMetadata, InheritMetadata, Metadata, MetadataInheritance, Quantity, ResolvedMetadata,
+    WithMetadata,
+};
+pub use metadata_edit::{MetadataEdit, MetadataEditError};
+pub use metadata_history::MetadataChange;
 pub use project::Project;
 pub use resources::ResourceProperties;
+pub use schema::{MetadataSchema, MetadataSchemas, SchemaViolation};
 pub use script::{Script, ScriptEnv, ScriptLang, Scripts};
 pub use script_association::{RunParameters, ScriptAssociation};
+pub use typed_value::{DateTimeRange, DateTimeValue, Link};
+pub use vocabulary::{Vocabularies, Vocabulary, VocabularyError};
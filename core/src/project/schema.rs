@@ -0,0 +1,255 @@
+//! Metadata schemas.
+//!
+//! Schemas constrain the [`Metadata`](super::Metadata) of resources by their kind.
+use super::{DateTimeValue, InheritMetadata, Link, Quantity};
+use serde_json::Value as JsValue;
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ************
+// *** Mode ***
+// ************
+
+/// How schema violations are handled.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub enum ValidationMode {
+    /// Changes are accepted, and violations reported.
+    #[default]
+    Warn,
+
+    /// Changes with violations are rejected.
+    Reject,
+}
+
+// *************
+// *** Types ***
+// *************
+
+/// Type of a metadatum value.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum ValueType {
+    String,
+    Number,
+    Integer,
+    Bool,
+    Array,
+    Object,
+
+    /// A [`Quantity`].
+    Quantity,
+
+    /// A [`DateTimeValue`].
+    DateTime,
+
+    /// A [`Link`] to another resource.
+    Link,
+}
+
+impl ValueType {
+    /// Returns whether the value is of this type.
+    pub fn matches(&self, value: &JsValue) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Bool => value.is_boolean(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+            Self::Quantity => Quantity::is_quantity(value),
+            Self::DateTime => DateTimeValue::is_datetime(value),
+            Self::Link => Link::is_link(value),
+        }
+    }
+}
+
+// **************
+// *** Schema ***
+// **************
+
+/// Constraints on a single metadatum.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct MetadatumSchema {
+    /// Type of the value.
+    #[cfg_attr(feature = "serde", serde(rename = "type", default))]
+    pub value_type: Option<ValueType>,
+
+    /// Whether the key must be present.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub required: bool,
+
+    /// Allowed values.
+    #[cfg_attr(feature = "serde", serde(rename = "enum", default))]
+    pub options: Option<Vec<JsValue>>,
+
+    /// Minimum value, inclusive.
+    /// Only applies to numbers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min: Option<f64>,
+
+    /// Maximum value, inclusive.
+    /// Only applies to numbers.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max: Option<f64>,
+}
+
+impl MetadatumSchema {
+    /// Validates a value against the schema.
+    fn validate(&self, key: &str, value: &JsValue) -> Option<SchemaViolation> {
+        if let Some(value_type) = self.value_type {
+            if !value_type.matches(value) {
+                return Some(SchemaViolation::InvalidType {
+                    key: key.to_string(),
+                    expected: value_type,
+                });
+            }
+        }
+
+        if let Some(options) = self.options.as_ref() {
+            if !options.contains(value) {
+                return Some(SchemaViolation::NotAllowed {
+                    key: key.to_string(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        if let Some(number) = value.as_f64() {
+            let too_small = self.min.is_some_and(|min| number < min);
+            let too_large = self.max.is_some_and(|max| number > max);
+            if too_small || too_large {
+                return Some(SchemaViolation::OutOfRange {
+                    key: key.to_string(),
+                    value: number,
+                });
+            }
+        }
+
+        None
+    }
+}
+
+/// Schema for the [`Metadata`](super::Metadata) of resources of a kind.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct MetadataSchema {
+    /// Constraints on metadata, by key.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: HashMap<String, MetadatumSchema>,
+
+    /// Whether keys not in the schema are allowed.
+    #[cfg_attr(feature = "serde", serde(default = "additional_keys_default"))]
+    pub additional_keys: bool,
+}
+
+impl MetadataSchema {
+    /// Validates metadata against the schema.
+    ///
+    /// # Returns
+    /// Violations of the schema.
+    ///
+    /// # Notes
+    /// + Values and required keys are checked on all metadata.
+    /// + Only owned keys are checked for being unexpected.
+    pub fn validate(&self, metadata: &impl InheritMetadata) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        for (key, schema) in self.metadata.iter() {
+            match metadata.metadata_all().get(key) {
+                Some(value) => violations.extend(schema.validate(key, value)),
+                None if schema.required => {
+                    violations.push(SchemaViolation::Missing { key: key.clone() })
+                }
+                None => {}
+            }
+        }
+
+        if !self.additional_keys {
+            for key in metadata.metadata_owned().keys() {
+                if !self.metadata.contains_key(key) {
+                    violations.push(SchemaViolation::Unexpected { key: key.clone() });
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl Default for MetadataSchema {
+    fn default() -> Self {
+        Self {
+            metadata: HashMap::default(),
+            additional_keys: true,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn additional_keys_default() -> bool {
+    true
+}
+
+/// [`MetadataSchema`]s by resource kind.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct MetadataSchemas {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub mode: ValidationMode,
+
+    /// Schemas, by resource kind.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kinds: HashMap<String, MetadataSchema>,
+}
+
+impl MetadataSchemas {
+    /// Validates the metadata of a resource against the schema of its kind.
+    ///
+    /// # Returns
+    /// Violations of the schema.
+    /// Empty if the resource has no kind, or its kind has no schema.
+    pub fn validate(
+        &self,
+        kind: Option<&String>,
+        metadata: &impl InheritMetadata,
+    ) -> Vec<SchemaViolation> {
+        let Some(schema) = kind.and_then(|kind| self.kinds.get(kind)) else {
+            return Vec::new();
+        };
+
+        schema.validate(metadata)
+    }
+}
+
+// *****************
+// *** Violation ***
+// *****************
+
+/// A violation of a [`MetadataSchema`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Error, PartialEq, Clone, Debug)]
+pub enum SchemaViolation {
+    #[error("required key `{key}` is missing")]
+    Missing { key: String },
+
+    #[error("value of `{key}` should be of type `{expected:?}`")]
+    InvalidType { key: String, expected: ValueType },
+
+    #[error("value `{value}` of `{key}` is not allowed")]
+    NotAllowed { key: String, value: JsValue },
+
+    #[error("value `{value}` of `{key}` is out of range")]
+    OutOfRange { key: String, value: f64 },
+
+    #[error("key `{key}` is not in the schema")]
+    Unexpected { key: String },
+}
+
+#[cfg(test)]
+#[path = "./schema_test.rs"]
+mod schema_test;
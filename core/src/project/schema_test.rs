@@ -0,0 +1,195 @@
+use super::*;
+use crate::project::metadata::{InheritedMetadata, InheritedMetadatum};
+use crate::project::{Metadata, ResolvedMetadata};
+use crate::types::ResourceId;
+use serde_json::json;
+
+#[test]
+fn metadata_schema_validate_should_work() {
+    // setup
+    let mut schema = MetadataSchema::default();
+    schema.metadata.insert(
+        "temperature".to_string(),
+        MetadatumSchema {
+            value_type: Some(ValueType::Number),
+            required: true,
+            min: Some(0.0),
+            max: Some(100.0),
+            ..Default::default()
+        },
+    );
+
+    schema.metadata.insert(
+        "material".to_string(),
+        MetadatumSchema {
+            options: Some(vec![json!("steel"), json!("aluminum")]),
+            ..Default::default()
+        },
+    );
+
+    // test
+    let metadata = Metadata::from([
+        ("temperature".to_string(), json!(20)),
+        ("material".to_string(), json!("steel")),
+    ]);
+    assert!(
+        schema.validate(&owned(metadata.clone())).is_empty(),
+        "metadata should be valid"
+    );
+
+    let metadata = Metadata::from([("material".to_string(), json!("steel"))]);
+    assert_eq!(
+        vec![SchemaViolation::Missing {
+            key: "temperature".to_string()
+        }],
+        schema.validate(&owned(metadata.clone())),
+        "missing required key should be reported"
+    );
+
+    let metadata = Metadata::from([("temperature".to_string(), json!("20"))]);
+    assert_eq!(
+        vec![SchemaViolation::InvalidType {
+            key: "temperature".to_string(),
+            expected: ValueType::Number
+        }],
+        schema.validate(&owned(metadata.clone())),
+        "invalid type should be reported"
+    );
+
+    let metadata = Metadata::from([
+        ("temperature".to_string(), json!(120)),
+        ("material".to_string(), json!("wood")),
+    ]);
+    assert_eq!(
+        2,
+        schema.validate(&owned(metadata.clone())).len(),
+        "range and enum should be checked"
+    );
+
+    schema.additional_keys = false;
+    let metadata = Metadata::from([
+        ("temperature".to_string(), json!(20)),
+        ("color".to_string(), json!("red")),
+    ]);
+    assert_eq!(
+        vec![SchemaViolation::Unexpected {
+            key: "color".to_string()
+        }],
+        schema.validate(&owned(metadata.clone())),
+        "additional keys should be reported"
+    );
+}
+
+#[test]
+fn metadata_schema_validate_should_allow_inherited_additional_keys() {
+    // setup
+    let mut schema = MetadataSchema::default();
+    schema.additional_keys = false;
+    schema.metadata.insert(
+        "temperature".to_string(),
+        MetadatumSchema {
+            value_type: Some(ValueType::Number),
+            ..Default::default()
+        },
+    );
+
+    let parent = ResourceId::new();
+    let mut inherited = InheritedMetadata::new();
+    inherited.insert(
+        "site".to_string(),
+        InheritedMetadatum {
+            value: json!("north"),
+            source: parent.clone(),
+            sealed: false,
+        },
+    );
+
+    inherited.insert(
+        "temperature".to_string(),
+        InheritedMetadatum {
+            value: json!("hot"),
+            source: parent,
+            sealed: false,
+        },
+    );
+
+    // test
+    let metadata = ResolvedMetadata::new(Metadata::new(), &inherited);
+    assert_eq!(
+        vec![SchemaViolation::InvalidType {
+            key: "temperature".to_string(),
+            expected: ValueType::Number
+        }],
+        schema.validate(&metadata),
+        "inherited keys should only be checked for their values"
+    );
+}
+
+#[test]
+fn metadata_schemas_validate_should_use_kind() {
+    // setup
+    let mut schema = MetadataSchema::default();
+    schema.metadata.insert(
+        "run".to_string(),
+        MetadatumSchema {
+            required: true,
+            ..Default::default()
+        },
+    );
+
+    let mut schemas = MetadataSchemas::default();
+    schemas.kinds.insert("trial".to_string(), schema);
+
+    // test
+    let metadata = Metadata::new();
+    assert_eq!(
+        1,
+        schemas
+            .validate(Some(&"trial".to_string()), &owned(metadata.clone()))
+            .len()
+    );
+
+    assert!(schemas
+        .validate(Some(&"other".to_string()), &owned(metadata.clone()))
+        .is_empty());
+
+    assert!(schemas.validate(None, &owned(metadata)).is_empty());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn metadata_schemas_should_deserialize() {
+    let schemas = json!({
+        "mode": "Reject",
+        "kinds": {
+            "trial": {
+                "metadata": {
+                    "run": { "type": "integer", "required": true, "min": 0 }
+                }
+            }
+        }
+    });
+
+    let schemas: MetadataSchemas =
+        serde_json::from_value(schemas).expect("could not deserialize schemas");
+
+    assert_eq!(ValidationMode::Reject, schemas.mode);
+    let schema = schemas.kinds.get("trial").unwrap();
+    assert!(
+        schema.additional_keys,
+        "additional keys should be allowed by default"
+    );
+
+    let run = schema.metadata.get("run").unwrap();
+    assert_eq!(Some(ValueType::Integer), run.value_type);
+    assert!(run.required);
+    assert_eq!(Some(0.0), run.min);
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+fn owned(metadata: Metadata) -> ResolvedMetadata {
+    ResolvedMetadata::new(metadata, &InheritedMetadata::new())
+}
@@ -1,5 +1,5 @@
 use crate::error::ScriptError;
-use crate::types::{ResourceId, ResourceMap, ResourcePath};
+use crate::types::{Creator, ResourceId, ResourceMap, ResourcePath};
 use crate::{Error, Result};
 use chrono::prelude::*;
 use has_id::HasId;
@@ -35,6 +35,14 @@ pub struct Script {
     pub env: ScriptEnv,
     pub creator: Option<ResourceId>,
     created: DateTime<Utc>,
+
+    /// When the script was last modified.
+    #[cfg_attr(feature = "serde", serde(default))]
+    modified: Option<DateTime<Utc>>,
+
+    /// Who last modified the script.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub modified_by: Option<Creator>,
 }
 
 impl Script {
@@ -51,6 +59,8 @@ impl Script {
             description: None,
             creator: None,
             created: Utc::now(),
+            modified: None,
+            modified_by: None,
             env,
         })
     }
@@ -61,6 +71,18 @@ impl Script {
     pub fn created(&self) -> &DateTime<Utc> {
         &self.created
     }
+
+    /// Returns the date-time the script was last modified.
+    /// As with `created`, this refers to the abstract Script object.
+    pub fn modified(&self) -> Option<&DateTime<Utc>> {
+        self.modified.as_ref()
+    }
+
+    /// Records a modification of the script, now.
+    pub fn mark_modified(&mut self, by: Creator) {
+        self.modified = Some(Utc::now());
+        self.modified_by = Some(by);
+    }
 }
 
 // ***************
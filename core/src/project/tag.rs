@@ -0,0 +1,128 @@
+//! Hierarchical tags.
+//!
+//! Tags form a hierarchy using `/` as a separator,
+//! e.g. `stimulus/visual/grating` is a descendant of `stimulus/visual` and `stimulus`.
+//! A tag matches itself and all of its descendants.
+use std::collections::{BTreeMap, HashSet};
+
+/// Separator between the levels of a tag.
+pub const SEPARATOR: char = '/';
+
+/// Returns whether `tag` is `ancestor` or one of its descendants.
+pub fn is_descendant_or_self(tag: &str, ancestor: &str) -> bool {
+    match tag.strip_prefix(ancestor) {
+        Some(rest) => rest.is_empty() || rest.starts_with(SEPARATOR),
+        None => false,
+    }
+}
+
+/// Returns whether any of `tags` is `tag` or one of its descendants.
+pub fn matches(tags: &[String], tag: &str) -> bool {
+    tags.iter().any(|t| is_descendant_or_self(t, tag))
+}
+
+/// Ancestors of a tag, from the root down.
+/// The tag itself is not included.
+pub fn ancestors(tag: &str) -> impl Iterator<Item = &str> {
+    tag.match_indices(SEPARATOR)
+        .map(move |(index, _)| &tag[..index])
+}
+
+/// Parent of a tag.
+///
+/// # Returns
+/// `None` if the tag is a root.
+pub fn parent(tag: &str) -> Option<&str> {
+    tag.rsplit_once(SEPARATOR).map(|(parent, _)| parent)
+}
+
+/// Normalizes a tag by trimming each level and removing empty levels.
+///
+/// # Examples
+/// ` stimulus / visual/` normalizes to `stimulus/visual`.
+pub fn normalize(tag: &str) -> String {
+    tag.split(SEPARATOR)
+        .map(|level| level.trim())
+        .filter(|level| !level.is_empty())
+        .collect::<Vec<_>>()
+        .join(&SEPARATOR.to_string())
+}
+
+/// Renames a tag node.
+///
+/// # Returns
+/// + The renamed tag, if `tag` is `from` or one of its descendants.
+/// + `None`, otherwise.
+pub fn rename(tag: &str, from: &str, to: &str) -> Option<String> {
+    if !is_descendant_or_self(tag, from) {
+        return None;
+    }
+
+    Some(format!("{to}{}", &tag[from.len()..]))
+}
+
+/// Renames a tag node in a list of tags, cascading to its descendants.
+/// Duplicates resulting from the rename are removed.
+///
+/// # Returns
+/// Whether any tag was renamed.
+pub fn rename_in(tags: &mut Vec<String>, from: &str, to: &str) -> bool {
+    let mut changed = false;
+    let mut renamed = Vec::with_capacity(tags.len());
+    for tag in tags.drain(..) {
+        let tag = match rename(&tag, from, to) {
+            Some(new) => {
+                changed |= new != tag;
+                new
+            }
+
+            None => tag,
+        };
+
+        if !renamed.contains(&tag) {
+            renamed.push(tag);
+        }
+    }
+
+    *tags = renamed;
+    changed
+}
+
+/// Removes a tag node from a list of tags, including its descendants.
+///
+/// # Returns
+/// Whether any tag was removed.
+pub fn remove_in(tags: &mut Vec<String>, tag: &str) -> bool {
+    let len = tags.len();
+    tags.retain(|t| !is_descendant_or_self(t, tag));
+    tags.len() != len
+}
+
+/// Aggregates the tags of resources.
+///
+/// # Arguments
+/// 1. Tags of each resource.
+///
+/// # Returns
+/// Number of resources matching each tag node, including ancestors.
+pub fn aggregate<'a>(
+    resources: impl IntoIterator<Item = &'a Vec<String>>,
+) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    for tags in resources {
+        let nodes = tags
+            .iter()
+            .flat_map(|tag| ancestors(tag).chain(std::iter::once(tag.as_str())))
+            .collect::<HashSet<_>>();
+
+        for node in nodes {
+            *counts.entry(node.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    counts
+}
+
+#[cfg(test)]
+#[path = "./tag_test.rs"]
+mod tag_test;
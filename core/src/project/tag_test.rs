@@ -0,0 +1,88 @@
+use super::*;
+
+#[test]
+fn is_descendant_or_self_should_work() {
+    assert!(is_descendant_or_self("stimulus", "stimulus"));
+    assert!(is_descendant_or_self("stimulus/visual", "stimulus"));
+    assert!(is_descendant_or_self("stimulus/visual/grating", "stimulus"));
+    assert!(!is_descendant_or_self("stimulus", "stimulus/visual"));
+    assert!(
+        !is_descendant_or_self("stimuli", "stim"),
+        "prefixes of a level should not match"
+    );
+}
+
+#[test]
+fn ancestors_should_work() {
+    assert_eq!(
+        vec!["stimulus", "stimulus/visual"],
+        ancestors("stimulus/visual/grating").collect::<Vec<_>>()
+    );
+
+    assert_eq!(0, ancestors("stimulus").count());
+    assert_eq!(Some("stimulus/visual"), parent("stimulus/visual/grating"));
+    assert_eq!(None, parent("stimulus"));
+}
+
+#[test]
+fn normalize_should_work() {
+    assert_eq!("stimulus/visual", normalize(" stimulus / visual/"));
+    assert_eq!("stimulus", normalize("stimulus"));
+}
+
+#[test]
+fn rename_in_should_cascade() {
+    let mut tags = vec![
+        "stimulus/visual".to_string(),
+        "stimulus/visual/grating".to_string(),
+        "stimulus/auditory".to_string(),
+        "vision/grating".to_string(),
+    ];
+
+    assert!(rename_in(&mut tags, "stimulus/visual", "vision"));
+    assert_eq!(
+        vec![
+            "vision".to_string(),
+            "vision/grating".to_string(),
+            "stimulus/auditory".to_string(),
+        ],
+        tags,
+        "descendants should be renamed and duplicates removed"
+    );
+
+    assert!(!rename_in(&mut tags, "other", "new"));
+}
+
+#[test]
+fn remove_in_should_remove_descendants() {
+    let mut tags = vec![
+        "stimulus/visual/grating".to_string(),
+        "stimulus/auditory".to_string(),
+        "stimuli".to_string(),
+    ];
+
+    assert!(remove_in(&mut tags, "stimulus"));
+    assert_eq!(vec!["stimuli".to_string()], tags);
+}
+
+#[test]
+fn aggregate_should_count_resources_per_node() {
+    let resources = vec![
+        vec![
+            "stimulus/visual/grating".to_string(),
+            "stimulus/visual/dots".to_string(),
+        ],
+        vec!["stimulus/auditory".to_string()],
+    ];
+
+    let counts = aggregate(&resources);
+    assert_eq!(Some(&2), counts.get("stimulus"));
+    assert_eq!(
+        Some(&1),
+        counts.get("stimulus/visual"),
+        "resources should be counted once per node"
+    );
+    assert_eq!(Some(&1), counts.get("stimulus/visual/grating"));
+    assert_eq!(Some(&1), counts.get("stimulus/auditory"));
+    assert_eq!(5, counts.len());
+}
@@ -0,0 +1,262 @@
+//! Typed metadatum values.
+//!
+//! Metadata values are JSON. Types beyond JSON's are stored as an object
+//! with exactly one key naming the type.
+//!
+//! | Type              | Convention                                         |
+//! |-------------------|----------------------------------------------------|
+//! | [`DateTimeValue`] | `{"datetime": "2023-04-01T12:00:00Z"}` (RFC 3339)  |
+//! | [`Link`]          | `{"link": "<ResourceId>"}`                         |
+//!
+//! Enumerations are stored as plain values,
+//! their options are given by the `enum` of a [`MetadatumSchema`](super::schema::MetadatumSchema).
+//!
+//! # Search
+//! [`DateTimeRange`]s, `{"after": ..., "before": ...}` with either key optional,
+//! match date-time values when used in a [`StandardSearchFilter`](crate::db::StandardSearchFilter).
+use crate::types::ResourceId;
+use chrono::{DateTime as ChronoDateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
+use serde_json::{Map as JsMap, Value as JsValue};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+// *****************
+// *** Date time ***
+// *****************
+
+/// A point in time.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Debug)]
+pub struct DateTimeValue(pub ChronoDateTime<Utc>);
+
+impl DateTimeValue {
+    pub const KEY: &'static str = "datetime";
+
+    /// The current time.
+    pub fn now() -> Self {
+        Self(Utc::now())
+    }
+
+    /// Interprets a metadatum value as a date-time.
+    ///
+    /// # Returns
+    /// `None` if the value does not have the shape of a date-time.
+    pub fn from_value(value: &JsValue) -> Option<Self> {
+        let value = single_entry(value, Self::KEY)?;
+        value.as_str()?.parse().ok()
+    }
+
+    /// Returns whether the metadatum value is a date-time.
+    pub fn is_datetime(value: &JsValue) -> bool {
+        Self::from_value(value).is_some()
+    }
+}
+
+impl From<ChronoDateTime<Utc>> for DateTimeValue {
+    fn from(datetime: ChronoDateTime<Utc>) -> Self {
+        Self(datetime)
+    }
+}
+
+impl From<DateTimeValue> for JsValue {
+    fn from(datetime: DateTimeValue) -> Self {
+        tagged(DateTimeValue::KEY, datetime.to_string().into())
+    }
+}
+
+impl fmt::Display for DateTimeValue {
+    /// Formats as RFC 3339 in UTC.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl FromStr for DateTimeValue {
+    type Err = ParseDateTimeError;
+
+    /// Parses an ISO 8601 date-time.
+    ///
+    /// # Notes
+    /// + Date-times with an offset are converted to UTC.
+    /// + Date-times without an offset, e.g. `2023-04-01T12:00`, and dates are taken as UTC.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(datetime) = ChronoDateTime::parse_from_rfc3339(s) {
+            return Ok(Self(datetime.with_timezone(&Utc)));
+        }
+
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"] {
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+                return Ok(Self(datetime.and_utc()));
+            }
+        }
+
+        match NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+            Ok(date) => Ok(Self(
+                date.and_hms_opt(0, 0, 0)
+                    .expect("midnight is valid")
+                    .and_utc(),
+            )),
+
+            Err(_) => Err(ParseDateTimeError::InvalidFormat(s.to_string())),
+        }
+    }
+}
+
+#[derive(Error, PartialEq, Debug)]
+pub enum ParseDateTimeError {
+    #[error("`{0}` is not an ISO 8601 date-time")]
+    InvalidFormat(String),
+}
+
+// ******************
+// *** Date range ***
+// ******************
+
+/// A range of date-times used to search metadata.
+///
+/// # Fields
+/// + `after`: Start of the range, inclusive.
+/// + `before`: End of the range, exclusive.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct DateTimeRange {
+    pub after: Option<DateTimeValue>,
+    pub before: Option<DateTimeValue>,
+}
+
+impl DateTimeRange {
+    pub const AFTER_KEY: &'static str = "after";
+    pub const BEFORE_KEY: &'static str = "before";
+
+    /// Interprets a search value as a date-time range.
+    ///
+    /// # Returns
+    /// `None` if the value does not have the shape of a range,
+    /// i.e. an object with only `after` and `before` date-time strings,
+    /// with at least one present.
+    pub fn from_value(value: &JsValue) -> Option<Self> {
+        let JsValue::Object(value) = value else {
+            return None;
+        };
+
+        if value.is_empty()
+            || value
+                .keys()
+                .any(|key| key != Self::AFTER_KEY && key != Self::BEFORE_KEY)
+        {
+            return None;
+        }
+
+        let bound = |key| match value.get(key) {
+            None => Some(None),
+            Some(bound) => bound.as_str()?.parse().ok().map(Some),
+        };
+
+        Some(Self {
+            after: bound(Self::AFTER_KEY)?,
+            before: bound(Self::BEFORE_KEY)?,
+        })
+    }
+
+    /// Returns whether the date-time is in the range.
+    pub fn contains(&self, datetime: &DateTimeValue) -> bool {
+        self.after.as_ref().map_or(true, |after| datetime >= after)
+            && self
+                .before
+                .as_ref()
+                .map_or(true, |before| datetime < before)
+    }
+}
+
+impl From<DateTimeRange> for JsValue {
+    fn from(range: DateTimeRange) -> Self {
+        let mut value = JsMap::new();
+        if let Some(after) = range.after {
+            value.insert(
+                DateTimeRange::AFTER_KEY.to_string(),
+                after.to_string().into(),
+            );
+        }
+
+        if let Some(before) = range.before {
+            value.insert(
+                DateTimeRange::BEFORE_KEY.to_string(),
+                before.to_string().into(),
+            );
+        }
+
+        JsValue::Object(value)
+    }
+}
+
+// ************
+// *** Link ***
+// ************
+
+/// A reference to another resource.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Link(pub ResourceId);
+
+impl Link {
+    pub const KEY: &'static str = "link";
+
+    /// Interprets a metadatum value as a link.
+    ///
+    /// # Returns
+    /// `None` if the value does not have the shape of a link.
+    pub fn from_value(value: &JsValue) -> Option<Self> {
+        let value = single_entry(value, Self::KEY)?;
+        value.as_str()?.parse().ok().map(Self)
+    }
+
+    /// Returns whether the metadatum value is a link.
+    pub fn is_link(value: &JsValue) -> bool {
+        Self::from_value(value).is_some()
+    }
+}
+
+impl From<ResourceId> for Link {
+    fn from(rid: ResourceId) -> Self {
+        Self(rid)
+    }
+}
+
+impl From<Link> for JsValue {
+    fn from(link: Link) -> Self {
+        tagged(Link::KEY, link.to_string().into())
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Returns the value of an object with exactly the one key.
+fn single_entry<'a>(value: &'a JsValue, key: &str) -> Option<&'a JsValue> {
+    let JsValue::Object(value) = value else {
+        return None;
+    };
+
+    if value.len() != 1 {
+        return None;
+    }
+
+    value.get(key)
+}
+
+/// Creates an object with the single entry.
+fn tagged(key: &str, value: JsValue) -> JsValue {
+    let mut map = JsMap::with_capacity(1);
+    map.insert(key.to_string(), value);
+    JsValue::Object(map)
+}
+
+#[cfg(test)]
+#[path = "./typed_value_test.rs"]
+mod typed_value_test;
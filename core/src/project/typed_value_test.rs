@@ -0,0 +1,60 @@
+use super::*;
+use serde_json::json;
+
+#[test]
+fn datetime_value_from_str_should_work() {
+    let expected = "2023-04-01T12:00:00Z".parse::<DateTimeValue>().unwrap();
+    assert_eq!(
+        expected,
+        "2023-04-01T14:00:00+02:00".parse().unwrap(),
+        "offsets should be converted to UTC"
+    );
+    assert_eq!(expected, "2023-04-01T12:00".parse().unwrap());
+    assert_eq!(
+        "2023-04-01T00:00:00Z".parse::<DateTimeValue>().unwrap(),
+        "2023-04-01".parse().unwrap(),
+        "dates should be midnight"
+    );
+    assert!("April 1st".parse::<DateTimeValue>().is_err());
+}
+
+#[test]
+fn datetime_value_from_value_should_work() {
+    let datetime = "2023-04-01T12:00:00Z".parse::<DateTimeValue>().unwrap();
+    let value: JsValue = datetime.clone().into();
+
+    assert_eq!(json!({ "datetime": "2023-04-01T12:00:00Z" }), value);
+    assert_eq!(Some(datetime), DateTimeValue::from_value(&value));
+    assert_eq!(None, DateTimeValue::from_value(&json!("2023-04-01")));
+    assert_eq!(
+        None,
+        DateTimeValue::from_value(&json!({ "datetime": "2023-04-01", "unit": "s" })),
+        "extra keys should not be a date-time"
+    );
+}
+
+#[test]
+fn datetime_range_should_work() {
+    let range = DateTimeRange::from_value(&json!({ "after": "2023-04-01" }))
+        .expect("range should be parsed");
+
+    assert!(range.contains(&"2023-04-01".parse().unwrap()));
+    assert!(!range.contains(&"2023-03-31T23:59:59Z".parse().unwrap()));
+
+    assert_eq!(None, DateTimeRange::from_value(&json!({})));
+    assert_eq!(
+        None,
+        DateTimeRange::from_value(&json!({ "after": "2023-04-01", "magnitude": 1 }))
+    );
+    assert_eq!(None, DateTimeRange::from_value(&json!({ "before": 5 })));
+}
+
+#[test]
+fn link_from_value_should_work() {
+    let rid = ResourceId::new();
+    let value: JsValue = Link(rid.clone()).into();
+
+    assert_eq!(json!({ "link": rid.to_string() }), value);
+    assert_eq!(Some(Link(rid)), Link::from_value(&value));
+    assert_eq!(None, Link::from_value(&json!({ "link": "not an id" })));
+}
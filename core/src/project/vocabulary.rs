@@ -0,0 +1,276 @@
+//! Controlled vocabularies.
+//!
+//! Vocabularies map the tags and kinds of resources to canonical terms.
+use super::{AssetProperties, ContainerProperties};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// ******************
+// *** Vocabulary ***
+// ******************
+
+/// Canonical terms and their aliases.
+///
+/// # Notes
+/// + Terms are matched case-insensitively.
+/// + A vocabulary without terms does not constrain values, even if strict.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Vocabulary {
+    /// Aliases, keyed by canonical term.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub terms: HashMap<String, Vec<String>>,
+
+    /// Whether terms not in the vocabulary are rejected.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strict: bool,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a canonical term with its aliases,
+    /// replacing the aliases of an existing term.
+    pub fn insert_term(&mut self, term: impl Into<String>, aliases: Vec<String>) {
+        self.terms.insert(term.into(), aliases);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Gets the canonical form of a term.
+    ///
+    /// # Returns
+    /// `None` if the term is not in the vocabulary.
+    pub fn canonical(&self, term: &str) -> Option<&str> {
+        if let Some((canonical, _)) = self.terms.get_key_value(term) {
+            return Some(canonical);
+        }
+
+        let term = term.trim().to_lowercase();
+        self.terms
+            .iter()
+            .find(|(canonical, aliases)| {
+                canonical.to_lowercase() == term
+                    || aliases.iter().any(|alias| alias.to_lowercase() == term)
+            })
+            .map(|(canonical, _)| canonical.as_str())
+    }
+
+    /// Normalizes a term to its canonical form.
+    ///
+    /// # Returns
+    /// + The canonical term, if the term is in the vocabulary.
+    /// + The term, if it is not in the vocabulary and the vocabulary is not strict.
+    /// + `None`, if the term is not in the vocabulary and the vocabulary is strict.
+    pub fn normalize(&self, term: &str) -> Option<String> {
+        match self.canonical(term) {
+            Some(canonical) => Some(canonical.to_string()),
+            None if self.strict && !self.is_empty() => None,
+            None => Some(term.to_string()),
+        }
+    }
+
+    /// Normalizes a term to its canonical form,
+    /// keeping it unchanged if it is not in the vocabulary.
+    pub fn normalize_lossy(&self, term: &str) -> String {
+        self.canonical(term).unwrap_or(term).to_string()
+    }
+
+    /// Canonical terms, sorted.
+    pub fn canonical_terms(&self) -> Vec<&str> {
+        let mut terms = self
+            .terms
+            .keys()
+            .map(|term| term.as_str())
+            .collect::<Vec<_>>();
+        terms.sort();
+        terms
+    }
+}
+
+// ********************
+// *** Vocabularies ***
+// ********************
+
+/// Vocabularies of a `Project`.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Vocabularies {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub tags: Vocabulary,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub container_kinds: Vocabulary,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub asset_kinds: Vocabulary,
+}
+
+impl Vocabularies {
+    /// Normalizes tags to their canonical forms.
+    /// Duplicates resulting from normalization are removed.
+    ///
+    /// # Errors
+    /// + [`VocabularyError::UnknownTag`]: If a tag is rejected by the vocabulary.
+    pub fn normalize_tags(&self, tags: &Vec<String>) -> Result<Vec<String>, VocabularyError> {
+        let mut normalized = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let Some(tag) = self.tags.normalize(tag) else {
+                return Err(VocabularyError::UnknownTag(tag.clone()));
+            };
+
+            if !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+
+        Ok(normalized)
+    }
+
+    /// Normalizes a `Container` kind to its canonical form.
+    ///
+    /// # Errors
+    /// + [`VocabularyError::UnknownContainerKind`]: If the kind is rejected by the vocabulary.
+    pub fn normalize_container_kind(
+        &self,
+        kind: Option<&String>,
+    ) -> Result<Option<String>, VocabularyError> {
+        let Some(kind) = kind else {
+            return Ok(None);
+        };
+
+        match self.container_kinds.normalize(kind) {
+            Some(kind) => Ok(Some(kind)),
+            None => Err(VocabularyError::UnknownContainerKind(kind.clone())),
+        }
+    }
+
+    /// Normalizes an `Asset` kind to its canonical form.
+    ///
+    /// # Errors
+    /// + [`VocabularyError::UnknownAssetKind`]: If the kind is rejected by the vocabulary.
+    pub fn normalize_asset_kind(
+        &self,
+        kind: Option<&String>,
+    ) -> Result<Option<String>, VocabularyError> {
+        let Some(kind) = kind else {
+            return Ok(None);
+        };
+
+        match self.asset_kinds.normalize(kind) {
+            Some(kind) => Ok(Some(kind)),
+            None => Err(VocabularyError::UnknownAssetKind(kind.clone())),
+        }
+    }
+
+    /// Normalizes the kind and tags of a `Container`.
+    ///
+    /// # Errors
+    /// + If the kind or a tag is rejected by its vocabulary.
+    /// The properties are left unchanged.
+    pub fn normalize_container_properties(
+        &self,
+        properties: &mut ContainerProperties,
+    ) -> Result<(), VocabularyError> {
+        let kind = self.normalize_container_kind(properties.kind.as_ref())?;
+        let tags = self.normalize_tags(&properties.tags)?;
+        properties.kind = kind;
+        properties.tags = tags;
+        Ok(())
+    }
+
+    /// Normalizes the kind and tags of an `Asset`.
+    ///
+    /// # Errors
+    /// + If the kind or a tag is rejected by its vocabulary.
+    /// The properties are left unchanged.
+    pub fn normalize_asset_properties(
+        &self,
+        properties: &mut AssetProperties,
+    ) -> Result<(), VocabularyError> {
+        let kind = self.normalize_asset_kind(properties.kind.as_ref())?;
+        let tags = self.normalize_tags(&properties.tags)?;
+        properties.kind = kind;
+        properties.tags = tags;
+        Ok(())
+    }
+
+    /// Migrates the kind and tags of a `Container` to their canonical forms.
+    /// Terms not in the vocabularies are kept.
+    ///
+    /// # Returns
+    /// Whether the properties were changed.
+    pub fn migrate_container_properties(&self, properties: &mut ContainerProperties) -> bool {
+        let kind = properties
+            .kind
+            .as_ref()
+            .map(|kind| self.container_kinds.normalize_lossy(kind));
+
+        let tags = migrate_tags(&self.tags, &properties.tags);
+        let changed = kind != properties.kind || tags != properties.tags;
+        properties.kind = kind;
+        properties.tags = tags;
+        changed
+    }
+
+    /// Migrates the kind and tags of an `Asset` to their canonical forms.
+    /// Terms not in the vocabularies are kept.
+    ///
+    /// # Returns
+    /// Whether the properties were changed.
+    pub fn migrate_asset_properties(&self, properties: &mut AssetProperties) -> bool {
+        let kind = properties
+            .kind
+            .as_ref()
+            .map(|kind| self.asset_kinds.normalize_lossy(kind));
+
+        let tags = migrate_tags(&self.tags, &properties.tags);
+        let changed = kind != properties.kind || tags != properties.tags;
+        properties.kind = kind;
+        properties.tags = tags;
+        changed
+    }
+}
+
+/// Normalizes tags to their canonical forms, keeping unknown tags.
+/// Duplicates resulting from normalization are removed.
+fn migrate_tags(vocabulary: &Vocabulary, tags: &Vec<String>) -> Vec<String> {
+    let mut migrated = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = vocabulary.normalize_lossy(tag);
+        if !migrated.contains(&tag) {
+            migrated.push(tag);
+        }
+    }
+
+    migrated
+}
+
+// *************
+// *** Error ***
+// *************
+
+/// A term rejected by a strict [`Vocabulary`].
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Error, PartialEq, Clone, Debug)]
+pub enum VocabularyError {
+    #[error("tag `{0}` is not in the vocabulary")]
+    UnknownTag(String),
+
+    #[error("Container kind `{0}` is not in the vocabulary")]
+    UnknownContainerKind(String),
+
+    #[error("Asset kind `{0}` is not in the vocabulary")]
+    UnknownAssetKind(String),
+}
+
+#[cfg(test)]
+#[path = "./vocabulary_test.rs"]
+mod vocabulary_test;
@@ -0,0 +1,99 @@
+use super::*;
+
+fn vocabulary(strict: bool) -> Vocabulary {
+    let mut vocabulary = Vocabulary::new();
+    vocabulary.strict = strict;
+    vocabulary.insert_term("control", vec!["ctrl".to_string(), "ctl".to_string()]);
+    vocabulary.insert_term("treated", Vec::new());
+    vocabulary
+}
+
+#[test]
+fn vocabulary_normalize_should_work() {
+    let loose = vocabulary(false);
+    assert_eq!(Some("control"), loose.canonical("Control"));
+    assert_eq!(Some("control"), loose.canonical("CTRL"));
+    assert_eq!(Some("treated"), loose.canonical(" treated "));
+    assert_eq!(None, loose.canonical("other"));
+
+    assert_eq!(Some("control".to_string()), loose.normalize("ctl"));
+    assert_eq!(
+        Some("other".to_string()),
+        loose.normalize("other"),
+        "unknown terms should be kept"
+    );
+
+    let strict = vocabulary(true);
+    assert_eq!(Some("control".to_string()), strict.normalize("Ctrl"));
+    assert_eq!(
+        None,
+        strict.normalize("other"),
+        "unknown terms should be rejected"
+    );
+    assert_eq!("other", strict.normalize_lossy("other"));
+
+    let empty = Vocabulary {
+        strict: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        Some("other".to_string()),
+        empty.normalize("other"),
+        "empty vocabularies should not constrain terms"
+    );
+}
+
+#[test]
+fn vocabularies_normalize_properties_should_work() {
+    // setup
+    let mut vocabularies = Vocabularies::default();
+    vocabularies.tags = vocabulary(false);
+    vocabularies.asset_kinds = Vocabulary::new();
+    vocabularies.asset_kinds.strict = true;
+    vocabularies
+        .asset_kinds
+        .insert_term("image", vec!["img".to_string()]);
+
+    let mut properties = AssetProperties::new();
+    properties.kind = Some("IMG".to_string());
+    properties.tags = vec!["Control".to_string(), "ctrl".to_string(), "new".to_string()];
+
+    // test
+    vocabularies
+        .normalize_asset_properties(&mut properties)
+        .expect("properties should normalize");
+
+    assert_eq!(Some("image".to_string()), properties.kind);
+    assert_eq!(
+        vec!["control".to_string(), "new".to_string()],
+        properties.tags,
+        "tags should be canonical and unique"
+    );
+
+    properties.kind = Some("video".to_string());
+    properties.tags.push("ctl".to_string());
+    assert_eq!(
+        Err(VocabularyError::UnknownAssetKind("video".to_string())),
+        vocabularies.normalize_asset_properties(&mut properties)
+    );
+
+    assert_eq!(
+        3,
+        properties.tags.len(),
+        "rejected properties should be unchanged"
+    );
+
+    // migrate
+    assert!(vocabularies.migrate_asset_properties(&mut properties));
+    assert_eq!(Some("video".to_string()), properties.kind);
+    assert_eq!(
+        vec!["control".to_string(), "new".to_string()],
+        properties.tags
+    );
+
+    assert!(
+        !vocabularies.migrate_asset_properties(&mut properties),
+        "canonical properties should not change"
+    );
+}
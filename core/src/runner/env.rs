@@ -1,2 +1,4 @@
 //! Environment variables for runner.
 pub static CONTAINER_ID_KEY: &str = "THOT_CONTAINER_ID";
+pub static SCRIPT_ID_KEY: &str = "THOT_SCRIPT_ID";
+pub static RUN_ID_KEY: &str = "THOT_RUN_ID";
@@ -5,5 +5,5 @@ pub mod resources;
 pub mod runner;
 
 // Re-exports
-pub use env::CONTAINER_ID_KEY;
+pub use env::{CONTAINER_ID_KEY, RUN_ID_KEY, SCRIPT_ID_KEY};
 pub use runner::{Runner, RunnerHooks, ScriptExecutionContext};
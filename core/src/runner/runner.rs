@@ -1,11 +1,12 @@
 //! Thot project runner.
 use super::resources::script_groups::{ScriptGroups, ScriptSet};
-use super::CONTAINER_ID_KEY;
+use super::{CONTAINER_ID_KEY, RUN_ID_KEY, SCRIPT_ID_KEY};
 use crate::error::{ResourceError, RunnerError};
 use crate::graph::ResourceTree;
 use crate::project::{Container, Script};
 use crate::types::ResourceId;
 use crate::{Error, Result};
+use has_id::HasId;
 use std::collections::HashSet;
 use std::result::Result as StdResult;
 use std::{process, str};
@@ -22,6 +23,10 @@ pub struct ScriptExecutionContext {
 
     /// [`ResourceId`] of the [`Container`] the script was executed on.
     pub container: ResourceId,
+
+    /// Id of the analysis run.
+    /// Each call to run a tree is a new run.
+    pub run: ResourceId,
 }
 
 // *************
@@ -31,6 +36,10 @@ pub struct ScriptExecutionContext {
 /// Retrieves a [`Script`] from its [`ResouceId`].
 pub type GetScriptHook = fn(&ResourceId) -> Result<Script>;
 
+/// Retrieves the [`ResourceId`]s of a [`Container`]'s [`Asset`](crate::project::Asset)s.
+/// Used to find the `Asset`s added by a script.
+pub type GetAssetsHook = fn(&ResourceId) -> Result<HashSet<ResourceId>>;
+
 /// Used to handle script errors during execution.
 ///
 /// # Arguments
@@ -70,6 +79,10 @@ pub struct RunnerHooks {
     /// Retrieve a [`Script`] from its [`ResourceId`].
     pub get_script: GetScriptHook,
 
+    /// Retrieve the `Asset`s of a [`Container`].
+    /// Required for `assets_added` to receive the added `Asset`s.
+    pub get_assets: Option<GetAssetsHook>,
+
     /// Run when a script errors.
     /// Should return `Ok` if evaluation should continue, or
     /// `Err` to defer to the `ignore_errors` state of the execution.
@@ -93,6 +106,7 @@ impl RunnerHooks {
     pub fn new(get_script: GetScriptHook) -> Self {
         Self {
             get_script,
+            get_assets: None,
             script_error: None,
             pre_script: None,
             post_script: None,
@@ -175,16 +189,19 @@ impl Runner {
         root: &ResourceId,
         tasks: Option<usize>,
     ) -> Result {
-        // recurse on children
-        let Some(children) = tree.children(root).cloned() else {
-            return Err(ResourceError::does_not_exist("`Node` children not found").into());
+        // children are evaluated before their parent
+        let Some(order) = tree.iter_post_order(root) else {
+            return Err(ResourceError::does_not_exist("`Node` not found").into());
         };
 
-        for child in children {
-            self.evaluate_tree(tree, &child, tasks)?;
+        let order = order.map(|node| node.id().clone()).collect::<Vec<_>>();
+
+        let run = ResourceId::new();
+        for container in order {
+            self.evaluate_container(tree, &container, &run, None, false, false)?;
         }
 
-        self.evaluate_container(tree, root, None, false, false)
+        Ok(())
     }
 
     /// Evaluates a single container.
@@ -192,6 +209,7 @@ impl Runner {
     /// # Arguments
     /// 1. The [`ContainerTree`].
     /// 1. The [`Container`] to evaluate.
+    /// 1. Id of the analysis run.
     /// 2. `None` to run all scripts set to `autorun`.
     ///     Otherwise a [`HashSet`] of the scripts to run.
     /// + `ignore_errors`: Whether to continue running on a script error.
@@ -201,6 +219,7 @@ impl Runner {
         &self,
         tree: &mut ContainerTree,
         container: &ResourceId,
+        run: &ResourceId,
         script_filter: Option<HashSet<ResourceId>>,
         ignore_errors: bool,
         verbose: bool,
@@ -231,7 +250,7 @@ impl Runner {
                 })
                 .collect();
 
-            self.run_scripts(scripts, &container, ignore_errors, verbose)?;
+            self.run_scripts(scripts, &container, run, ignore_errors, verbose)?;
         }
 
         Ok(())
@@ -262,6 +281,7 @@ impl Runner {
         &self,
         scripts: Vec<Script>,
         container: &Container,
+        run: &ResourceId,
         ignore_errors: bool,
         verbose: bool,
     ) -> Result {
@@ -269,16 +289,22 @@ impl Runner {
             let exec_ctx = ScriptExecutionContext {
                 script: script.rid.clone(),
                 container: container.rid.clone(),
+                run: run.clone(),
             };
 
             if let Some(pre_script) = self.hooks.pre_script {
                 pre_script(exec_ctx.clone(), verbose);
             }
 
-            let run_res = self.run_script(script, &container);
+            let prev_assets = self.container_assets(&container.rid);
+            let run_res = self.run_script(script, &container, run);
 
             if let Some(assets_added) = self.hooks.assets_added {
-                let assets = HashSet::new(); // @todo[1]: Collect `ResourceId`s of `Assets`.
+                let assets = match (prev_assets, self.container_assets(&container.rid)) {
+                    (Some(prev), Some(current)) => current.difference(&prev).cloned().collect(),
+                    _ => HashSet::new(),
+                };
+
                 assets_added(exec_ctx.clone(), assets, verbose);
             }
 
@@ -311,6 +337,21 @@ impl Runner {
         Ok(())
     }
 
+    /// Gets the `Asset`s of a `Container` using the `get_assets` hook.
+    ///
+    /// # Returns
+    /// `None` if the hook is not set or errored.
+    fn container_assets(&self, container: &ResourceId) -> Option<HashSet<ResourceId>> {
+        let get_assets = self.hooks.get_assets?;
+        match get_assets(container) {
+            Ok(assets) => Some(assets),
+            Err(err) => {
+                tracing::warn!(?err, "could not get `Container` `Asset`s");
+                None
+            }
+        }
+    }
+
     /// Runs an individual script.
     ///
     /// # Returns
@@ -319,7 +360,12 @@ impl Runner {
     /// # Errors
     /// + [`RunnerError`]: The script returned a `status` other than `0`.
     #[tracing::instrument(skip(self))]
-    fn run_script(&self, script: Script, container: &Container) -> Result<process::Output> {
+    fn run_script(
+        &self,
+        script: Script,
+        container: &Container,
+        run: &ResourceId,
+    ) -> Result<process::Output> {
         #[cfg(target_os = "windows")]
         let mut out = process::Command::new("cmd");
 
@@ -333,18 +379,22 @@ impl Runner {
             .arg(script.path.as_path())
             .args(&script.env.args)
             .env(CONTAINER_ID_KEY, container.rid.clone().to_string())
+            .env(SCRIPT_ID_KEY, script.rid.clone().to_string())
+            .env(RUN_ID_KEY, run.to_string())
             .envs(&script.env.env)
-            .output() {
-                Ok(out) => out,
-                Err(err) => {
-                    tracing::debug!(?err);
-                    return Err(RunnerError::CommandError{
-                        script: script.rid.clone(),
-                        container: container.rid.clone(),
-                        cmd: format!("{out:?}")
-                    }.into())
+            .output()
+        {
+            Ok(out) => out,
+            Err(err) => {
+                tracing::debug!(?err);
+                return Err(RunnerError::CommandError {
+                    script: script.rid.clone(),
+                    container: container.rid.clone(),
+                    cmd: format!("{out:?}"),
                 }
-            };
+                .into());
+            }
+        };
 
         if !out.status.success() {
             let stderr = str::from_utf8(out.stderr.as_slice())
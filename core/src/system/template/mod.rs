@@ -1,7 +1,7 @@
 //! Templates.
 pub mod project;
-pub mod tree;
+pub mod subtree;
 
 // Re-exports
 pub use project::Project;
-pub use tree::ResourceTree;
+pub use subtree::{InstanceOptions, Subtree};
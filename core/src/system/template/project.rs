@@ -1,13 +1,11 @@
 //! A [`Project`](crate::project::Project) template.
-use super::ResourceTree;
-use crate::graph::ResourceTree as GraphTree;
-use crate::project::Project as PrjProject;
+use crate::graph::ResourceTree;
+use crate::project::{Container, Project as PrjProject, Scripts};
 use crate::types::{ResourceId, UserPermissions};
 use chrono::prelude::*;
 use has_id::HasId;
 use has_id::HasIdSerde;
 use serde::{Deserialize, Serialize};
-use serde_json::{Result as SerdeResult, Value as JsValue};
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
@@ -60,36 +58,40 @@ pub struct Project {
     /// Project info.
     pub project: ProjectInfo,
 
-    // graph should be stored separately and loaded in
-    #[serde(skip)]
-    graph: JsValue,
+    /// `Container` tree of the data root.
+    pub graph: ResourceTree<Container>,
+
+    /// `Script`s of the project.
+    #[serde(default)]
+    pub scripts: Scripts,
 
     /// Projects derived from the template.
     pub children: HashSet<ResourceId>,
 }
 
 impl Project {
-    pub fn new<T>(
+    /// Creates a new template.
+    ///
+    /// # Arguments
+    /// 1. Name of the template.
+    /// 2. Project info.
+    /// 3. `Container` tree of the data root.
+    pub fn new(
+        name: impl Into<String>,
         project: ProjectInfo,
-        graph: GraphTree<T>,
-        name: String,
-        path: PathBuf,
-    ) -> SerdeResult<Self>
-    where
-        T: Serialize + HasId<Id = ResourceId> + HasIdSerde<'static, Id = ResourceId>,
-    {
-        let graph = ResourceTree::from_tree(graph)?;
-
-        Ok(Self {
+        graph: ResourceTree<Container>,
+    ) -> Self {
+        Self {
             rid: ResourceId::new(),
             creator: None,
             created: Utc::now(),
             permissions: HashMap::new(),
-            name,
+            name: name.into(),
             description: None,
             project,
             graph,
+            scripts: Scripts::default(),
             children: HashSet::new(),
-        })
+        }
     }
 }
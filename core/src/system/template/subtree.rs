@@ -0,0 +1,142 @@
+//! A [`Container`](crate::project::Container) subtree template.
+use crate::graph::ResourceTree;
+use crate::project::{Container, Metadata};
+use crate::types::ResourceId;
+use chrono::prelude::*;
+use has_id::{HasId, HasIdSerde};
+use serde::{Deserialize, Serialize};
+
+// ***************
+// *** Subtree ***
+// ***************
+
+/// Template of a `Container` subtree, used to repeatedly create the same structure.
+#[derive(HasId, Debug, Clone, Serialize, Deserialize, HasIdSerde)]
+pub struct Subtree {
+    #[id]
+    pub rid: ResourceId,
+
+    /// User id of the creator.
+    pub creator: Option<ResourceId>,
+    pub created: DateTime<Utc>,
+
+    pub name: String,
+    pub description: Option<String>,
+
+    /// `Container` tree, without `Asset`s.
+    pub graph: ResourceTree<Container>,
+}
+
+impl Subtree {
+    /// Creates a new template.
+    /// `Asset`s are removed from the graph.
+    pub fn new(name: impl Into<String>, mut graph: ResourceTree<Container>) -> Self {
+        let root = graph.root().clone();
+        graph
+            .visit_mut(&root, |node| {
+                node.assets.clear();
+                crate::graph::Visit::Continue
+            })
+            .expect("root not in graph");
+
+        Self {
+            rid: ResourceId::new(),
+            creator: None,
+            created: Utc::now(),
+            name: name.into(),
+            description: None,
+            graph,
+        }
+    }
+}
+
+// *****************
+// *** Instances ***
+// *****************
+
+/// Options for creating instances of a [`Subtree`].
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InstanceOptions {
+    /// Pattern for the name of each instance's root.
+    /// `{i}` is replaced by the instance's index,
+    /// `{i:0N}` by the index zero padded to a width of `N`.
+    /// e.g. `Session {i:02}`
+    pub name: String,
+
+    /// Number of instances to create.
+    pub count: usize,
+
+    /// Index of the first instance.
+    #[serde(default = "InstanceOptions::default_start")]
+    pub start: usize,
+
+    /// Metadata added to each instance's root, by instance.
+    /// Instances without an entry only use the template's metadata.
+    #[serde(default)]
+    pub metadata: Vec<Metadata>,
+}
+
+impl InstanceOptions {
+    pub fn new(name: impl Into<String>, count: usize) -> Self {
+        Self {
+            name: name.into(),
+            count,
+            start: Self::default_start(),
+            metadata: Vec::new(),
+        }
+    }
+
+    /// Returns the name of the instance at the given position.
+    ///
+    /// # Arguments
+    /// 1. Position of the instance, starting at 0.
+    pub fn instance_name(&self, instance: usize) -> String {
+        format_name(&self.name, self.start + instance)
+    }
+
+    /// Returns the additional metadata of the instance at the given position.
+    pub fn instance_metadata(&self, instance: usize) -> Option<&Metadata> {
+        self.metadata.get(instance)
+    }
+
+    fn default_start() -> usize {
+        1
+    }
+}
+
+/// Formats a name pattern for the given index.
+///
+/// # See also
+/// + [`InstanceOptions::name`]
+pub fn format_name(pattern: &str, index: usize) -> String {
+    let mut name = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+    while let Some(start) = rest.find("{i") {
+        name.push_str(&rest[..start]);
+        let tail = &rest[start + 2..];
+        let Some(end) = tail.find('}') else {
+            name.push_str(&rest[start..]);
+            return name;
+        };
+
+        match &tail[..end] {
+            "" => name.push_str(&index.to_string()),
+            spec => match spec
+                .strip_prefix(":0")
+                .and_then(|width| width.parse::<usize>().ok())
+            {
+                Some(width) => name.push_str(&format!("{index:0width$}")),
+                None => name.push_str(&rest[start..start + 2 + end + 1]),
+            },
+        }
+
+        rest = &tail[end + 1..];
+    }
+
+    name.push_str(rest);
+    name
+}
+
+#[cfg(test)]
+#[path = "./subtree_test.rs"]
+mod subtree_test;
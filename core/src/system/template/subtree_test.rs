@@ -0,0 +1,48 @@
+use super::*;
+use crate::project::Asset;
+use crate::types::ResourcePath;
+use std::path::PathBuf;
+
+#[test]
+fn format_name_should_work() {
+    assert_eq!("Session 03", format_name("Session {i:02}", 3));
+    assert_eq!("Session 3", format_name("Session {i}", 3));
+    assert_eq!("S12-012", format_name("S{i}-{i:03}", 12));
+    assert_eq!("Session", format_name("Session", 3));
+    assert_eq!("Session {j}", format_name("Session {j}", 3));
+    assert_eq!("Session {i:x}", format_name("Session {i:x}", 3));
+    assert_eq!("Session {i", format_name("Session {i", 3));
+}
+
+#[test]
+fn instance_options_should_start_at_one() {
+    let opts = InstanceOptions::new("Subject {i:02}", 2);
+    assert_eq!("Subject 01", opts.instance_name(0));
+    assert_eq!("Subject 02", opts.instance_name(1));
+    assert!(opts.instance_metadata(0).is_none());
+}
+
+#[test]
+fn subtree_new_should_remove_assets() {
+    let mut root = Container::new("root");
+    root.insert_asset(Asset::new(ResourcePath::Relative(PathBuf::from(
+        "data.csv",
+    ))));
+
+    let rid = root.rid.clone();
+    let mut child = Container::new("child");
+    child.insert_asset(Asset::new(ResourcePath::Relative(PathBuf::from(
+        "data.csv",
+    ))));
+
+    let mut graph = ResourceTree::new(root);
+    graph.insert(rid, child).unwrap();
+
+    let template = Subtree::new("template", graph);
+    assert_eq!(2, template.graph.nodes().len());
+    assert!(template
+        .graph
+        .nodes()
+        .values()
+        .all(|node| node.assets.is_empty()));
+}
@@ -0,0 +1,44 @@
+//! Commands related to analysis flags.
+use tauri::State;
+use thot_core::project::Flag;
+use thot_core::types::ResourceId;
+use thot_desktop_lib::error::{Error as LibError, Result as LibResult};
+use thot_local_database::client::Client as DbClient;
+use thot_local_database::command::AnalysisCommand;
+use thot_local_database::Result as DbResult;
+
+/// Retrieves the unresolved [`Flag`]s of the resources in a subtree, oldest first.
+#[tauri::command]
+pub fn get_flags(db: State<DbClient>, root: ResourceId) -> LibResult<Vec<Flag>> {
+    let res = db
+        .send(
+            AnalysisCommand::Flags {
+                root,
+                include_resolved: false,
+            }
+            .into(),
+        )
+        .expect("could not retrieve flags");
+
+    let res: DbResult<Vec<Flag>> =
+        serde_json::from_value(res).expect("could not convert result of `Flags` from JsValue");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))
+}
+
+/// Resolves a [`Flag`] as the active user.
+#[tauri::command]
+pub fn resolve_flag(
+    db: State<DbClient>,
+    resource: ResourceId,
+    flag: ResourceId,
+) -> LibResult<Flag> {
+    let res = db
+        .send(AnalysisCommand::Resolve { resource, flag }.into())
+        .expect("could not resolve flag");
+
+    let res: DbResult<Flag> =
+        serde_json::from_value(res).expect("could not convert result of `Resolve` from JsValue");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))
+}
@@ -3,11 +3,15 @@ use crate::error::Result;
 use std::collections::HashMap;
 use std::path::PathBuf;
 use tauri::State;
-use thot_core::project::{Asset, AssetProperties};
+use thot_core::project::{
+    Asset, AssetProperties, MetadataChange, ResolvedMetadata, SchemaViolation,
+};
 use thot_core::types::ResourceId;
+use thot_desktop_lib::error::{Error as LibError, Result as LibResult};
 use thot_local_database::client::Client as DbClient;
 use thot_local_database::command::asset::{AssetPropertiesUpdate, BulkUpdateAssetPropertiesArgs};
 use thot_local_database::command::AssetCommand;
+use thot_local_database::Result as DbResult;
 
 /// Gets `Asset`s.
 #[tauri::command]
@@ -22,18 +26,75 @@ pub fn get_assets(
     serde_json::from_value(assets).expect("could not convert result of `GetAssets` to `Vec<Asset>`")
 }
 
+/// Retrieves an `Asset`'s metadata resolved with that it inherits,
+/// or `None` if it is not loaded.
+#[tauri::command]
+pub fn get_asset_metadata(db: State<DbClient>, rid: ResourceId) -> Option<ResolvedMetadata> {
+    let metadata = db
+        .send(AssetCommand::ResolvedMetadata(rid).into())
+        .expect("could not retrieve `Asset` metadata");
+
+    serde_json::from_value(metadata)
+        .expect("could not convert `ResolvedMetadata` result to `ResolvedMetadata`")
+}
+
+/// Retrieves the history of changes to an `Asset`'s metadata, oldest first.
+#[tauri::command]
+pub fn get_asset_metadata_history(
+    db: State<DbClient>,
+    rid: ResourceId,
+) -> LibResult<Vec<MetadataChange>> {
+    let res = db
+        .send(AssetCommand::MetadataHistory(rid).into())
+        .expect("could not retrieve `Asset` metadata history");
+
+    let res: DbResult<Vec<MetadataChange>> = serde_json::from_value(res)
+        .expect("could not convert result of `MetadataHistory` from JsValue");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))
+}
+
+/// Searches `Asset`s within a subtree by text.
+/// Results are ordered by relevance.
+#[tauri::command]
+pub fn search_assets(
+    db: State<DbClient>,
+    root: ResourceId,
+    query: String,
+    limit: Option<usize>,
+) -> Vec<Asset> {
+    let assets = db
+        .send(AssetCommand::Search { root, query, limit }.into())
+        .expect("could not search `Asset`s");
+
+    serde_json::from_value(assets).expect("could not convert `Search` result to `Vec<Asset>`")
+}
+
 /// Update an `Asset`'s properties.
+///
+/// # Returns
+/// Violations of the metadata schema.
 #[tauri::command]
-pub fn update_asset_properties(db: State<DbClient>, rid: ResourceId, properties: String) -> Result {
+pub fn update_asset_properties(
+    db: State<DbClient>,
+    rid: ResourceId,
+    properties: String,
+) -> LibResult<Vec<SchemaViolation>> {
     let properties: AssetProperties = serde_json::from_str(&properties).unwrap();
-    db.send(
-        AssetCommand::UpdateProperties {
-            asset: rid,
-            properties,
-        }
-        .into(),
-    )?;
-    Ok(())
+    let res = db
+        .send(
+            AssetCommand::UpdateProperties {
+                asset: rid,
+                properties,
+            }
+            .into(),
+        )
+        .expect("could not update `Asset` properties");
+
+    let res: DbResult<Vec<SchemaViolation>> = serde_json::from_value(res)
+        .expect("could not convert result of `UpdateAssetProperties` from JsValue");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))
 }
 
 /// Remove an `Asset`.
@@ -4,7 +4,9 @@ use std::fs;
 use std::path::PathBuf;
 use tauri::State;
 use thot_core::project::container::ScriptMap;
-use thot_core::project::{Container, ContainerProperties};
+use thot_core::project::{
+    Container, ContainerProperties, MetadataChange, ResolvedMetadata, SchemaViolation,
+};
 use thot_core::types::ResourceId;
 use thot_desktop_lib::error::{Error as LibError, Result as LibResult};
 use thot_desktop_lib::types::AddAssetInfo;
@@ -32,7 +34,55 @@ pub fn get_container(db: State<DbClient>, rid: ResourceId) -> Option<Container>
         .expect("could not convert `GetContainer` result to `Container`")
 }
 
+/// Retrieves a [`Container`]'s metadata resolved with that it inherits,
+/// or `None` if it is not loaded.
+#[tauri::command]
+pub fn get_container_metadata(db: State<DbClient>, rid: ResourceId) -> Option<ResolvedMetadata> {
+    let metadata = db
+        .send(ContainerCommand::ResolvedMetadata(rid).into())
+        .expect("could not retrieve `Container` metadata");
+
+    serde_json::from_value(metadata)
+        .expect("could not convert `ResolvedMetadata` result to `ResolvedMetadata`")
+}
+
+/// Retrieves the history of changes to a [`Container`]'s metadata, oldest first.
+#[tauri::command]
+pub fn get_container_metadata_history(
+    db: State<DbClient>,
+    rid: ResourceId,
+) -> LibResult<Vec<MetadataChange>> {
+    let res = db
+        .send(ContainerCommand::MetadataHistory(rid).into())
+        .expect("could not retrieve `Container` metadata history");
+
+    let res: DbResult<Vec<MetadataChange>> = serde_json::from_value(res)
+        .expect("could not convert result of `MetadataHistory` from JsValue");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))
+}
+
+/// Searches [`Container`]s within a subtree by text.
+/// Results are ordered by relevance.
+#[tauri::command]
+pub fn search_containers(
+    db: State<DbClient>,
+    root: ResourceId,
+    query: String,
+    limit: Option<usize>,
+) -> Vec<Container> {
+    let containers = db
+        .send(ContainerCommand::Search { root, query, limit }.into())
+        .expect("could not search `Container`s");
+
+    serde_json::from_value(containers)
+        .expect("could not convert `Search` result to `Vec<Container>`")
+}
+
 /// Updates an existing [`Container`](LocalContainer)'s properties and persists changes to disk.
+///
+/// # Returns
+/// Violations of the metadata schema.
 #[tauri::command]
 pub fn update_container_properties(
     db: State<DbClient>,
@@ -40,7 +90,7 @@ pub fn update_container_properties(
     properties: String, // TODO Issue with deserializing `HashMap` of `metadata`. perform manually.
                         // See: https://github.com/tauri-apps/tauri/issues/6078
                         // properties: ContainerProperties,
-) -> LibResult {
+) -> LibResult<Vec<SchemaViolation>> {
     let properties: ContainerProperties = serde_json::from_str(&properties)
         .expect("could not deserialize into `ContainerProperties`");
 
@@ -48,7 +98,7 @@ pub fn update_container_properties(
         .send(ContainerCommand::UpdateProperties(UpdatePropertiesArgs { rid, properties }).into())
         .expect("could not update `Container` properties");
 
-    let res: DbResult = serde_json::from_value(res)
+    let res: DbResult<Vec<SchemaViolation>> = serde_json::from_value(res)
         .expect("could not convert result of `UpdateContainerProperties` from JsValue");
 
     res.map_err(|err| LibError::Database(format!("{err:?}")))
@@ -1,18 +1,18 @@
 //! Graph commands.
 use crate::error::{DesktopSettingsError, Result};
 use crate::state::AppState;
-use std::fs;
 use std::path::PathBuf;
 use tauri::State;
 use thot_core::error::{Error as CoreError, ResourceError};
 use thot_core::graph::ResourceTree;
 use thot_core::project::{Container as CoreContainer, Project};
+use thot_core::system::template::{InstanceOptions, Subtree as SubtreeTemplate};
 use thot_core::types::{Creator, ResourceId, UserId};
 use thot_desktop_lib::error::{Error as LibError, Result as LibResult};
-use thot_local::error::{ContainerError, Error as LocalError};
 use thot_local::project::resources::Container as LocalContainer;
 use thot_local_database::client::Client as DbClient;
-use thot_local_database::command::{ContainerCommand, GraphCommand, ProjectCommand};
+use thot_local_database::command::graph::{InstantiateTemplateArgs, NewChildArgs};
+use thot_local_database::command::{GraphCommand, ProjectCommand};
 use thot_local_database::Result as DbResult;
 
 type ContainerTree = ResourceTree<CoreContainer>;
@@ -116,19 +116,20 @@ pub fn load_project_graph(db: State<DbClient>, rid: ResourceId) -> LibResult<Con
 /// 2. `parent`: [`ResourceId`] of the parent [`Container`](LocalContainer).
 #[tracing::instrument(level = "debug", skip(db))]
 #[tauri::command]
-pub fn new_child(db: State<DbClient>, name: String, parent: ResourceId) -> Result {
-    let path = db.send(ContainerCommand::Path(parent).into()).unwrap();
-    let Some(mut path) = serde_json::from_value::<Option<PathBuf>>(path).unwrap() else {
-        panic!("could not get container path");
-    };
+pub fn new_child(
+    db: State<DbClient>,
+    name: String,
+    parent: ResourceId,
+) -> LibResult<CoreContainer> {
+    let child = db
+        .send(GraphCommand::NewChild(NewChildArgs { name, parent }).into())
+        .expect("could not create child");
 
-    path.push(name);
-    if path.exists() {
-        return Err(LocalError::ContainerError(ContainerError::InvalidChildPath(path)).into());
-    }
+    let child: DbResult<CoreContainer> = serde_json::from_value(child)
+        .expect("could not convert result of `NewChild` to `Container`");
 
-    fs::create_dir(path).unwrap();
-    Ok(())
+    let child = child.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(child)
 }
 
 /// Duplicates a [`Container`](LocalContainer) tree.
@@ -149,17 +150,130 @@ pub fn duplicate_container_tree(db: State<DbClient>, rid: ResourceId) -> LibResu
     Ok(dup)
 }
 
+/// Saves a [`Container`](LocalContainer) tree as a template.
+///
+/// # Arguments
+/// 1. Id of the root of the `Container` tree.
+/// 2. Name of the template.
+#[tracing::instrument(skip(db))]
+#[tauri::command]
+pub fn save_subtree_template(
+    db: State<DbClient>,
+    root: ResourceId,
+    name: String,
+) -> LibResult<SubtreeTemplate> {
+    let template = db
+        .send(GraphCommand::SaveTemplate { root, name }.into())
+        .expect("could not save template");
+
+    let template: DbResult<SubtreeTemplate> = serde_json::from_value(template)
+        .expect("could not convert result of `SaveTemplate` to `Subtree`");
+
+    let template = template.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(template)
+}
+
+/// Gets the saved [`Container`](LocalContainer) tree templates.
+#[tauri::command]
+pub fn get_subtree_templates(db: State<DbClient>) -> LibResult<Vec<SubtreeTemplate>> {
+    let templates = db
+        .send(GraphCommand::Templates.into())
+        .expect("could not get templates");
+
+    let templates: DbResult<Vec<SubtreeTemplate>> = serde_json::from_value(templates)
+        .expect("could not convert result of `Templates` to `Vec<Subtree>`");
+
+    let templates = templates.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(templates)
+}
+
+/// Creates instances of a [`Container`](LocalContainer) tree template.
+///
+/// # Arguments
+/// 1. Id of the template.
+/// 2. Id of the parent `Container`.
+/// 3. Instance options.
+///
+/// # Returns
+/// Created `Container` trees.
+#[tracing::instrument(skip(db))]
+#[tauri::command]
+pub fn instantiate_subtree_template(
+    db: State<DbClient>,
+    template: ResourceId,
+    parent: ResourceId,
+    options: InstanceOptions,
+) -> LibResult<Vec<ContainerTree>> {
+    let instances = db
+        .send(
+            GraphCommand::Instantiate(InstantiateTemplateArgs {
+                template,
+                parent,
+                options,
+            })
+            .into(),
+        )
+        .expect("could not instantiate template");
+
+    let instances: DbResult<Vec<ContainerTree>> = serde_json::from_value(instances)
+        .expect("could not convert result of `Instantiate` to `Container` trees");
+
+    let instances = instances.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(instances)
+}
+
 /// Removes a [`Container`](LocalContainer) tree.
 ///
 /// # Arguments
 /// 1. Id of the root of the `Container` tree to remove.
+#[tracing::instrument(skip(db))]
 #[tauri::command]
-pub fn remove_container_tree(db: State<DbClient>, rid: ResourceId) -> Result {
-    let path = db.send(ContainerCommand::Path(rid).into()).unwrap();
-    let Some(path) = serde_json::from_value::<Option<PathBuf>>(path).unwrap() else {
-        panic!("could not get container path");
-    };
+pub fn remove_container_tree(db: State<DbClient>, rid: ResourceId) -> LibResult {
+    let removed = db
+        .send(GraphCommand::Remove(rid).into())
+        .expect("could not remove graph");
+
+    let removed: DbResult<ContainerTree> = serde_json::from_value(removed)
+        .expect("could not convert result of `Remove` to `Container` tree");
+
+    removed.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(())
+}
+
+/// Moves a [`Container`](LocalContainer) tree to a new parent.
+///
+/// # Arguments
+/// 1. Id of the root of the `Container` tree to move.
+/// 2. Id of the new parent.
+#[tracing::instrument(skip(db))]
+#[tauri::command]
+pub fn move_container_tree(db: State<DbClient>, rid: ResourceId, parent: ResourceId) -> LibResult {
+    let res = db
+        .send(GraphCommand::Move { root: rid, parent }.into())
+        .expect("could not move graph");
+
+    let res: DbResult =
+        serde_json::from_value(res).expect("could not convert result of `Move` to `Result`");
+
+    res.map_err(|err| LibError::Database(format!("{err:?}")))?;
+    Ok(())
+}
+
+/// Moves a [`Container`](LocalContainer) to a new position among its siblings.
+///
+/// # Arguments
+/// 1. Id of the `Container`.
+/// 2. New position.
+#[tracing::instrument(skip(db))]
+#[tauri::command]
+pub fn reorder_container(db: State<DbClient>, rid: ResourceId, index: usize) -> LibResult {
+    let res = db
+        .send(GraphCommand::Reorder { root: rid, index }.into())
+        .expect("could not reorder graph");
+
+    let res: DbResult =
+        serde_json::from_value(res).expect("could not convert result of `Reorder` to `Result`");
 
-    trash::delete(path).unwrap();
+    res.map_err(|err| LibError::Database(format!("{err:?}")))?;
     Ok(())
 }
@@ -1,4 +1,5 @@
 //! Invokable commands from the front end.
+pub mod analysis;
 pub mod asset;
 pub mod authenticate;
 pub mod common;
@@ -10,6 +11,7 @@ pub mod settings;
 pub mod user;
 
 // Re-exports
+pub use analysis::*;
 pub use asset::*;
 pub use authenticate::*;
 pub use common::*;
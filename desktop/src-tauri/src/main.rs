@@ -93,6 +93,9 @@ fn main() {
             get_project,
             update_project,
             analyze,
+            // analysis
+            get_flags,
+            resolve_flag,
             // graph
             init_project_graph,
             load_project_graph,
@@ -102,17 +105,28 @@ fn main() {
             bulk_update_container_properties,
             bulk_update_container_script_associations,
             get_container,
+            get_container_metadata,
+            get_container_metadata_history,
             get_container_path,
             new_child,
             update_container_properties,
             update_container_script_associations,
             duplicate_container_tree,
+            save_subtree_template,
+            get_subtree_templates,
+            instantiate_subtree_template,
             remove_container_tree,
+            move_container_tree,
+            reorder_container,
+            search_containers,
             // asset
             bulk_update_asset_properties,
             get_assets,
+            get_asset_metadata,
+            get_asset_metadata_history,
             update_asset_properties,
             remove_asset,
+            search_assets,
             // script
             get_project_scripts,
             add_script,
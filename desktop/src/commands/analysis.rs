@@ -0,0 +1,20 @@
+//! Resources for [`analysis commands`](thot_desktop_tauri::commands::analysis).
+use serde::Serialize;
+use thot_core::types::ResourceId;
+
+/// Arguments for [`get_flags`](thot_desktop_tauri::commands::analysis::get_flags).
+#[derive(Serialize)]
+pub struct GetFlagsArgs {
+    /// Root `Container` of the subtree.
+    pub root: ResourceId,
+}
+
+/// Arguments for [`resolve_flag`](thot_desktop_tauri::commands::analysis::resolve_flag).
+#[derive(Serialize)]
+pub struct ResolveFlagArgs {
+    /// Resource the flag is attached to.
+    pub resource: ResourceId,
+
+    /// Flag to resolve.
+    pub flag: ResourceId,
+}
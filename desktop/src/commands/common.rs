@@ -21,6 +21,19 @@ pub struct PathBufArgs {
     pub path: PathBuf,
 }
 
+/// Arguments to search resources by text.
+#[derive(Serialize)]
+pub struct SearchArgs {
+    /// Root `Container` of the subtree to search.
+    pub root: ResourceId,
+
+    /// Search text.
+    pub query: String,
+
+    /// Maximum number of results.
+    pub limit: Option<usize>,
+}
+
 /// Bulk update resources.
 #[derive(Clone, Serialize)]
 pub struct BulkUpdateResourcePropertiesArgs {
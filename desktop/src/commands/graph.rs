@@ -1,6 +1,7 @@
 //! Resources for [`graph commands`](thot_desktop_tauri::commands::graph).
 use serde::Serialize;
 use std::path::PathBuf;
+use thot_core::system::template::InstanceOptions;
 use thot_core::types::ResourceId;
 
 /// Arguments for [`init_project_graph`](thot_desktop_tauri::commands::graph::init_project_graph).
@@ -12,3 +13,34 @@ pub struct InitProjectGraphArgs {
     /// Project id.
     pub project: ResourceId,
 }
+
+/// Arguments for [`save_subtree_template`](thot_desktop_tauri::commands::graph::save_subtree_template).
+#[derive(Serialize)]
+pub struct SaveSubtreeTemplateArgs {
+    /// Root of the subtree.
+    pub root: ResourceId,
+
+    /// Name of the template.
+    pub name: String,
+}
+
+/// Arguments for [`instantiate_subtree_template`](thot_desktop_tauri::commands::graph::instantiate_subtree_template).
+#[derive(Serialize)]
+pub struct InstantiateSubtreeTemplateArgs {
+    /// Template id.
+    pub template: ResourceId,
+
+    /// Parent `Container`.
+    pub parent: ResourceId,
+    pub options: InstanceOptions,
+}
+
+/// Arguments for [`reorder_container`](thot_desktop_tauri::commands::graph::reorder_container).
+#[derive(Serialize)]
+pub struct ReorderContainerArgs {
+    /// `Container` to move.
+    pub rid: ResourceId,
+
+    /// New index among its siblings.
+    pub index: usize,
+}
@@ -1,4 +1,5 @@
 //! Resources for [`commands`](thot_desktop_tauri::commands).
+pub mod analysis;
 pub mod asset;
 pub mod authenticate;
 pub mod common;
@@ -10,6 +10,7 @@ pub use thot_core::project::Metadata;
 pub struct TagsAction {
     pub insert: Vec<String>,
     pub remove: Vec<String>,
+    pub rename: Vec<(String, String)>,
 }
 
 #[derive(Serialize, Clone, Default, Debug)]
@@ -8,11 +8,14 @@ use super::{
     GraphStateAction, GraphStateReducer,
 };
 use crate::app::{AppStateAction, AppStateReducer, ProjectsStateAction, ProjectsStateReducer};
+use crate::commands::analysis::GetFlagsArgs;
 use crate::commands::container::UpdatePropertiesArgs as UpdateContainerPropertiesArgs;
+use crate::common::invoke;
 use crate::constants::MESSAGE_TIMEOUT;
 use crate::hooks::{use_load_project_scripts, use_project_graph};
 use crate::routes::Route;
 use futures::stream::StreamExt;
+use thot_core::project::Flag;
 use thot_core::types::ResourceId;
 use thot_local_database::event::{
     Analysis as AnalysisUpdate, Asset as AssetUpdate, Container as ContainerUpdate,
@@ -129,6 +132,11 @@ pub fn project_canvas(props: &ProjectCanvasProps) -> HtmlResult {
                                     name,
                                 });
                             }
+
+                            GraphUpdate::Reordered { root, index } => {
+                                graph_state
+                                    .dispatch(GraphStateAction::ReorderContainer { root, index });
+                            }
                         },
 
                         ProjectUpdate::Asset(update) => match update {
@@ -160,6 +168,13 @@ pub fn project_canvas(props: &ProjectCanvasProps) -> HtmlResult {
                                 graph_state
                                     .dispatch(GraphStateAction::UpdateAssetPath { asset, path });
                             }
+
+                            AssetUpdate::Properties { asset, properties } => {
+                                graph_state.dispatch(GraphStateAction::UpdateAssetProperties {
+                                    asset,
+                                    properties,
+                                });
+                            }
                         },
 
                         ProjectUpdate::Script(update) => match update {
@@ -189,7 +204,8 @@ pub fn project_canvas(props: &ProjectCanvasProps) -> HtmlResult {
                         },
 
                         ProjectUpdate::Analysis(update) => match update {
-                            AnalysisUpdate::Flag { resource, message } => {
+                            AnalysisUpdate::Flag(flag) => {
+                                let resource = flag.resource.clone();
                                 let name = if let Some(container) = graph_state.graph.get(&resource)
                                 {
                                     container.properties.name.clone()
@@ -206,16 +222,23 @@ pub fn project_canvas(props: &ProjectCanvasProps) -> HtmlResult {
                                     return;
                                 };
 
-                                canvas_state.dispatch(CanvasStateAction::AddFlag {
-                                    resource,
-                                    message: message.clone(),
-                                });
+                                let message = flag.message.clone();
+                                canvas_state.dispatch(CanvasStateAction::AddFlag(flag));
 
                                 let mut msg =
                                     Message::warning(format!("Resource `{name}` was flagged"));
                                 msg.set_details(message);
                                 app_state.dispatch(AppStateAction::AddMessage(msg));
                             }
+
+                            AnalysisUpdate::Resolved(flag) => {
+                                canvas_state
+                                    .dispatch(CanvasStateAction::RemoveFlags(vec![flag.rid]));
+                            }
+
+                            AnalysisUpdate::Cleared(flags) => {
+                                canvas_state.dispatch(CanvasStateAction::RemoveFlags(flags));
+                            }
                         },
                     }
                 }
@@ -249,6 +272,27 @@ pub fn project_canvas(props: &ProjectCanvasProps) -> HtmlResult {
         });
     }
 
+    {
+        let app_state = app_state.clone();
+        let canvas_state = canvas_state.clone();
+        let root = graph_state.graph.root().clone();
+
+        use_effect_with(root, move |root| {
+            let root = root.clone();
+            spawn_local(async move {
+                match invoke::<Vec<Flag>>("get_flags", GetFlagsArgs { root }).await {
+                    Ok(flags) => canvas_state.dispatch(CanvasStateAction::SetFlags(flags)),
+                    Err(err) => {
+                        tracing::debug!(?err);
+                        let mut msg = Message::error("Could not load flags");
+                        msg.set_details(format!("{err:?}"));
+                        app_state.dispatch(AppStateAction::AddMessage(msg));
+                    }
+                }
+            });
+        });
+    }
+
     let onkeydown = {
         let drawers_visible_state = drawers_visible_state.clone();
         Callback::from(move |e: KeyboardEvent| {
@@ -2,6 +2,7 @@
 use super::details_bar::DetailsBarWidget;
 use std::collections::HashSet;
 use std::rc::Rc;
+use thot_core::project::Flag;
 use thot_core::types::{ResourceId, ResourceMap};
 use thot_ui::types::ContainerPreview;
 use yew::prelude::*;
@@ -54,20 +55,14 @@ pub enum CanvasStateAction {
     /// Toggle canvas drawers visibility.
     ToggleDrawers,
 
-    /// Adds a flag to a resource.
-    AddFlag {
-        resource: ResourceId,
-        message: String,
-    },
+    /// Sets the unresolved flags.
+    SetFlags(Vec<Flag>),
 
-    /// Removes the flag at the given index for the resource.
-    RemoveFlag { resource: ResourceId, index: usize },
+    /// Adds a flag to its resource.
+    AddFlag(Flag),
 
-    /// Clears all the flags for the given resource.
-    ClearResourceFlags(ResourceId),
-
-    /// Clears all flags.
-    ClearFlags,
+    /// Removes flags.
+    RemoveFlags(Vec<ResourceId>),
 }
 
 #[derive(Clone, PartialEq)]
@@ -87,8 +82,8 @@ pub struct CanvasState {
     /// If canvas drawers are visible.
     pub drawers_visible: bool,
 
-    /// Flag messages for each resource.
-    pub flags: ResourceMap<Vec<String>>,
+    /// Unresolved flags for each resource.
+    pub flags: ResourceMap<Vec<Flag>>,
 
     /// `Container` tree visibility state.
     /// Key indicates the root of the hidden tree.
@@ -131,6 +126,18 @@ impl CanvasState {
         self.resource_types.remove(rid);
     }
 
+    /// Adds an unresolved flag to its resource.
+    fn add_flag(&mut self, flag: Flag) {
+        if flag.is_resolved() {
+            return;
+        }
+
+        self.flags
+            .entry(flag.resource.clone())
+            .or_insert(Vec::new())
+            .push(flag);
+    }
+
     #[tracing::instrument(skip(self))]
     fn details_bar_widget_from_selected(&self) -> Option<DetailsBarWidget> {
         match self.selected.len() {
@@ -242,23 +249,25 @@ impl Reducible for CanvasState {
                 current.drawers_visible = !current.drawers_visible;
             }
 
-            CanvasStateAction::AddFlag { resource, message } => {
-                let resource_flags = current.flags.entry(resource).or_insert(Vec::new());
-                resource_flags.push(message);
-            }
-
-            CanvasStateAction::RemoveFlag { resource, index } => {
-                if let Some(resource_flags) = current.flags.get_mut(&resource) {
-                    resource_flags.remove(index);
+            CanvasStateAction::SetFlags(flags) => {
+                current.flags.clear();
+                for flag in flags {
+                    current.add_flag(flag);
                 }
             }
 
-            CanvasStateAction::ClearResourceFlags(resource) => {
-                current.flags.remove(&resource);
+            CanvasStateAction::AddFlag(flag) => {
+                current.add_flag(flag);
             }
 
-            CanvasStateAction::ClearFlags => {
-                current.flags.clear();
+            CanvasStateAction::RemoveFlags(flags) => {
+                for resource_flags in current.flags.values_mut() {
+                    resource_flags.retain(|flag| !flags.contains(&flag.rid));
+                }
+
+                current
+                    .flags
+                    .retain(|_, resource_flags| !resource_flags.is_empty());
             }
         }
 
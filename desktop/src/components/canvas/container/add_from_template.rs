@@ -0,0 +1,196 @@
+//! Add `Container` trees from a subtree template.
+use crate::app::{AppStateAction, AppStateReducer};
+use crate::commands::common::EmptyArgs;
+use crate::commands::graph::InstantiateSubtreeTemplateArgs;
+use crate::common::invoke;
+use crate::components::canvas::{GraphStateAction, GraphStateReducer};
+use thot_core::graph::ResourceTree;
+use thot_core::project::Container as CoreContainer;
+use thot_core::system::template::{InstanceOptions, Subtree};
+use thot_core::types::ResourceId;
+use thot_ui::types::Message;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+type Graph = ResourceTree<CoreContainer>;
+
+/// Props for [`AddFromTemplate`].
+#[derive(Properties, PartialEq)]
+pub struct AddFromTemplateProps {
+    /// `Container` to add the instances to.
+    pub parent: ResourceId,
+
+    #[prop_or_default]
+    pub onsuccess: Option<Callback<()>>,
+}
+
+/// Creates instances of a subtree template.
+#[function_component(AddFromTemplate)]
+pub fn add_from_template(props: &AddFromTemplateProps) -> Html {
+    let app_state = use_context::<AppStateReducer>().expect("`AppStateReducer` context not found");
+    let graph_state =
+        use_context::<GraphStateReducer>().expect("`GraphStateReducer` context not found");
+
+    let templates = use_state(|| None);
+    let template_ref = use_node_ref();
+    let name_ref = use_node_ref();
+    let count_ref = use_node_ref();
+    let start_ref = use_node_ref();
+
+    {
+        let app_state = app_state.clone();
+        let templates = templates.clone();
+
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match invoke::<Vec<Subtree>>("get_subtree_templates", EmptyArgs {}).await {
+                    Ok(loaded) => templates.set(Some(loaded)),
+                    Err(err) => {
+                        web_sys::console::error_1(&format!("{err:?}").into());
+                        app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                            "Could not load templates",
+                        )));
+                    }
+                }
+            });
+        });
+    }
+
+    let onsubmit = {
+        let app_state = app_state.clone();
+        let graph_state = graph_state.clone();
+        let parent = props.parent.clone();
+        let onsuccess = props.onsuccess.clone();
+        let template_ref = template_ref.clone();
+        let name_ref = name_ref.clone();
+        let count_ref = count_ref.clone();
+        let start_ref = start_ref.clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let template = template_ref
+                .cast::<web_sys::HtmlSelectElement>()
+                .expect("could not cast element to select")
+                .value();
+
+            let Ok(template) = template.parse::<ResourceId>() else {
+                app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                    "Select a template",
+                )));
+                return;
+            };
+
+            let input_value = |node: &NodeRef| {
+                node.cast::<web_sys::HtmlInputElement>()
+                    .expect("could not cast element to input")
+                    .value()
+            };
+
+            let (Ok(count), Ok(start)) = (
+                input_value(&count_ref).parse::<usize>(),
+                input_value(&start_ref).parse::<usize>(),
+            ) else {
+                app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                    "Invalid count or start",
+                )));
+                return;
+            };
+
+            let mut options = InstanceOptions::new(input_value(&name_ref), count);
+            options.start = start;
+
+            let app_state = app_state.clone();
+            let graph_state = graph_state.clone();
+            let parent = parent.clone();
+            let onsuccess = onsuccess.clone();
+            spawn_local(async move {
+                let instances = invoke::<Vec<Graph>>(
+                    "instantiate_subtree_template",
+                    InstantiateSubtreeTemplateArgs {
+                        template,
+                        parent: parent.clone(),
+                        options,
+                    },
+                )
+                .await;
+
+                let instances = match instances {
+                    Ok(instances) => instances,
+                    Err(err) => {
+                        web_sys::console::error_1(&format!("{err:?}").into());
+                        app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                            "Could not add from template",
+                        )));
+                        return;
+                    }
+                };
+
+                let mut graph = graph_state.graph.clone();
+                for instance in instances {
+                    if graph.insert_tree(&parent, instance).is_err() {
+                        app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                            "Could not add from template",
+                        )));
+                        return;
+                    }
+                }
+
+                graph_state.dispatch(GraphStateAction::SetGraph(graph));
+                if let Some(onsuccess) = onsuccess {
+                    onsuccess.emit(());
+                }
+            });
+        })
+    };
+
+    let Some(templates) = templates.as_ref() else {
+        return html! { { "Loading" } };
+    };
+
+    if templates.is_empty() {
+        return html! { { "No templates saved" } };
+    }
+
+    html! {
+        <div class={classes!("thot-ui-add-from-template")}>
+            <form {onsubmit}>
+                <div>
+                    <label>
+                        { "Template" }
+                        <select ref={template_ref}>
+                            { templates
+                                .iter()
+                                .map(|template| html! {
+                                    <option value={template.rid.to_string()}>
+                                        { &template.name }
+                                    </option>
+                                })
+                                .collect::<Html>()
+                            }
+                        </select>
+                    </label>
+                </div>
+                <div>
+                    <label>
+                        { "Name" }
+                        <input ref={name_ref} value={"Session {i:02}"} />
+                    </label>
+                </div>
+                <div>
+                    <label>
+                        { "Count" }
+                        <input ref={count_ref} type={"number"} min={"1"} value={"1"} />
+                    </label>
+                    <label>
+                        { "Start" }
+                        <input ref={start_ref} type={"number"} min={"0"} value={"1"} />
+                    </label>
+                </div>
+                <div>
+                    <button>{ "Add" }</button>
+                </div>
+            </form>
+        </div>
+    }
+}
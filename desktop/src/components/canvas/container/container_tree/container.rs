@@ -4,8 +4,10 @@ use crate::app::ShadowBox;
 use crate::app::{AppStateAction, AppStateReducer, ProjectsStateReducer};
 use crate::commands::common::{PathBufArgs, ResourceIdArgs};
 use crate::commands::container::AddAssetWindowsArgs;
+use crate::commands::graph::SaveSubtreeTemplateArgs;
 use crate::common::invoke;
 use crate::components::canvas::asset::CreateAssets;
+use crate::components::canvas::container::AddFromTemplate;
 use crate::components::canvas::selection_action::{selection_action, SelectionAction};
 use crate::components::canvas::{
     CanvasStateAction, CanvasStateReducer, GraphStateAction, GraphStateReducer,
@@ -15,6 +17,7 @@ use crate::routes::Route;
 use std::path::PathBuf;
 use thot_core::graph::ResourceTree;
 use thot_core::project::{Asset as CoreAsset, Container as CoreContainer};
+use thot_core::system::template::Subtree;
 use thot_core::types::{ResourceId, ResourceMap};
 use thot_ui::types::Message;
 use thot_ui::widgets::container::container_tree::{
@@ -61,6 +64,7 @@ pub fn container(props: &ContainerProps) -> HtmlResult {
 
     let navigator = use_navigator().expect("navigator not found");
     let show_create_assets = use_state(|| false);
+    let show_add_from_template = use_state(|| false);
     let selected = canvas_state.selected.contains(&props.rid);
     let multiple_selected = canvas_state.selected.len() > 1;
 
@@ -137,11 +141,48 @@ pub fn container(props: &ContainerProps) -> HtmlResult {
         let graph_state = graph_state.clone();
         let rid = props.rid.clone();
         let show_create_assets = show_create_assets.clone();
+        let show_add_from_template = show_add_from_template.clone();
 
         Callback::from(move |event: ContainerMenuEvent| {
             let rid = rid.clone();
             match event {
                 ContainerMenuEvent::AddAssets => show_create_assets.set(true),
+                ContainerMenuEvent::AddFromTemplate => show_add_from_template.set(true),
+
+                ContainerMenuEvent::SaveTemplate => {
+                    let app_state = app_state.clone();
+                    let name = graph_state
+                        .graph
+                        .get(&rid)
+                        .expect("`Container` not found")
+                        .properties
+                        .name
+                        .clone();
+
+                    spawn_local(async move {
+                        match invoke::<Subtree>(
+                            "save_subtree_template",
+                            SaveSubtreeTemplateArgs { root: rid, name },
+                        )
+                        .await
+                        {
+                            Ok(template) => {
+                                app_state.dispatch(AppStateAction::AddMessageWithTimeout(
+                                    Message::success(format!("Saved template `{}`", template.name)),
+                                    MESSAGE_TIMEOUT,
+                                    app_state.clone(),
+                                ))
+                            }
+
+                            Err(err) => {
+                                web_sys::console::error_1(&format!("{err:?}").into());
+                                app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                                    "Could not save template",
+                                )));
+                            }
+                        }
+                    });
+                }
 
                 ContainerMenuEvent::OpenFolder => {
                     let app_state = app_state.clone();
@@ -319,6 +360,22 @@ pub fn container(props: &ContainerProps) -> HtmlResult {
         })
     };
 
+    let close_add_from_template = {
+        let show_add_from_template = show_add_from_template.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            show_add_from_template.set(false);
+        })
+    };
+
+    let onadd_from_template = {
+        let show_add_from_template = show_add_from_template.clone();
+
+        Callback::from(move |_: ()| {
+            show_add_from_template.set(false);
+        })
+    };
+
     let onadd_assets = {
         let show_create_assets = show_create_assets.clone();
 
@@ -461,6 +518,16 @@ pub fn container(props: &ContainerProps) -> HtmlResult {
                     onsuccess={onadd_assets} />
             </ShadowBox>
         }
+        if *show_add_from_template {
+            <ShadowBox
+                title={format!("Add to {container_name} from template")}
+                onclose={close_add_from_template}>
+
+                <AddFromTemplate
+                    parent={container.rid.clone()}
+                    onsuccess={onadd_from_template} />
+            </ShadowBox>
+        }
         </>
     })
 }
@@ -2,6 +2,7 @@
 use super::Container as ContainerUi;
 use crate::app::{AppStateAction, AppStateReducer, AuthStateReducer, ShadowBox};
 use crate::commands::container::{NewChildArgs, UpdatePropertiesArgs};
+use crate::commands::graph::ReorderContainerArgs;
 use crate::common::invoke;
 use crate::components::canvas::{
     CanvasStateAction, CanvasStateReducer, GraphStateAction, GraphStateReducer,
@@ -78,6 +79,8 @@ fn new_child_name(props: &NewChildNameProps) -> Html {
 // *** Container Tree ***
 // **********************
 
+/// Drag data type used to reorder `Container`s.
+const CONTAINER_DRAG_TYPE: &str = "application/x-thot-container";
 const CONNECTOR_CLASS: &str = "container-tree-node-connector";
 const VISIBILITY_CONTROL_CLASS: &str = "container-tree-visibility-control";
 const VISIBILITY_CONTROL_SIZE: u8 = 20;
@@ -141,7 +144,7 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
 
             spawn_local(async move {
                 // create child
-                let Ok(_) = invoke::<()>(
+                let Ok(_) = invoke::<Container>(
                     "new_child",
                     NewChildArgs {
                         name,
@@ -159,6 +162,103 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
         })
     };
 
+    // ---------------
+    // --- reorder ---
+    // ---------------
+
+    let ondragstart = Callback::from(move |e: DragEvent| {
+        let Some(target) = e.target_dyn_into::<web_sys::Element>() else {
+            return;
+        };
+
+        let Some(rid) = target.get_attribute("data-rid") else {
+            return;
+        };
+
+        e.stop_propagation();
+        let data = e.data_transfer().expect("could not get drag data");
+        data.set_data(CONTAINER_DRAG_TYPE, &rid)
+            .expect("could not set drag data");
+    });
+
+    let ondragover = Callback::from(move |e: DragEvent| {
+        let Some(data) = e.data_transfer() else {
+            return;
+        };
+
+        if data.types().includes(&CONTAINER_DRAG_TYPE.into(), 0) {
+            e.prevent_default();
+        }
+    });
+
+    let ondrop = {
+        let app_state = app_state.clone();
+        let graph_state = graph_state.clone();
+        let children_ref = children_ref.clone();
+        let root = props.root.clone();
+
+        Callback::from(move |e: DragEvent| {
+            let Some(data) = e.data_transfer() else {
+                return;
+            };
+
+            let Ok(Ok(rid)) = data
+                .get_data(CONTAINER_DRAG_TYPE)
+                .map(|rid| ResourceId::from_str(&rid))
+            else {
+                return;
+            };
+
+            let children = graph_state
+                .graph
+                .children(&root)
+                .expect("`Container` children not found");
+
+            if !children.contains(&rid) {
+                return;
+            }
+
+            e.prevent_default();
+            e.stop_propagation();
+
+            let children_elm = children_ref
+                .cast::<web_sys::Element>()
+                .expect("could not cast children node to element");
+
+            let Some(target) = e.target_dyn_into::<web_sys::Element>() else {
+                return;
+            };
+
+            let Some(target) = sibling_of(&children_elm, target) else {
+                return;
+            };
+
+            let Some(index) = target
+                .get_attribute("data-rid")
+                .and_then(|target| ResourceId::from_str(&target).ok())
+                .and_then(|target| children.get_index_of(&target))
+            else {
+                return;
+            };
+
+            if children.get_index_of(&rid) == Some(index) {
+                return;
+            }
+
+            let app_state = app_state.clone();
+            spawn_local(async move {
+                if let Err(err) =
+                    invoke::<()>("reorder_container", ReorderContainerArgs { rid, index }).await
+                {
+                    web_sys::console::error_1(&format!("{err:?}").into());
+                    app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                        "Could not reorder `Container`",
+                    )));
+                }
+            });
+        })
+    };
+
     // ----------
     // --- ui ---
     // ----------
@@ -209,7 +309,10 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
 
     let container_fallback = html! { <Loading text={"Loading container"} /> };
     Ok(html! {
-        <div class={classes!("container-tree")}>
+        <div class={classes!("container-tree")}
+            data-rid={props.root.clone()}
+            draggable={"true"}>
+
             <Suspense fallback={container_fallback}>
                 <svg ref={connectors_ref}
                     class="container-tree-node-connectors">
@@ -234,7 +337,12 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
                     rid={props.root.clone()}
                     {onadd_child} />
 
-                <div ref={children_ref} class={classes!("children")}>
+                <div ref={children_ref}
+                    class={classes!("children")}
+                    {ondragstart}
+                    {ondragover}
+                    {ondrop}>
+
                     { graph_state.graph
                         .children(&props.root)
                         .expect("`Container` children not found")
@@ -244,7 +352,8 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
                                 <ContainerTree root={rid.clone()} />
                             } else {
                                 <div class={classes!("child-node-marker")}
-                                    data-rid={rid.clone()}>
+                                    data-rid={rid.clone()}
+                                    draggable={"true"}>
 
                                     { &graph_state.graph.get(&rid)
                                         .expect("child `Container` not found")
@@ -272,6 +381,19 @@ pub fn container_tree(props: &ContainerTreeProps) -> HtmlResult {
 // *** helpers ***
 // ***************
 
+/// Returns the direct child of `parent` that contains `elm`.
+fn sibling_of(parent: &web_sys::Element, elm: web_sys::Element) -> Option<web_sys::Element> {
+    let mut elm = elm;
+    loop {
+        let next = elm.parent_element()?;
+        if &next == parent {
+            return Some(elm);
+        }
+
+        elm = next;
+    }
+}
+
 /// Creates connectors between `Container` nodes in a tree.
 fn create_connectors(
     root: NodeRef,
@@ -183,8 +183,6 @@ pub fn container_tree_controller() -> Html {
             let analysis_state = analysis_state.clone();
             let project_id = canvas_state.project.clone();
 
-            canvas_state.dispatch(CanvasStateAction::ClearFlags);
-
             spawn_local(async move {
                 let root = graph_state.graph.root();
 
@@ -253,19 +251,6 @@ pub fn container_tree_controller() -> Html {
                 .expect("a container should be selected")
                 .clone();
 
-            if let Some(descendants) = graph_state.graph.descendants(&selected_rid) {
-                for descendant in descendants {
-                    let descendant = graph_state.graph.get(&descendant).unwrap();
-                    for asset in descendant.assets.keys() {
-                        canvas_state.dispatch(CanvasStateAction::ClearResourceFlags(asset.clone()));
-                    }
-
-                    canvas_state.dispatch(CanvasStateAction::ClearResourceFlags(
-                        descendant.rid.clone(),
-                    ));
-                }
-            }
-
             spawn_local(async move {
                 let root = selected_rid;
 
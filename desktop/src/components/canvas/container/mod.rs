@@ -1,5 +1,7 @@
 //! Container related components.
+pub mod add_from_template;
 pub mod container_tree;
 
 // Re-exports
+pub use add_from_template::AddFromTemplate;
 pub use container_tree::ContainerTreeController;
@@ -1,10 +1,14 @@
 //! Asset editor.
+use super::inherited_metadata::InheritedMetadata;
+use super::metadata_history::use_metadata_history;
+use super::schema_violations_message;
 use crate::app::{AppStateAction, AppStateReducer};
 use crate::commands::asset::UpdatePropertiesStringArgs;
 use crate::common::invoke;
+use crate::components::canvas::canvas_state::ResourceType;
 use crate::components::canvas::{GraphStateAction, GraphStateReducer};
-use crate::hooks::use_asset;
-use thot_core::project::AssetProperties;
+use crate::hooks::{use_asset, use_canvas_project, use_project_schemas, use_project_vocabularies};
+use thot_core::project::{AssetProperties, SchemaViolation};
 use thot_core::types::ResourceId;
 use thot_ui::types::Message;
 use thot_ui::widgets::asset::AssetEditor as AssetEditorUi;
@@ -23,18 +27,42 @@ pub fn asset_editor(props: &AssetEditorProps) -> Html {
     let graph_state =
         use_context::<GraphStateReducer>().expect("`GraphStateReducer` context not found");
     let asset = use_asset(&props.rid);
+    let project = use_canvas_project();
+    let vocabularies = use_project_vocabularies(&project);
+    let schemas = use_project_schemas(&project);
+    let metadata_history = use_metadata_history(&props.rid, ResourceType::Asset);
+    let tag_suggestions = vocabularies
+        .tags
+        .canonical_terms()
+        .into_iter()
+        .map(|term| term.to_string())
+        .collect::<Vec<_>>();
+
+    let schema = asset
+        .properties
+        .kind
+        .as_ref()
+        .and_then(|kind| schemas.kinds.get(kind))
+        .cloned();
 
     let onchange_properties = {
         let asset = asset.clone();
         let app_state = app_state.clone();
         let graph_state = graph_state.clone();
 
-        Callback::from(move |properties: AssetProperties| {
+        Callback::from(move |mut properties: AssetProperties| {
+            if let Err(err) = vocabularies.normalize_asset_properties(&mut properties) {
+                let mut msg = Message::error("Could not save resource");
+                msg.set_details(err.to_string());
+                app_state.dispatch(AppStateAction::AddMessage(msg));
+                return;
+            }
+
             let asset = asset.clone();
             let app_state = app_state.clone();
             let graph_state = graph_state.clone();
             spawn_local(async move {
-                let Ok(_) = invoke::<()>(
+                let violations = match invoke::<Vec<SchemaViolation>>(
                     "update_asset_properties",
                     &UpdatePropertiesStringArgs {
                         rid: asset.rid.clone(),
@@ -42,14 +70,24 @@ pub fn asset_editor(props: &AssetEditorProps) -> Html {
                     },
                 )
                 .await
-                else {
-                    app_state.dispatch(AppStateAction::AddMessage(Message::success(
-                        "Could not save resource",
-                    )));
+                {
+                    Ok(violations) => violations,
+                    Err(err) => {
+                        tracing::debug!(?err);
+                        app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                            "Could not save resource",
+                        )));
 
-                    return;
+                        return;
+                    }
                 };
 
+                if !violations.is_empty() {
+                    app_state.dispatch(AppStateAction::AddMessage(schema_violations_message(
+                        &violations,
+                    )));
+                }
+
                 let mut asset = (*asset).clone();
                 asset.properties = properties;
                 graph_state.dispatch(GraphStateAction::UpdateAsset(asset));
@@ -58,6 +96,16 @@ pub fn asset_editor(props: &AssetEditorProps) -> Html {
     };
 
     html! {
-        <AssetEditorUi asset={(*asset).clone()} {onchange_properties} />
+        <>
+        <AssetEditorUi
+            asset={(*asset).clone()}
+            {tag_suggestions}
+            {metadata_history}
+            {schema}
+            {onchange_properties} />
+        <InheritedMetadata
+            rid={props.rid.clone()}
+            resource={ResourceType::Asset} />
+        </>
     }
 }
@@ -1,10 +1,15 @@
 //! Container editor widget.
+use super::inherited_metadata::InheritedMetadata;
+use super::metadata_history::use_metadata_history;
+use super::schema_violations_message;
 use super::script_associations_editor::ScriptAssociationsEditor;
 use crate::app::{AppStateAction, AppStateReducer};
 use crate::commands::container::{UpdatePropertiesArgs, UpdatePropertiesStringArgs};
 use crate::common::invoke;
+use crate::components::canvas::canvas_state::ResourceType;
 use crate::components::canvas::{GraphStateAction, GraphStateReducer};
-use thot_core::project::ContainerProperties;
+use crate::hooks::{use_canvas_project, use_project_schemas, use_project_vocabularies};
+use thot_core::project::{ContainerProperties, SchemaViolation};
 use thot_core::types::ResourceId;
 use thot_ui::types::Message;
 use thot_ui::widgets::container::ContainerPropertiesEditor;
@@ -24,6 +29,10 @@ pub struct ContainerEditorProps {
 pub fn container_editor(props: &ContainerEditorProps) -> Html {
     let app_state = use_context::<AppStateReducer>().expect("`AppStateReducer` context not found");
     let graph_state = use_context::<GraphStateReducer>().expect("`GraphReducer` context not found");
+    let project = use_canvas_project();
+    let vocabularies = use_project_vocabularies(&project);
+    let schemas = use_project_schemas(&project);
+    let metadata_history = use_metadata_history(&props.rid, ResourceType::Container);
 
     let container = graph_state
         .graph
@@ -74,14 +83,22 @@ pub fn container_editor(props: &ContainerEditorProps) -> Html {
                     properties: (*properties).clone(),
                 };
 
-                match invoke::<()>("update_container_properties", update_str).await {
+                match invoke::<Vec<SchemaViolation>>("update_container_properties", update_str)
+                    .await
+                {
                     Err(err) => {
                         tracing::debug!(?err);
                         app_state.dispatch(AppStateAction::AddMessage(Message::error(
                             "Could not save resource",
                         )));
                     }
-                    Ok(_) => {
+                    Ok(violations) => {
+                        if !violations.is_empty() {
+                            app_state.dispatch(AppStateAction::AddMessage(
+                                schema_violations_message(&violations),
+                            ));
+                        }
+
                         graph_state.dispatch(GraphStateAction::UpdateContainerProperties(update));
                     }
                 }
@@ -89,11 +106,32 @@ pub fn container_editor(props: &ContainerEditorProps) -> Html {
         });
     }
 
+    let schema = properties
+        .kind
+        .as_ref()
+        .and_then(|kind| schemas.kinds.get(kind))
+        .cloned();
+
+    let tag_suggestions = vocabularies
+        .tags
+        .canonical_terms()
+        .into_iter()
+        .map(|term| term.to_string())
+        .collect::<Vec<_>>();
+
     let onchange = {
+        let app_state = app_state.clone();
         let properties = properties.clone();
 
-        Callback::from(move |update: ContainerProperties| {
+        Callback::from(move |mut update: ContainerProperties| {
             tracing::debug!("properties changed");
+            if let Err(err) = vocabularies.normalize_container_properties(&mut update) {
+                let mut msg = Message::error("Could not save resource");
+                msg.set_details(err.to_string());
+                app_state.dispatch(AppStateAction::AddMessage(msg));
+                return;
+            }
+
             properties.set(update);
             dirty_state.set(true);
         })
@@ -103,8 +141,15 @@ pub fn container_editor(props: &ContainerEditorProps) -> Html {
         <div class={classes!("thot-ui-editor")}>
             <ContainerPropertiesEditor
                 properties={(*properties).clone()}
+                {tag_suggestions}
+                {metadata_history}
+                {schema}
                 onchange={onchange} />
 
+            <InheritedMetadata
+                rid={props.rid.clone()}
+                resource={ResourceType::Container} />
+
             <ScriptAssociationsEditor container={props.rid.clone()} />
             // TODO Allow Assets to be dropped here.
         </div>
@@ -0,0 +1,82 @@
+//! Metadata a resource inherits.
+use crate::app::{AppStateAction, AppStateReducer};
+use crate::commands::common::ResourceIdArgs;
+use crate::common::invoke;
+use crate::components::canvas::canvas_state::ResourceType;
+use crate::components::canvas::GraphStateReducer;
+use std::collections::HashMap;
+use thot_core::project::{InheritMetadata, ResolvedMetadata};
+use thot_core::types::ResourceId;
+use thot_ui::types::Message;
+use thot_ui::widgets::metadata::InheritedMetadataPreview;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct InheritedMetadataProps {
+    pub rid: ResourceId,
+    pub resource: ResourceType,
+}
+
+/// Displays the metadata a resource inherits and where it came from.
+#[tracing::instrument(skip(props), fields(?props.rid))]
+#[function_component(InheritedMetadata)]
+pub fn inherited_metadata(props: &InheritedMetadataProps) -> Html {
+    let app_state = use_context::<AppStateReducer>().expect("`AppStateReducer` context not found");
+    let graph_state = use_context::<GraphStateReducer>().expect("`GraphReducer` context not found");
+    let metadata = use_state(|| None::<ResolvedMetadata>);
+
+    {
+        // graph changes may change inherited values
+        let metadata = metadata.clone();
+
+        use_effect_with(
+            (
+                props.rid.clone(),
+                props.resource.clone(),
+                graph_state.clone(),
+            ),
+            move |(rid, resource, _)| {
+                let rid = rid.clone();
+                let command = match resource {
+                    ResourceType::Container => "get_container_metadata",
+                    ResourceType::Asset => "get_asset_metadata",
+                };
+
+                spawn_local(async move {
+                    match invoke::<Option<ResolvedMetadata>>(command, ResourceIdArgs { rid }).await
+                    {
+                        Ok(resolved) => metadata.set(resolved),
+                        Err(err) => {
+                            tracing::debug!(?err);
+                            app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                                "Could not get inherited metadata",
+                            )));
+                        }
+                    }
+                });
+            },
+        );
+    }
+
+    let Some(metadata) = metadata.as_ref() else {
+        return html! {};
+    };
+
+    let sources = metadata
+        .metadata_all()
+        .keys()
+        .filter_map(|key| metadata.source(key))
+        .filter_map(|source| {
+            let container = graph_state.graph.get(source)?;
+            Some((source.clone(), container.properties.name.clone()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    html! {
+        <div class={classes!("inherited-metadata")}>
+            <h3>{ "Inherited metadata" }</h3>
+            <InheritedMetadataPreview value={metadata.clone()} {sources} />
+        </div>
+    }
+}
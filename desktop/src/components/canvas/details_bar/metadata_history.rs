@@ -0,0 +1,47 @@
+//! History of a resource's metadata.
+use crate::commands::common::ResourceIdArgs;
+use crate::common::invoke;
+use crate::components::canvas::canvas_state::ResourceType;
+use crate::components::canvas::GraphStateReducer;
+use thot_core::project::MetadataChange;
+use thot_core::types::ResourceId;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// Gets the history of a resource's metadata, oldest first.
+/// Reloaded when the graph changes.
+///
+/// # Notes
+/// + If the history can not be loaded, it is empty.
+#[hook]
+pub fn use_metadata_history(rid: &ResourceId, resource: ResourceType) -> Vec<MetadataChange> {
+    let graph_state = use_context::<GraphStateReducer>().expect("`GraphReducer` context not found");
+    let history = use_state(Vec::new);
+
+    {
+        let history = history.clone();
+
+        use_effect_with(
+            (rid.clone(), resource, graph_state),
+            move |(rid, resource, _)| {
+                let rid = rid.clone();
+                let command = match resource {
+                    ResourceType::Container => "get_container_metadata_history",
+                    ResourceType::Asset => "get_asset_metadata_history",
+                };
+
+                spawn_local(async move {
+                    match invoke::<Vec<MetadataChange>>(command, ResourceIdArgs { rid }).await {
+                        Ok(changes) => history.set(changes),
+                        Err(err) => {
+                            tracing::debug!(?err);
+                            history.set(Vec::new());
+                        }
+                    }
+                });
+            },
+        );
+    }
+
+    (*history).clone()
+}
@@ -4,6 +4,8 @@ pub mod asset_editor;
 pub mod container_bulk_editor;
 pub mod container_editor;
 pub mod details_bar;
+pub mod inherited_metadata;
+pub mod metadata_history;
 pub mod mixed_bulk_editor;
 pub mod project_actions;
 pub mod project_scripts;
@@ -11,3 +13,20 @@ pub mod script_associations_editor;
 
 // Re-exports
 pub use details_bar::{DetailsBar, DetailsBarWidget};
+
+use thot_core::project::SchemaViolation;
+use thot_ui::types::Message;
+
+/// Creates a warning message for metadata schema violations.
+fn schema_violations_message(violations: &Vec<SchemaViolation>) -> Message {
+    let mut msg = Message::warning("Metadata does not match schema");
+    msg.set_details(
+        violations
+            .iter()
+            .map(|violation| format!("\u{2022} {violation}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+
+    msg
+}
@@ -11,7 +11,7 @@ use crate::commands::container::{
 use std::collections::HashMap;
 use std::rc::Rc;
 use thot_core::graph::ResourceTree;
-use thot_core::project::{container::AssetMap, Asset, Container, RunParameters};
+use thot_core::project::{container::AssetMap, Asset, AssetProperties, Container, RunParameters};
 use thot_core::types::{ResourceId, ResourcePath};
 use yew::prelude::*;
 
@@ -37,6 +37,12 @@ pub enum GraphStateAction {
         name: String,
     },
 
+    /// Move a `Container` to a new position among its siblings.
+    ReorderContainer {
+        root: ResourceId,
+        index: usize,
+    },
+
     /// Update a [`Container`]'s [`StandardProperties`](thot_::project::StandardProperties).
     UpdateContainerProperties(UpdateContainerPropertiesArgs),
 
@@ -77,6 +83,12 @@ pub enum GraphStateAction {
         path: ResourcePath,
     },
 
+    /// Update an [`Asset`]'s properties.
+    UpdateAssetProperties {
+        asset: ResourceId,
+        properties: AssetProperties,
+    },
+
     /// Move an `Asset` to another `Container`.  
     MoveAsset {
         asset: ResourceId,
@@ -313,6 +325,10 @@ impl Reducible for GraphState {
                 root.properties.name = name;
             }
 
+            GraphStateAction::ReorderContainer { root, index } => {
+                current.graph.move_index(&root, index).unwrap();
+            }
+
             GraphStateAction::UpdateContainerProperties(update) => {
                 let container = current
                     .graph
@@ -426,6 +442,13 @@ impl Reducible for GraphState {
                 asset.path = path;
             }
 
+            GraphStateAction::UpdateAssetProperties { asset, properties } => {
+                let container = current.asset_map.get(&asset).unwrap();
+                let container = current.graph.get_mut(container).unwrap();
+                let asset = container.assets.get_mut(&asset).unwrap();
+                asset.properties = properties;
+            }
+
             GraphStateAction::MoveAsset {
                 asset,
                 container,
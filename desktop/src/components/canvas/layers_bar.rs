@@ -1,14 +1,102 @@
 //! Layers.
+use crate::app::{AppStateAction, AppStateReducer};
+use crate::commands::analysis::ResolveFlagArgs;
+use crate::common::invoke;
+use crate::components::canvas::search_bar::SearchBar;
 use crate::components::canvas::selection_action::{selection_action, SelectionAction};
 use crate::components::canvas::{CanvasStateAction, CanvasStateReducer, GraphStateReducer};
-use thot_core::project::Asset as CoreAsset;
+use thot_core::project::{Asset as CoreAsset, Flag, FlagSeverity};
 use thot_core::types::ResourceId;
+use thot_ui::types::Message;
 use thot_ui::widgets::common::asset as asset_ui;
+use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 use yew_icons::{Icon, IconId};
 
 const ICON_SIZE: u8 = 16;
 
+/// Properties for [`Flags`].
+#[derive(Properties, PartialEq)]
+struct FlagsProps {
+    pub resource: ResourceId,
+}
+
+/// Indicates a resource's unresolved flags.
+/// Clicking resolves them.
+#[function_component(Flags)]
+fn flags(props: &FlagsProps) -> Html {
+    let app_state = use_context::<AppStateReducer>().expect("`AppStateReducer` context not found");
+    let canvas_state = use_context::<CanvasStateReducer>().unwrap();
+    let Some(flags) = canvas_state.flags.get(&props.resource) else {
+        return html! {};
+    };
+
+    let onclick = {
+        let app_state = app_state.clone();
+        let resource = props.resource.clone();
+        let flags = flags
+            .iter()
+            .map(|flag| flag.rid.clone())
+            .collect::<Vec<_>>();
+
+        Callback::from(move |e: MouseEvent| {
+            e.stop_propagation();
+            let app_state = app_state.clone();
+            let resource = resource.clone();
+            let flags = flags.clone();
+
+            spawn_local(async move {
+                for flag in flags {
+                    let args = ResolveFlagArgs {
+                        resource: resource.clone(),
+                        flag,
+                    };
+
+                    if let Err(err) = invoke::<Flag>("resolve_flag", args).await {
+                        tracing::debug!(?err);
+                        let mut msg = Message::error("Could not resolve flag");
+                        msg.set_details(format!("{err:?}"));
+                        app_state.dispatch(AppStateAction::AddMessage(msg));
+                    }
+                }
+            });
+        })
+    };
+
+    let severity = flags
+        .iter()
+        .map(|flag| flag.severity)
+        .max()
+        .unwrap_or_default();
+
+    let class = classes!(
+        "alert-icon",
+        match severity {
+            FlagSeverity::Info => "info",
+            FlagSeverity::Warning => "warning",
+            FlagSeverity::Error => "error",
+        }
+    );
+
+    let mut title = flags
+        .iter()
+        .map(|flag| format!("\u{2022} [{:?}] {}", flag.severity, flag.message))
+        .collect::<Vec<_>>();
+
+    title.push("Click to resolve".to_string());
+
+    html! {
+        <span {class}
+            title={title.join("\n")}
+            {onclick}>
+
+            <Icon icon_id={IconId::BootstrapExclamationTriangle}
+                width={ICON_SIZE.to_string()}
+                height={ICON_SIZE.to_string()} />
+        </span>
+    }
+}
+
 /// Properties for [`Assets`].
 #[derive(Properties, PartialEq)]
 struct AssetProps {
@@ -66,15 +154,7 @@ fn asset(props: &AssetProps) -> Html {
                 </span>
             </div>
             <div class={"controls-group"}>
-                if let Some(flags) = canvas_state.flags.get(&props.asset.rid) {
-                    <span class={"alert-icon"}
-                        title={flags.iter().map(|msg| format!("\u{2022} {msg}")).collect::<Vec<_>>().join("\n")}>
-
-                        <Icon icon_id={IconId::BootstrapExclamationTriangle}
-                            width={ICON_SIZE.to_string()}
-                            height={ICON_SIZE.to_string()} />
-                    </span>
-                }
+                <Flags resource={props.asset.rid.clone()} />
             </div>
         </div>
     }
@@ -270,15 +350,7 @@ fn layer(props: &LayerProps) -> Html {
                     </span>
                 </div>
                 <div class={"controls-group"}>
-                    if let Some(flags) = canvas_state.flags.get(&props.root) {
-                        <span class={"alert-icon"}
-                            title={flags.iter().map(|msg| format!("\u{2022} {msg}")).collect::<Vec<_>>().join("\n")}>
-
-                            <Icon icon_id={IconId::BootstrapExclamationTriangle}
-                                width={ICON_SIZE.to_string()}
-                                height={ICON_SIZE.to_string()} />
-                        </span>
-                    }
+                    <Flags resource={props.root.clone()} />
                     <span class={"visibility-toggle"}
                         onclick={onclick_toggle_visibility}>
                         if canvas_state.is_visible(&root.rid) {
@@ -317,6 +389,7 @@ pub fn layers_bar() -> Html {
     let root = graph_state.graph.root();
     html! {
         <div class={"layers-bar"}>
+            <SearchBar />
             <Layer root={root.clone()} expanded={true} />
         </div>
     }
@@ -7,6 +7,7 @@ pub mod details_bar;
 pub mod graph_state;
 pub mod layers_bar;
 pub mod project;
+pub mod search_bar;
 pub mod selection_action;
 
 // Re-exports
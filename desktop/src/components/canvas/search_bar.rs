@@ -0,0 +1,198 @@
+//! Text search of a project's resources.
+use crate::app::{AppStateAction, AppStateReducer};
+use crate::commands::common::SearchArgs;
+use crate::common::invoke;
+use crate::components::canvas::{CanvasStateAction, CanvasStateReducer, GraphStateReducer};
+use thot_core::project::{Asset as CoreAsset, Container as CoreContainer};
+use thot_ui::types::Message;
+use thot_ui::widgets::common::asset as asset_ui;
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+use yew_icons::{Icon, IconId};
+
+const ICON_SIZE: u8 = 16;
+
+/// Maximum number of results of each resource type.
+const SEARCH_LIMIT: usize = 50;
+
+/// Search results.
+#[derive(PartialEq, Default)]
+struct SearchResults {
+    containers: Vec<CoreContainer>,
+    assets: Vec<CoreAsset>,
+}
+
+impl SearchResults {
+    fn is_empty(&self) -> bool {
+        self.containers.is_empty() && self.assets.is_empty()
+    }
+}
+
+#[function_component(SearchBar)]
+pub fn search_bar() -> Html {
+    let app_state = use_context::<AppStateReducer>().expect("`AppStateReducer` context not found");
+    let canvas_state = use_context::<CanvasStateReducer>().unwrap();
+    let graph_state = use_context::<GraphStateReducer>().unwrap();
+    let results = use_state(|| None::<SearchResults>);
+    let query_ref = use_node_ref();
+
+    let onsubmit = {
+        let app_state = app_state.clone();
+        let results = results.clone();
+        let query_ref = query_ref.clone();
+        let root = graph_state.graph.root().clone();
+
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+
+            let query = query_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not cast input elm");
+
+            let query = query.value().trim().to_string();
+            if query.is_empty() {
+                results.set(None);
+                return;
+            }
+
+            let app_state = app_state.clone();
+            let results = results.clone();
+            let root = root.clone();
+            spawn_local(async move {
+                let containers = invoke::<Vec<CoreContainer>>(
+                    "search_containers",
+                    SearchArgs {
+                        root: root.clone(),
+                        query: query.clone(),
+                        limit: Some(SEARCH_LIMIT),
+                    },
+                )
+                .await;
+
+                let assets = invoke::<Vec<CoreAsset>>(
+                    "search_assets",
+                    SearchArgs {
+                        root,
+                        query,
+                        limit: Some(SEARCH_LIMIT),
+                    },
+                )
+                .await;
+
+                match (containers, assets) {
+                    (Ok(containers), Ok(assets)) => {
+                        results.set(Some(SearchResults { containers, assets }));
+                    }
+
+                    (containers, assets) => {
+                        tracing::debug!(?containers, ?assets);
+                        app_state.dispatch(AppStateAction::AddMessage(Message::error(
+                            "Could not search resources",
+                        )));
+                    }
+                }
+            });
+        })
+    };
+
+    let onclear = {
+        let results = results.clone();
+        let query_ref = query_ref.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            if let Some(query) = query_ref.cast::<web_sys::HtmlInputElement>() {
+                query.set_value("");
+            }
+
+            results.set(None);
+        })
+    };
+
+    let select_container = {
+        let canvas_state = canvas_state.clone();
+        move |container: &CoreContainer| {
+            let canvas_state = canvas_state.clone();
+            let container = container.rid.clone();
+            Callback::from(move |_: MouseEvent| {
+                canvas_state.dispatch(CanvasStateAction::ClearSelected);
+                canvas_state.dispatch(CanvasStateAction::SelectContainer(container.clone()));
+            })
+        }
+    };
+
+    let select_asset = {
+        let canvas_state = canvas_state.clone();
+        move |asset: &CoreAsset| {
+            let canvas_state = canvas_state.clone();
+            let asset = asset.rid.clone();
+            Callback::from(move |_: MouseEvent| {
+                canvas_state.dispatch(CanvasStateAction::SelectAssetOnly(asset.clone()));
+            })
+        }
+    };
+
+    html! {
+        <div class={"search-bar"}>
+            <form {onsubmit}>
+                <input ref={query_ref}
+                    type={"search"}
+                    placeholder={"Search"} />
+
+                if results.is_some() {
+                    <button type={"button"}
+                        class={"clear-search"}
+                        onclick={onclear}>
+                        <Icon icon_id={IconId::FontAwesomeSolidXmark}
+                            width={ICON_SIZE.to_string()}
+                            height={ICON_SIZE.to_string()} />
+                    </button>
+                }
+            </form>
+
+            if let Some(results) = results.as_ref() {
+                <div class={"search-results"}>
+                    if results.is_empty() {
+                        <div class={"no-results"}>{ "No results" }</div>
+                    }
+
+                    { results.containers.iter().map(|container| {
+                        let selected = canvas_state.selected.contains(&container.rid);
+                        html! {
+                            <div class={classes!("search-result", "container", selected.then_some("selected"))}
+                                onclick={select_container(container)}>
+
+                                <span class={"resource-icon"}>
+                                    <Icon icon_id={IconId::FontAwesomeRegularFolder}
+                                        width={ICON_SIZE.to_string()}
+                                        height={ICON_SIZE.to_string()} />
+                                </span>
+                                <span class={"name"} title={container.properties.name.clone()}>
+                                    { &container.properties.name }
+                                </span>
+                            </div>
+                        }
+                    }).collect::<Html>() }
+
+                    { results.assets.iter().map(|asset| {
+                        let selected = canvas_state.selected.contains(&asset.rid);
+                        let name = asset_ui::asset_display_name(asset);
+                        html! {
+                            <div class={classes!("search-result", "asset", selected.then_some("selected"))}
+                                onclick={select_asset(asset)}>
+
+                                <span class={"resource-icon"} style={asset_ui::asset_icon_color(asset)}>
+                                    <Icon icon_id={asset_ui::asset_icon_id(asset)}
+                                        width={ICON_SIZE.to_string()}
+                                        height={ICON_SIZE.to_string()} />
+                                </span>
+                                <span class={"name"} title={name.clone()}>
+                                    { name }
+                                </span>
+                            </div>
+                        }
+                    }).collect::<Html>() }
+                </div>
+            }
+        </div>
+    }
+}
@@ -3,6 +3,8 @@ pub mod active_project;
 pub mod load_project_scripts;
 pub mod open_projects;
 pub mod project;
+pub mod project_schemas;
+pub mod project_vocabularies;
 pub mod user_projects;
 
 // Re-exports
@@ -10,4 +12,6 @@ pub use active_project::use_active_project;
 pub use load_project_scripts::use_load_project_scripts;
 pub use open_projects::use_open_projects;
 pub use project::use_project;
+pub use project_schemas::use_project_schemas;
+pub use project_vocabularies::use_project_vocabularies;
 pub use user_projects::use_user_projects;
@@ -0,0 +1,19 @@
+//! Gets a `Project`'s metadata schemas.
+use crate::app::ProjectsStateReducer;
+use thot_core::project::MetadataSchemas;
+use thot_core::types::ResourceId;
+use yew::prelude::*;
+
+/// Gets a `Project`'s metadata schemas.
+/// Empty schemas are returned if the `Project`'s settings are not loaded.
+#[hook]
+pub fn use_project_schemas(project: &ResourceId) -> MetadataSchemas {
+    let projects_state =
+        use_context::<ProjectsStateReducer>().expect("`ProjectsStateReducer` context not found");
+
+    projects_state
+        .settings
+        .get(project)
+        .map(|settings| settings.schemas.clone())
+        .unwrap_or_default()
+}
@@ -0,0 +1,19 @@
+//! Gets a `Project`'s vocabularies.
+use crate::app::ProjectsStateReducer;
+use thot_core::project::Vocabularies;
+use thot_core::types::ResourceId;
+use yew::prelude::*;
+
+/// Gets a `Project`'s vocabularies.
+/// Empty vocabularies are returned if the `Project`'s settings are not loaded.
+#[hook]
+pub fn use_project_vocabularies(project: &ResourceId) -> Vocabularies {
+    let projects_state =
+        use_context::<ProjectsStateReducer>().expect("`ProjectsStateReducer` context not found");
+
+    projects_state
+        .settings
+        .get(project)
+        .map(|settings| settings.vocabularies.clone())
+        .unwrap_or_default()
+}
@@ -173,7 +173,8 @@ class Database:
         name: OptStr = None,
         type: OptStr = None,
         tags: OptTags = None,
-        metadata: OptMetadata = None
+        metadata: OptMetadata = None,
+        query: OptStr = None
     ) -> Containers:
         """
         Find Containers matching the filter.
@@ -183,24 +184,33 @@ class Database:
             type (OptStr, optional): Type filter. Defaults to `None`.
             tags (OptTags, optional): Tags filter. Defaults to `None`.
             metadata (OptMetadata, optional): Metadata filter. Defaults to `None`.
+            query (OptStr, optional): Name of a saved query of the project.
+                Can not be combined with other filters. Defaults to `None`.
 
         Returns:
             list[Container]: Containers matching the filter.
         """
-        f = {}
-        if name is not None:
-            f['name'] = name
-        if type is not None:
-            f['kind'] = type
-        if tags is not None:
-            f['tags'] = tags
-        if metadata is not None:
-            f['metadata'] = metadata
-        
-        self._socket.send_json({"ContainerCommand": {"FindWithMetadata": (self._root, f)}})
-        containers = self._socket.recv_json()
-        if 'Err' in containers:
-            raise RuntimeError(f"Error getting containers: {containers['Err']}")
+        if query is not None:
+            if any(v is not None for v in (name, type, tags, metadata)):
+                raise ValueError("`query` can not be combined with other filters")
+            
+            containers = self._find_query("ContainerCommand", query)
+        
+        else:
+            f = {}
+            if name is not None:
+                f['name'] = name
+            if type is not None:
+                f['kind'] = type
+            if tags is not None:
+                f['tags'] = tags
+            if metadata is not None:
+                f['metadata'] = metadata
+            
+            self._socket.send_json({"ContainerCommand": {"FindWithMetadata": (self._root, f)}})
+            containers = self._socket.recv_json()
+            if 'Err' in containers:
+                raise RuntimeError(f"Error getting containers: {containers['Err']}")
 
         return list(map(
             lambda container: dict_to_container(container, db = self),
@@ -212,7 +222,8 @@ class Database:
         name: OptStr = None,
         type: OptStr = None,
         tags: OptTags = None,
-        metadata: OptMetadata = None
+        metadata: OptMetadata = None,
+        query: OptStr = None
     ) -> Union[Container, None]:
         """
         Find a single Container matching the filter.
@@ -222,11 +233,13 @@ class Database:
             type (OptStr, optional): Type filter. Defaults to `None`.
             tags (OptTags, optional): Tags filter. Defaults to `None`.
             metadata (OptMetadata, optional): Metadata filter. Defaults to `None`.
+            query (OptStr, optional): Name of a saved query of the project.
+                Can not be combined with other filters. Defaults to `None`.
         
         Returns:
             Union[Container, None]: A Contianer, or `None`.
         """
-        containers = self.find_containers(name = name, type = type, tags = tags, metadata = metadata)
+        containers = self.find_containers(name = name, type = type, tags = tags, metadata = metadata, query = query)
         if len(containers) == 0:
             return None
         
@@ -237,7 +250,8 @@ class Database:
         name: OptStr = None,
         type: OptStr = None,
         tags: OptTags = None,
-        metadata: OptMetadata = None
+        metadata: OptMetadata = None,
+        query: OptStr = None
     ) -> Assets:
         """
         Find Assets matching the filter.
@@ -247,24 +261,33 @@ class Database:
             type (OptStr, optional): Type filter. Defaults to `None`.
             tags (OptTags, optional): Tags filter. Defaults to `None`.
             metadata (OptMetadata, optional): Metadata filter. Defaults to `None`.
+            query (OptStr, optional): Name of a saved query of the project.
+                Can not be combined with other filters. Defaults to `None`.
 
         Returns:
             list[Asset]: Assets matching the filter.
         """
-        f = {}
-        if name is not None:
-            f['name'] = name
-        if type is not None:
-            f['kind'] = type
-        if tags is not None:
-            f['tags'] = tags
-        if metadata is not None:
-            f['metadata'] = metadata
-        
-        self._socket.send_json({"AssetCommand": {"FindWithMetadata": (self._root, f)}})
-        assets = self._socket.recv_json()
-        if 'Err' in assets:
-            raise RuntimeError(f"Error getting assets: {assets['Err']}")
+        if query is not None:
+            if any(v is not None for v in (name, type, tags, metadata)):
+                raise ValueError("`query` can not be combined with other filters")
+            
+            assets = self._find_query("AssetCommand", query)
+        
+        else:
+            f = {}
+            if name is not None:
+                f['name'] = name
+            if type is not None:
+                f['kind'] = type
+            if tags is not None:
+                f['tags'] = tags
+            if metadata is not None:
+                f['metadata'] = metadata
+            
+            self._socket.send_json({"AssetCommand": {"FindWithMetadata": (self._root, f)}})
+            assets = self._socket.recv_json()
+            if 'Err' in assets:
+                raise RuntimeError(f"Error getting assets: {assets['Err']}")
 
         return list(map(
             lambda asset: dict_to_asset(asset, db = self),
@@ -276,7 +299,8 @@ class Database:
         name: OptStr = None,
         type: OptStr = None,
         tags: OptTags = None,
-        metadata: OptMetadata = None    
+        metadata: OptMetadata = None,
+        query: OptStr = None
     ) -> Union[Asset, None]:
         """
         Find a single Asset matching the filter.
@@ -286,11 +310,13 @@ class Database:
             type (OptStr, optional): Type filter. Defaults to `None`.
             tags (OptTags, optional): Tags filter. Defaults to `None`.
             metadata (OptMetadata, optional): Metadata filter. Defaults to `None`.
+            query (OptStr, optional): Name of a saved query of the project.
+                Can not be combined with other filters. Defaults to `None`.
         
         Returns: 
             Union[Asset, None]: An Asset or `None`.
         """
-        assets = self.find_assets(name = name, type = type, tags = tags, metadata = metadata)
+        assets = self.find_assets(name = name, type = type, tags = tags, metadata = metadata, query = query)
         if len(assets) == 0:
             return None
         
@@ -303,10 +329,12 @@ class Database:
         type: OptStr = None,
         description: OptStr = None,
         tags: Tags = [],
-        metadata: Metadata = {}    
+        metadata: Metadata = {},
+        inputs: Assets = []
     ) -> str:
         """
         Adds an Asset to the project.
+        When run as part of an analysis, the Asset's lineage is recorded.
         
         Args:
             file (str): File name of the associated data. Use relative paths to place the Asset in a bucket.
@@ -314,6 +342,7 @@ class Database:
             type (OptStr, optional): Type filter. Defaults to `None`.
             tags (OptTags, optional): Tags filter. Defaults to `None`.
             metadata (OptMetadata, optional): Metadata filter. Defaults to `None`.
+            inputs (list[Asset], optional): Assets used to produce the Asset. Defaults to `[]`.
 
         Returns:
             str: Path to save the Asset's file to.
@@ -327,9 +356,11 @@ class Database:
             raise RuntimeError("could not get active user")
         
         uid = user["rid"]
+        script = os.getenv("THOT_SCRIPT_ID")
+        creator = {"User": {"Id": uid}} if script is None else {"Script": script}
         properties = {
             "created": datetime.now().strftime("%Y-%m-%dT%H:%M:%SZ"),
-            "creator": {"User": {"Id": uid}},
+            "creator": creator,
             "name": name,
             "kind": type,
             "description": description,
@@ -343,7 +374,15 @@ class Database:
             'path': path
         }
         
-        self._socket.send_json({"AssetCommand": {"Add": (asset, self._root)}})
+        lineage = None
+        if script is not None:
+            lineage = {
+                "script": script,
+                "run": os.getenv("THOT_RUN_ID"),
+                "inputs": [input._rid for input in inputs]
+            }
+        
+        self._socket.send_json({"AssetCommand": {"Add": (asset, self._root, lineage)}})
         res = self._socket.recv_json()
         if "Ok" not in res:
             raise RuntimeError(f"could not create Asset: {res['Err']}")
@@ -352,15 +391,28 @@ class Database:
         os.makedirs(os.path.dirname(path), exist_ok=True) # ensure bucket directory exists
         return path
     
-    def flag(self, resource: Union[Container, Asset], message: str):
+    def flag(self, resource: Union[Container, Asset], message: str, severity: str = "Warning"):
         """Add a flag to the resource.
+        The flag is saved with the project until it is resolved.
 
         Args:
             resource (Union[Container, Asset]): Resource to flag.
             message (str): Message to display.
+            severity (str, optional): One of `"Info"`, `"Warning"`, or `"Error"`.
+                Defaults to `"Warning"`.
         """
-        self._socket.send_json({"AnalysisCommand": {"Flag": {"resource": resource._rid, "message": message}}})
+        flag = {
+            "resource": resource._rid,
+            "message": message,
+            "severity": severity,
+            "script": os.getenv("THOT_SCRIPT_ID"),
+            "run": os.getenv("THOT_RUN_ID")
+        }
+        
+        self._socket.send_json({"AnalysisCommand": {"Flag": flag}})
         res = self._socket.recv_json()
+        if "Ok" not in res:
+            raise RuntimeError(f"could not flag resource: {res['Err']}")
     
     def clone(self) -> 'Database':
         """Clones the Database.
@@ -380,6 +432,114 @@ class Database:
         clone._socket.connect(f'tcp://{LOCALHOST}:{THOT_PORT}')
         return clone
     
+    def _find_query(self, command: str, query: str) -> list:
+        """
+        Find resources matching a saved query.
+
+        Args:
+            command (str): Command type of the resource.
+            query (str): Name of the saved query.
+
+        Returns:
+            list: Resources matching the query.
+        """
+        self._socket.send_json({command: {"FindQuery": {"root": self._root, "query": query}}})
+        res = self._socket.recv_json()
+        if "Ok" not in res:
+            raise RuntimeError(f"Error running query `{query}`: {res['Err']}")
+        
+        return res["Ok"]
+    
+    def _resolve(self, rid: str) -> Union[Container, Asset, None]:
+        """
+        Resolve a resource id.
+
+        Args:
+            rid (str): Id of a Container or Asset.
+
+        Returns:
+            Union[Container, Asset, None]: The resource, or `None` if it does not exist.
+        """
+        self._socket.send_json({"ContainerCommand": {"GetWithMetadata": rid}})
+        container = self._socket.recv_json()
+        if container is not None:
+            return dict_to_container(container, db = self)
+        
+        self._socket.send_json({"AssetCommand": {"Parent": rid}})
+        parent = self._socket.recv_json()
+        if parent is None:
+            return None
+        
+        self._socket.send_json({"ContainerCommand": {"GetWithMetadata": parent["rid"]}})
+        parent = self._socket.recv_json()
+        if parent is None:
+            return None
+        
+        parent = dict_to_container(parent, db = self)
+        return next((asset for asset in parent._assets if asset._rid == rid), None)
+    
+    def _linked(self, links: dict, relation: OptStr = None) -> list:
+        """
+        Resolve linked resources.
+
+        Args:
+            links (dict): Links, as ids by relation.
+            relation (OptStr, optional): Relation to resolve.
+                Resolves all relations if `None`. Defaults to `None`.
+
+        Returns:
+            list: Linked resources.
+        """
+        if relation is None:
+            targets = [rid for rids in links.values() for rid in rids]
+        else:
+            targets = links.get(relation, [])
+        
+        resources = map(self._resolve, dict.fromkeys(targets))
+        return [resource for resource in resources if resource is not None]
+    
+    def _backlinks(self, rid: str) -> list:
+        """
+        Resolve the resources linking to a resource.
+
+        Args:
+            rid (str): Id of the linked resource.
+
+        Returns:
+            list: Tuples of the linking resource and the relation.
+        """
+        self._socket.send_json({"LinkCommand": {"Backlinks": rid}})
+        res = self._socket.recv_json()
+        if "Ok" not in res:
+            raise RuntimeError(f"could not get backlinks: {res['Err']}")
+        
+        backlinks = []
+        for link in res["Ok"]:
+            source = self._resolve(link["source"])
+            if source is not None:
+                backlinks.append((source, link["relation"]))
+        
+        return backlinks
+    
+    def _lineage(self, direction: str, rid: str) -> Assets:
+        """
+        Resolve the lineage of an Asset.
+
+        Args:
+            direction (str): `"Upstream"` or `"Downstream"`.
+            rid (str): Id of the Asset.
+
+        Returns:
+            list[Asset]: Related Assets, nearest first.
+        """
+        self._socket.send_json({"LineageCommand": {direction: rid}})
+        res = self._socket.recv_json()
+        if "Ok" not in res:
+            raise RuntimeError(f"could not get lineage: {res['Err']}")
+        
+        assets = map(self._resolve, res["Ok"])
+        return [asset for asset in assets if asset is not None]
+    
     def _active_user(self) -> OptStr:
         """
         Get the active user.
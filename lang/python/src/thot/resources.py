@@ -1,18 +1,23 @@
 from typing import Union
 
 from thot import _LEGACY_
-from .types import OptStr, Tags, Metadata, Properties
+from .types import OptStr, Tags, Metadata, Properties, Links
 from .common import dev_mode
 
 OptDatabase = Union['Database', None]
 OptContainer = Union['Container', None]
 if _LEGACY_:
     from typing import List
+    from typing import Tuple
     Assets = List['Asset']
     ContainerList = List['Container']
+    Resources = List[Union['Container', 'Asset']]
+    Backlinks = List[Tuple[Union['Container', 'Asset'], str]]
 else:
     Assets = list['Asset']
     ContainerList = list['Container']
+    Resources = list[Union['Container', 'Asset']]
+    Backlinks = list[tuple[Union['Container', 'Asset'], str]]
     
 class Container:
     """
@@ -27,6 +32,7 @@ class Container:
         tags: Tags = [],
         metadata: Metadata = {},
         assets: Assets = [],
+        links: Links = {},
         db: OptDatabase = None
     ):
         """
@@ -39,6 +45,7 @@ class Container:
         self._tags: Tags = tags
         self._metadata: Metadata = metadata
         self._assets: Assets = assets
+        self._links: Links = links
         
         self._db: OptDatabase = db
         self._parent: OptContainer = None
@@ -102,6 +109,39 @@ class Container:
         self._assets = container._assets
         return self._assets
     
+    @property
+    def links(self) -> Links:
+        """
+        Returns:
+            Links: Container's links, as ids by relation.
+        """
+        return self._links
+
+    def linked(self, relation: OptStr = None) -> Resources:
+        """
+        Args:
+            relation (OptStr, optional): Relation to resolve.
+                Resolves all relations if `None`. Defaults to `None`.
+
+        Returns:
+            list[Union[Container, Asset]]: Resources the Container links to.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._linked(self._links, relation)
+
+    def backlinks(self) -> Backlinks:
+        """
+        Returns:
+            list[tuple[Union[Container, Asset], str]]: Resources linking to the Container,
+            with the relation of each link.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._backlinks(self._rid)
+
     def children(self) -> ContainerList:
         """
         Returns:
@@ -182,6 +222,7 @@ class Asset:
         type: OptStr = None,
         tags: Tags = [],
         metadata: Metadata = {},
+        links: Links = {},
         db: OptDatabase = None,
         parent: OptContainer = None,
     ):
@@ -195,6 +236,7 @@ class Asset:
         self._description: OptStr = description
         self._tags: Tags = tags
         self._metadata: Metadata = metadata
+        self._links: Links = links
         self._db = db
         self._parent = parent
     
@@ -246,6 +288,59 @@ class Asset:
         """
         return self._file
     
+    @property
+    def links(self) -> Links:
+        """
+        Returns:
+            Links: Asset's links, as ids by relation.
+        """
+        return self._links
+
+    def linked(self, relation: OptStr = None) -> Resources:
+        """
+        Args:
+            relation (OptStr, optional): Relation to resolve.
+                Resolves all relations if `None`. Defaults to `None`.
+
+        Returns:
+            list[Union[Container, Asset]]: Resources the Asset links to.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._linked(self._links, relation)
+
+    def backlinks(self) -> Backlinks:
+        """
+        Returns:
+            list[tuple[Union[Container, Asset], str]]: Resources linking to the Asset,
+            with the relation of each link.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._backlinks(self._rid)
+
+    def upstream(self) -> Assets:
+        """
+        Returns:
+            list[Asset]: Assets the Asset was derived from, nearest first.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._lineage("Upstream", self._rid)
+
+    def downstream(self) -> Assets:
+        """
+        Returns:
+            list[Asset]: Assets derived from the Asset, nearest first.
+        """
+        if self._db is None:
+            raise RuntimeError("No database connector")
+
+        return self._db._lineage("Downstream", self._rid)
+
     def parent(self) -> 'Container':
         """
         Returns:
@@ -286,6 +381,7 @@ def dict_to_container(d: Properties, db: OptDatabase = None) -> Container:
         description = d["properties"]["description"],
         tags = d["properties"]["tags"],
         metadata = d["properties"]["metadata"],
+        links = d["properties"].get("links", {}),
         db = db
     )
     
@@ -318,6 +414,7 @@ def dict_to_asset(d: Properties, db: OptDatabase = None, parent: OptContainer =
         description = d["properties"]["description"],
         tags = d["properties"]["tags"],
         metadata = d["properties"]["metadata"],
+        links = d["properties"].get("links", {}),
         db = db,
         parent = parent
     )
\ No newline at end of file
@@ -8,7 +8,9 @@ if _LEGACY_:
     Tags = List[str]
     Metadata = Dict[str, Any]
     Properties = Dict[str, Any]
+    Links = Dict[str, List[str]]
 else:
     Tags = list[str]
     Metadata = dict[str, Any]
-    Properties = dict[str, Any]
\ No newline at end of file
+    Properties = dict[str, Any]
+    Links = dict[str, list[str]]
\ No newline at end of file
@@ -7,13 +7,14 @@ use std::str::FromStr;
 use std::{env, fs};
 use thot_core::db::StandardSearchFilter as StdFilter;
 use thot_core::graph::ResourceTree;
-use thot_core::project::{Asset, Container, Project};
-use thot_core::runner::{common as thot_runner, CONTAINER_ID_KEY};
-use thot_core::types::ResourceId;
+use thot_core::project::{Asset, Backlink, Container, Lineage, Links, Project};
+use thot_core::runner::{common as thot_runner, CONTAINER_ID_KEY, RUN_ID_KEY, SCRIPT_ID_KEY};
+use thot_core::types::{Creator, ResourceId};
 use thot_local::project::project::project_resource_root_path;
+use thot_local_database::command::lineage::AssetLineageArgs;
 use thot_local_database::{
-    AssetCommand, Client as DbClient, ContainerCommand, GraphCommand, ProjectCommand,
-    Result as DbResult,
+    AssetCommand, Client as DbClient, ContainerCommand, GraphCommand, LineageCommand, LinkCommand,
+    ProjectCommand, Result as DbResult,
 };
 
 pub type ContainerTree = ResourceTree<Container>;
@@ -144,6 +145,25 @@ impl Database {
         Ok(containers)
     }
 
+    /// Finds all Containers matching a saved query of the project.
+    ///
+    /// # Arguments
+    /// 1. Name of the query.
+    pub fn find_containers_by_query(&self, query: &str) -> Result<HashSet<Container>> {
+        let containers = self.db.send(
+            ContainerCommand::FindQuery {
+                root: self.root.clone(),
+                query: query.to_string(),
+            }
+            .into(),
+        )?;
+
+        let containers: DbResult<HashSet<Container>> = serde_json::from_value(containers)
+            .expect("could not convert result of `FindQuery` to `HashSet<Container>`");
+
+        Ok(containers?)
+    }
+
     /// Finds a single Asset matching the search filter.
     pub fn find_asset(&self, filter: StdFilter) -> Result<Option<Asset>> {
         let assets = self.find_assets(filter)?;
@@ -166,6 +186,181 @@ impl Database {
         Ok(assets)
     }
 
+    /// Finds all Assets matching a saved query of the project.
+    ///
+    /// # Arguments
+    /// 1. Name of the query.
+    pub fn find_assets_by_query(&self, query: &str) -> Result<HashSet<Asset>> {
+        let assets = self.db.send(
+            AssetCommand::FindQuery {
+                root: self.root.clone(),
+                query: query.to_string(),
+            }
+            .into(),
+        )?;
+
+        let assets: DbResult<HashSet<Asset>> = serde_json::from_value(assets)
+            .expect("could not convert result of `FindQuery` to `HashSet<Asset>`");
+
+        Ok(assets?)
+    }
+
+    /// Searches Containers by the words of their name, description, tags,
+    /// and string metadata values.
+    ///
+    /// # Arguments
+    /// 1. Search text.
+    /// 2. Maximum number of results.
+    ///
+    /// # Returns
+    /// Containers ordered by relevance.
+    pub fn search_containers(&self, query: &str, limit: Option<usize>) -> Result<Vec<Container>> {
+        let containers = self.db.send(
+            ContainerCommand::Search {
+                root: self.root.clone(),
+                query: query.to_string(),
+                limit,
+            }
+            .into(),
+        )?;
+
+        let containers: Vec<Container> = serde_json::from_value(containers)
+            .expect("could not convert result of `Search` to `Vec<Container>`");
+
+        Ok(containers)
+    }
+
+    /// Searches Assets by the words of their name, description, tags,
+    /// and string metadata values.
+    ///
+    /// # Arguments
+    /// 1. Search text.
+    /// 2. Maximum number of results.
+    ///
+    /// # Returns
+    /// Assets ordered by relevance.
+    pub fn search_assets(&self, query: &str, limit: Option<usize>) -> Result<Vec<Asset>> {
+        let assets = self.db.send(
+            AssetCommand::Search {
+                root: self.root.clone(),
+                query: query.to_string(),
+                limit,
+            }
+            .into(),
+        )?;
+
+        let assets: Vec<Asset> = serde_json::from_value(assets)
+            .expect("could not convert result of `Search` to `Vec<Asset>`");
+
+        Ok(assets)
+    }
+
+    /// Gets the links of a Container or Asset.
+    pub fn links(&self, resource: &ResourceId) -> Result<Links> {
+        let links = self.db.send(LinkCommand::Get(resource.clone()).into())?;
+
+        let links: Option<Links> =
+            serde_json::from_value(links).expect("could not convert result of `Get` to `Links`");
+
+        let Some(links) = links else {
+            return Err(Error::Value("Resource does not exist".into()));
+        };
+
+        Ok(links)
+    }
+
+    /// Gets the Containers a resource links to.
+    ///
+    /// # Arguments
+    /// 1. Resource holding the links.
+    /// 2. Relation name.
+    pub fn linked_containers(
+        &self,
+        resource: &ResourceId,
+        relation: &str,
+    ) -> Result<Vec<Container>> {
+        let mut containers = Vec::new();
+        for target in self.links(resource)?.targets(relation) {
+            let container = self
+                .db
+                .send(ContainerCommand::GetWithMetadata(target.clone()).into())?;
+
+            let container: Option<Container> = serde_json::from_value(container)
+                .expect("could not convert result of `GetWithMetadata` to `Container`");
+
+            containers.extend(container);
+        }
+
+        Ok(containers)
+    }
+
+    /// Gets the Assets a resource links to.
+    ///
+    /// # Arguments
+    /// 1. Resource holding the links.
+    /// 2. Relation name.
+    pub fn linked_assets(&self, resource: &ResourceId, relation: &str) -> Result<Vec<Asset>> {
+        let targets = self.links(resource)?.targets(relation).to_vec();
+        let assets = self.db.send(AssetCommand::GetMany(targets).into())?;
+        let assets: Vec<Asset> = serde_json::from_value(assets)
+            .expect("could not convert result of `GetMany` to `Vec<Asset>`");
+
+        Ok(assets)
+    }
+
+    /// Gets the links to a Container or Asset.
+    pub fn backlinks(&self, resource: &ResourceId) -> Result<Vec<Backlink>> {
+        let backlinks = self
+            .db
+            .send(LinkCommand::Backlinks(resource.clone()).into())?;
+
+        let backlinks: DbResult<Vec<Backlink>> = serde_json::from_value(backlinks)
+            .expect("could not convert result of `Backlinks` to `Vec<Backlink>`");
+
+        Ok(backlinks?)
+    }
+
+    /// Gets how an Asset was produced.
+    pub fn lineage(&self, asset: &ResourceId) -> Result<Option<Lineage>> {
+        let lineage = self.db.send(LineageCommand::Get(asset.clone()).into())?;
+        let lineage: DbResult<Option<Lineage>> = serde_json::from_value(lineage)
+            .expect("could not convert result of `Get` to `Lineage`");
+
+        Ok(lineage?)
+    }
+
+    /// Gets the Assets an Asset was derived from, nearest first.
+    pub fn upstream(&self, asset: &ResourceId) -> Result<Vec<Asset>> {
+        let rids = self
+            .db
+            .send(LineageCommand::Upstream(asset.clone()).into())?;
+
+        let rids: DbResult<Vec<ResourceId>> = serde_json::from_value(rids)
+            .expect("could not convert result of `Upstream` to `Vec<ResourceId>`");
+
+        self.get_assets(rids?)
+    }
+
+    /// Gets the Assets derived from an Asset, nearest first.
+    pub fn downstream(&self, asset: &ResourceId) -> Result<Vec<Asset>> {
+        let rids = self
+            .db
+            .send(LineageCommand::Downstream(asset.clone()).into())?;
+
+        let rids: DbResult<Vec<ResourceId>> = serde_json::from_value(rids)
+            .expect("could not convert result of `Downstream` to `Vec<ResourceId>`");
+
+        self.get_assets(rids?)
+    }
+
+    fn get_assets(&self, rids: Vec<ResourceId>) -> Result<Vec<Asset>> {
+        let assets = self.db.send(AssetCommand::GetMany(rids).into())?;
+        let assets: Vec<Asset> = serde_json::from_value(assets)
+            .expect("could not convert result of `GetMany` to `Vec<Asset>`");
+
+        Ok(assets)
+    }
+
     // @todo: Allow either an Asset object or dictionary.
     /// Adds an Asset to the database.
     ///
@@ -176,6 +371,35 @@ impl Database {
     /// # Returns
     /// The Asset's file path.
     pub fn add_asset(&self, asset: Asset) -> Result<PathBuf> {
+        self.add_asset_from(asset, Vec::new())
+    }
+
+    /// Adds an Asset to the database, recording the Assets it was derived from.
+    /// Lineage is only recorded when run as part of an analysis.
+    ///
+    /// # Arguments
+    /// 1. Asset to add.
+    /// 2. Assets used to produce the Asset.
+    ///
+    /// # Returns
+    /// The Asset's file path.
+    pub fn add_asset_from(&self, mut asset: Asset, inputs: Vec<ResourceId>) -> Result<PathBuf> {
+        let script = env::var(SCRIPT_ID_KEY)
+            .ok()
+            .and_then(|script| ResourceId::from_str(&script).ok());
+
+        if let Some(script) = script.as_ref() {
+            asset.properties.creator = Creator::Script(script.clone());
+        }
+
+        let lineage = script.map(|script| AssetLineageArgs {
+            script,
+            run: env::var(RUN_ID_KEY)
+                .ok()
+                .and_then(|run| ResourceId::from_str(&run).ok()),
+            inputs,
+        });
+
         let root = self
             .db
             .send(ContainerCommand::Get(self.root.clone()).into())?;
@@ -189,6 +413,7 @@ impl Database {
             AssetCommand::Add {
                 asset,
                 container: root.rid.clone(),
+                lineage,
             }
             .into(),
         )?;
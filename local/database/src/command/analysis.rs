@@ -1,11 +1,75 @@
 //! Analysis commands.
 use serde::{Deserialize, Serialize};
+use thot_core::project::FlagSeverity;
 use thot_core::types::ResourceId;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub enum AnalysisCommand {
+    /// Flags a resource.
+    /// The flag is saved with the resource's `Container`.
+    ///
+    /// # Fields
+    /// + `resource`: `Container` or `Asset` to flag.
+    /// + `severity`: Defaults to `Warning`.
+    /// + `script`: Script raising the flag.
+    /// + `run`: Root `Container` of the analysis run raising the flag.
+    ///
+    /// # Returns
+    /// `Result<Flag>` of the created flag.
     Flag {
         resource: ResourceId,
         message: String,
+
+        #[serde(default)]
+        severity: FlagSeverity,
+
+        #[serde(default)]
+        script: Option<ResourceId>,
+
+        #[serde(default)]
+        run: Option<ResourceId>,
+    },
+
+    /// Lists the flags of the resources in a subtree.
+    ///
+    /// # Fields
+    /// + `root`: Root `Container` of the subtree.
+    /// + `include_resolved`: Whether to include resolved flags.
+    ///
+    /// # Returns
+    /// `Result<Vec<Flag>>`, oldest first.
+    Flags {
+        root: ResourceId,
+
+        #[serde(default)]
+        include_resolved: bool,
+    },
+
+    /// Resolves a flag as the active user.
+    ///
+    /// # Fields
+    /// + `resource`: Resource the flag is attached to.
+    /// + `flag`: Flag to resolve.
+    ///
+    /// # Returns
+    /// `Result<Flag>` of the resolved flag.
+    Resolve {
+        resource: ResourceId,
+        flag: ResourceId,
+    },
+
+    /// Removes the flags of the resources in a subtree.
+    ///
+    /// # Fields
+    /// + `root`: Root `Container` of the subtree.
+    /// + `resolved_only`: Only remove resolved flags.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>` of the removed flags.
+    Clear {
+        root: ResourceId,
+
+        #[serde(default)]
+        resolved_only: bool,
     },
 }
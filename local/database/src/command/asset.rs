@@ -1,4 +1,5 @@
 //! Asset realated commands.
+use super::lineage::AssetLineageArgs;
 use super::types::{MetadataAction, TagsAction};
 use serde::{Deserialize, Serialize};
 use thot_core::db::StandardSearchFilter;
@@ -18,6 +19,10 @@ pub enum AssetCommand {
     /// are not included.
     GetMany(Vec<ResourceId>),
 
+    /// Retrieves an [`Asset`]'s metadata, resolved with that inherited from its ancestors,
+    /// and the source of each inherited value.
+    ResolvedMetadata(ResourceId),
+
     /// Return the absolute path to the `Asset`'s file.
     Path(ResourceId),
 
@@ -28,7 +33,15 @@ pub enum AssetCommand {
     Parent(ResourceId),
 
     /// Insert's an [`Asset`] into a [`Container`](thot_core::project::Container).
-    Add { asset: Asset, container: ResourceId },
+    /// If `lineage` is given it is recorded for the `Asset`,
+    /// and the `Asset` is not added if the lineage can not be recorded.
+    Add {
+        asset: Asset,
+        container: ResourceId,
+
+        #[serde(default)]
+        lineage: Option<AssetLineageArgs>,
+    },
 
     /// Updates an [`Asset`].
     UpdateProperties {
@@ -57,8 +70,45 @@ pub enum AssetCommand {
         filter: StandardSearchFilter,
     },
 
+    /// Retrieves [`Asset`]s matching a saved query
+    /// of the root `Container`'s `Project`.
+    /// Lineage is compiled.
+    ///
+    /// # Fields
+    /// 1. Root `Container`.
+    /// Ignored if the query specifies its own root.
+    /// 2. Name of the query.
+    FindQuery { root: ResourceId, query: String },
+
+    /// Searches [`Asset`]s by the words of their
+    /// name, description, tags, and string metadata values.
+    /// Results are ordered by relevance.
+    ///
+    /// # Fields
+    /// 1. Root `Container`.
+    /// 2. Search text.
+    /// 3. Maximum number of results.
+    Search {
+        root: ResourceId,
+        query: String,
+        limit: Option<usize>,
+    },
+
     /// Update multiple [`Asset`]s' properties.
     BulkUpdateProperties(BulkUpdateAssetPropertiesArgs),
+
+    /// Re-runs the extractors on an [`Asset`]'s file,
+    /// merging the extracted properties into its current properties.
+    ///
+    /// # Returns
+    /// `Result<Vec<SchemaViolation>>` of the updated properties.
+    Extract(ResourceId),
+
+    /// Gets the history of changes to an [`Asset`]'s metadata.
+    ///
+    /// # Returns
+    /// `Result<Vec<MetadataChange>>`, oldest first.
+    MetadataHistory(ResourceId),
 }
 
 #[derive(Serialize, Deserialize, Default, Debug)]
@@ -1,8 +1,8 @@
 //! Commands.
 use super::{
     analysis::AnalysisCommand, asset::AssetCommand, container::ContainerCommand,
-    database::DatabaseCommand, graph::GraphCommand, project::ProjectCommand, script::ScriptCommand,
-    user::UserCommand,
+    database::DatabaseCommand, graph::GraphCommand, lineage::LineageCommand, link::LinkCommand,
+    project::ProjectCommand, script::ScriptCommand, user::UserCommand,
 };
 use serde::{Deserialize, Serialize};
 
@@ -17,6 +17,8 @@ pub enum Command {
     ScriptCommand(ScriptCommand),
     UserCommand(UserCommand),
     AnalysisCommand(AnalysisCommand),
+    LinkCommand(LinkCommand),
+    LineageCommand(LineageCommand),
 }
 
 impl From<AssetCommand> for Command {
@@ -66,3 +68,15 @@ impl From<AnalysisCommand> for Command {
         Self::AnalysisCommand(cmd)
     }
 }
+
+impl From<LinkCommand> for Command {
+    fn from(cmd: LinkCommand) -> Self {
+        Self::LinkCommand(cmd)
+    }
+}
+
+impl From<LineageCommand> for Command {
+    fn from(cmd: LineageCommand) -> Self {
+        Self::LineageCommand(cmd)
+    }
+}
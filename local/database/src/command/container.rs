@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use thot_core::db::StandardSearchFilter;
 use thot_core::project::container::ScriptMap;
-use thot_core::project::{ContainerProperties, ScriptAssociation};
+use thot_core::project::{ContainerProperties, MetadataEdit, ScriptAssociation};
 use thot_core::types::ResourceId;
 
 /// Container related commands.
@@ -16,6 +16,10 @@ pub enum ContainerCommand {
     /// Retrievea a [`Container`](thot_core::project::Container) with inherited metadata by [`ResourceId`].
     GetWithMetadata(ResourceId),
 
+    /// Retrieves a [`Container`](thot_core::project::Container)'s metadata, resolved with
+    /// that inherited from its ancestors, and the source of each inherited value.
+    ResolvedMetadata(ResourceId),
+
     /// Retrieves a [`Container`](thot_core::project::Container) by its path.
     ByPath(PathBuf),
 
@@ -34,6 +38,30 @@ pub enum ContainerCommand {
     /// 2. Search filter.
     FindWithMetadata(ResourceId, StandardSearchFilter),
 
+    /// Retrieves [`Container`](thot_core::project::Container)s matching a saved query
+    /// of the root `Container`'s `Project`.
+    /// Lineage is compiled.
+    ///
+    /// # Fields
+    /// 1. Root `Container`.
+    /// Ignored if the query specifies its own root.
+    /// 2. Name of the query.
+    FindQuery { root: ResourceId, query: String },
+
+    /// Searches [`Container`](thot_core::project::Container)s by the words of their
+    /// name, description, tags, and string metadata values.
+    /// Results have inherited metadata and are ordered by relevance.
+    ///
+    /// # Fields
+    /// 1. Root `Container`.
+    /// 2. Search text.
+    /// 3. Maximum number of results.
+    Search {
+        root: ResourceId,
+        query: String,
+        limit: Option<usize>,
+    },
+
     /// Updates a [`Container`](thot_core::project::Container)'s properties.
     UpdateProperties(UpdatePropertiesArgs),
 
@@ -52,6 +80,19 @@ pub enum ContainerCommand {
 
     /// Update multiple `Container`s `ScriptAssociations`.
     BulkUpdateScriptAssociations(BulkUpdateScriptAssociationsArgs),
+
+    /// Edits the metadata of the `Container`s and `Asset`s of a subtree.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>` of the affected resources.
+    /// If any resource can not be edited, no changes are made.
+    EditMetadata(EditMetadataArgs),
+
+    /// Gets the history of changes to a `Container`'s metadata.
+    ///
+    /// # Returns
+    /// `Result<Vec<MetadataChange>>`, oldest first.
+    MetadataHistory(ResourceId),
 }
 
 // *****************
@@ -88,6 +129,23 @@ pub struct UpdateScriptAssociationsArgs {
     pub associations: ScriptMap,
 }
 
+/// Arguments for editing metadata across a subtree.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct EditMetadataArgs {
+    /// Root `Container` of the subtree.
+    pub root: ResourceId,
+
+    /// Only edit resources matching the filter.
+    #[serde(default)]
+    pub filter: Option<StandardSearchFilter>,
+
+    pub edit: MetadataEdit,
+
+    /// List the affected resources without changing them.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BulkUpdateScriptAssociationsArgs {
     pub containers: Vec<ResourceId>,
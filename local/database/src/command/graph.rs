@@ -1,5 +1,6 @@
 //! Container related commands.
 use serde::{Deserialize, Serialize};
+use thot_core::system::template::InstanceOptions;
 use thot_core::types::ResourceId;
 
 /// Graph related commands.
@@ -19,11 +20,80 @@ pub enum GraphCommand {
 
     /// Get the parent of the Container.
     Parent(ResourceId),
+
+    /// Creates a new child `Container`.
+    /// The child's folder is named after it, made unique if needed.
+    ///
+    /// # Returns
+    /// `Result<Container>` of the new child.
+    NewChild(NewChildArgs),
+
+    /// Removes a subtree, moving its folder to the trash.
+    ///
+    /// # Returns
+    /// `Result<ResourceTree<Container>>` of the removed subtree.
+    Remove(ResourceId),
+
+    /// Moves a subtree to a new parent.
+    ///
+    /// # Fields
+    /// + `root`: Root of the subtree.
+    /// + `parent`: New parent.
+    ///
+    /// # Returns
+    /// `Result` with the error if the move could not be made.
+    Move {
+        root: ResourceId,
+        parent: ResourceId,
+    },
+
+    /// Moves a `Container` to a new position among its siblings.
+    ///
+    /// # Fields
+    /// + `root`: `Container` to move.
+    /// + `index`: New position.
+    ///
+    /// # Returns
+    /// `Result` with the error if the position could not be changed.
+    Reorder { root: ResourceId, index: usize },
+
+    /// Saves a subtree as a template.
+    ///
+    /// # Fields
+    /// + `root`: Root `Container` of the subtree.
+    /// + `name`: Name of the template.
+    ///
+    /// # Returns
+    /// `Result<Subtree>` of the saved template.
+    SaveTemplate { root: ResourceId, name: String },
+
+    /// Lists the saved subtree templates.
+    ///
+    /// # Returns
+    /// `Result<Vec<Subtree>>`.
+    Templates,
+
+    /// Creates instances of a subtree template.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceTree<Container>>>` of the created trees, in order.
+    Instantiate(InstantiateTemplateArgs),
 }
 
-/// Arguments for [`Command::NewChild`].
+/// Arguments for [`GraphCommand::NewChild`].
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NewChildArgs {
     pub name: String,
     pub parent: ResourceId,
 }
+
+/// Arguments for [`GraphCommand::Instantiate`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InstantiateTemplateArgs {
+    /// Id of the template.
+    pub template: ResourceId,
+
+    /// `Container` to create the instances in.
+    pub parent: ResourceId,
+    pub options: InstanceOptions,
+}
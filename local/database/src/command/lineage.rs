@@ -0,0 +1,68 @@
+//! Lineage commands.
+use serde::{Deserialize, Serialize};
+use thot_core::types::ResourceId;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum LineageCommand {
+    /// Records how an `Asset` was produced.
+    /// Replaces the `Asset`'s previous record.
+    ///
+    /// # Returns
+    /// `Result<Lineage>` of the recorded lineage.
+    Record(RecordLineageArgs),
+
+    /// Gets the lineage record of an `Asset`.
+    ///
+    /// # Returns
+    /// `Result<Option<Lineage>>`.
+    Get(ResourceId),
+
+    /// Gets the `Asset`s an `Asset` was derived from, directly or transitively.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>`, nearest first.
+    Upstream(ResourceId),
+
+    /// Gets the `Asset`s derived from an `Asset`, directly or transitively.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>`, nearest first.
+    Downstream(ResourceId),
+}
+
+/// Arguments for [`LineageCommand::Record`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordLineageArgs {
+    /// Produced `Asset`.
+    pub asset: ResourceId,
+
+    /// `Script` that produced the `Asset`.
+    pub script: ResourceId,
+
+    /// `Container` the `Script` was executed on.
+    pub container: ResourceId,
+
+    /// Analysis run the `Script` was executed in.
+    #[serde(default)]
+    pub run: Option<ResourceId>,
+
+    /// `Asset`s used to produce the `Asset`.
+    #[serde(default)]
+    pub inputs: Vec<ResourceId>,
+}
+
+/// How an added `Asset` was produced.
+/// See [`AssetCommand::Add`](super::AssetCommand::Add).
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AssetLineageArgs {
+    /// `Script` that produced the `Asset`.
+    pub script: ResourceId,
+
+    /// Analysis run the `Script` was executed in.
+    #[serde(default)]
+    pub run: Option<ResourceId>,
+
+    /// `Asset`s used to produce the `Asset`.
+    #[serde(default)]
+    pub inputs: Vec<ResourceId>,
+}
@@ -0,0 +1,43 @@
+//! Link commands.
+use serde::{Deserialize, Serialize};
+use thot_core::types::ResourceId;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum LinkCommand {
+    /// Gets the links of a `Container` or `Asset`.
+    ///
+    /// # Returns
+    /// `Option<Links>`.
+    Get(ResourceId),
+
+    /// Gets the links to a `Container` or `Asset` from the resources of its `Project`.
+    ///
+    /// # Returns
+    /// `Result<Vec<Backlink>>`.
+    Backlinks(ResourceId),
+
+    /// Links a resource to another.
+    ///
+    /// # Returns
+    /// `Result`.
+    Add(LinkArgs),
+
+    /// Removes a link between resources.
+    ///
+    /// # Returns
+    /// `Result`.
+    Remove(LinkArgs),
+}
+
+/// Arguments for [`LinkCommand::Add`] and [`LinkCommand::Remove`].
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LinkArgs {
+    /// Resource holding the link.
+    pub source: ResourceId,
+
+    /// Relation name.
+    pub relation: String,
+
+    /// Linked resource.
+    pub target: ResourceId,
+}
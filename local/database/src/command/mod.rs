@@ -5,6 +5,8 @@ pub mod command;
 pub mod container;
 pub mod database;
 pub mod graph;
+pub mod lineage;
+pub mod link;
 pub mod project;
 pub mod script;
 pub mod types;
@@ -17,6 +19,8 @@ pub use command::Command;
 pub use container::ContainerCommand;
 pub use database::DatabaseCommand;
 pub use graph::GraphCommand;
+pub use lineage::LineageCommand;
+pub use link::LinkCommand;
 pub use project::ProjectCommand;
 pub use script::ScriptCommand;
 pub use user::UserCommand;
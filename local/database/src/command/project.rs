@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use thot_core::project::Project as CoreProject;
 use thot_core::types::ResourceId;
+use thot_local::types::SavedQuery;
 
 /// Project related commands.
 #[derive(Serialize, Deserialize, Debug)]
@@ -46,4 +47,58 @@ pub enum ProjectCommand {
 
     /// Get's the project root path of a resource given its path.
     ResourceRootPath(PathBuf),
+
+    /// Lists a [`Project`](CoreProject)'s saved queries.
+    ///
+    /// # Fields
+    /// 1. `Project`'s id.
+    ListQueries(ResourceId),
+
+    /// Saves a query to a [`Project`](CoreProject)'s settings,
+    /// replacing any query with the same name.
+    ///
+    /// # Returns
+    /// The replaced query.
+    SaveQuery {
+        project: ResourceId,
+        query: SavedQuery,
+    },
+
+    /// Deletes a saved query from a [`Project`](CoreProject)'s settings.
+    ///
+    /// # Returns
+    /// The deleted query.
+    DeleteQuery { project: ResourceId, name: String },
+
+    /// Migrates the kinds and tags of a [`Project`](CoreProject)'s resources
+    /// to the canonical terms of its vocabularies.
+    /// Terms not in the vocabularies are kept.
+    ///
+    /// # Fields
+    /// 1. `Project`'s id.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>` of the updated `Container`s and `Asset`s.
+    MigrateVocabularies(ResourceId),
+
+    /// Renames a tag across a [`Project`](CoreProject)'s resources,
+    /// cascading to its descendants.
+    ///
+    /// # Returns
+    /// `Result<Vec<ResourceId>>` of the updated `Container`s and `Asset`s.
+    RenameTag {
+        project: ResourceId,
+        from: String,
+        to: String,
+    },
+
+    /// Aggregates the tags of a [`Project`](CoreProject)'s resources.
+    ///
+    /// # Fields
+    /// 1. `Project`'s id.
+    ///
+    /// # Returns
+    /// `Result<BTreeMap<String, usize>>` of the number of resources matching each tag,
+    /// including ancestor tags.
+    Tags(ResourceId),
 }
@@ -1,8 +1,12 @@
 //! Types used for `Command`s.
 use serde::{Deserialize, Serialize};
-use thot_core::project::Metadata;
+use thot_core::project::{tag, Metadata, Vocabulary};
 
 /// Actions to be taken on tags.
+///
+/// # Notes
+/// + Tags are hierarchical, so removing or renaming a tag
+/// also removes or renames its descendants.
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub struct TagsAction {
     /// Values to insert.
@@ -10,6 +14,41 @@ pub struct TagsAction {
 
     /// Values to remove.
     pub remove: Vec<String>,
+
+    /// Tags to rename, as `(from, to)` pairs.
+    #[serde(default)]
+    pub rename: Vec<(String, String)>,
+}
+
+impl TagsAction {
+    /// Applies the actions to tags.
+    /// Renames are applied first, then insertions, then removals.
+    ///
+    /// # Arguments
+    /// 1. Tags to update.
+    /// 2. Vocabulary used to match removed tags.
+    pub fn apply(&self, tags: &mut Vec<String>, vocabulary: &Vocabulary) {
+        for (from, to) in self.rename.iter() {
+            tag::rename_in(tags, from, to);
+        }
+
+        tags.append(&mut self.insert.clone());
+        tags.sort();
+        tags.dedup();
+
+        let remove = self
+            .remove
+            .iter()
+            .map(|t| vocabulary.normalize_lossy(t))
+            .collect::<Vec<_>>();
+
+        tags.retain(|t| {
+            let t = vocabulary.normalize_lossy(t);
+            !remove
+                .iter()
+                .any(|removed| tag::is_descendant_or_self(&t, removed))
+        });
+    }
 }
 
 /// Actions to be taken on metadata.
@@ -2,6 +2,8 @@
 use serde::{Deserialize, Serialize};
 use std::result::Result as StdResult;
 use thiserror::Error;
+use thot_core::project::{MetadataEditError, SchemaViolation, VocabularyError};
+use thot_core::types::ResourceId;
 use thot_core::Error as CoreError;
 use thot_local::Error as LocalError;
 
@@ -45,6 +47,22 @@ pub enum Error {
     /// The database has become out of sync.
     #[error("out of sync")]
     OutOfSync,
+
+    /// Metadata does not match its schema.
+    #[error("metadata does not match schema")]
+    SchemaViolations(Vec<SchemaViolation>),
+
+    /// A tag or kind is rejected by its vocabulary.
+    #[error("{0}")]
+    VocabularyError(VocabularyError),
+
+    /// A metadata edit could not be applied to a resource.
+    ///
+    /// # Fields
+    /// 1. Resource the edit failed on.
+    /// 2. Error.
+    #[error("{1}")]
+    MetadataEditError(ResourceId, MetadataEditError),
 }
 
 impl From<zmq::Error> for Error {
@@ -59,6 +77,12 @@ impl From<CoreError> for Error {
     }
 }
 
+impl From<VocabularyError> for Error {
+    fn from(err: VocabularyError) -> Self {
+        Error::VocabularyError(err)
+    }
+}
+
 impl From<LocalError> for Error {
     fn from(err: LocalError) -> Self {
         Error::LocalError(format!("{err:?}"))
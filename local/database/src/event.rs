@@ -4,7 +4,9 @@
 //! e.g. `project:123-4567-890
 use serde::{Deserialize, Serialize};
 use thot_core::graph::ResourceTree;
-use thot_core::project::{Container as CoreContainer, ContainerProperties, Script as CoreScript};
+use thot_core::project::{
+    AssetProperties, Container as CoreContainer, ContainerProperties, Flag, Script as CoreScript,
+};
 use thot_core::types::{ResourceId, ResourcePath};
 
 // **************
@@ -89,6 +91,13 @@ pub enum Graph {
 
     /// Subgraph was removed.
     Removed(ResourceTree<CoreContainer>),
+
+    /// A `Container` was moved to a new position among its siblings.
+    ///
+    /// # Fields
+    /// `root`: `Container` that was moved.
+    /// `index`: New position.
+    Reordered { root: ResourceId, index: usize },
 }
 
 // *****************
@@ -131,6 +140,12 @@ pub enum Asset {
     },
 
     Removed(ResourceId),
+
+    /// `Asset`'s properties were modified.
+    Properties {
+        asset: ResourceId,
+        properties: AssetProperties,
+    },
 }
 
 // **************
@@ -160,8 +175,12 @@ pub enum Script {
 /// Analysis updates.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub enum Analysis {
-    Flag {
-        resource: ResourceId,
-        message: String,
-    },
+    /// A resource was flagged.
+    Flag(Flag),
+
+    /// A flag was resolved.
+    Resolved(Flag),
+
+    /// Flags were removed.
+    Cleared(Vec<ResourceId>),
 }
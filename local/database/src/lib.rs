@@ -24,8 +24,8 @@ pub mod server;
 
 // Re-exports
 pub use command::{
-    AssetCommand, Command, ContainerCommand, DatabaseCommand, GraphCommand, ProjectCommand,
-    ScriptCommand,
+    AssetCommand, Command, ContainerCommand, DatabaseCommand, GraphCommand, LineageCommand,
+    LinkCommand, ProjectCommand, ScriptCommand,
 };
 
 pub use event::Update;
@@ -2,30 +2,204 @@
 use super::super::Database;
 use crate::command::AnalysisCommand;
 use crate::event::{Analysis as AnalysisUpdate, Update};
+use crate::Result;
 use serde_json::Value as JsValue;
+use std::path::PathBuf;
+use thot_core::error::{Error as CoreError, ResourceError};
+use thot_core::project::{Flag, FlagSeverity};
+use thot_core::types::ResourceId;
+use thot_local::project::flags;
 
 impl Database {
     #[tracing::instrument(skip(self))]
     pub fn handle_command_analysis(&mut self, cmd: AnalysisCommand) -> JsValue {
         match cmd {
-            AnalysisCommand::Flag { resource, message } => {
-                let project = if let Some(project) = self.store.get_container_project(&resource) {
-                    project.clone()
-                } else if let Some(container) = self.store.get_asset_container_id(&resource) {
-                    self.store.get_container_project(container).unwrap().clone()
-                } else {
-                    tracing::debug!("resource `{resource:?}` not found");
-                    panic!("resource not found");
-                };
-
-                self.publish_update(&Update::Project {
-                    project,
-                    update: AnalysisUpdate::Flag { resource, message }.into(),
-                })
-                .unwrap();
-
-                serde_json::to_value(JsValue::Null).unwrap()
+            AnalysisCommand::Flag {
+                resource,
+                message,
+                severity,
+                script,
+                run,
+            } => {
+                let res = self.flag(resource, message, severity, script, run);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            AnalysisCommand::Flags {
+                root,
+                include_resolved,
+            } => {
+                let res = self.flags(&root, include_resolved);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            AnalysisCommand::Resolve { resource, flag } => {
+                let res = self.resolve_flag(&resource, &flag);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            AnalysisCommand::Clear {
+                root,
+                resolved_only,
+            } => {
+                let res = self.clear_flags(&root, resolved_only);
+                serde_json::to_value(res).expect("could not convert result to JSON")
             }
         }
     }
+
+    /// Creates and saves a flag.
+    fn flag(
+        &self,
+        resource: ResourceId,
+        message: String,
+        severity: FlagSeverity,
+        script: Option<ResourceId>,
+        run: Option<ResourceId>,
+    ) -> Result<Flag> {
+        let (project, container) = self.flag_location(&resource)?;
+        let mut flag = Flag::new(resource, message);
+        flag.severity = severity;
+        flag.script = script;
+        flag.run = run;
+
+        let mut container_flags = flags::load(&container)?;
+        container_flags.push(flag.clone());
+        flags::save(&container, &container_flags)?;
+
+        if let Err(err) = self.publish_update(&Update::Project {
+            project,
+            update: AnalysisUpdate::Flag(flag.clone()).into(),
+        }) {
+            tracing::error!(?err);
+        }
+
+        Ok(flag)
+    }
+
+    /// # Arguments
+    /// 1. Root `Container` of the subtree.
+    /// 2. Whether to include resolved flags.
+    fn flags(&self, root: &ResourceId, include_resolved: bool) -> Result<Vec<Flag>> {
+        let mut subtree_flags = Vec::new();
+        for container in self.subtree_paths(root)? {
+            subtree_flags.extend(
+                flags::load(&container)?
+                    .into_iter()
+                    .filter(|flag| include_resolved || !flag.is_resolved()),
+            );
+        }
+
+        subtree_flags.sort_by(|a, b| a.created().cmp(b.created()));
+        Ok(subtree_flags)
+    }
+
+    /// Resolves a flag as the active user.
+    fn resolve_flag(&self, resource: &ResourceId, flag: &ResourceId) -> Result<Flag> {
+        let (project, container) = self.flag_location(resource)?;
+        let mut container_flags = flags::load(&container)?;
+        let Some(resolved) = container_flags.iter_mut().find(|f| &f.rid == flag) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Flag` does not exist",
+            ))
+            .into());
+        };
+
+        resolved.resolve(self.modifier());
+        let resolved = resolved.clone();
+        flags::save(&container, &container_flags)?;
+
+        if let Err(err) = self.publish_update(&Update::Project {
+            project,
+            update: AnalysisUpdate::Resolved(resolved.clone()).into(),
+        }) {
+            tracing::error!(?err);
+        }
+
+        Ok(resolved)
+    }
+
+    /// Removes the flags in a subtree.
+    ///
+    /// # Arguments
+    /// 1. Root `Container` of the subtree.
+    /// 2. Only remove resolved flags.
+    fn clear_flags(&self, root: &ResourceId, resolved_only: bool) -> Result<Vec<ResourceId>> {
+        let Some(project) = self.store.get_container_project(root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let mut removed = Vec::new();
+        for container in self.subtree_paths(root)? {
+            let (clear, keep): (Vec<_>, Vec<_>) = flags::load(&container)?
+                .into_iter()
+                .partition(|flag| !resolved_only || flag.is_resolved());
+
+            if clear.is_empty() {
+                continue;
+            }
+
+            flags::save(&container, &keep)?;
+            removed.extend(clear.into_iter().map(|flag| flag.rid));
+        }
+
+        if !removed.is_empty() {
+            if let Err(err) = self.publish_update(&Update::Project {
+                project,
+                update: AnalysisUpdate::Cleared(removed.clone()).into(),
+            }) {
+                tracing::error!(?err);
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Project and path of the `Container` a resource's flags are saved in.
+    fn flag_location(&self, resource: &ResourceId) -> Result<(ResourceId, PathBuf)> {
+        let container = match self.store.get_container(resource) {
+            Some(container) => container,
+            None => match self.store.get_asset_container(resource) {
+                Some(container) => container,
+                None => {
+                    return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                        "resource does not exist",
+                    ))
+                    .into())
+                }
+            },
+        };
+
+        let project = self
+            .store
+            .get_container_project(&container.rid)
+            .expect("`Container` should be in a project")
+            .clone();
+
+        Ok((project, container.base_path().to_path_buf()))
+    }
+
+    /// Paths of the `Container`s in a subtree.
+    fn subtree_paths(&self, root: &ResourceId) -> Result<Vec<PathBuf>> {
+        let Some(graph) = self.store.get_container_graph(root) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` graph not found",
+            ))
+            .into());
+        };
+
+        Ok(graph
+            .descendants(root)
+            .expect("`Container` not found in graph")
+            .into_iter()
+            .map(|rid| graph.get(&rid).unwrap().base_path().to_path_buf())
+            .collect())
+    }
 }
+
+#[cfg(test)]
+#[path = "./analysis_test.rs"]
+mod analysis_test;
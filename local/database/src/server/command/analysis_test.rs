@@ -0,0 +1,75 @@
+use super::*;
+use crate::command::{GraphCommand, ProjectCommand};
+use dev_utils::fs::TempDir;
+use thot_local::project::container;
+use thot_local::project::resources::Project as LocalProject;
+
+#[test]
+fn flags_should_persist_and_resolve() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    // test
+    let flag = db
+        .flag(
+            rid.clone(),
+            "check".to_string(),
+            FlagSeverity::Error,
+            None,
+            None,
+        )
+        .expect("could not flag `Container`");
+
+    let saved = flags::load(&data_dir).expect("could not load flags");
+    assert_eq!(vec![flag.clone()], saved, "flag should be saved");
+
+    let resolved = db
+        .resolve_flag(&rid, &flag.rid)
+        .expect("could not resolve flag");
+
+    assert!(resolved.is_resolved());
+    assert!(
+        db.flags(&rid, false).unwrap().is_empty(),
+        "resolved flags should be excluded"
+    );
+
+    assert_eq!(1, db.flags(&rid, true).unwrap().len());
+
+    db.flag(
+        rid.clone(),
+        "open".to_string(),
+        FlagSeverity::Info,
+        None,
+        None,
+    )
+    .expect("could not flag `Container`");
+
+    let removed = db.clear_flags(&rid, true).expect("could not clear flags");
+    assert_eq!(
+        vec![flag.rid],
+        removed,
+        "only resolved flags should be removed"
+    );
+    assert_eq!(1, db.flags(&rid, true).unwrap().len());
+
+    assert!(
+        db.resolve_flag(&ResourceId::new(), &ResourceId::new())
+            .is_err(),
+        "missing resource should error"
+    );
+}
@@ -1,13 +1,19 @@
 //! Handle `Asset` related functionality.
 use super::super::Database;
 use crate::command::asset::{AssetPropertiesUpdate, BulkUpdateAssetPropertiesArgs};
+use crate::command::lineage::{AssetLineageArgs, RecordLineageArgs};
 use crate::command::AssetCommand;
 use crate::error::Result;
 use serde_json::Value as JsValue;
+use std::collections::HashSet;
 use std::path::PathBuf;
+use thot_core::db::StandardSearchFilter;
 use thot_core::error::{Error as CoreError, ResourceError};
-use thot_core::project::{Asset, AssetProperties, Container as CoreContainer};
-use thot_core::types::ResourceId;
+use thot_core::project::{
+    Asset, AssetProperties, Container as CoreContainer, MetadataChange, SchemaViolation,
+};
+use thot_core::types::{ResourceId, ResourceMap};
+use thot_local::project::metadata_history;
 
 impl Database {
     #[tracing::instrument(skip(self))]
@@ -44,6 +50,12 @@ impl Database {
                 serde_json::to_value(assets).expect("could not convert `Vec<Asset>` to JSON")
             }
 
+            AssetCommand::ResolvedMetadata(rid) => {
+                let metadata = self.store.get_asset_metadata(&rid);
+                serde_json::to_value(metadata)
+                    .expect("could not convert `ResolvedMetadata` to JSON")
+            }
+
             AssetCommand::Path(asset) => {
                 let Some(container) = self.store.get_asset_container(&asset) else {
                     let res: Option<PathBuf> = None;
@@ -65,13 +77,21 @@ impl Database {
                 serde_json::to_value(container).expect("could not convert `Container` to JSON")
             }
 
-            AssetCommand::Add { asset, container } => {
-                let res = self.store.add_asset(asset, container);
+            AssetCommand::Add {
+                asset,
+                container,
+                lineage,
+            } => {
+                let res = self.add_asset(asset, container, lineage);
                 serde_json::to_value(res).expect("could not convert result to JSON")
             }
 
             AssetCommand::UpdateProperties { asset, properties } => {
-                let res = self.update_asset_properties(&asset, properties);
+                let res = self.update_asset_properties(
+                    &asset,
+                    properties,
+                    "AssetCommand::UpdateProperties",
+                );
                 serde_json::to_value(res).expect("could not convert result to JSON")
             }
 
@@ -81,7 +101,21 @@ impl Database {
             }
 
             AssetCommand::FindWithMetadata { root, filter } => {
-                let assets = self.store.find_assets_with_metadata(&root, filter);
+                let assets = self.find_assets_with_metadata(&root, filter);
+                serde_json::to_value(assets).expect("could not convert result to JSON")
+            }
+
+            AssetCommand::FindQuery { root, query } => {
+                let res = self.resolve_project_query(&root, &query).map(|query| {
+                    let root = query.root.expect("query root not resolved");
+                    self.find_assets_with_metadata(&root, query.filter)
+                });
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            AssetCommand::Search { root, query, limit } => {
+                let assets = self.store.search_assets(&root, &query, limit);
                 serde_json::to_value(assets).expect("could not convert result to JSON")
             }
 
@@ -89,10 +123,145 @@ impl Database {
                 let res = self.bulk_update_asset_properties(&rids, &update);
                 serde_json::to_value(res).unwrap()
             }
+
+            AssetCommand::Extract(asset) => {
+                let res = self.extract_asset_properties(&asset);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            AssetCommand::MetadataHistory(asset) => {
+                let res = self.asset_metadata_history(&asset);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+        }
+    }
+
+    /// # Arguments
+    /// 1. Root `Container`.
+    /// 2. Search filter.
+    ///
+    /// # Returns
+    /// Matching `Asset`s, with their resolved `Metadata` as their own.
+    fn find_assets_with_metadata(
+        &self,
+        root: &ResourceId,
+        filter: StandardSearchFilter,
+    ) -> HashSet<Asset> {
+        self.store
+            .find_assets_with_metadata(root, filter)
+            .into_iter()
+            .map(|asset| asset.into_resolved())
+            .collect()
+    }
+
+    /// Adds an `Asset` to a `Container`, recording its lineage if given.
+    /// If the lineage can not be recorded the `Container`'s `Asset`s are restored.
+    ///
+    /// # Returns
+    /// The `Asset` the added `Asset` replaced, if any.
+    #[tracing::instrument(skip(self))]
+    fn add_asset(
+        &mut self,
+        asset: Asset,
+        container: ResourceId,
+        lineage: Option<AssetLineageArgs>,
+    ) -> Result<Option<Asset>> {
+        let Some(lineage) = lineage else {
+            return self.store.add_asset(asset, container);
+        };
+
+        let mut aid = asset.rid.clone();
+        let prev = self.store.add_asset(asset, container.clone())?;
+        if let Some(prev) = prev.as_ref() {
+            // `Asset`s with the same path are replaced
+            aid = prev.rid.clone();
+        }
+
+        let record = RecordLineageArgs {
+            asset: aid.clone(),
+            script: lineage.script,
+            container: container.clone(),
+            run: lineage.run,
+            inputs: lineage.inputs,
+        };
+
+        if let Err(err) = self.record_lineage(record) {
+            match prev {
+                Some(prev) => {
+                    self.store.add_asset(prev, container)?;
+                }
+
+                None => {
+                    self.store.remove_asset(&aid)?;
+                }
+            }
+
+            return Err(err);
         }
+
+        Ok(prev)
+    }
+
+    /// Re-runs the extractors on an `Asset`'s file,
+    /// updating its properties with the extracted values.
+    ///
+    /// # Returns
+    /// Metadata schema violations.
+    fn extract_asset_properties(&mut self, rid: &ResourceId) -> Result<Vec<SchemaViolation>> {
+        let Some(container) = self.store.get_asset_container(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let asset = container
+            .assets
+            .get(rid)
+            .expect("`Asset` not in `Container`");
+        let path = container.base_path().join(asset.path.as_path());
+        let mut properties = asset.properties.clone();
+
+        let extracted = self.extractors.extract(&path)?;
+        extracted.apply(&mut properties);
+        self.update_asset_properties(rid, properties, "AssetCommand::Extract")
+    }
+
+    /// Gets the history of changes to an `Asset`'s metadata.
+    fn asset_metadata_history(&self, rid: &ResourceId) -> Result<Vec<MetadataChange>> {
+        let Some(container) = self.store.get_asset_container(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        Ok(metadata_history::load_resource(
+            &container.base_path(),
+            rid,
+        )?)
     }
 
-    fn update_asset_properties(&mut self, rid: &ResourceId, properties: AssetProperties) -> Result {
+    /// Updates an `Asset`'s properties,
+    /// recording changes to its metadata in its `Container`'s history.
+    ///
+    /// # Arguments
+    /// 1. `Asset`.
+    /// 2. Updated properties.
+    /// 3. Command making the update.
+    ///
+    /// # Returns
+    /// Metadata schema violations.
+    pub(super) fn update_asset_properties(
+        &mut self,
+        rid: &ResourceId,
+        mut properties: AssetProperties,
+        command: &str,
+    ) -> Result<Vec<SchemaViolation>> {
+        self.store
+            .normalize_asset_properties(rid, &mut properties)?;
+        let violations = self.store.validate_asset_properties(rid, &properties)?;
+        properties.mark_modified(self.modifier());
         let Some(container) = self.store.get_asset_container_id(&rid).cloned() else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Asset` does not exist",
@@ -114,9 +283,13 @@ impl Database {
             .into());
         };
 
-        asset.properties = properties;
+        let old = std::mem::replace(&mut asset.properties, properties);
+        let new = asset.properties.metadata.clone();
         container.save()?;
-        Ok(())
+        let path = container.base_path().to_path_buf();
+        self.record_metadata_history(&path, rid, &old.metadata, &new, command);
+        self.store.reindex_asset(rid)?;
+        Ok(violations)
     }
 
     #[tracing::instrument(skip(self))]
@@ -130,84 +303,85 @@ impl Database {
     }
 
     /// Bulk update `Asset` properties.
+    ///
+    /// # Returns
+    /// Metadata schema violations of each `Asset` that has any.
+    ///
+    /// # Errors
+    /// + If an `Asset` can not be updated.
+    /// `Asset`s preceding it remain updated.
     #[tracing::instrument(skip(self))]
     fn bulk_update_asset_properties(
         &mut self,
         assets: &Vec<ResourceId>,
         update: &AssetPropertiesUpdate,
-    ) -> Result {
+    ) -> Result<ResourceMap<Vec<SchemaViolation>>> {
+        let mut violations = ResourceMap::new();
         for asset in assets {
-            self.update_asset_properties_from_update(asset, update)?;
+            let asset_violations = self.update_asset_properties_from_update(asset, update)?;
+            if !asset_violations.is_empty() {
+                violations.insert(asset.clone(), asset_violations);
+            }
         }
 
-        Ok(())
+        Ok(violations)
     }
 
     /// Update a `Asset`'s properties.
+    ///
+    /// # Returns
+    /// Metadata schema violations.
     #[tracing::instrument(skip(self))]
     fn update_asset_properties_from_update(
         &mut self,
         rid: &ResourceId,
         update: &AssetPropertiesUpdate,
-    ) -> Result {
-        let Some(container) = self.store.get_asset_container_id(&rid).cloned() else {
+    ) -> Result<Vec<SchemaViolation>> {
+        let Some(container) = self.store.get_asset_container(&rid) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Asset` does not exist",
             ))
             .into());
         };
 
-        let Some(container) = self.store.get_container_mut(&container) else {
-            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
-                "`Container` does not exist",
-            ))
-            .into());
-        };
-
-        let Some(asset) = container.assets.get_mut(&rid) else {
+        let Some(asset) = container.assets.get(&rid) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Asset` does not exist",
             ))
             .into());
         };
 
+        let mut properties = asset.properties.clone();
+
         // basic properties
         if let Some(name) = update.name.as_ref() {
-            asset.properties.name = name.clone();
+            properties.name = name.clone();
         }
 
         if let Some(kind) = update.kind.as_ref() {
-            asset.properties.kind = kind.clone();
+            properties.kind = kind.clone();
         }
 
         if let Some(description) = update.description.as_ref() {
-            asset.properties.description = description.clone();
+            properties.description = description.clone();
         }
 
         // tags
-        asset
-            .properties
-            .tags
-            .append(&mut update.tags.insert.clone());
-
-        asset.properties.tags.sort();
-        asset.properties.tags.dedup();
-        asset
-            .properties
-            .tags
-            .retain(|tag| !update.tags.remove.contains(tag));
+        let Some(vocabularies) = self.store.get_container_vocabularies(&container.rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
 
-        // metadata
-        asset
-            .properties
-            .metadata
-            .extend(update.metadata.insert.clone());
+        update.tags.apply(&mut properties.tags, &vocabularies.tags);
 
+        // metadata
+        properties.metadata.extend(update.metadata.insert.clone());
         for key in update.metadata.remove.iter() {
-            asset.properties.metadata.remove(key);
+            properties.metadata.remove(key);
         }
 
-        container.save()?;
-        Ok(())
+        self.update_asset_properties(rid, properties, "AssetCommand::BulkUpdateProperties")
     }
 }
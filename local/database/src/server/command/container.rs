@@ -2,10 +2,12 @@
 use super::super::Database;
 use crate::command::container::{
     BulkUpdateContainerPropertiesArgs, BulkUpdateScriptAssociationsArgs, ContainerPropertiesUpdate,
-    ScriptAssociationBulkUpdate, UpdatePropertiesArgs, UpdateScriptAssociationsArgs,
+    EditMetadataArgs, ScriptAssociationBulkUpdate, UpdatePropertiesArgs,
+    UpdateScriptAssociationsArgs,
 };
 use crate::command::ContainerCommand;
-use crate::Result;
+use crate::event::{Asset as AssetUpdate, Container as ContainerUpdate, Update};
+use crate::{Error, Result};
 use serde_json::Value as JsValue;
 use std::collections::HashSet;
 use std::fs;
@@ -13,11 +15,15 @@ use std::path::PathBuf;
 use thot_core::db::StandardSearchFilter;
 use thot_core::error::{Error as CoreError, ResourceError};
 use thot_core::project::container::ScriptMap;
-use thot_core::project::{Container as CoreContainer, ContainerProperties, RunParameters};
-use thot_core::types::ResourceId;
+use thot_core::project::{
+    AssetProperties, Container as CoreContainer, ContainerProperties, MetadataChange,
+    RunParameters, SchemaViolation,
+};
+use thot_core::types::{ResourceId, ResourceMap};
 use thot_local::common;
 use thot_local::error::ContainerError;
 use thot_local::error::Error as LocalError;
+use thot_local::project::metadata_history;
 use thot_local::project::resources::Container;
 
 impl Database {
@@ -37,10 +43,20 @@ impl Database {
             }
 
             ContainerCommand::GetWithMetadata(rid) => {
-                let container = self.store.get_container_with_metadata(&rid);
+                let container = self
+                    .store
+                    .get_container_with_metadata(&rid)
+                    .map(|container| self.store.flatten_container_metadata(container));
+
                 serde_json::to_value(container).expect("could not convert `Container` to JSON")
             }
 
+            ContainerCommand::ResolvedMetadata(rid) => {
+                let metadata = self.store.get_container_metadata(&rid);
+                serde_json::to_value(metadata)
+                    .expect("could not convert `ResolvedMetadata` to JSON")
+            }
+
             ContainerCommand::ByPath(path) => {
                 let Some(rid) = self.store.get_path_container_canonical(&path).unwrap() else {
                     let value: Option<CoreContainer> = None;
@@ -68,8 +84,32 @@ impl Database {
                 serde_json::to_value(containers).expect("could not convert `Container`s to JSON")
             }
 
+            ContainerCommand::FindQuery { root, query } => {
+                let res = self.resolve_project_query(&root, &query).map(|query| {
+                    let root = query.root.expect("query root not resolved");
+                    self.find_containers_with_metadata(&root, query.filter)
+                });
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            ContainerCommand::Search { root, query, limit } => {
+                let containers = self
+                    .store
+                    .search_containers(&root, &query, limit)
+                    .into_iter()
+                    .map(|container| self.store.flatten_container_metadata(container))
+                    .collect::<Vec<_>>();
+
+                serde_json::to_value(containers).expect("could not convert `Container`s to JSON")
+            }
+
             ContainerCommand::UpdateProperties(UpdatePropertiesArgs { rid, properties }) => {
-                let res = self.update_container_properties(rid, properties);
+                let res = self.update_container_properties(
+                    rid,
+                    properties,
+                    "ContainerCommand::UpdateProperties",
+                );
                 serde_json::to_value(res).expect("could not convert result to JSON")
             }
 
@@ -112,6 +152,16 @@ impl Database {
                 let res = self.bulk_update_container_script_associations(&containers, &update);
                 serde_json::to_value(res).unwrap()
             }
+
+            ContainerCommand::EditMetadata(args) => {
+                let res = self.edit_subtree_metadata(args);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            ContainerCommand::MetadataHistory(rid) => {
+                let res = self.container_metadata_history(&rid);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
         }
     }
 
@@ -133,20 +183,56 @@ impl Database {
     /// # Arguments
     /// 1. Root `Container`.
     /// 2. Search filter.
+    ///
+    /// # Returns
+    /// Matching `Container`s, with their resolved `Metadata` as their own.
     fn find_containers_with_metadata(
         &self,
         root: &ResourceId,
         filter: StandardSearchFilter,
     ) -> HashSet<CoreContainer> {
-        self.store.find_containers_with_metadata(&root, filter)
+        self.store
+            .find_containers_with_metadata(root, filter)
+            .into_iter()
+            .map(|container| self.store.flatten_container_metadata(container))
+            .collect()
+    }
+
+    /// Gets the history of changes to a `Container`'s metadata.
+    fn container_metadata_history(&self, rid: &ResourceId) -> Result<Vec<MetadataChange>> {
+        let Some(container) = self.store.get_container(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        Ok(metadata_history::load_resource(
+            &container.base_path(),
+            rid,
+        )?)
     }
 
+    /// Updates a `Container`'s properties,
+    /// recording changes to its metadata in its history.
+    ///
+    /// # Arguments
+    /// 1. `Container`.
+    /// 2. Updated properties.
+    /// 3. Command making the update.
+    ///
+    /// # Returns
+    /// Metadata schema violations.
     #[tracing::instrument(skip(self))]
-    fn update_container_properties(
+    pub(super) fn update_container_properties(
         &mut self,
         rid: ResourceId,
-        properties: ContainerProperties,
-    ) -> Result {
+        mut properties: ContainerProperties,
+        command: &str,
+    ) -> Result<Vec<SchemaViolation>> {
+        self.store
+            .normalize_container_properties(&rid, &mut properties)?;
+
         let Some(container) = self.store.get_container(&rid) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Container` does not exist",
@@ -154,19 +240,28 @@ impl Database {
             .into());
         };
 
+        let violations = self
+            .store
+            .validate_container_properties(&rid, &properties)?;
+
         let graph = self.store.get_container_graph(&rid).unwrap();
         if properties.name != container.properties.name && &container.rid != graph.root() {
             self.rename_container_folder(&rid, &properties.name)?;
         }
 
+        properties.mark_modified(self.modifier());
         let container = self
             .store
             .get_container_mut(&rid)
             .expect("Container no longer exists");
 
-        container.properties = properties;
+        let old = std::mem::replace(&mut container.properties, properties);
         container.save()?;
-        Ok(())
+        let path = container.base_path().to_path_buf();
+        let new = container.properties.metadata.clone();
+        self.record_metadata_history(&path, &rid, &old.metadata, &new, command);
+        self.store.reindex_container(&rid)?;
+        Ok(violations)
     }
 
     /// Renames a Container's folder.
@@ -280,71 +375,104 @@ impl Database {
     }
 
     /// Bulk update `Container` properties.
+    ///
+    /// # Returns
+    /// Metadata schema violations of each `Container` that has any.
+    ///
+    /// # Errors
+    /// + If a `Container` can not be updated.
+    /// `Container`s preceding it remain updated.
     #[tracing::instrument(skip(self))]
     fn bulk_update_container_properties(
         &mut self,
         containers: &Vec<ResourceId>,
         update: &ContainerPropertiesUpdate,
-    ) -> Result {
+    ) -> Result<ResourceMap<Vec<SchemaViolation>>> {
+        let mut violations = ResourceMap::new();
         for container in containers {
-            self.update_container_properties_from_update(container, update)?;
+            let container_violations =
+                self.update_container_properties_from_update(container, update)?;
+
+            if !container_violations.is_empty() {
+                violations.insert(container.clone(), container_violations);
+            }
         }
 
-        Ok(())
+        Ok(violations)
     }
 
     /// Update a `Container`'s properties.
+    ///
+    /// # Returns
+    /// Metadata schema violations.
     #[tracing::instrument(skip(self))]
     fn update_container_properties_from_update(
         &mut self,
         rid: &ResourceId,
         update: &ContainerPropertiesUpdate,
-    ) -> Result {
-        let Some(container) = self.store.get_container_mut(&rid) else {
+    ) -> Result<Vec<SchemaViolation>> {
+        let Some(container) = self.store.get_container(&rid) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Container` does not exist",
             ))
             .into());
         };
 
+        let mut properties = container.properties.clone();
+
         // basic properties
         if let Some(name) = update.name.as_ref() {
-            container.properties.name = name.clone();
+            properties.name = name.clone();
         }
 
         if let Some(kind) = update.kind.as_ref() {
-            container.properties.kind = kind.clone();
+            properties.kind = kind.clone();
         }
 
         if let Some(description) = update.description.as_ref() {
-            container.properties.description = description.clone();
+            properties.description = description.clone();
         }
 
         // tags
-        container
-            .properties
-            .tags
-            .append(&mut update.tags.insert.clone());
-
-        container.properties.tags.sort();
-        container.properties.tags.dedup();
-        container
-            .properties
-            .tags
-            .retain(|tag| !update.tags.remove.contains(tag));
+        let Some(vocabularies) = self.store.get_container_vocabularies(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
 
-        // metadata
-        container
-            .properties
-            .metadata
-            .extend(update.metadata.insert.clone());
+        update.tags.apply(&mut properties.tags, &vocabularies.tags);
 
+        // metadata
+        properties.metadata.extend(update.metadata.insert.clone());
         for key in update.metadata.remove.iter() {
-            container.properties.metadata.remove(key);
+            properties.metadata.remove(key);
         }
 
+        self.store
+            .normalize_container_properties(rid, &mut properties)?;
+
+        let violations = self.store.validate_container_properties(rid, &properties)?;
+
+        properties.mark_modified(self.modifier());
+        let container = self
+            .store
+            .get_container_mut(&rid)
+            .expect("Container no longer exists");
+
+        let old = std::mem::replace(&mut container.properties, properties);
         container.save()?;
-        Ok(())
+        let path = container.base_path().to_path_buf();
+        let new = container.properties.metadata.clone();
+        self.record_metadata_history(
+            &path,
+            rid,
+            &old.metadata,
+            &new,
+            "ContainerCommand::BulkUpdateProperties",
+        );
+        self.store.reindex_container(rid)?;
+        Ok(violations)
     }
 
     fn bulk_update_container_script_associations(
@@ -409,6 +537,209 @@ impl Database {
         container.save()?;
         Ok(())
     }
+
+    /// Edits the metadata of the `Container`s and `Asset`s of a subtree.
+    ///
+    /// # Returns
+    /// Affected resources.
+    ///
+    /// # Errors
+    /// + If the edit or validation fails for any resource.
+    /// No changes are made.
+    /// + If saving any resource fails.
+    /// Resources already saved are restored.
+    ///
+    /// # Side effects
+    /// + Publishes an update for each affected resource, unless a dry run.
+    fn edit_subtree_metadata(&mut self, args: EditMetadataArgs) -> Result<Vec<ResourceId>> {
+        let EditMetadataArgs {
+            root,
+            filter,
+            edit,
+            dry_run,
+        } = args;
+
+        let Some(project) = self.store.get_container_project(&root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let filter = filter.unwrap_or_default();
+        let containers = self
+            .store
+            .find_containers(&root, filter.clone())
+            .into_iter()
+            .map(|container| (container.rid.clone(), container.properties.clone()))
+            .collect::<Vec<_>>();
+
+        let assets = self
+            .store
+            .find_assets(&root, filter)
+            .into_iter()
+            .map(|asset| (asset.rid, asset.properties))
+            .collect::<Vec<_>>();
+
+        // edit and validate all resources before saving any
+        let mut edited_containers = Vec::new();
+        for (rid, original) in containers {
+            let mut properties = original.clone();
+            match edit.apply(&mut properties.metadata) {
+                Ok(true) => {
+                    self.store
+                        .normalize_container_properties(&rid, &mut properties)?;
+                    self.store
+                        .validate_container_properties(&rid, &properties)?;
+                    edited_containers.push((rid, original, properties));
+                }
+                Ok(false) => {}
+                Err(err) => return Err(Error::MetadataEditError(rid, err)),
+            }
+        }
+
+        let mut edited_assets = Vec::new();
+        for (rid, original) in assets {
+            let mut properties = original.clone();
+            match edit.apply(&mut properties.metadata) {
+                Ok(true) => {
+                    self.store
+                        .normalize_asset_properties(&rid, &mut properties)?;
+                    self.store.validate_asset_properties(&rid, &properties)?;
+                    edited_assets.push((rid, original, properties));
+                }
+                Ok(false) => {}
+                Err(err) => return Err(Error::MetadataEditError(rid, err)),
+            }
+        }
+
+        let mut affected = edited_containers
+            .iter()
+            .map(|(rid, _, _)| rid.clone())
+            .collect::<Vec<_>>();
+
+        affected.extend(edited_assets.iter().map(|(rid, _, _)| rid.clone()));
+        if dry_run {
+            return Ok(affected);
+        }
+
+        // save, restoring saved resources if any fails
+        let mut saved_containers = Vec::with_capacity(edited_containers.len());
+        for (rid, original, properties) in edited_containers {
+            if let Err(err) = self.update_container_properties(
+                rid.clone(),
+                properties,
+                "ContainerCommand::EditMetadata",
+            ) {
+                self.restore_properties(saved_containers, Vec::new());
+                return Err(err);
+            }
+
+            saved_containers.push((rid, original));
+        }
+
+        let mut saved_assets = Vec::with_capacity(edited_assets.len());
+        for (rid, original, properties) in edited_assets {
+            if let Err(err) =
+                self.update_asset_properties(&rid, properties, "ContainerCommand::EditMetadata")
+            {
+                self.restore_properties(saved_containers, saved_assets);
+                return Err(err);
+            }
+
+            saved_assets.push((rid, original));
+        }
+
+        for (rid, _) in saved_containers {
+            let properties = self
+                .store
+                .get_container(&rid)
+                .expect("`Container` not in store")
+                .properties
+                .clone();
+
+            self.publish_update(&Update::Project {
+                project: project.clone(),
+                update: ContainerUpdate::Properties {
+                    container: rid,
+                    properties,
+                }
+                .into(),
+            })?;
+        }
+
+        for (rid, _) in saved_assets {
+            let properties = self
+                .store
+                .get_asset_container(&rid)
+                .and_then(|container| container.assets.get(&rid))
+                .expect("`Asset` not in store")
+                .properties
+                .clone();
+
+            self.publish_update(&Update::Project {
+                project: project.clone(),
+                update: AssetUpdate::Properties {
+                    asset: rid,
+                    properties,
+                }
+                .into(),
+            })?;
+        }
+
+        Ok(affected)
+    }
+
+    /// Restores the properties of `Container`s and `Asset`s.
+    /// Failures are logged, as restoring is done while handling another error.
+    fn restore_properties(
+        &mut self,
+        containers: Vec<(ResourceId, ContainerProperties)>,
+        assets: Vec<(ResourceId, AssetProperties)>,
+    ) {
+        for (rid, properties) in containers {
+            let container = self
+                .store
+                .get_container_mut(&rid)
+                .expect("`Container` not in store");
+
+            container.properties = properties;
+            if let Err(err) = container.save() {
+                tracing::error!(?err, ?rid, "could not restore `Container` properties");
+            }
+
+            if let Err(err) = self.store.reindex_container(&rid) {
+                tracing::error!(?err, ?rid, "could not reindex `Container`");
+            }
+        }
+
+        for (rid, properties) in assets {
+            let container = self
+                .store
+                .get_asset_container_id(&rid)
+                .cloned()
+                .expect("`Asset` not in store");
+
+            let container = self
+                .store
+                .get_container_mut(&container)
+                .expect("`Container` not in store");
+
+            container
+                .assets
+                .get_mut(&rid)
+                .expect("`Asset` not in `Container`")
+                .properties = properties;
+
+            if let Err(err) = container.save() {
+                tracing::error!(?err, ?rid, "could not restore `Asset` properties");
+            }
+
+            if let Err(err) = self.store.reindex_asset(&rid) {
+                tracing::error!(?err, ?rid, "could not reindex `Asset`");
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -6,7 +6,8 @@ use fake::faker::lorem::raw::Word;
 use fake::locales::EN;
 use fake::Fake;
 use std::path::Path;
-use thot_core::project::{Container as CoreContainer, ContainerProperties};
+use thot_core::project::schema::ValueType;
+use thot_core::project::{Container as CoreContainer, ContainerProperties, MetadataEdit};
 use thot_local::project::resources::{Container as LocalContainer, Project as LocalProject};
 use thot_local::project::{container, project};
 
@@ -149,3 +150,138 @@ fn database_command_update_container_name_should_work() {
         "child folder should be renamed"
     );
 }
+
+#[test]
+fn edit_subtree_metadata_should_work() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut container = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    container
+        .properties
+        .metadata
+        .insert("temp".to_string(), "21.5".into());
+    container.save().expect("could not save `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let rename = MetadataEdit::RenameKey {
+        from: "temp".to_string(),
+        to: "temperature_c".to_string(),
+    };
+
+    // test
+    let affected = db
+        .edit_subtree_metadata(EditMetadataArgs {
+            root: rid.clone(),
+            filter: None,
+            edit: rename.clone(),
+            dry_run: true,
+        })
+        .expect("dry run should work");
+
+    assert_eq!(vec![rid.clone()], affected, "incorrect resources affected");
+    let saved = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    assert!(
+        saved.properties.metadata.contains_key("temp"),
+        "dry run should not change resources"
+    );
+
+    let convert = MetadataEdit::ConvertType {
+        key: "temp".to_string(),
+        to: ValueType::Bool,
+    };
+
+    let res = db.edit_subtree_metadata(EditMetadataArgs {
+        root: rid.clone(),
+        filter: None,
+        edit: convert,
+        dry_run: false,
+    });
+
+    assert!(
+        matches!(res, Err(Error::MetadataEditError(..))),
+        "invalid conversions should error"
+    );
+
+    db.edit_subtree_metadata(EditMetadataArgs {
+        root: rid.clone(),
+        filter: None,
+        edit: rename,
+        dry_run: false,
+    })
+    .expect("rename should work");
+
+    let saved = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    assert_eq!(
+        Some(&"21.5".into()),
+        saved.properties.metadata.get("temperature_c"),
+        "key not renamed"
+    );
+    assert!(
+        saved.properties.modified().is_some(),
+        "modification should be recorded"
+    );
+}
+
+#[test]
+fn update_container_properties_should_record_metadata_history() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let mut properties = db
+        .store
+        .get_container(&rid)
+        .expect("`Container` not loaded")
+        .properties
+        .clone();
+
+    properties.metadata.insert("temp".to_string(), 21.5.into());
+
+    // test
+    db.update_container_properties(rid.clone(), properties.clone(), "test")
+        .expect("could not update properties");
+
+    properties.metadata.insert("temp".to_string(), 22.into());
+    db.update_container_properties(rid.clone(), properties, "test")
+        .expect("could not update properties");
+
+    let history = db
+        .container_metadata_history(&rid)
+        .expect("could not get history");
+
+    assert_eq!(2, history.len(), "each change should be recorded");
+    assert_eq!("temp", history[0].key);
+    assert_eq!(None, history[0].old);
+    assert_eq!(Some(21.5.into()), history[1].old);
+    assert_eq!(Some(22.into()), history[1].new);
+    assert_eq!("test", history[1].command);
+}
@@ -1,20 +1,24 @@
 //! Implementation of graph related functionality.
 use super::super::Database;
-use crate::command::graph::NewChildArgs;
+use crate::command::graph::{InstantiateTemplateArgs, NewChildArgs};
 use crate::command::GraphCommand;
-use crate::server::store::ContainerTree;
+use crate::event::{Graph as GraphUpdate, Update};
+use crate::server::store::{subgraph_resources, ContainerTree};
 use crate::{Error, Result};
 use serde_json::Value as JsValue;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::result::Result as StdResult;
-use thot_core::error::{Error as CoreError, ProjectError, ResourceError};
+use thot_core::error::{Error as CoreError, GraphError, ProjectError, ResourceError};
 use thot_core::graph::ResourceTree;
 use thot_core::project::Container as CoreContainer;
+use thot_core::system::template::{InstanceOptions, Subtree as SubtreeTemplate};
 use thot_core::types::ResourceId;
 use thot_local::common::unique_file_name;
 use thot_local::graph::{ContainerTreeDuplicator, ContainerTreeLoader, ContainerTreeTransformer};
 use thot_local::project::container;
 use thot_local::project::resources::container::Container;
+use thot_local::system::template::Subtree;
 
 impl Database {
     #[tracing::instrument(skip(self))]
@@ -98,6 +102,43 @@ impl Database {
                 let children = graph.children(&rid).unwrap();
                 serde_json::to_value(children).expect("could not convert `Container` to JsValue")
             }
+
+            GraphCommand::NewChild(NewChildArgs { name, parent }) => {
+                let res = self.new_child(&parent, name);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::Remove(root) => {
+                let res = self.remove_container_tree(&root);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::Move { root, parent } => {
+                let res = self.move_container_tree(&root, &parent);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::Reorder { root, index } => {
+                let res = self.reorder_container(&root, index);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::SaveTemplate { root, name } => {
+                let res = self.save_subtree_template(&root, name);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::Templates => {
+                let res: Result<Vec<SubtreeTemplate>> =
+                    Subtree::load_all().map_err(|err| err.into());
+
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
+
+            GraphCommand::Instantiate(args) => {
+                let res = self.instantiate_subtree_template(args);
+                serde_json::to_value(res).expect("could not convert `Result` to JsValue")
+            }
         }
     }
 
@@ -120,6 +161,7 @@ impl Database {
             let path = project.base_path().join(data_root);
             let graph: ContainerTree = ContainerTreeLoader::load(&path)?;
             self.store.insert_project_graph(pid.clone(), graph);
+            self.store.remove_dangling_links(pid)?;
         }
 
         let Some(graph) = self.store.get_project_graph(pid) else {
@@ -133,6 +175,7 @@ impl Database {
 
     /// Duplicates a tree in its parent.
     /// Returns the id of the duplicated tree's root node.
+    /// The duplicate's folder is removed if it can not be added to the graph.
     #[tracing::instrument(skip(self))]
     fn duplicate_container_tree(&mut self, rid: &ResourceId) -> Result<ResourceId> {
         let Some(project) = self.store.get_container_project(rid) else {
@@ -180,32 +223,442 @@ impl Database {
         root.save()?;
 
         // insert duplicate
-        let res = self.store.insert_subgraph(&parent, dup);
-        match res {
-            Ok(_) => Ok(dup_root),
-            Err(err) => Err(err),
+        if let Err(err) = self.store.insert_subgraph(&parent, dup) {
+            fs::remove_dir_all(&dup_path).map_err(CoreError::from)?;
+            return Err(err);
+        }
+
+        if let Err(err) = self.save_child_order(&parent) {
+            self.store.remove_subgraph(&dup_root)?;
+            fs::remove_dir_all(&dup_path).map_err(CoreError::from)?;
+            return Err(err);
         }
+
+        Ok(dup_root)
+    }
+
+    /// Saves a subtree as a template.
+    fn save_subtree_template(&self, root: &ResourceId, name: String) -> Result<SubtreeTemplate> {
+        let Some(graph) = self.store.get_container_graph(root) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let template = Subtree::from_graph(graph, root, name)?;
+        template.save()?;
+        Ok((*template).clone())
     }
 
-    fn new_child(&mut self, parent: &ResourceId, name: String) -> Result<ResourceId> {
-        let Some(parent) = self.store.get_container(&parent) else {
+    /// Creates instances of a subtree template and inserts them into the parent's graph.
+    #[tracing::instrument(skip(self))]
+    fn instantiate_subtree_template(
+        &mut self,
+        args: InstantiateTemplateArgs,
+    ) -> Result<Vec<ResourceTree<CoreContainer>>> {
+        let template = Subtree::load(&args.template)?;
+        self.insert_template_instances(&args.parent, &template, &args.options)
+    }
+
+    /// Creates instances of a template and inserts them into the parent's graph.
+    /// If any instance can not be inserted, all instances are removed
+    /// from the graph and disk.
+    ///
+    /// # Returns
+    /// Graphs of the instances, in order.
+    fn insert_template_instances(
+        &mut self,
+        parent: &ResourceId,
+        template: &Subtree,
+        options: &InstanceOptions,
+    ) -> Result<Vec<ResourceTree<CoreContainer>>> {
+        let Some(parent_container) = self.store.get_container(parent) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Container` does not exist",
             ))
             .into());
         };
 
+        let Some(project) = self.store.get_container_project(parent).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
+
+        let parent_path = parent_container.base_path().to_path_buf();
+        let instances = template.instantiate(&parent_path, options)?;
+        let paths = instances
+            .iter()
+            .map(|instance| {
+                let root = instance.get(instance.root()).expect("root not in graph");
+                root.base_path().to_path_buf()
+            })
+            .collect::<Vec<_>>();
+
+        let mut inserted = Vec::with_capacity(instances.len());
+        let mut graphs = Vec::with_capacity(instances.len());
+        for instance in instances {
+            let root = instance.root().clone();
+            let graph = ContainerTreeTransformer::local_to_core(&instance);
+            if let Err(err) = self.store.insert_subgraph(parent, instance) {
+                self.remove_template_instances(&inserted, &paths)?;
+                return Err(err);
+            }
+
+            inserted.push(root);
+            graphs.push(graph);
+        }
+
+        if let Err(err) = self.save_child_order(parent) {
+            self.remove_template_instances(&inserted, &paths)?;
+            return Err(err);
+        }
+
+        for graph in graphs.iter() {
+            self.publish_update(&Update::Project {
+                project: project.clone(),
+                update: GraphUpdate::Created {
+                    parent: parent.clone(),
+                    graph: graph.clone(),
+                }
+                .into(),
+            })?;
+        }
+
+        Ok(graphs)
+    }
+
+    /// Removes instances of a template from the graph and disk.
+    /// Used to roll back a failed instantiation.
+    ///
+    /// # Arguments
+    /// 1. Roots of the instances inserted into the graph.
+    /// 2. Folders of all instances.
+    fn remove_template_instances(&mut self, inserted: &[ResourceId], paths: &[PathBuf]) -> Result {
+        for root in inserted {
+            self.store.remove_subgraph(root)?;
+        }
+
+        for path in paths {
+            fs::remove_dir_all(path).map_err(CoreError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new child `Container`.
+    /// The child's folder is removed if it can not be added to the graph.
+    #[tracing::instrument(skip(self))]
+    fn new_child(&mut self, parent: &ResourceId, name: String) -> Result<CoreContainer> {
+        let Some(parent_container) = self.store.get_container(&parent) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(project) = self.store.get_container_project(parent).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
+
         // create child
-        // TODO Ensure unique and valid path.
-        let child_path = unique_file_name(parent.base_path().join(&name))?;
-        let cid = container::new(&child_path)?;
-        let mut child = Container::load_from(child_path)?;
-        child.properties.name = name;
-        child.save()?;
+        let child_path = unique_file_name(parent_container.base_path().join(&name))?;
+        let child = match create_container(&child_path, name) {
+            Ok(child) => child,
+            Err(err) => {
+                let _ = fs::remove_dir_all(&child_path);
+                return Err(err);
+            }
+        };
 
         // insert into graph
+        let child_container = (*child).clone();
         let child = ContainerTree::new(child);
-        self.store.insert_subgraph(&parent.rid.clone(), child)?;
-        Ok(cid)
+        let graph = ContainerTreeTransformer::local_to_core(&child);
+        if let Err(err) = self.store.insert_subgraph(parent, child) {
+            fs::remove_dir_all(&child_path).map_err(CoreError::from)?;
+            return Err(err);
+        }
+
+        if let Err(err) = self.save_child_order(parent) {
+            self.store.remove_subgraph(&child_container.rid)?;
+            fs::remove_dir_all(&child_path).map_err(CoreError::from)?;
+            return Err(err);
+        }
+
+        self.publish_update(&Update::Project {
+            project,
+            update: GraphUpdate::Created {
+                parent: parent.clone(),
+                graph,
+            }
+            .into(),
+        })?;
+
+        Ok(child_container)
+    }
+
+    /// Removes a subtree, moving its folder to the trash.
+    /// The subtree is restored to the graph if its folder can not be trashed.
+    ///
+    /// # Returns
+    /// The removed subtree.
+    #[tracing::instrument(skip(self))]
+    fn remove_container_tree(&mut self, root: &ResourceId) -> Result<ResourceTree<CoreContainer>> {
+        let Some(graph) = self.store.get_container_graph(root) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(parent) = graph.parent(root)?.cloned() else {
+            return Err(CoreError::GraphError(GraphError::invalid_graph(
+                "can not remove graph root",
+            ))
+            .into());
+        };
+
+        let Some(project) = self.store.get_container_project(root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
+
+        let index = graph
+            .children(&parent)
+            .and_then(|children| children.get_index_of(root))
+            .expect("`Container` not a child of its parent");
+
+        let path = graph.get(root).unwrap().base_path().to_path_buf();
+        let graph = self.store.detach_subgraph(root)?;
+        if let Err(err) = trash::delete(&path) {
+            self.store.insert_subgraph(&parent, graph)?;
+            self.store
+                .get_project_graph_mut(&project)
+                .expect("`Project` graph not found")
+                .move_index(root, index)?;
+
+            return Err(err.into());
+        }
+
+        self.store
+            .remove_links_to(&project, &subgraph_resources(&graph))?;
+
+        let graph = ContainerTreeTransformer::local_to_core(&graph);
+        self.publish_update(&Update::Project {
+            project,
+            update: GraphUpdate::Removed(graph.clone()).into(),
+        })?;
+
+        Ok(graph)
+    }
+
+    /// Moves a subtree to a new parent.
+    /// The folder is moved back if the graph can not be updated.
+    #[tracing::instrument(skip(self))]
+    fn move_container_tree(&mut self, root: &ResourceId, parent: &ResourceId) -> Result {
+        let Some(graph) = self.store.get_container_graph(root) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(parent_container) = graph.get(parent) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "parent `Container` does not exist in `Project`",
+            ))
+            .into());
+        };
+
+        let Some(from_parent) = graph.parent(root)?.cloned() else {
+            return Err(CoreError::GraphError(GraphError::invalid_graph(
+                "can not move graph root",
+            ))
+            .into());
+        };
+
+        let index = graph
+            .children(&from_parent)
+            .and_then(|children| children.get_index_of(root))
+            .expect("`Container` not a child of its parent");
+
+        if graph
+            .descendants(root)
+            .expect("`Container` not in graph")
+            .contains(parent)
+        {
+            return Err(CoreError::GraphError(GraphError::invalid_graph(
+                "can not move `Container` into its own subtree",
+            ))
+            .into());
+        }
+
+        let root_container = graph.get(root).unwrap();
+        let from = root_container.base_path().to_path_buf();
+        let name = root_container.properties.name.clone();
+        let to = parent_container
+            .base_path()
+            .join(from.file_name().expect("invalid `Container` path"));
+
+        if to.exists() {
+            return Err(CoreError::ResourceError(ResourceError::already_exists(
+                "`Container` path already exists in parent",
+            ))
+            .into());
+        }
+
+        let Some(project) = self.store.get_container_project(root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` does not exist",
+            ))
+            .into());
+        };
+
+        fs::rename(&from, &to).map_err(CoreError::from)?;
+        if let Err(err) = self.store.update_subgraph_path(root, &to) {
+            fs::rename(&to, &from).map_err(CoreError::from)?;
+            return Err(err);
+        }
+
+        if let Err(err) = self.store.move_subgraph(root, parent) {
+            self.store.update_subgraph_path(root, &from)?;
+            fs::rename(&to, &from).map_err(CoreError::from)?;
+            return Err(err);
+        }
+
+        if let Err(err) = self
+            .save_child_order(&from_parent)
+            .and_then(|_| self.save_child_order(parent))
+        {
+            self.store.move_subgraph(root, &from_parent)?;
+            self.store
+                .get_project_graph_mut(&project)
+                .expect("`Project` graph not found")
+                .move_index(root, index)?;
+
+            self.store.update_subgraph_path(root, &from)?;
+            fs::rename(&to, &from).map_err(CoreError::from)?;
+            self.save_child_order(&from_parent)?;
+            self.save_child_order(parent)?;
+            return Err(err);
+        }
+
+        self.store.reindex_container(root)?;
+        self.publish_update(&Update::Project {
+            project,
+            update: GraphUpdate::Moved {
+                root: root.clone(),
+                parent: parent.clone(),
+                name,
+            }
+            .into(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Moves a `Container` to a new position among its siblings.
+    #[tracing::instrument(skip(self))]
+    fn reorder_container(&mut self, root: &ResourceId, index: usize) -> Result {
+        let Some(project) = self.store.get_container_project(root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let graph = self
+            .store
+            .get_project_graph_mut(&project)
+            .expect("`Project` graph not found");
+
+        let Some(parent) = graph.parent(root)?.cloned() else {
+            return Err(CoreError::GraphError(GraphError::invalid_graph(
+                "can not reorder graph root",
+            ))
+            .into());
+        };
+
+        let children = graph
+            .children(&parent)
+            .expect("parent `Container` not found");
+        let prev_index = children
+            .get_index_of(root)
+            .expect("`Container` not a child of its parent");
+
+        graph.move_index(root, index)?;
+        if let Err(err) = self.save_child_order(&parent) {
+            self.store
+                .get_project_graph_mut(&project)
+                .expect("`Project` graph not found")
+                .move_index(root, prev_index)
+                .expect("could not restore `Container` index");
+
+            return Err(err);
+        }
+
+        self.publish_update(&Update::Project {
+            project,
+            update: GraphUpdate::Reordered {
+                root: root.clone(),
+                index,
+            }
+            .into(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Saves the order of a `Container`'s children in the graph to its settings.
+    /// The settings are left unchanged if they can not be saved.
+    fn save_child_order(&mut self, parent: &ResourceId) -> Result {
+        let Some(project) = self.store.get_container_project(parent).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let graph = self
+            .store
+            .get_project_graph_mut(&project)
+            .expect("`Project` graph not found");
+
+        let order = graph
+            .children(parent)
+            .expect("`Container` not found")
+            .iter()
+            .cloned()
+            .collect();
+
+        let container = graph.get_mut(parent).expect("`Container` not found");
+        let prev_order = std::mem::replace(&mut container.settings_mut().children, order);
+        if let Err(err) = container.save() {
+            container.settings_mut().children = prev_order;
+            return Err(err.into());
+        }
+
+        Ok(())
     }
 }
+
+/// Initializes a folder as a `Container` with the given name.
+fn create_container(path: &Path, name: String) -> Result<Container> {
+    container::new(path)?;
+    let mut container = Container::load_from(path)?;
+    container.properties.name = name;
+    container.save()?;
+    Ok(container)
+}
+
+#[cfg(test)]
+#[path = "./graph_test.rs"]
+mod graph_test;
@@ -0,0 +1,234 @@
+use super::*;
+use crate::command::ProjectCommand;
+use dev_utils::fs::TempDir;
+use thot_local::project::resources::Project as LocalProject;
+
+#[test]
+fn graph_mutations_should_update_disk_and_store() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let root = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    // test
+    let a = db
+        .new_child(&root, "a".to_string())
+        .expect("could not create child");
+
+    let b = db
+        .new_child(&root, "b".to_string())
+        .expect("could not create child");
+
+    let a_path = data_dir.join("a");
+    assert!(a_path.join(thot_local::common::thot_dir()).exists());
+    assert_eq!("a", a.properties.name);
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    assert_eq!(
+        vec![a.rid.clone(), b.rid.clone()],
+        graph.get(&root).unwrap().settings().children,
+        "new children should be added to the order"
+    );
+
+    db.reorder_container(&b.rid, 0)
+        .expect("could not reorder `Container`");
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    assert_eq!(&b.rid, graph.children(&root).unwrap().get_index(0).unwrap());
+    let loaded = ContainerTreeLoader::load(&data_dir).expect("could not load graph");
+    assert_eq!(
+        &b.rid,
+        loaded
+            .children(loaded.root())
+            .unwrap()
+            .get_index(0)
+            .unwrap(),
+        "order should be persisted"
+    );
+
+    assert!(db.reorder_container(&b.rid, 2).is_err());
+
+    db.move_container_tree(&a.rid, &b.rid)
+        .expect("could not move `Container`");
+
+    let moved_path = data_dir.join("b").join("a");
+    assert!(!a_path.exists(), "folder should be moved");
+    assert!(moved_path.exists(), "folder should be moved");
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    assert_eq!(Some(&b.rid), graph.parent(&a.rid).unwrap());
+    assert_eq!(
+        vec![b.rid.clone()],
+        graph.get(&root).unwrap().settings().children,
+        "moved `Container` should be removed from old parent's order"
+    );
+    assert_eq!(
+        vec![a.rid.clone()],
+        graph.get(&b.rid).unwrap().settings().children,
+        "moved `Container` should be added to new parent's order"
+    );
+
+    let loaded = ContainerTreeLoader::load(&data_dir).expect("could not load graph");
+    assert_eq!(Some(&b.rid), loaded.parent(&a.rid).unwrap());
+    assert_eq!(
+        vec![b.rid.clone()],
+        loaded.get(loaded.root()).unwrap().settings().children
+    );
+    assert_eq!(moved_path, graph.get(&a.rid).unwrap().base_path());
+    assert!(
+        db.move_container_tree(&b.rid, &a.rid).is_err(),
+        "moving into own subtree should error"
+    );
+
+    assert!(
+        db.remove_container_tree(&root).is_err(),
+        "root should not be removed"
+    );
+
+    assert!(db.new_child(&ResourceId::new(), "c".to_string()).is_err());
+}
+
+#[test]
+fn insert_template_instances_should_roll_back_on_failure() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let root = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let a = db
+        .new_child(&root, "a".to_string())
+        .expect("could not create child");
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    let template = Subtree::from_graph(graph, &a.rid, "template").unwrap();
+    let options = InstanceOptions::new("instance {i}", 2);
+
+    // prevent the parent's child order from being saved
+    let settings_path = thot_local::common::container_settings_file_of(&data_dir);
+    fs::remove_file(&settings_path).unwrap();
+    fs::create_dir(&settings_path).unwrap();
+
+    // test
+    assert!(db
+        .insert_template_instances(&root, &template, &options)
+        .is_err());
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    assert_eq!(
+        vec![&a.rid],
+        graph.children(&root).unwrap().iter().collect::<Vec<_>>(),
+        "instances should be removed from the graph"
+    );
+
+    for name in ["instance 1", "instance 2"] {
+        let path = data_dir.join(name);
+        assert!(!path.exists(), "instance folders should be removed");
+        assert!(db.store.get_path_container(&path).is_none());
+    }
+
+    fs::remove_dir(&settings_path).unwrap();
+    let instances = db
+        .insert_template_instances(&root, &template, &options)
+        .expect("could not instantiate template");
+
+    assert_eq!(2, instances.len());
+    let loaded = ContainerTreeLoader::load(&data_dir).expect("could not load graph");
+    let mut order = vec![a.rid.clone()];
+    order.extend(instances.iter().map(|instance| instance.root().clone()));
+    assert_eq!(
+        order,
+        loaded.get(loaded.root()).unwrap().settings().children,
+        "instances should be added to the order"
+    );
+}
+
+#[test]
+fn duplicate_container_tree_should_preserve_child_order() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let root = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let a = db
+        .new_child(&root, "a".to_string())
+        .expect("could not create child");
+
+    for name in ["x", "y", "z"] {
+        db.new_child(&a.rid, name.to_string())
+            .expect("could not create child");
+    }
+
+    let graph = db.store.get_container_graph(&root).unwrap();
+    let z = graph
+        .children(&a.rid)
+        .unwrap()
+        .get_index(2)
+        .unwrap()
+        .clone();
+    db.reorder_container(&z, 0)
+        .expect("could not reorder `Container`");
+
+    // test
+    let dup = db
+        .duplicate_container_tree(&a.rid)
+        .expect("could not duplicate tree");
+
+    let loaded = ContainerTreeLoader::load(&data_dir).expect("could not load graph");
+    assert_eq!(
+        vec![a.rid.clone(), dup.clone()],
+        loaded.get(loaded.root()).unwrap().settings().children,
+        "duplicate should be added to the parent's order"
+    );
+
+    let names = loaded
+        .children(&dup)
+        .unwrap()
+        .iter()
+        .map(|child| loaded.get(child).unwrap().properties.name.clone())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        vec!["z", "x", "y"],
+        names,
+        "child order should survive reload"
+    );
+}
@@ -0,0 +1,136 @@
+//! Handle lineage commands.
+use super::super::Database;
+use crate::command::lineage::RecordLineageArgs;
+use crate::command::LineageCommand;
+use crate::Result;
+use serde_json::Value as JsValue;
+use std::path::PathBuf;
+use thot_core::error::{Error as CoreError, ResourceError};
+use thot_core::project::{lineage, Lineage};
+use thot_core::types::{ResourceId, ResourcePath};
+use thot_local::project::lineage as local_lineage;
+
+impl Database {
+    #[tracing::instrument(skip(self))]
+    pub fn handle_command_lineage(&mut self, cmd: LineageCommand) -> JsValue {
+        match cmd {
+            LineageCommand::Record(args) => {
+                let res = self.record_lineage(args);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            LineageCommand::Get(asset) => {
+                let res = self
+                    .lineage_records(&asset)
+                    .map(|records| records.into_iter().find(|record| record.asset == asset));
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            LineageCommand::Upstream(asset) => {
+                let res = self
+                    .lineage_records(&asset)
+                    .map(|records| lineage::upstream(&records, &asset));
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            LineageCommand::Downstream(asset) => {
+                let res = self
+                    .lineage_records(&asset)
+                    .map(|records| lineage::downstream(&records, &asset));
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+        }
+    }
+
+    /// Records how an `Asset` was produced, replacing its previous record.
+    ///
+    /// # Errors
+    /// + If the `Asset`, `Container`, or an input is not in the same `Project`.
+    pub(super) fn record_lineage(&self, args: RecordLineageArgs) -> Result<Lineage> {
+        let project = self.asset_project_path(&args.asset)?;
+        let project_id = self
+            .store
+            .get_resource_project(&args.asset)
+            .expect("`Asset` `Project` not found");
+
+        for rid in args.inputs.iter().chain([&args.container]) {
+            if self.store.get_resource_project(rid) != Some(project_id) {
+                return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                    "resource does not exist in `Project`",
+                ))
+                .into());
+            }
+        }
+
+        let mut record = Lineage::new(args.asset, args.script, args.container);
+        record.run = args.run;
+        record.inputs = args.inputs;
+        record.script_hash = self.script_hash(&record.script);
+
+        let mut records = local_lineage::load(&project)?;
+        records.retain(|prev| prev.asset != record.asset);
+        records.push(record.clone());
+        local_lineage::save(&project, &records)?;
+
+        Ok(record)
+    }
+
+    /// Lineage records of the `Project` an `Asset` belongs to.
+    fn lineage_records(&self, asset: &ResourceId) -> Result<Vec<Lineage>> {
+        let project = self.asset_project_path(asset)?;
+        Ok(local_lineage::load(&project)?)
+    }
+
+    /// Path of the `Project` an `Asset` belongs to.
+    fn asset_project_path(&self, asset: &ResourceId) -> Result<PathBuf> {
+        let Some(project) = self
+            .store
+            .get_asset_container_id(asset)
+            .and_then(|container| self.store.get_container_project(container))
+        else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let project = self
+            .store
+            .get_project(project)
+            .expect("`Project` not loaded");
+
+        Ok(project.base_path().to_path_buf())
+    }
+
+    /// Hash of a `Script`'s file.
+    ///
+    /// # Returns
+    /// `None` if the `Script`'s file could not be read.
+    fn script_hash(&self, script: &ResourceId) -> Option<String> {
+        let path = match &self.store.get_script(script)?.path {
+            ResourcePath::Absolute(path) => path.clone(),
+            ResourcePath::Relative(path) => {
+                let project = self.store.get_script_project(script)?;
+                let project = self.store.get_project(project)?;
+                project.analysis_root_path()?.join(path)
+            }
+
+            ResourcePath::Root(_, _) => return None,
+        };
+
+        match local_lineage::file_hash(&path) {
+            Ok(hash) => Some(hash),
+            Err(err) => {
+                tracing::warn!(?err, "could not hash `Script`");
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./lineage_test.rs"]
+mod lineage_test;
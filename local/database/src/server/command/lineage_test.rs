@@ -0,0 +1,190 @@
+use super::*;
+use crate::command::lineage::AssetLineageArgs;
+use crate::command::{AssetCommand, GraphCommand, ProjectCommand};
+use dev_utils::fs::TempDir;
+use std::fs;
+use std::path::PathBuf;
+use thot_core::project::{Asset, Script};
+use thot_local::project::container;
+use thot_local::project::resources::{Project as LocalProject, Scripts as LocalScripts};
+
+#[test]
+fn lineage_should_be_recorded_and_queried() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+    let analysis_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.analysis_root = Some(analysis_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let cid = container::InitOptions::init()
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    for file in ["raw.csv", "filtered.csv", "stats.csv"] {
+        fs::write(data_dir.join(file), "").unwrap();
+    }
+
+    fs::write(analysis_dir.join("filter.py"), "print('filter')").unwrap();
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid.clone()));
+
+    let script = Script::new(ResourcePath::Relative(PathBuf::from("filter.py")))
+        .expect("could not create `Script`");
+
+    let sid = script.rid.clone();
+    let mut scripts = LocalScripts::new(dir.path().to_path_buf());
+    scripts
+        .insert_script(script)
+        .expect("could not insert `Script`");
+
+    db.store.insert_project_scripts(pid, scripts);
+
+    let mut aids = Vec::new();
+    for file in ["raw.csv", "filtered.csv", "stats.csv"] {
+        let asset = Asset::new(ResourcePath::Relative(PathBuf::from(file)));
+        aids.push(asset.rid.clone());
+        db.store
+            .add_asset(asset, cid.clone())
+            .expect("could not add `Asset`");
+    }
+
+    let (raw, filtered, stats) = (aids[0].clone(), aids[1].clone(), aids[2].clone());
+
+    // test
+    let record = db
+        .record_lineage(RecordLineageArgs {
+            asset: filtered.clone(),
+            script: sid.clone(),
+            container: cid.clone(),
+            run: None,
+            inputs: vec![raw.clone()],
+        })
+        .expect("could not record lineage");
+
+    assert!(record.script_hash.is_some(), "`Script` should be hashed");
+
+    db.record_lineage(RecordLineageArgs {
+        asset: stats.clone(),
+        script: sid.clone(),
+        container: cid.clone(),
+        run: None,
+        inputs: vec![filtered.clone()],
+    })
+    .expect("could not record lineage");
+
+    let records = local_lineage::load(dir.path()).unwrap();
+    assert_eq!(2, records.len());
+    assert_eq!(
+        vec![filtered.clone(), raw.clone()],
+        lineage::upstream(&records, &stats)
+    );
+
+    let downstream: Result<Vec<ResourceId>> =
+        serde_json::from_value(db.handle_command_lineage(LineageCommand::Downstream(raw.clone())))
+            .unwrap();
+    assert_eq!(vec![filtered.clone(), stats.clone()], downstream.unwrap());
+
+    // re-recording replaces the previous record
+    db.record_lineage(RecordLineageArgs {
+        asset: stats.clone(),
+        script: sid,
+        container: cid,
+        run: None,
+        inputs: vec![raw.clone()],
+    })
+    .expect("could not record lineage");
+
+    let records = db.lineage_records(&stats).unwrap();
+    assert_eq!(2, records.len());
+    assert_eq!(vec![raw], lineage::upstream(&records, &stats));
+
+    let invalid = db.record_lineage(RecordLineageArgs {
+        asset: filtered,
+        script: ResourceId::new(),
+        container: ResourceId::new(),
+        run: None,
+        inputs: Vec::new(),
+    });
+    assert!(
+        invalid.is_err(),
+        "`Container` outside `Project` should error"
+    );
+}
+
+#[test]
+fn add_asset_should_not_add_asset_if_lineage_is_not_recorded() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let cid = container::InitOptions::init()
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    for file in ["raw.csv", "filtered.csv"] {
+        fs::write(data_dir.join(file), "").unwrap();
+    }
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let raw = Asset::new(ResourcePath::Relative(PathBuf::from("raw.csv")));
+    let raw_id = raw.rid.clone();
+    db.store
+        .add_asset(raw, cid.clone())
+        .expect("could not add `Asset`");
+
+    let script = ResourceId::new();
+
+    // test
+    let filtered = Asset::new(ResourcePath::Relative(PathBuf::from("filtered.csv")));
+    let filtered_id = filtered.rid.clone();
+    let res = db.handle_command_asset(AssetCommand::Add {
+        asset: filtered,
+        container: cid.clone(),
+        lineage: Some(AssetLineageArgs {
+            script: script.clone(),
+            run: None,
+            inputs: vec![ResourceId::new()],
+        }),
+    });
+
+    let res: Result<Option<Asset>> = serde_json::from_value(res).unwrap();
+
+    assert!(res.is_err(), "missing input should error");
+    assert!(
+        db.store.get_asset_container(&filtered_id).is_none(),
+        "`Asset` should not be added"
+    );
+
+    let filtered = Asset::new(ResourcePath::Relative(PathBuf::from("filtered.csv")));
+    let filtered_id = filtered.rid.clone();
+    let res = db.handle_command_asset(AssetCommand::Add {
+        asset: filtered,
+        container: cid,
+        lineage: Some(AssetLineageArgs {
+            script,
+            run: None,
+            inputs: vec![raw_id.clone()],
+        }),
+    });
+
+    let res: Result<Option<Asset>> = serde_json::from_value(res).unwrap();
+    res.expect("could not add `Asset`");
+
+    let records = db.lineage_records(&filtered_id).unwrap();
+    assert_eq!(vec![raw_id], lineage::upstream(&records, &filtered_id));
+}
@@ -0,0 +1,101 @@
+//! Handle link commands.
+use super::super::Database;
+use crate::command::link::LinkArgs;
+use crate::command::LinkCommand;
+use crate::Result;
+use serde_json::Value as JsValue;
+use thot_core::error::{Error as CoreError, ResourceError};
+use thot_core::project::{Backlink, Links};
+use thot_core::types::ResourceId;
+
+impl Database {
+    #[tracing::instrument(skip(self))]
+    pub fn handle_command_link(&mut self, cmd: LinkCommand) -> JsValue {
+        match cmd {
+            LinkCommand::Get(rid) => {
+                let links = self.store.get_links(&rid);
+                serde_json::to_value(links).expect("could not convert result to JSON")
+            }
+
+            LinkCommand::Backlinks(rid) => {
+                let res = self.backlinks(&rid);
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            LinkCommand::Add(LinkArgs {
+                source,
+                relation,
+                target,
+            }) => {
+                let res = self.update_links(&source, "LinkCommand::Add", |links| {
+                    links.insert(relation, target);
+                });
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+
+            LinkCommand::Remove(LinkArgs {
+                source,
+                relation,
+                target,
+            }) => {
+                let res = self.update_links(&source, "LinkCommand::Remove", |links| {
+                    links.remove(&relation, &target);
+                });
+
+                serde_json::to_value(res).expect("could not convert result to JSON")
+            }
+        }
+    }
+
+    fn backlinks(&self, target: &ResourceId) -> Result<Vec<Backlink>> {
+        let Some(backlinks) = self.store.get_backlinks(target) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "resource does not exist",
+            ))
+            .into());
+        };
+
+        Ok(backlinks)
+    }
+
+    /// Updates the links of a `Container` or `Asset`.
+    ///
+    /// # Arguments
+    /// 1. Resource to update.
+    /// 2. Command making the update.
+    /// 3. Function modifying the links.
+    fn update_links(
+        &mut self,
+        rid: &ResourceId,
+        command: &str,
+        f: impl FnOnce(&mut Links),
+    ) -> Result {
+        if let Some(container) = self.store.get_container(rid) {
+            let mut properties = container.properties.clone();
+            f(&mut properties.links);
+            self.update_container_properties(rid.clone(), properties, command)?;
+            return Ok(());
+        }
+
+        let Some(asset) = self
+            .store
+            .get_asset_container(rid)
+            .and_then(|container| container.assets.get(rid))
+        else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "resource does not exist",
+            ))
+            .into());
+        };
+
+        let mut properties = asset.properties.clone();
+        f(&mut properties.links);
+        self.update_asset_properties(rid, properties, command)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "./link_test.rs"]
+mod link_test;
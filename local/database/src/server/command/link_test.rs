@@ -0,0 +1,192 @@
+use super::*;
+use crate::command::{GraphCommand, ProjectCommand};
+use dev_utils::fs::TempDir;
+use std::fs;
+use std::path::PathBuf;
+use thot_core::project::Asset;
+use thot_core::types::ResourcePath;
+use thot_local::project::container;
+use thot_local::project::resources::{Container, Project as LocalProject};
+
+#[test]
+fn links_should_persist_and_be_pruned() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::init();
+    builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut cids = Vec::new();
+    for name in ["reference", "session"] {
+        let path = data_dir.join(name);
+        fs::create_dir(&path).unwrap();
+        cids.push(builder.build(&path).expect("could not init `Container`"));
+    }
+
+    let (reference, session) = (cids[0].clone(), cids[1].clone());
+    fs::write(data_dir.join("session").join("data.csv"), "").unwrap();
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let asset = Asset::new(ResourcePath::Relative(PathBuf::from("data.csv")));
+    let aid = asset.rid.clone();
+    db.store
+        .add_asset(asset, session.clone())
+        .expect("could not add `Asset`");
+
+    // test
+    db.update_links(&session, "test", |links| {
+        links.insert("reference", reference.clone());
+    })
+    .expect("could not link `Container`");
+
+    db.update_links(&aid, "test", |links| {
+        links.insert("calibration", reference.clone());
+    })
+    .expect("could not link `Asset`");
+
+    let saved = Container::load_from(data_dir.join("session")).unwrap();
+    assert_eq!(
+        &[reference.clone()],
+        saved.properties.links.targets("reference")
+    );
+    assert_eq!(
+        &[reference.clone()],
+        saved
+            .assets
+            .get(&aid)
+            .unwrap()
+            .properties
+            .links
+            .targets("calibration")
+    );
+
+    let mut backlinks = db.backlinks(&reference).unwrap();
+    backlinks.sort_by(|a, b| a.relation.cmp(&b.relation));
+    assert_eq!(
+        vec![
+            Backlink {
+                source: aid.clone(),
+                relation: "calibration".to_string()
+            },
+            Backlink {
+                source: session.clone(),
+                relation: "reference".to_string()
+            },
+        ],
+        backlinks
+    );
+
+    assert!(
+        db.update_links(&session, "test", |links| {
+            links.insert("self", session.clone());
+        })
+        .is_err(),
+        "self links should be rejected"
+    );
+
+    assert!(
+        db.update_links(&session, "test", |links| {
+            links.insert("missing", ResourceId::new());
+        })
+        .is_err(),
+        "missing targets should be rejected"
+    );
+
+    let _moved = db.handle_command_graph(GraphCommand::Move {
+        root: reference.clone(),
+        parent: session.clone(),
+    });
+
+    assert_eq!(2, db.backlinks(&reference).unwrap().len());
+
+    let _removed = db.handle_command_graph(GraphCommand::Remove(reference.clone()));
+    let links = db.store.get_links(&session).unwrap();
+    assert!(
+        links.is_empty(),
+        "links to removed resources should be pruned"
+    );
+
+    let saved = Container::load_from(data_dir.join("session")).unwrap();
+    assert!(saved.properties.links.is_empty());
+    assert!(saved.assets.get(&aid).unwrap().properties.links.is_empty());
+}
+
+#[test]
+fn links_should_not_be_copied_and_dangling_links_should_be_pruned_on_load() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new temp dir");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::init();
+    builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut cids = Vec::new();
+    for name in ["reference", "session"] {
+        let path = data_dir.join(name);
+        fs::create_dir(&path).unwrap();
+        cids.push(builder.build(&path).expect("could not init `Container`"));
+    }
+
+    let (reference, session) = (cids[0].clone(), cids[1].clone());
+    let missing = ResourceId::new();
+    let mut saved = Container::load_from(data_dir.join("session")).unwrap();
+    saved
+        .properties
+        .links
+        .insert("reference", reference.clone());
+    saved.properties.links.insert("missing", missing.clone());
+    saved.save().unwrap();
+
+    // test
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid));
+
+    let links = db.store.get_links(&session).unwrap();
+    assert_eq!(&[reference.clone()], links.targets("reference"));
+    assert!(
+        links.targets("missing").is_empty(),
+        "dangling links should be pruned"
+    );
+
+    let saved = Container::load_from(data_dir.join("session")).unwrap();
+    assert!(saved.properties.links.targets("missing").is_empty());
+
+    db.update_links(&session, "test", |links| {
+        links.insert("other", reference.clone());
+    })
+    .expect("could not link `Container`");
+
+    let _dup = db.handle_command_graph(GraphCommand::Duplicate(session.clone()));
+    let graph = db.store.get_container_graph(&session).unwrap();
+    let root = graph.root().clone();
+    let dup = graph
+        .children(&root)
+        .unwrap()
+        .iter()
+        .find(|child| **child != reference && **child != session)
+        .expect("`Container` should be duplicated");
+
+    assert!(
+        db.store.get_links(dup).unwrap().is_empty(),
+        "links should not be copied"
+    );
+}
@@ -5,6 +5,8 @@ pub mod asset;
 pub mod container;
 pub mod database;
 pub mod graph;
+pub mod lineage;
+pub mod link;
 pub mod project;
 pub mod script;
 pub mod user;
@@ -2,15 +2,17 @@
 use super::super::Database;
 use crate::command::ProjectCommand;
 use crate::error::{Error, Result};
+use crate::event::{Asset as AssetUpdate, Container as ContainerUpdate, Update};
 use serde_json::Value as JsValue;
+use std::collections::BTreeMap;
 use std::path::Path;
 use thot_core::error::{Error as CoreError, ResourceError};
-use thot_core::project::Project as CoreProject;
+use thot_core::project::{tag, Project as CoreProject, VocabularyError};
 use thot_core::types::{Creator, ResourceId, UserPermissions};
 use thot_local::project::project::project_resource_root_path;
 use thot_local::project::resources::project::Project as LocalProject;
 use thot_local::system::collections::projects::Projects;
-use thot_local::types::ProjectSettings;
+use thot_local::types::{ProjectSettings, SavedQuery};
 
 impl Database {
     /// Directs the command to the correct handler.
@@ -141,6 +143,36 @@ impl Database {
                 let path = project_resource_root_path(&path);
                 serde_json::to_value(path).expect("could not convert `PathBuf` to JsValue")
             }
+
+            ProjectCommand::ListQueries(project) => {
+                let queries = self.list_project_queries(&project);
+                serde_json::to_value(queries).expect("could not convert queries to JsValue")
+            }
+
+            ProjectCommand::SaveQuery { project, query } => {
+                let res = self.save_project_query(&project, query);
+                serde_json::to_value(res).expect("could not convert result to JsValue")
+            }
+
+            ProjectCommand::DeleteQuery { project, name } => {
+                let res = self.delete_project_query(&project, &name);
+                serde_json::to_value(res).expect("could not convert result to JsValue")
+            }
+
+            ProjectCommand::MigrateVocabularies(project) => {
+                let res = self.migrate_project_vocabularies(&project);
+                serde_json::to_value(res).expect("could not convert result to JsValue")
+            }
+
+            ProjectCommand::RenameTag { project, from, to } => {
+                let res = self.rename_project_tag(&project, &from, &to);
+                serde_json::to_value(res).expect("could not convert result to JsValue")
+            }
+
+            ProjectCommand::Tags(project) => {
+                let res = self.project_tags(&project);
+                serde_json::to_value(res).expect("could not convert result to JsValue")
+            }
         }
     }
 
@@ -230,6 +262,322 @@ impl Database {
         project.save()?;
         Ok(())
     }
+
+    fn list_project_queries(&self, rid: &ResourceId) -> Result<Vec<SavedQuery>> {
+        let Some(project) = self.store.get_project(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` does not exist",
+            ))
+            .into());
+        };
+
+        Ok(project.settings().queries.clone())
+    }
+
+    fn save_project_query(
+        &mut self,
+        rid: &ResourceId,
+        query: SavedQuery,
+    ) -> Result<Option<SavedQuery>> {
+        let Some(project) = self.store.get_project_mut(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` does not exist",
+            ))
+            .into());
+        };
+
+        let old = project.settings_mut().insert_query(query);
+        project.save()?;
+        Ok(old)
+    }
+
+    fn delete_project_query(&mut self, rid: &ResourceId, name: &str) -> Result<Option<SavedQuery>> {
+        let Some(project) = self.store.get_project_mut(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` does not exist",
+            ))
+            .into());
+        };
+
+        let query = project.settings_mut().remove_query(name);
+        if query.is_some() {
+            project.save()?;
+        }
+
+        Ok(query)
+    }
+
+    /// Migrates the kinds and tags of a `Project`'s resources
+    /// to the canonical terms of its vocabularies.
+    ///
+    /// # Returns
+    /// Updated `Container`s and `Asset`s.
+    fn migrate_project_vocabularies(&mut self, rid: &ResourceId) -> Result<Vec<ResourceId>> {
+        let Some(project) = self.store.get_project(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` does not exist",
+            ))
+            .into());
+        };
+
+        let vocabularies = project.settings().vocabularies.clone();
+        let Some(graph) = self.store.get_project_graph(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` graph not loaded",
+            ))
+            .into());
+        };
+
+        let containers = graph.nodes().keys().cloned().collect::<Vec<_>>();
+        let modifier = self.modifier();
+        let mut updated = Vec::new();
+        for rid in containers {
+            let container = self
+                .store
+                .get_container_mut(&rid)
+                .expect("`Container` not in store");
+
+            let container_changed =
+                vocabularies.migrate_container_properties(&mut container.properties);
+
+            if container_changed {
+                container.properties.mark_modified(modifier.clone());
+            }
+
+            let assets = container
+                .assets
+                .iter_mut()
+                .filter_map(|(aid, asset)| {
+                    if !vocabularies.migrate_asset_properties(&mut asset.properties) {
+                        return None;
+                    }
+
+                    asset.properties.mark_modified(modifier.clone());
+                    Some(aid.clone())
+                })
+                .collect::<Vec<_>>();
+
+            if !container_changed && assets.is_empty() {
+                continue;
+            }
+
+            container.save()?;
+            if container_changed {
+                self.store.reindex_container(&rid)?;
+                updated.push(rid);
+            }
+
+            for asset in assets {
+                self.store.reindex_asset(&asset)?;
+                updated.push(asset);
+            }
+        }
+
+        self.publish_properties_updates(rid, &updated)?;
+        Ok(updated)
+    }
+
+    /// Publishes the properties of updated `Container`s and `Asset`s.
+    fn publish_properties_updates(&self, project: &ResourceId, updated: &[ResourceId]) -> Result {
+        for rid in updated {
+            let update = if let Some(container) = self.store.get_container(rid) {
+                ContainerUpdate::Properties {
+                    container: rid.clone(),
+                    properties: container.properties.clone(),
+                }
+                .into()
+            } else {
+                let asset = self
+                    .store
+                    .get_asset_container(rid)
+                    .and_then(|container| container.assets.get(rid))
+                    .expect("updated `Asset` not found");
+
+                AssetUpdate::Properties {
+                    asset: rid.clone(),
+                    properties: asset.properties.clone(),
+                }
+                .into()
+            };
+
+            self.publish_update(&Update::Project {
+                project: project.clone(),
+                update,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Renames a tag across a `Project`, cascading to its descendants.
+    /// Tags are normalized before renaming.
+    ///
+    /// # Returns
+    /// Updated `Container`s and `Asset`s.
+    ///
+    /// # Errors
+    /// + If either tag is empty.
+    /// + [`VocabularyError::UnknownTag`]:
+    /// If a renamed tag is rejected by the `Project`'s vocabulary.
+    /// No resources are modified.
+    fn rename_project_tag(
+        &mut self,
+        rid: &ResourceId,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<ResourceId>> {
+        let from = tag::normalize(from);
+        let to = tag::normalize(to);
+        if from.is_empty() || to.is_empty() {
+            return Err(CoreError::value("tag can not be empty").into());
+        }
+
+        let Some(project) = self.store.get_project(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` does not exist",
+            ))
+            .into());
+        };
+
+        let vocabulary = &project.settings().vocabularies.tags;
+        let Some(graph) = self.store.get_project_graph(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` graph not loaded",
+            ))
+            .into());
+        };
+
+        // validate renamed tags before modifying any resource
+        let tags = graph.nodes().values().flat_map(|container| {
+            container.properties.tags.iter().chain(
+                container
+                    .assets
+                    .values()
+                    .flat_map(|asset| asset.properties.tags.iter()),
+            )
+        });
+
+        for renamed in
+            std::iter::once(to.clone()).chain(tags.filter_map(|t| tag::rename(t, &from, &to)))
+        {
+            if vocabulary.normalize(&renamed).is_none() {
+                return Err(VocabularyError::UnknownTag(renamed).into());
+            }
+        }
+
+        let containers = graph.nodes().keys().cloned().collect::<Vec<_>>();
+        let modifier = self.modifier();
+        let mut updated = Vec::new();
+        for rid in containers {
+            let container = self
+                .store
+                .get_container_mut(&rid)
+                .expect("`Container` not in store");
+
+            let container_changed = tag::rename_in(&mut container.properties.tags, &from, &to);
+            if container_changed {
+                container.properties.mark_modified(modifier.clone());
+            }
+
+            let assets = container
+                .assets
+                .iter_mut()
+                .filter_map(|(aid, asset)| {
+                    if !tag::rename_in(&mut asset.properties.tags, &from, &to) {
+                        return None;
+                    }
+
+                    asset.properties.mark_modified(modifier.clone());
+                    Some(aid.clone())
+                })
+                .collect::<Vec<_>>();
+
+            if !container_changed && assets.is_empty() {
+                continue;
+            }
+
+            container.save()?;
+            if container_changed {
+                self.store.reindex_container(&rid)?;
+                updated.push(rid);
+            }
+
+            for asset in assets {
+                self.store.reindex_asset(&asset)?;
+                updated.push(asset);
+            }
+        }
+
+        self.publish_properties_updates(rid, &updated)?;
+        Ok(updated)
+    }
+
+    /// Aggregates the tags of a `Project`'s resources.
+    ///
+    /// # Returns
+    /// Number of resources matching each tag, including ancestors.
+    fn project_tags(&self, rid: &ResourceId) -> Result<BTreeMap<String, usize>> {
+        let Some(graph) = self.store.get_project_graph(rid) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` graph not loaded",
+            ))
+            .into());
+        };
+
+        let tags = graph.nodes().values().flat_map(|container| {
+            std::iter::once(&container.properties.tags).chain(
+                container
+                    .assets
+                    .values()
+                    .map(|asset| &asset.properties.tags),
+            )
+        });
+
+        Ok(tag::aggregate(tags))
+    }
+
+    /// Gets a saved query of a `Container`'s `Project`.
+    ///
+    /// # Returns
+    /// The query with its root resolved,
+    /// using `container` if the query does not specify one.
+    pub(super) fn resolve_project_query(
+        &self,
+        container: &ResourceId,
+        name: &str,
+    ) -> Result<SavedQuery> {
+        let Some(project) = self.store.get_container_project(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
+        let Some(project) = self.store.get_project(project) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` not loaded",
+            ))
+            .into());
+        };
+
+        let Some(query) = project.settings().query(name) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "query does not exist",
+            ))
+            .into());
+        };
+
+        let mut query = query.clone();
+        let root = query.root.get_or_insert_with(|| container.clone());
+        if self.store.get_container(root).is_none() {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "query root `Container` not loaded",
+            ))
+            .into());
+        }
+
+        Ok(query)
+    }
 }
 
 // ************************
@@ -1,11 +1,16 @@
+use crate::command::{GraphCommand, ProjectCommand};
 use crate::server::Database;
 use dev_utils::fs::TempDir;
 use fake::faker::lorem::raw::Word;
 use fake::locales::EN;
 use fake::Fake;
-use thot_core::project::Project as CoreProject;
-use thot_local::project::project;
-use thot_local::project::resources::Project as LocalProject;
+use std::collections::HashSet;
+use thot_core::db::StandardSearchFilter;
+use thot_core::project::{Project as CoreProject, Vocabulary};
+use thot_core::types::ResourceId;
+use thot_local::project::resources::{Container as LocalContainer, Project as LocalProject};
+use thot_local::project::{container, project};
+use thot_local::types::SavedQuery;
 
 #[test]
 fn load_user_projects_should_work() {
@@ -44,3 +49,247 @@ fn update_project_when_project_does_not_exist_should_error() {
     let mut db = Database::new();
     db.update_project(project).unwrap();
 }
+
+#[test]
+fn save_and_delete_project_query_should_work() {
+    // setup
+    let _dir = TempDir::new().expect("could not create new `TempDir`");
+    project::init(_dir.path()).expect("could not init `Project`");
+    let project = LocalProject::load_from(_dir.path()).expect("could not load `Project`");
+    let pid = project.rid.clone();
+
+    let mut db = Database::new();
+    db.store
+        .insert_project(project)
+        .expect("could not insert `Project`");
+
+    let name = Word(EN).fake::<String>();
+    let query = SavedQuery::new(name.clone(), StandardSearchFilter::default());
+
+    // test
+    let old = db
+        .save_project_query(&pid, query.clone())
+        .expect("save query should work");
+
+    assert!(old.is_none(), "no query should be replaced");
+
+    let queries = db
+        .list_project_queries(&pid)
+        .expect("list queries should work");
+
+    assert_eq!(vec![query.clone()], queries, "query not saved");
+
+    let saved = LocalProject::load_from(_dir.path()).expect("could not load `Project`");
+    assert_eq!(
+        Some(&query),
+        saved.settings().query(&name),
+        "query not persisted"
+    );
+
+    let deleted = db
+        .delete_project_query(&pid, &name)
+        .expect("delete query should work");
+
+    assert_eq!(Some(query), deleted, "incorrect query deleted");
+    assert!(db.list_project_queries(&pid).unwrap().is_empty());
+}
+
+#[test]
+fn resolve_project_query_should_error_if_root_not_loaded() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new `TempDir`");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let root = container::InitOptions::init()
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid.clone()));
+
+    let name = Word(EN).fake::<String>();
+    let mut query = SavedQuery::new(name.clone(), StandardSearchFilter::default());
+    query.root = Some(ResourceId::new());
+    db.save_project_query(&pid, query)
+        .expect("save query should work");
+
+    // test
+    assert!(
+        db.resolve_project_query(&root, &name).is_err(),
+        "query root outside loaded `Project`s should error"
+    );
+    assert!(db.resolve_project_query(&ResourceId::new(), &name).is_err());
+}
+
+#[test]
+fn migrate_project_vocabularies_should_work() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new `TempDir`");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut tags = Vocabulary::new();
+    tags.insert_term("control", vec!["ctrl".to_string()]);
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.settings_mut().vocabularies.tags = tags;
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut container = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    container.properties.tags = vec!["Ctrl".to_string(), "other".to_string()];
+    container.save().expect("could not save `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid.clone()));
+
+    // test
+    let updated = db
+        .migrate_project_vocabularies(&pid)
+        .expect("migration should work");
+
+    assert_eq!(vec![rid.clone()], updated, "incorrect resources updated");
+
+    let expected = vec!["control".to_string(), "other".to_string()];
+    let stored = db
+        .store
+        .get_container(&rid)
+        .expect("`Container` not stored");
+    assert_eq!(expected, stored.properties.tags, "tags not migrated");
+
+    let saved = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    assert_eq!(expected, saved.properties.tags, "tags not persisted");
+
+    let updated = db
+        .migrate_project_vocabularies(&pid)
+        .expect("migration should work");
+
+    assert!(
+        updated.is_empty(),
+        "canonical resources should not be updated"
+    );
+}
+
+#[test]
+fn rename_project_tag_should_cascade() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new `TempDir`");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut container = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    container.properties.tags = vec![
+        "stimulus/visual".to_string(),
+        "stimulus/visual/grating".to_string(),
+        "other".to_string(),
+    ];
+    container.save().expect("could not save `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid.clone()));
+
+    // test
+    let tags = db.project_tags(&pid).expect("aggregation should work");
+    assert_eq!(
+        Some(&1),
+        tags.get("stimulus"),
+        "ancestors should be aggregated"
+    );
+
+    let updated = db
+        .rename_project_tag(&pid, "stimulus/visual", "vision")
+        .expect("rename should work");
+
+    assert_eq!(vec![rid.clone()], updated, "incorrect resources updated");
+
+    let expected = vec![
+        "vision".to_string(),
+        "vision/grating".to_string(),
+        "other".to_string(),
+    ];
+
+    let saved = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    assert_eq!(expected, saved.properties.tags, "tags not persisted");
+
+    let mut filter = StandardSearchFilter::default();
+    filter.tags = Some(HashSet::from(["vision".to_string()]));
+    let found = db.store.find_containers(&rid, filter);
+    assert_eq!(1, found.len(), "renamed tags should be indexed");
+}
+
+#[test]
+fn rename_project_tag_should_validate_tags() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create new `TempDir`");
+    let data_dir = dir.mkdir().unwrap();
+
+    let mut tags = Vocabulary::new();
+    tags.insert_term("control", Vec::new());
+    tags.insert_term("vision", Vec::new());
+    tags.strict = true;
+
+    let mut project = LocalProject::new(dir.path().to_path_buf()).unwrap();
+    project.data_root = Some(data_dir.clone());
+    project.settings_mut().vocabularies.tags = tags;
+    project.save().unwrap();
+    let pid = project.rid.clone();
+
+    let builder = container::InitOptions::new();
+    let rid = builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let mut container = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    container.properties.tags = vec!["control".to_string()];
+    container.save().expect("could not save `Container`");
+
+    let mut db = Database::new();
+    let _project = db.handle_command_project(ProjectCommand::Load(dir.path().into()));
+    let _graph = db.handle_command_graph(GraphCommand::Load(pid.clone()));
+
+    // test
+    assert!(
+        db.rename_project_tag(&pid, " / ", "vision").is_err(),
+        "empty tags should be rejected"
+    );
+
+    assert!(
+        db.rename_project_tag(&pid, "control", "other").is_err(),
+        "tags not in a strict vocabulary should be rejected"
+    );
+
+    let saved = LocalContainer::load_from(&data_dir).expect("could not load `Container`");
+    assert_eq!(
+        vec!["control".to_string()],
+        saved.properties.tags,
+        "rejected rename should not modify resources"
+    );
+
+    let updated = db
+        .rename_project_tag(&pid, " control/ ", "vision")
+        .expect("rename should work");
+
+    assert_eq!(vec![rid], updated, "tags should be normalized");
+}
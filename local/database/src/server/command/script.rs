@@ -122,7 +122,7 @@ impl Database {
     }
 
     /// Update a `Script`.
-    fn update_script(&mut self, script: CoreScript) -> Result {
+    fn update_script(&mut self, mut script: CoreScript) -> Result {
         let Some(project) = self.store.get_script_project(&script.rid) else {
             return Err(CoreError::ResourceError(ResourceError::does_not_exist(
                 "`Script` does not exist",
@@ -130,7 +130,9 @@ impl Database {
             .into());
         };
 
-        self.store.insert_script(project.clone(), script)?;
+        let project = project.clone();
+        script.mark_modified(self.modifier());
+        self.store.insert_script(project, script)?;
         Ok(())
     }
 
@@ -15,9 +15,14 @@ use crate::event::Update;
 use crate::{common, constants, Result};
 use notify_debouncer_full::DebounceEventResult;
 use serde_json::Value as JsValue;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::thread;
+use thot_core::project::{Metadata, MetadataChange};
+use thot_core::types::{Creator, ResourceId, UserId};
+use thot_local::project::extractors::Extractors;
+use thot_local::project::metadata_history;
+use thot_local::system::users;
 
 /// Database.
 pub struct Database {
@@ -27,6 +32,9 @@ pub struct Database {
 
     /// Publication socket to broadcast updates.
     update_tx: zmq::Socket,
+
+    /// Extractors run on `Asset` files.
+    extractors: Extractors,
 }
 
 impl Database {
@@ -50,6 +58,7 @@ impl Database {
             event_rx,
             file_system_tx,
             update_tx,
+            extractors: Extractors::default(),
         }
     }
 
@@ -82,6 +91,49 @@ impl Database {
             .unwrap();
     }
 
+    /// Id of the system's active user.
+    /// Recorded as the modifier of resources.
+    fn active_user(&self) -> Option<ResourceId> {
+        match users::get_active_user_id() {
+            Ok(user) => user,
+            Err(err) => {
+                tracing::warn!(?err, "could not load active user");
+                None
+            }
+        }
+    }
+
+    /// The system's active user, as the modifier of resources.
+    fn modifier(&self) -> Creator {
+        Creator::User(self.active_user().map(|user| UserId::Id(user.into())))
+    }
+
+    /// Appends the changes between two versions of a resource's metadata
+    /// to its `Container`'s metadata history.
+    ///
+    /// # Arguments
+    /// 1. Path to the `Container` holding the resource's history.
+    /// 2. Resource.
+    /// 3. Metadata before the update.
+    /// 4. Metadata after the update.
+    /// 5. Command that made the update.
+    ///
+    /// # Notes
+    /// + The update is already saved, so failing to record it is only logged.
+    fn record_metadata_history(
+        &self,
+        container: &Path,
+        resource: &ResourceId,
+        old: &Metadata,
+        new: &Metadata,
+        command: &str,
+    ) {
+        let changes = MetadataChange::diff(resource, old, new, &self.modifier(), command);
+        if let Err(err) = metadata_history::append(container, &changes) {
+            tracing::warn!(?err, ?resource, "could not record metadata history");
+        }
+    }
+
     /// Publish an update to subscribers.
     /// Triggered by file system events.
     fn publish_update(&self, update: &Update) -> zmq::Result<()> {
@@ -113,6 +165,8 @@ impl Database {
             Command::ScriptCommand(cmd) => self.handle_command_script(cmd),
             Command::UserCommand(cmd) => self.handle_command_user(cmd),
             Command::AnalysisCommand(cmd) => self.handle_command_analysis(cmd),
+            Command::LinkCommand(cmd) => self.handle_command_link(cmd),
+            Command::LineageCommand(cmd) => self.handle_command_lineage(cmd),
         }
     }
 
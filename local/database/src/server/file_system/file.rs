@@ -4,15 +4,22 @@ use crate::event::{Asset as AssetUpdate, Graph as GraphUpdate, Update};
 use crate::server::Database;
 use crate::Result;
 use std::path::PathBuf;
+use thot_core::error::{Error as CoreError, ResourceError};
 use thot_core::types::{ResourceId, ResourcePath};
 use thot_local::graph::{ContainerTreeLoader, ContainerTreeTransformer};
 use thot_local::project::container;
+use thot_local::project::extractors;
 use thot_local::project::resources::Asset;
 
 impl Database {
     pub fn handle_thot_event_file(&mut self, event: FileEvent) -> Result {
         match event {
             FileEvent::Created(path) => {
+                // sidecar files are read by their data file's extractors
+                if extractors::is_sidecar(&path) {
+                    return Ok(());
+                }
+
                 let container_path =
                     thot_local::project::asset::container_from_path_ancestor(&path)?;
 
@@ -68,7 +75,20 @@ impl Database {
 
     #[tracing::instrument(skip(self))]
     fn handle_file_as_asset(&mut self, asset_path: PathBuf, container: ResourceId) -> Result {
-        let asset = Asset::new(ResourcePath::new(asset_path)?)?;
+        let Some(parent) = self.store.get_container(&container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let file_path = parent.base_path().join(&asset_path);
+
+        let mut asset = Asset::new(ResourcePath::new(asset_path)?)?;
+        self.extractors
+            .extract_lossy(&file_path)
+            .apply(&mut asset.properties);
+
         let aid = asset.rid.clone();
         self.store.add_asset(asset, container.clone())?;
 
@@ -143,6 +143,7 @@ impl Database {
 
         let root = self.store.get_container(&rid).unwrap();
         root.save()?;
+        self.store.reindex_container(&rid)?;
         Ok(())
     }
 }
@@ -0,0 +1,309 @@
+//! Secondary indexes over resource properties.
+//!
+//! Indexes map property values to the resources holding them,
+//! so common search criteria resolve to set lookups rather than tree walks.
+//! Lookups only narrow the search, results must still be checked against the filter.
+use serde_json::Value as JsValue;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use thot_core::db::StandardSearchFilter as StdFilter;
+use thot_core::project::{tag, AssetProperties, ContainerProperties, DerivedMetadata, Metadata};
+use thot_core::types::{ResourceId, ResourceMap};
+
+// *************
+// *** Types ***
+// *************
+
+pub type ResourceSet = HashSet<ResourceId>;
+
+/// Map from an indexed value to the resources holding it.
+type KeyMap<K> = HashMap<K, ResourceSet>;
+
+// *************
+// *** Entry ***
+// *************
+
+/// Indexed values of a single resource.
+#[derive(PartialEq, Clone, Debug)]
+pub struct IndexEntry {
+    name: Option<String>,
+    kind: Option<String>,
+    tags: Vec<String>,
+
+    /// Metadata keys and the index key of their value, if indexable.
+    metadata: Vec<(String, Option<String>)>,
+
+    /// Derived metadata keys.
+    derived: Vec<String>,
+}
+
+impl IndexEntry {
+    fn new(
+        name: Option<String>,
+        kind: Option<String>,
+        tags: Vec<String>,
+        metadata: &Metadata,
+        derived: &DerivedMetadata,
+    ) -> Self {
+        let metadata = metadata
+            .iter()
+            .map(|(key, value)| (key.clone(), value_key(value)))
+            .collect();
+
+        Self {
+            name,
+            kind,
+            tags,
+            metadata,
+            derived: derived.keys().cloned().collect(),
+        }
+    }
+}
+
+impl From<&ContainerProperties> for IndexEntry {
+    fn from(properties: &ContainerProperties) -> Self {
+        Self::new(
+            Some(properties.name.clone()),
+            properties.kind.clone(),
+            properties.tags.clone(),
+            &properties.metadata,
+            &properties.derived,
+        )
+    }
+}
+
+impl From<&AssetProperties> for IndexEntry {
+    fn from(properties: &AssetProperties) -> Self {
+        Self::new(
+            properties.name.clone(),
+            properties.kind.clone(),
+            properties.tags.clone(),
+            &properties.metadata,
+            &properties.derived,
+        )
+    }
+}
+
+// *************
+// *** Index ***
+// *************
+
+/// Index of resources by their properties.
+///
+/// # Notes
+/// + Only owned metadata is indexed.
+/// Inherited metadata must be resolved by walking the graph.
+/// + Derived metadata is only indexed by key, as its value is computed when loaded.
+/// + Tags are indexed under each of their ancestors,
+/// so a lookup by tag includes its descendants.
+#[derive(Default, Debug)]
+pub struct PropertyIndex {
+    names: KeyMap<Option<String>>,
+    kinds: KeyMap<Option<String>>,
+    tags: KeyMap<String>,
+    metadata_keys: KeyMap<String>,
+    metadata_values: KeyMap<(String, String)>,
+    derived_keys: KeyMap<String>,
+
+    /// Current entry of each indexed resource.
+    entries: ResourceMap<IndexEntry>,
+}
+
+impl PropertyIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indexes a resource, replacing its previous entry.
+    pub fn insert(&mut self, rid: ResourceId, entry: IndexEntry) {
+        self.remove(&rid);
+
+        insert_key(&mut self.names, entry.name.clone(), &rid);
+        insert_key(&mut self.kinds, entry.kind.clone(), &rid);
+        for tag in tag_nodes(&entry.tags) {
+            insert_key(&mut self.tags, tag.to_string(), &rid);
+        }
+
+        for (key, value) in entry.metadata.iter() {
+            insert_key(&mut self.metadata_keys, key.clone(), &rid);
+            if let Some(value) = value {
+                insert_key(
+                    &mut self.metadata_values,
+                    (key.clone(), value.clone()),
+                    &rid,
+                );
+            }
+        }
+
+        for key in entry.derived.iter() {
+            insert_key(&mut self.derived_keys, key.clone(), &rid);
+        }
+
+        self.entries.insert(rid, entry);
+    }
+
+    /// Removes a resource from the index.
+    ///
+    /// # Returns
+    /// The resource's entry, if it was indexed.
+    pub fn remove(&mut self, rid: &ResourceId) -> Option<IndexEntry> {
+        let Some(entry) = self.entries.remove(rid) else {
+            return None;
+        };
+
+        remove_key(&mut self.names, &entry.name, rid);
+        remove_key(&mut self.kinds, &entry.kind, rid);
+        for tag in tag_nodes(&entry.tags) {
+            remove_key(&mut self.tags, &tag.to_string(), rid);
+        }
+
+        for (key, value) in entry.metadata.iter() {
+            remove_key(&mut self.metadata_keys, key, rid);
+            if let Some(value) = value {
+                remove_key(
+                    &mut self.metadata_values,
+                    &(key.clone(), value.clone()),
+                    rid,
+                );
+            }
+        }
+
+        for key in entry.derived.iter() {
+            remove_key(&mut self.derived_keys, key, rid);
+        }
+
+        Some(entry)
+    }
+
+    /// Returns whether the resource is indexed.
+    pub fn contains(&self, rid: &ResourceId) -> bool {
+        self.entries.contains_key(rid)
+    }
+
+    /// Resources owning the metadatum.
+    ///
+    /// # Notes
+    /// + Arrays and objects are only indexed by key,
+    /// so all resources owning `key` are returned for them.
+    pub fn with_metadatum(&self, key: &str, value: &JsValue) -> ResourceSet {
+        let found = match value_key(value) {
+            Some(value) => self.metadata_values.get(&(key.to_string(), value)),
+            None => self.metadata_keys.get(key),
+        };
+
+        found.cloned().unwrap_or_default()
+    }
+
+    /// Resources deriving the metadata key.
+    pub fn with_derived(&self, key: &str) -> ResourceSet {
+        self.derived_keys.get(key).cloned().unwrap_or_default()
+    }
+
+    /// Candidate resources for a filter.
+    ///
+    /// # Arguments
+    /// 1. Search filter.
+    /// 2. Whether to narrow on the filter's `metadata`.
+    /// Should be `false` when metadata is inherited.
+    ///
+    /// # Returns
+    /// + `None` if the filter has no indexed criteria.
+    /// + A superset of the resources matching the filter, otherwise.
+    pub fn candidates(&self, filter: &StdFilter, metadata: bool) -> Option<ResourceSet> {
+        let mut sets = Vec::new();
+        if let Some(rid) = filter.rid.as_ref() {
+            let mut set = ResourceSet::new();
+            if self.contains(rid) {
+                set.insert(rid.clone());
+            }
+
+            sets.push(set);
+        }
+
+        if let Some(name) = filter.name.as_ref() {
+            sets.push(self.names.get(name).cloned().unwrap_or_default());
+        }
+
+        if let Some(kind) = filter.kind.as_ref() {
+            sets.push(self.kinds.get(kind).cloned().unwrap_or_default());
+        }
+
+        if let Some(tags) = filter.tags.as_ref() {
+            for tag in tags {
+                sets.push(self.tags.get(tag).cloned().unwrap_or_default());
+            }
+        }
+
+        if metadata {
+            if let Some(md) = filter.metadata.as_ref() {
+                for (key, value) in md {
+                    sets.push(self.with_metadatum(key, value));
+                }
+            }
+        }
+
+        intersect(sets)
+    }
+}
+
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Intersects sets.
+///
+/// # Returns
+/// `None` if no sets are given.
+pub fn intersect(mut sets: Vec<ResourceSet>) -> Option<ResourceSet> {
+    sets.sort_by_key(|set| set.len());
+    let mut sets = sets.into_iter();
+    let Some(mut found) = sets.next() else {
+        return None;
+    };
+
+    for set in sets {
+        found.retain(|rid| set.contains(rid));
+    }
+
+    Some(found)
+}
+
+/// Key used to index a metadatum's value.
+/// Numbers are keyed by their value, not type, to match
+/// the comparison of [`StandardSearchFilter`](StdFilter).
+///
+/// # Returns
+/// `None` for arrays and objects.
+fn value_key(value: &JsValue) -> Option<String> {
+    match value {
+        JsValue::Null | JsValue::Bool(_) | JsValue::String(_) => Some(value.to_string()),
+        JsValue::Number(value) => value.as_f64().map(|value| format!("{value:?}")),
+        JsValue::Array(_) | JsValue::Object(_) => None,
+    }
+}
+
+/// Tags and their ancestors.
+fn tag_nodes(tags: &[String]) -> HashSet<&str> {
+    tags.iter()
+        .flat_map(|t| tag::ancestors(t).chain(std::iter::once(t.as_str())))
+        .collect()
+}
+
+fn insert_key<K: Hash + Eq>(map: &mut KeyMap<K>, key: K, rid: &ResourceId) {
+    map.entry(key).or_default().insert(rid.clone());
+}
+
+fn remove_key<K: Hash + Eq>(map: &mut KeyMap<K>, key: &K, rid: &ResourceId) {
+    let Some(rids) = map.get_mut(key) else {
+        return;
+    };
+
+    rids.remove(rid);
+    if rids.is_empty() {
+        map.remove(key);
+    }
+}
+
+#[cfg(test)]
+#[path = "./index_test.rs"]
+mod index_test;
@@ -0,0 +1,125 @@
+use super::*;
+use thot_core::project::ContainerProperties;
+
+#[test]
+fn property_index_candidates_should_work() {
+    // setup
+    let mut index = PropertyIndex::new();
+
+    let mut p0 = ContainerProperties::new("c0");
+    p0.kind = Some("find".to_string());
+    p0.tags = vec!["t0".to_string(), "t1".to_string()];
+    p0.metadata.insert("n".to_string(), 1.into());
+
+    let mut p1 = ContainerProperties::new("c1");
+    p1.kind = Some("find".to_string());
+    p1.tags = vec!["t1".to_string()];
+    p1.metadata.insert("n".to_string(), 2.into());
+
+    let r0 = ResourceId::new();
+    let r1 = ResourceId::new();
+    index.insert(r0.clone(), (&p0).into());
+    index.insert(r1.clone(), (&p1).into());
+
+    // test
+    let filter = StdFilter::default();
+    assert!(
+        index.candidates(&filter, true).is_none(),
+        "empty filter should not be indexed"
+    );
+
+    let mut filter = StdFilter::default();
+    filter.kind = Some(Some("find".to_string()));
+    let found = index.candidates(&filter, true).unwrap();
+    assert_eq!(2, found.len(), "both resources should be found by kind");
+
+    filter.tags = Some(HashSet::from(["t0".to_string()]));
+    let found = index.candidates(&filter, true).unwrap();
+    assert_eq!(1, found.len(), "one resource should be found by tag");
+    assert!(found.contains(&r0), "incorrect resource found");
+
+    let mut filter = StdFilter::default();
+    filter.metadata = Some(Metadata::from([("n".to_string(), 2.0.into())]));
+    let found = index.candidates(&filter, true).unwrap();
+    assert_eq!(1, found.len(), "numbers should match by value");
+    assert!(found.contains(&r1), "incorrect resource found");
+
+    assert!(
+        index.candidates(&filter, false).is_none(),
+        "metadata should not be narrowed on"
+    );
+}
+
+#[test]
+fn property_index_candidates_should_include_tag_descendants() {
+    // setup
+    let mut index = PropertyIndex::new();
+
+    let mut p0 = ContainerProperties::new("c0");
+    p0.tags = vec!["stimulus/visual/grating".to_string()];
+
+    let mut p1 = ContainerProperties::new("c1");
+    p1.tags = vec!["stimulus/auditory".to_string()];
+
+    let r0 = ResourceId::new();
+    let r1 = ResourceId::new();
+    index.insert(r0.clone(), (&p0).into());
+    index.insert(r1.clone(), (&p1).into());
+
+    // test
+    let mut filter = StdFilter::default();
+    filter.tags = Some(HashSet::from(["stimulus".to_string()]));
+    let found = index.candidates(&filter, true).unwrap();
+    assert_eq!(2, found.len(), "descendants should be found by parent tag");
+
+    filter.tags = Some(HashSet::from(["stimulus/visual".to_string()]));
+    let found = index.candidates(&filter, true).unwrap();
+    assert_eq!(1, found.len(), "only descendants should be found");
+    assert!(found.contains(&r0), "incorrect resource found");
+
+    index.remove(&r0);
+    index.remove(&r1);
+    assert!(index.tags.is_empty(), "ancestor keys should be removed");
+}
+
+#[test]
+fn property_index_insert_should_replace_entry() {
+    // setup
+    let mut index = PropertyIndex::new();
+    let rid = ResourceId::new();
+
+    let mut props = ContainerProperties::new("c0");
+    props.kind = Some("old".to_string());
+    index.insert(rid.clone(), (&props).into());
+
+    // test
+    props.kind = Some("new".to_string());
+    index.insert(rid.clone(), (&props).into());
+
+    let mut filter = StdFilter::default();
+    filter.kind = Some(Some("old".to_string()));
+    let found = index.candidates(&filter, true).unwrap();
+    assert!(found.is_empty(), "old kind should be unindexed");
+
+    filter.kind = Some(Some("new".to_string()));
+    let found = index.candidates(&filter, true).unwrap();
+    assert!(found.contains(&rid), "new kind should be indexed");
+}
+
+#[test]
+fn property_index_remove_should_work() {
+    // setup
+    let mut index = PropertyIndex::new();
+    let rid = ResourceId::new();
+
+    let mut props = ContainerProperties::new("c0");
+    props.tags = vec!["t0".to_string()];
+    index.insert(rid.clone(), (&props).into());
+
+    // test
+    let entry = index.remove(&rid);
+    assert!(entry.is_some(), "entry should be returned");
+    assert!(!index.contains(&rid), "resource should not be indexed");
+    assert!(index.tags.is_empty(), "empty keys should be removed");
+    assert!(index.names.is_empty(), "empty keys should be removed");
+}
@@ -3,7 +3,9 @@
 //! the database acts as the single source of truth.
 pub(self) mod database;
 mod event;
+pub(self) mod index;
 pub(self) mod store;
+pub(self) mod text_index;
 
 // Re-exports
 pub use database::Database;
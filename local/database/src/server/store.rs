@@ -1,6 +1,10 @@
 //! Database for storing resources.
-use crate::error::Result;
+use super::index::{intersect, IndexEntry, PropertyIndex, ResourceSet};
+use super::text_index::{TextEntry, TextIndex};
+use crate::error::{Error, Result};
 use has_id::HasId;
+use serde_json::Value as JsValue;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
@@ -9,7 +13,13 @@ use std::{fs, io};
 use thot_core::db::{SearchFilter, StandardSearchFilter as StdFilter};
 use thot_core::error::{Error as CoreError, ResourceError};
 use thot_core::graph::ResourceTree;
-use thot_core::project::{Asset, Container as CoreContainer, Metadata, Script as CoreScript};
+use thot_core::project::metadata::{inherit_metadata, InheritedMetadata};
+use thot_core::project::schema::ValidationMode;
+use thot_core::project::{
+    Asset, AssetProperties, Backlink, Container as CoreContainer, ContainerProperties, Links,
+    MetadataSchemas, ResolvedMetadata, SchemaViolation, Script as CoreScript, Vocabularies,
+    WithMetadata,
+};
 use thot_core::types::{ResourceId, ResourceMap, ResourcePath};
 use thot_local::project::resources::{
     Container as LocalContainer, Project as LocalProject, Scripts as ProjectScripts,
@@ -144,6 +154,18 @@ pub struct Datastore {
 
     /// Holds a `Project`'s `Scripts`.
     scripts: ProjectScriptsMap,
+
+    /// Index of `Container`s by their properties.
+    container_index: PropertyIndex,
+
+    /// Index of `Asset`s by their properties.
+    asset_index: PropertyIndex,
+
+    /// Full-text index of `Container`s.
+    container_text_index: TextIndex,
+
+    /// Full-text index of `Asset`s.
+    asset_text_index: TextIndex,
 }
 
 impl Datastore {
@@ -158,6 +180,10 @@ impl Datastore {
             asset_paths: PathMap::new(),
             script_projects: IdMap::new(),
             scripts: ProjectScriptsMap::new(),
+            container_index: PropertyIndex::new(),
+            asset_index: PropertyIndex::new(),
+            container_text_index: TextIndex::new(),
+            asset_text_index: TextIndex::new(),
         }
     }
 
@@ -210,6 +236,20 @@ impl Datastore {
         self.container_projects.get(container)
     }
 
+    /// Gets the metadata schemas of a `Container`'s `Project`.
+    pub fn get_container_schemas(&self, container: &ResourceId) -> Option<&MetadataSchemas> {
+        let project = self.get_container_project(container)?;
+        let project = self.get_project(project)?;
+        Some(&project.settings().schemas)
+    }
+
+    /// Gets the vocabularies of a `Container`'s `Project`.
+    pub fn get_container_vocabularies(&self, container: &ResourceId) -> Option<&Vocabularies> {
+        let project = self.get_container_project(container)?;
+        let project = self.get_project(project)?;
+        Some(&project.settings().vocabularies)
+    }
+
     // *************
     // *** graph ***
     // *************
@@ -289,10 +329,19 @@ impl Datastore {
             self.container_paths
                 .insert_canonical(node.base_path().into(), cid.clone());
 
+            self.container_index
+                .insert(cid.clone(), (&node.properties).into());
+            self.container_text_index
+                .insert(cid.clone(), (&node.properties).into());
+
             // map assets
             for (aid, asset) in node.data().assets.iter() {
                 let asset_path = node.base_path().join(asset.path.as_path());
-                self.insert_asset_canonical(aid.clone(), asset_path, cid.clone())
+                self.insert_asset_canonical(aid.clone(), asset_path, cid.clone());
+                self.asset_index
+                    .insert(aid.clone(), (&asset.properties).into());
+                self.asset_text_index
+                    .insert(aid.clone(), (&asset.properties).into());
             }
         }
 
@@ -324,10 +373,19 @@ impl Datastore {
             self.container_paths
                 .insert_canonical(container.base_path().into(), cid.clone());
 
+            self.container_index
+                .insert(cid.clone(), (&container.properties).into());
+            self.container_text_index
+                .insert(cid.clone(), (&container.properties).into());
+
             // map assets
             for (aid, asset) in container.assets.iter() {
                 let asset_path = container.base_path().join(asset.path.as_path());
                 self.insert_asset_canonical(aid.clone(), asset_path, cid.clone());
+                self.asset_index
+                    .insert(aid.clone(), (&asset.properties).into());
+                self.asset_text_index
+                    .insert(aid.clone(), (&asset.properties).into());
             }
         }
 
@@ -361,7 +419,8 @@ impl Datastore {
         Ok(())
     }
 
-    /// Remove the subgraph with the given root.
+    /// Remove the subgraph with the given root,
+    /// and links to its resources.
     ///
     /// # Returns
     /// Removed subgraph.
@@ -374,6 +433,28 @@ impl Datastore {
             .into());
         };
 
+        let sub_graph = self.detach_subgraph(root)?;
+        self.remove_links_to(&project, &subgraph_resources(&sub_graph))?;
+        Ok(sub_graph)
+    }
+
+    /// Remove the subgraph with the given root,
+    /// keeping links to its resources.
+    ///
+    /// # Returns
+    /// Removed subgraph.
+    ///
+    /// # Notes
+    /// + Use [`remove_links_to`](Self::remove_links_to) once the removal is final.
+    #[tracing::instrument(skip(self))]
+    pub fn detach_subgraph(&mut self, root: &ResourceId) -> Result<ContainerTree> {
+        let Some(project) = self.get_container_project(root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
         let graph = self
             .graphs
             .get_mut(&project)
@@ -386,8 +467,13 @@ impl Datastore {
             self.container_paths
                 .remove_canonical(&container.base_path());
 
+            self.container_index.remove(cid);
+            self.container_text_index.remove(cid);
+
             for (aid, asset) in container.assets.iter() {
                 self.asset_containers.remove(aid);
+                self.asset_index.remove(aid);
+                self.asset_text_index.remove(aid);
 
                 let asset_path = container.base_path().join(asset.path.as_path());
                 self.asset_paths.remove_canonical(&asset_path);
@@ -477,8 +563,32 @@ impl Datastore {
         Some(node)
     }
 
-    /// Get a Container with inherited metadata.
-    pub fn get_container_with_metadata(&self, container: &ResourceId) -> Option<CoreContainer> {
+    /// Updates the index of a `Container`'s properties.
+    /// Must be called after its properties are modified.
+    pub fn reindex_container(&mut self, container: &ResourceId) -> Result {
+        let Some(container) = self.get_container(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist",
+            ))
+            .into());
+        };
+
+        let cid = container.rid.clone();
+        let entry = IndexEntry::from(&container.properties);
+        let text_entry = TextEntry::from(&container.properties);
+        self.container_index.insert(cid.clone(), entry);
+        self.container_text_index.insert(cid, text_entry);
+        Ok(())
+    }
+
+    /// Get a Container with inherited and derived metadata.
+    ///
+    /// # Notes
+    /// + `Asset` paths are absolute.
+    pub fn get_container_with_metadata(
+        &self,
+        container: &ResourceId,
+    ) -> Option<WithMetadata<CoreContainer>> {
         let Some(container) = self.get_container(container) else {
             return None;
         };
@@ -487,33 +597,102 @@ impl Datastore {
             .get_container_graph(container.id())
             .expect("could not find `Container`'s graph");
 
-        let metadata = graph.ancestors(container.id()).into_iter().rfold(
-            Metadata::new(),
-            |mut metadata, ancestor| {
-                let container = graph.get(&ancestor).expect("`Container` not found");
-                for (key, value) in container.properties.metadata.clone() {
-                    metadata.insert(key, value);
-                }
-
-                metadata
-            },
-        );
-
         let container_path = container.base_path().to_owned();
+        let metadata = resolve_container_metadata(graph, container);
         let mut container = (*container).clone();
-        container.properties.metadata = metadata;
         for asset in container.assets.values_mut() {
-            for (key, value) in container.properties.metadata.iter() {
-                if !asset.properties.metadata.contains_key(key) {
-                    asset.properties.metadata.insert(key.clone(), value.clone());
-                }
-            }
-
             let path = fs::canonicalize(container_path.join(asset.path.as_path())).unwrap();
             asset.path = ResourcePath::new(path).expect("could not set absolute path");
         }
 
-        Some(container)
+        Some(WithMetadata::new(container, metadata))
+    }
+
+    /// Flattens a `Container`'s resolved `Metadata`, and that of its `Asset`s,
+    /// into their properties.
+    /// Used for clients that expect a single map of metadata, e.g. scripts.
+    ///
+    /// # Panics
+    /// + If the `Container` is not in the store.
+    pub fn flatten_container_metadata(
+        &self,
+        container: WithMetadata<CoreContainer>,
+    ) -> CoreContainer {
+        let graph = self
+            .get_container_graph(&container.resource.rid)
+            .expect("could not find `Container`'s graph");
+
+        let inherited = inherited_metadata(graph, &container.resource.rid);
+        let mut container = container.into_resolved();
+        for asset in container.assets.values_mut() {
+            asset.properties.metadata =
+                ResolvedMetadata::for_asset(&asset.properties, &inherited).into_all();
+        }
+
+        container
+    }
+
+    /// Gets a `Container`'s `Metadata`, resolved with that inherited from its ancestors.
+    pub fn get_container_metadata(&self, container: &ResourceId) -> Option<ResolvedMetadata> {
+        let graph = self.get_container_graph(container)?;
+        let container = graph.get(container)?;
+        Some(resolve_container_metadata(graph, container))
+    }
+
+    /// Validates a `Container`'s properties against its `Project`'s metadata schemas.
+    /// Inherited `Metadata` is included in the validation.
+    ///
+    /// # Returns
+    /// Schema violations.
+    ///
+    /// # Errors
+    /// + If there are violations and the schemas are in reject mode.
+    /// + If the links are invalid. See [`Self::validate_links`].
+    pub fn validate_container_properties(
+        &self,
+        container: &ResourceId,
+        properties: &ContainerProperties,
+    ) -> Result<Vec<SchemaViolation>> {
+        let Some(schemas) = self.get_container_schemas(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
+        let graph = self
+            .get_container_graph(container)
+            .expect("could not find `Container`'s graph");
+
+        let inherited = match graph.parent(container)? {
+            Some(parent) => inherited_metadata(graph, parent),
+            None => InheritedMetadata::new(),
+        };
+
+        self.validate_links(container, &properties.links)?;
+        let metadata = ResolvedMetadata::for_container(properties, &inherited);
+        let violations = schemas.validate(properties.kind.as_ref(), &metadata);
+        check_violations(schemas, violations)
+    }
+
+    /// Normalizes a `Container`'s kind and tags using its `Project`'s vocabularies.
+    ///
+    /// # Errors
+    /// + If the kind or a tag is rejected by its vocabulary.
+    pub fn normalize_container_properties(
+        &self,
+        container: &ResourceId,
+        properties: &mut ContainerProperties,
+    ) -> Result {
+        let Some(vocabularies) = self.get_container_vocabularies(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
+        vocabularies.normalize_container_properties(properties)?;
+        Ok(())
     }
 
     /// Finds `Container`'s that match the filter.
@@ -537,16 +716,21 @@ impl Datastore {
             return found;
         };
 
-        let nodes = graph
-            .descendants(&root)
-            .expect("`Container` not found in graph");
+        let nodes = match self.container_index.candidates(&filter, true) {
+            Some(candidates) => candidates
+                .into_iter()
+                .filter(|node| is_in_subtree(graph, root, node))
+                .collect(),
+
+            None => graph
+                .descendants(&root)
+                .expect("`Container` not found in graph"),
+        };
 
         for node in nodes {
             let node = graph.get(&node).expect("`Container` not found in graph");
-
-            // @todo[4]: Implement for `LocalContainer`.
-            let container: CoreContainer = (*node.data()).clone();
-            if filter.matches(&container) {
+            let container: &CoreContainer = node.data();
+            if filter.matches(container) {
                 found.insert(node.data());
             }
         }
@@ -568,76 +752,135 @@ impl Datastore {
         &self,
         root: &ResourceId,
         filter: StdFilter,
-    ) -> HashSet<CoreContainer> {
-        /// Recursively finds mathcing `Containers`, inheriting metadata.
-        #[tracing::instrument(skip(graph))]
-        fn find_containers_with_metadata_recursive(
-            root: &ResourceId,
-            graph: &ContainerTree,
-            filter: StdFilter,
-            mut metadata: Metadata,
-        ) -> HashSet<CoreContainer> {
-            let mut found = HashSet::new();
-            let root = graph.get(root).expect("`Container` not in graph");
-
-            let children = graph.children(root.id()).expect("`Container` not in graph");
-            for (key, value) in root.data().properties.metadata.clone().into_iter() {
-                metadata.insert(key, value);
-            }
+    ) -> Vec<WithMetadata<CoreContainer>> {
+        let mut found = Vec::new();
+        let Some(graph) = self.get_container_graph(root) else {
+            return found;
+        };
 
-            for child in children {
-                let node = graph.get(&child).expect("child `Container` not in graph");
-                for matching in find_containers_with_metadata_recursive(
-                    node.id(),
-                    &graph,
-                    filter.clone(),
-                    metadata.clone(),
-                )
+        let nodes = match self.container_candidates_with_metadata(graph, &filter) {
+            Some(candidates) => candidates
                 .into_iter()
-                {
-                    found.insert(matching);
-                }
+                .filter(|node| is_in_subtree(graph, root, node))
+                .collect(),
+
+            None => graph
+                .descendants(&root)
+                .expect("`Container` not found in graph"),
+        };
+
+        for node in nodes {
+            let node = graph.get(&node).expect("`Container` not found in graph");
+            let metadata = resolve_container_metadata(graph, node);
+            let mut container = WithMetadata::new((*node.data()).clone(), metadata);
+            if !filter.matches(&container) {
+                continue;
             }
 
-            let mut container: CoreContainer = (*root.data()).clone();
-            container.properties.metadata = metadata;
-            if filter.matches(&container) {
-                for asset in container.assets.values_mut() {
-                    for (key, value) in container.properties.metadata.iter() {
-                        if !asset.properties.metadata.contains_key(key) {
-                            asset.properties.metadata.insert(key.clone(), value.clone());
-                        }
-                    }
+            for asset in container.resource.assets.values_mut() {
+                let path = node.base_path().join(asset.path.as_path());
+                asset.path = ResourcePath::new(path).expect("could not set absolute path");
+            }
 
-                    let path = root.base_path().join(asset.path.as_path());
-                    asset.path = ResourcePath::new(path).expect("could not set absolute path");
-                }
+            found.push(container);
+        }
+
+        found
+    }
+
+    /// Candidate `Container`s for a filter, accounting for inherited `Metadata`.
+    ///
+    /// # Returns
+    /// `None` if the filter has no indexed criteria.
+    fn container_candidates_with_metadata(
+        &self,
+        graph: &ContainerTree,
+        filter: &StdFilter,
+    ) -> Option<ResourceSet> {
+        let mut sets = Vec::new();
+        if let Some(candidates) = self.container_index.candidates(filter, false) {
+            sets.push(candidates);
+        }
 
-                found.insert(container);
+        if let Some(metadata) = filter.metadata.as_ref() {
+            for (key, value) in metadata {
+                sets.push(self.metadatum_inheritors(graph, key, value));
             }
+        }
+
+        intersect(sets)
+    }
 
-            found
+    /// `Container`s of the graph that own or inherit a metadatum.
+    ///
+    /// # Notes
+    /// + Includes `Container`s that override the inherited value.
+    /// + Includes `Container`s that derive, or inherit a derived value for, the key.
+    fn metadatum_inheritors(
+        &self,
+        graph: &ContainerTree,
+        key: &str,
+        value: &JsValue,
+    ) -> ResourceSet {
+        let mut holders = self.container_index.with_metadatum(key, value);
+        holders.extend(self.container_index.with_derived(key));
+
+        let mut inheritors = ResourceSet::new();
+        for holder in holders {
+            if inheritors.contains(&holder) {
+                // descendants already included
+                continue;
+            }
+
+            if let Some(descendants) = graph.descendants(&holder) {
+                inheritors.extend(descendants);
+            }
         }
 
-        // run fn
+        inheritors
+    }
+    /// Searches `Container`s by text.
+    ///
+    /// # Arguments
+    /// 1. Root of subtree to search within.
+    /// 2. Search query.
+    /// 3. Maximum number of results.
+    ///
+    /// # Returns
+    /// `Container`s with inherited `Metadata`, ordered by relevance.
+    ///
+    /// # Notes
+    /// + Only owned `Metadata` is searched.
+    pub fn search_containers(
+        &self,
+        root: &ResourceId,
+        query: &str,
+        limit: Option<usize>,
+    ) -> Vec<WithMetadata<CoreContainer>> {
         let Some(graph) = self.get_container_graph(root) else {
-            return HashSet::new();
+            return Vec::new();
         };
 
-        let metadata =
-            graph
-                .ancestors(root)
-                .into_iter()
-                .rfold(Metadata::new(), |mut metadata, ancestor| {
-                    let container = graph.get(&ancestor).expect("`Container` not found");
-                    for (key, value) in container.properties.metadata.clone() {
-                        metadata.insert(key, value);
-                    }
-
-                    metadata
-                });
-
-        find_containers_with_metadata_recursive(root, graph, filter, metadata)
+        let mut found = self
+            .container_text_index
+            .search(query)
+            .into_iter()
+            .filter(|(cid, _)| is_in_subtree(graph, root, cid))
+            .map(|(cid, score)| {
+                let container = graph.get(&cid).expect("`Container` not found in graph");
+                (cid, score, container.properties.name.clone())
+            })
+            .collect::<Vec<_>>();
+
+        found.sort_by(compare_search_results);
+        found
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(cid, _, _)| {
+                self.get_container_with_metadata(&cid)
+                    .expect("`Container` not found")
+            })
+            .collect()
     }
 
     /// Get a `Container`'s id by its path.
@@ -749,6 +992,8 @@ impl Datastore {
 
         let aid = asset.rid.clone();
         let cid = container.rid.clone();
+        let entry = IndexEntry::from(&asset.properties);
+        let text_entry = TextEntry::from(&asset.properties);
         let asset_path = container.base_path().join(asset.path.as_path());
         let o_asset = container.insert_asset(asset);
         container.save()?;
@@ -764,6 +1009,8 @@ impl Datastore {
             }
         };
 
+        self.asset_index.insert(aid.clone(), entry);
+        self.asset_text_index.insert(aid.clone(), text_entry);
         self.insert_asset(aid, asset_path, cid);
         Ok(o_asset)
     }
@@ -799,6 +1046,16 @@ impl Datastore {
         let asset = container.assets.remove(rid);
         container.save()?;
         self.asset_containers.remove(rid);
+        self.asset_index.remove(rid);
+        self.asset_text_index.remove(rid);
+
+        let project = self
+            .container_projects
+            .get(&cid)
+            .expect("`Container` `Project` not found")
+            .clone();
+
+        self.remove_links_to(&project, &HashSet::from([rid.clone()]))?;
 
         if let Some(asset) = asset {
             let path = container_path.join(asset.path.as_path());
@@ -823,6 +1080,106 @@ impl Datastore {
         }
     }
 
+    /// Updates the index of an `Asset`'s properties.
+    /// Must be called after its properties are modified.
+    pub fn reindex_asset(&mut self, asset: &ResourceId) -> Result {
+        let Some(container) = self.get_asset_container(asset) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(asset) = container.assets.get(asset) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let aid = asset.rid.clone();
+        let entry = IndexEntry::from(&asset.properties);
+        let text_entry = TextEntry::from(&asset.properties);
+        self.asset_index.insert(aid.clone(), entry);
+        self.asset_text_index.insert(aid, text_entry);
+        Ok(())
+    }
+
+    /// Gets an `Asset`'s `Metadata`, resolved with that inherited from its `Container`.
+    pub fn get_asset_metadata(&self, asset: &ResourceId) -> Option<ResolvedMetadata> {
+        let container = self.get_asset_container_id(asset)?;
+        let graph = self.get_container_graph(container)?;
+        let properties = &graph.get(container)?.assets.get(asset)?.properties;
+        let inherited = inherited_metadata(graph, container);
+        Some(ResolvedMetadata::for_asset(properties, &inherited))
+    }
+
+    /// Validates an `Asset`'s properties against its `Project`'s metadata schemas.
+    /// Inherited `Metadata` is included in the validation.
+    ///
+    /// # Returns
+    /// Schema violations.
+    ///
+    /// # Errors
+    /// + If there are violations and the schemas are in reject mode.
+    /// + If the links are invalid. See [`Self::validate_links`].
+    pub fn validate_asset_properties(
+        &self,
+        asset: &ResourceId,
+        properties: &AssetProperties,
+    ) -> Result<Vec<SchemaViolation>> {
+        let Some(container) = self.get_asset_container_id(asset) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(schemas) = self.get_container_schemas(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
+        let graph = self
+            .get_container_graph(container)
+            .expect("could not find `Container`'s graph");
+
+        self.validate_links(asset, &properties.links)?;
+        let inherited = inherited_metadata(graph, container);
+        let metadata = ResolvedMetadata::for_asset(properties, &inherited);
+        let violations = schemas.validate(properties.kind.as_ref(), &metadata);
+        check_violations(schemas, violations)
+    }
+
+    /// Normalizes an `Asset`'s kind and tags using its `Project`'s vocabularies.
+    ///
+    /// # Errors
+    /// + If the kind or a tag is rejected by its vocabulary.
+    pub fn normalize_asset_properties(
+        &self,
+        asset: &ResourceId,
+        properties: &mut AssetProperties,
+    ) -> Result {
+        let Some(container) = self.get_asset_container_id(asset) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Asset` does not exist",
+            ))
+            .into());
+        };
+
+        let Some(vocabularies) = self.get_container_vocabularies(container) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` `Project` not found",
+            ))
+            .into());
+        };
+
+        vocabularies.normalize_asset_properties(properties)?;
+        Ok(())
+    }
+
     /// Updates an [`Asset`]'s path.
     /// Sets the `Asset`'s path relative to its `Container`.
     ///
@@ -904,22 +1261,32 @@ impl Datastore {
             return found;
         };
 
-        let nodes = graph
-            .descendants(root)
-            .expect("`Container` not found in graph");
+        let assets = match self.asset_index.candidates(&filter, true) {
+            Some(candidates) => self.assets_in_subtree(graph, root, candidates),
+            None => subtree_assets(graph, root),
+        };
 
-        for node in nodes {
-            let container = graph.get(&node).expect("`Container` not found in graph");
+        for aid in assets {
+            let container = self
+                .asset_containers
+                .get(&aid)
+                .expect("`Asset`'s `Container` not found");
 
-            for asset in container.data().assets.values() {
-                if filter.matches(asset) {
-                    // set path to absolute
-                    let mut asset = asset.clone();
-                    let path = container.base_path().join(asset.path.as_path());
-                    asset.path = ResourcePath::new(path).expect("could not set absolute path");
+            let container = graph
+                .get(container)
+                .expect("`Container` not found in graph");
+            let asset = container
+                .assets
+                .get(&aid)
+                .expect("`Asset` not found in `Container`");
 
-                    found.insert(asset);
-                }
+            if filter.matches(asset) {
+                // set path to absolute
+                let mut asset = asset.clone();
+                let path = container.base_path().join(asset.path.as_path());
+                asset.path = ResourcePath::new(path).expect("could not set absolute path");
+
+                found.insert(asset);
             }
         }
 
@@ -938,73 +1305,329 @@ impl Datastore {
         &self,
         root: &ResourceId,
         filter: StdFilter,
-    ) -> HashSet<Asset> {
-        /// Recursively finds mathcing `Containers`, inheriting metadata.
-        fn find_assets_with_metadata_recursive(
-            root: &ResourceId,
-            graph: &ContainerTree,
-            filter: StdFilter,
-            mut metadata: Metadata,
-        ) -> HashSet<Asset> {
-            let mut found = HashSet::new();
-            let root = graph.get(root).expect("`Container` not in graph");
-
-            let children = graph.children(root.id()).expect("`Container` not in graph");
-            for (key, value) in root.data().properties.metadata.clone().into_iter() {
-                metadata.insert(key, value);
+    ) -> Vec<WithMetadata<Asset>> {
+        let mut found = Vec::new();
+        let Some(graph) = self.get_container_graph(root) else {
+            return found;
+        };
+
+        let assets = match self.asset_candidates_with_metadata(graph, &filter) {
+            Some(candidates) => self.assets_in_subtree(graph, root, candidates),
+            None => subtree_assets(graph, root),
+        };
+
+        // inherited metadata, by `Container`
+        let mut container_metadata = ResourceMap::new();
+        for aid in assets {
+            let cid = self
+                .asset_containers
+                .get(&aid)
+                .expect("`Asset`'s `Container` not found");
+
+            let container = graph.get(cid).expect("`Container` not found in graph");
+            let asset = container
+                .assets
+                .get(&aid)
+                .expect("`Asset` not found in `Container`");
+
+            let inherited = container_metadata
+                .entry(cid.clone())
+                .or_insert_with(|| inherited_metadata(graph, cid));
+
+            let metadata = ResolvedMetadata::for_asset(&asset.properties, inherited);
+            let mut asset = WithMetadata::new(asset.clone(), metadata);
+            if filter.matches(&asset) {
+                // set path to absolute
+                let path = container.base_path().join(asset.resource.path.as_path());
+                asset.resource.path = ResourcePath::new(path).expect("could not set absolute path");
+
+                found.push(asset);
             }
+        }
 
-            for child in children {
-                let node = graph.get(&child).expect("child `Container` not in graph");
-                for matching in find_assets_with_metadata_recursive(
-                    node.id(),
-                    &graph,
-                    filter.clone(),
-                    metadata.clone(),
-                )
-                .into_iter()
-                {
-                    found.insert(matching);
-                }
+        found
+    }
+
+    /// Searches `Asset`s by text.
+    ///
+    /// # Arguments
+    /// 1. Root of subtree to search within.
+    /// 2. Search query.
+    /// 3. Maximum number of results.
+    ///
+    /// # Returns
+    /// `Asset`s with absolute paths, ordered by relevance.
+    ///
+    /// # Notes
+    /// + Only owned `Metadata` is searched.
+    pub fn search_assets(
+        &self,
+        root: &ResourceId,
+        query: &str,
+        limit: Option<usize>,
+    ) -> Vec<Asset> {
+        let Some(graph) = self.get_container_graph(root) else {
+            return Vec::new();
+        };
+
+        let mut matches = Vec::new();
+        for (aid, score) in self.asset_text_index.search(query) {
+            let Some(cid) = self.asset_containers.get(&aid) else {
+                continue;
+            };
+
+            if !is_in_subtree(graph, root, cid) {
+                continue;
             }
 
-            for asset in root.data().assets.values() {
-                let mut asset = asset.clone();
-                for (key, value) in metadata.clone().into_iter() {
-                    asset.properties.metadata.entry(key).or_insert(value);
+            let container = graph.get(cid).expect("`Container` not found in graph");
+            let asset = container
+                .assets
+                .get(&aid)
+                .expect("`Asset` not found in `Container`");
+
+            let name = asset.properties.name.clone().unwrap_or_default();
+            matches.push((aid, score, name));
+        }
+
+        matches.sort_by(compare_search_results);
+        matches
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|(aid, _, _)| {
+                let cid = self.asset_containers.get(&aid).expect("`Asset` not found");
+                let container = graph.get(cid).expect("`Container` not found in graph");
+                let mut asset = container
+                    .assets
+                    .get(&aid)
+                    .expect("`Asset` not found in `Container`")
+                    .clone();
+
+                // set path to absolute
+                let path = container.base_path().join(asset.path.as_path());
+                asset.path = ResourcePath::new(path).expect("could not set absolute path");
+                asset
+            })
+            .collect()
+    }
+
+    /// Candidate `Asset`s for a filter, accounting for inherited `Metadata`.
+    ///
+    /// # Returns
+    /// `None` if the filter has no indexed criteria.
+    fn asset_candidates_with_metadata(
+        &self,
+        graph: &ContainerTree,
+        filter: &StdFilter,
+    ) -> Option<ResourceSet> {
+        let mut sets = Vec::new();
+        if let Some(candidates) = self.asset_index.candidates(filter, false) {
+            sets.push(candidates);
+        }
+
+        if let Some(metadata) = filter.metadata.as_ref() {
+            for (key, value) in metadata {
+                let mut assets = self.asset_index.with_metadatum(key, value);
+                assets.extend(self.asset_index.with_derived(key));
+                for container in self.metadatum_inheritors(graph, key, value) {
+                    let container = graph.get(&container).expect("`Container` not found");
+                    assets.extend(container.assets.keys().cloned());
                 }
 
-                if filter.matches(&asset) {
-                    // set path to absolute
-                    let path = root.base_path().join(asset.path.as_path());
-                    asset.path = ResourcePath::new(path).expect("could not set absolute path");
+                sets.push(assets);
+            }
+        }
+
+        intersect(sets)
+    }
+
+    /// Filters `Asset`s to those within a subtree.
+    fn assets_in_subtree(
+        &self,
+        graph: &ContainerTree,
+        root: &ResourceId,
+        assets: ResourceSet,
+    ) -> ResourceSet {
+        assets
+            .into_iter()
+            .filter(|asset| {
+                let Some(container) = self.asset_containers.get(asset) else {
+                    return false;
+                };
+
+                is_in_subtree(graph, root, container)
+            })
+            .collect()
+    }
+
+    // *************
+    // *** links ***
+    // *************
+
+    /// Returns the `Project` of a `Container` or `Asset`.
+    pub fn get_resource_project(&self, rid: &ResourceId) -> Option<&ResourceId> {
+        let container = self.asset_containers.get(rid).unwrap_or(rid);
+        self.container_projects.get(container)
+    }
+
+    /// Returns the links of a `Container` or `Asset`.
+    pub fn get_links(&self, rid: &ResourceId) -> Option<&Links> {
+        if let Some(container) = self.get_container(rid) {
+            return Some(&container.properties.links);
+        }
+
+        let container = self.get_asset_container(rid)?;
+        container
+            .assets
+            .get(rid)
+            .map(|asset| &asset.properties.links)
+    }
+
+    /// Returns the links to a resource from the resources of its `Project`.
+    ///
+    /// # Returns
+    /// `None` if the resource is not loaded.
+    pub fn get_backlinks(&self, target: &ResourceId) -> Option<Vec<Backlink>> {
+        let project = self.get_resource_project(target)?;
+        let graph = self.graphs.get(project)?;
+        let mut backlinks = Vec::new();
+        let mut collect = |source: &ResourceId, links: &Links| {
+            for relation in links.relations_to(target) {
+                backlinks.push(Backlink {
+                    source: source.clone(),
+                    relation,
+                });
+            }
+        };
+
+        for container in graph.nodes().values() {
+            collect(&container.rid, &container.properties.links);
+            for asset in container.assets.values() {
+                collect(&asset.rid, &asset.properties.links);
+            }
+        }
+
+        Some(backlinks)
+    }
+
+    /// Validates the links of a resource.
+    /// Only targets the resource does not already link to are validated,
+    /// so existing links do not block other edits.
+    ///
+    /// # Errors
+    /// + If a target is the resource itself.
+    /// + If a target is not in the resource's `Project`.
+    pub fn validate_links(&self, source: &ResourceId, links: &Links) -> Result {
+        let Some(project) = self.get_resource_project(source) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "resource does not exist",
+            ))
+            .into());
+        };
+
+        let current = self
+            .get_links(source)
+            .map(|links| links.all_targets().collect::<HashSet<_>>())
+            .unwrap_or_default();
+
+        for target in links.all_targets() {
+            if current.contains(target) {
+                continue;
+            }
+
+            if target == source {
+                return Err(CoreError::value("a resource can not link to itself").into());
+            }
+
+            if self.get_resource_project(target) != Some(project) {
+                return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                    "link target does not exist in `Project`",
+                ))
+                .into());
+            }
+        }
+
+        Ok(())
+    }
 
-                    found.insert(asset);
+    /// Removes links to resources that are not in a `Project`,
+    /// e.g. that were removed while the `Project` was closed.
+    /// Modified `Container`s are saved.
+    ///
+    /// # Returns
+    /// Resources whose links were modified.
+    #[tracing::instrument(skip(self))]
+    pub fn remove_dangling_links(&mut self, project: &ResourceId) -> Result<Vec<ResourceId>> {
+        let Some(graph) = self.graphs.get_mut(project) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` graph not found",
+            ))
+            .into());
+        };
+
+        let resources = subgraph_resources(graph);
+        let mut modified = Vec::new();
+        let containers = graph.nodes().keys().cloned().collect::<Vec<_>>();
+        for cid in containers {
+            let container = graph.get_mut(&cid).expect("`Container` not in graph");
+            let modified_len = modified.len();
+            if remove_dangling_targets(&mut container.properties.links, &resources) {
+                modified.push(cid.clone());
+            }
+
+            for (aid, asset) in container.assets.iter_mut() {
+                if remove_dangling_targets(&mut asset.properties.links, &resources) {
+                    modified.push(aid.clone());
                 }
             }
 
-            found
+            if modified.len() != modified_len {
+                container.save()?;
+            }
         }
 
-        // find mathing containers
-        let Some(graph) = self.get_container_graph(root) else {
-            return HashSet::new();
+        Ok(modified)
+    }
+
+    /// Removes links to the given targets from the resources of a `Project`.
+    /// Modified `Container`s are saved.
+    ///
+    /// # Returns
+    /// Resources whose links were modified.
+    #[tracing::instrument(skip(self))]
+    pub fn remove_links_to(
+        &mut self,
+        project: &ResourceId,
+        targets: &HashSet<ResourceId>,
+    ) -> Result<Vec<ResourceId>> {
+        let Some(graph) = self.graphs.get_mut(project) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Project` graph not found",
+            ))
+            .into());
         };
 
-        let metadata =
-            graph
-                .ancestors(root)
-                .into_iter()
-                .rfold(Metadata::new(), |mut metadata, ancestor| {
-                    let container = graph.get(&ancestor).expect("`Container` not found");
-                    for (key, value) in container.properties.metadata.clone() {
-                        metadata.insert(key, value);
-                    }
+        let mut modified = Vec::new();
+        let containers = graph.nodes().keys().cloned().collect::<Vec<_>>();
+        for cid in containers {
+            let container = graph.get_mut(&cid).expect("`Container` not in graph");
+            let modified_len = modified.len();
+            if remove_targets(&mut container.properties.links, targets) {
+                modified.push(cid.clone());
+            }
 
-                    metadata
-                });
+            for (aid, asset) in container.assets.iter_mut() {
+                if remove_targets(&mut asset.properties.links, targets) {
+                    modified.push(aid.clone());
+                }
+            }
+
+            if modified.len() != modified_len {
+                container.save()?;
+            }
+        }
 
-        find_assets_with_metadata_recursive(root, graph, filter, metadata)
+        Ok(modified)
     }
 
     // **************
@@ -1132,6 +1755,125 @@ impl Datastore {
     }
 }
 
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Removes the targets from links.
+///
+/// # Returns
+/// `true` if any link was removed.
+fn remove_targets(links: &mut Links, targets: &HashSet<ResourceId>) -> bool {
+    let mut removed = false;
+    for target in targets.iter() {
+        removed |= links.remove_target(target);
+    }
+
+    removed
+}
+
+/// Removes targets that are not in `resources` from links.
+///
+/// # Returns
+/// `true` if any link was removed.
+fn remove_dangling_targets(links: &mut Links, resources: &HashSet<ResourceId>) -> bool {
+    let dangling = links
+        .all_targets()
+        .filter(|target| !resources.contains(target))
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    remove_targets(links, &dangling)
+}
+
+/// Ids of the `Container`s and `Asset`s in a graph.
+pub fn subgraph_resources(graph: &ContainerTree) -> HashSet<ResourceId> {
+    let mut resources = HashSet::new();
+    for (cid, container) in graph.nodes() {
+        resources.insert(cid.clone());
+        resources.extend(container.assets.keys().cloned());
+    }
+
+    resources
+}
+
+/// `Metadata` a `Container` passes down to its children and `Asset`s.
+/// Includes `Metadata` the `Container` inherits.
+fn inherited_metadata(graph: &ContainerTree, container: &ResourceId) -> InheritedMetadata {
+    let ancestors = graph.ancestors(container);
+    inherit_metadata(
+        ancestors.iter().rev().map(|ancestor| -> &CoreContainer {
+            graph.get(ancestor).expect("`Container` not found")
+        }),
+    )
+}
+
+/// Resolves a `Container`'s `Metadata` with that inherited from its ancestors.
+/// Includes derived `Metadata`.
+fn resolve_container_metadata(
+    graph: &ContainerTree,
+    container: &CoreContainer,
+) -> ResolvedMetadata {
+    let inherited = match graph
+        .parent(&container.rid)
+        .expect("`Container` not found in graph")
+    {
+        Some(parent) => inherited_metadata(graph, parent),
+        None => InheritedMetadata::new(),
+    };
+
+    ResolvedMetadata::for_container(&container.properties, &inherited)
+}
+
+/// Applies the schemas' validation mode to violations.
+///
+/// # Errors
+/// + If there are violations and the schemas are in reject mode.
+fn check_violations(
+    schemas: &MetadataSchemas,
+    violations: Vec<SchemaViolation>,
+) -> Result<Vec<SchemaViolation>> {
+    if schemas.mode == ValidationMode::Reject && !violations.is_empty() {
+        return Err(Error::SchemaViolations(violations));
+    }
+
+    Ok(violations)
+}
+
+/// Orders text search results by descending score.
+/// Ties are broken by name, then id, so results are stable across searches.
+fn compare_search_results(
+    (a_rid, a_score, a_name): &(ResourceId, f64, String),
+    (b_rid, b_score, b_name): &(ResourceId, f64, String),
+) -> Ordering {
+    b_score
+        .total_cmp(a_score)
+        .then_with(|| a_name.cmp(b_name))
+        .then_with(|| (**a_rid).cmp(&**b_rid))
+}
+
+/// Returns whether `node` is in the subtree of `root`.
+fn is_in_subtree(graph: &ContainerTree, root: &ResourceId, node: &ResourceId) -> bool {
+    graph.ancestors(node).contains(root)
+}
+
+/// `Asset`s of all `Container`s in a subtree.
+fn subtree_assets(graph: &ContainerTree, root: &ResourceId) -> ResourceSet {
+    graph
+        .descendants(root)
+        .expect("`Container` not found in graph")
+        .iter()
+        .flat_map(|container| {
+            graph
+                .get(container)
+                .expect("`Container` not found in graph")
+                .assets
+                .keys()
+                .cloned()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 #[path = "./store_test.rs"]
 mod store_test;
@@ -2,6 +2,7 @@ use super::*;
 use dev_utils::fs::TempDir;
 use dev_utils::path::resource_path::resource_path;
 use thot_core::db::StandardSearchFilter as StdFilter;
+use thot_core::project::{InheritMetadata, Metadata, WithMetadata};
 use thot_core::types::ResourceId;
 use thot_local::graph::ContainerTreeLoader;
 use thot_local::project::resources::{
@@ -395,3 +396,304 @@ fn remove_project_script_should_work() {
         "project map for not removed script should exist"
     );
 }
+
+#[test]
+fn find_containers_with_metadata_should_work() {
+    // setup
+    let mut dir = TempDir::new().expect("new `TempDir` should work");
+    let child_1_dir = dir.mkdir().expect("mkdir should work");
+    let child_2_dir = dir.mkdir().expect("mkdir should work");
+
+    let mut root = LocalContainer::new(dir.path());
+    let child_1 = LocalContainer::new(&child_1_dir);
+    let mut child_2 = LocalContainer::new(&child_2_dir);
+
+    let root_rid = root.rid.clone();
+    let child_1_rid = child_1.rid.clone();
+    let child_2_rid = child_2.rid.clone();
+
+    root.properties
+        .metadata
+        .insert("key".to_string(), "inherited".into());
+
+    child_2
+        .properties
+        .metadata
+        .insert("key".to_string(), "overridden".into());
+
+    let mut db = Datastore::new();
+    let mut graph = ResourceTree::new(root);
+    graph.insert(root_rid.clone(), child_1).unwrap();
+    graph.insert(root_rid.clone(), child_2).unwrap();
+    db.insert_project_graph(ResourceId::new(), graph);
+
+    let mut filter = StdFilter::default();
+    filter.metadata = Some(Metadata::from([("key".to_string(), "inherited".into())]));
+
+    // test
+    let found = db
+        .find_containers_with_metadata(&root_rid, filter.clone())
+        .into_iter()
+        .map(|c| c.resource.rid)
+        .collect::<Vec<ResourceId>>();
+
+    assert!(
+        found.contains(&root_rid),
+        "root `Container` should be found"
+    );
+    assert!(
+        found.contains(&child_1_rid),
+        "inheriting `Container` should be found"
+    );
+
+    assert!(
+        !found.contains(&child_2_rid),
+        "overriding `Container` should not be found"
+    );
+
+    // update index
+    db.get_container_mut(&child_2_rid)
+        .unwrap()
+        .properties
+        .metadata
+        .remove("key");
+
+    db.reindex_container(&child_2_rid)
+        .expect("reindex `Container` should work");
+
+    let found = db
+        .find_containers_with_metadata(&child_2_rid, filter)
+        .into_iter()
+        .map(|c| c.resource.rid)
+        .collect::<Vec<ResourceId>>();
+
+    assert!(
+        found.contains(&child_2_rid),
+        "`Container` should inherit after update"
+    );
+}
+
+#[test]
+fn get_container_metadata_should_respect_inheritance() {
+    // setup
+    let mut dir = TempDir::new().expect("new `TempDir` should work");
+    let child_dir = dir.mkdir().expect("mkdir should work");
+
+    let mut root = LocalContainer::new(dir.path());
+    let mut child = LocalContainer::new(&child_dir);
+
+    let root_rid = root.rid.clone();
+    let child_rid = child.rid.clone();
+
+    root.properties
+        .metadata
+        .insert("inherited".to_string(), "root".into());
+    root.properties
+        .metadata
+        .insert("sealed".to_string(), "root".into());
+    root.properties
+        .metadata
+        .insert("private".to_string(), "root".into());
+    root.properties.inheritance.seal("sealed");
+    root.properties.inheritance.set_non_inheritable("private");
+
+    child
+        .properties
+        .metadata
+        .insert("sealed".to_string(), "child".into());
+
+    let mut db = Datastore::new();
+    let mut graph = ResourceTree::new(root);
+    graph.insert(root_rid.clone(), child).unwrap();
+    db.insert_project_graph(ResourceId::new(), graph);
+
+    // test
+    let metadata = db
+        .get_container_metadata(&child_rid)
+        .expect("`Container` should exist");
+
+    let all = metadata.metadata_all();
+    assert_eq!(
+        Some(&"root".into()),
+        all.get("inherited"),
+        "key should be inherited"
+    );
+    assert_eq!(Some(&root_rid), metadata.source("inherited"));
+
+    assert_eq!(
+        Some(&"root".into()),
+        all.get("sealed"),
+        "sealed value should not be overridden"
+    );
+    assert!(metadata.is_overridden("sealed"));
+
+    assert!(
+        !all.contains_key("private"),
+        "non-inheritable key should not be inherited"
+    );
+
+    let container = db
+        .get_container_with_metadata(&child_rid)
+        .expect("`Container` should exist");
+
+    assert_eq!(
+        all,
+        container.metadata_all(),
+        "metadata should match resolved metadata"
+    );
+
+    assert_eq!(
+        &Metadata::from([("sealed".to_string(), "child".into())]),
+        container.metadata_owned(),
+        "owned metadata should only contain the `Container`'s keys"
+    );
+    assert_eq!(
+        container.metadata_owned(),
+        &container.resource.properties.metadata,
+        "`Container`'s properties should only hold owned metadata"
+    );
+
+    let inherited = container.metadata_inherited();
+    assert_eq!(Some(&"root".into()), inherited.get("inherited"));
+    assert_eq!(Some(&"root".into()), inherited.get("sealed"));
+    assert!(
+        !inherited.contains_key("private"),
+        "non-inheritable key should not be inherited"
+    );
+
+    let flat = db.flatten_container_metadata(container);
+    assert_eq!(
+        all, &flat.properties.metadata,
+        "flattened metadata should match resolved metadata"
+    );
+}
+
+#[test]
+fn find_containers_with_metadata_should_include_derived_metadata() {
+    // setup
+    let mut dir = TempDir::new().expect("new `TempDir` should work");
+    let child_dir = dir.mkdir().expect("mkdir should work");
+
+    let mut root = LocalContainer::new(dir.path());
+    let mut child = LocalContainer::new(&child_dir);
+
+    let root_rid = root.rid.clone();
+    let child_rid = child.rid.clone();
+
+    root.properties.name = "root".to_string();
+    root.properties
+        .metadata
+        .insert("volume".to_string(), 4.into());
+
+    child.properties.name = "child".to_string();
+    child
+        .properties
+        .metadata
+        .insert("mass".to_string(), 10.into());
+
+    child
+        .properties
+        .derived
+        .insert("concentration".to_string(), "mass / volume".to_string());
+
+    child.properties.derived.insert(
+        "label".to_string(),
+        "concat(join($ancestors, '/'), '/', $name)".to_string(),
+    );
+
+    let mut db = Datastore::new();
+    let mut graph = ResourceTree::new(root);
+    graph.insert(root_rid.clone(), child).unwrap();
+    db.insert_project_graph(ResourceId::new(), graph);
+
+    // test
+    let metadata = db
+        .get_container_metadata(&child_rid)
+        .expect("`Container` should exist");
+
+    let all = metadata.metadata_all();
+    assert_eq!(
+        Some(&2.5.into()),
+        all.get("concentration"),
+        "derived value should use inherited metadata"
+    );
+    assert!(metadata.is_derived("concentration"));
+
+    assert_eq!(
+        Some(&"root/child".into()),
+        all.get("label"),
+        "derived value should use ancestor names"
+    );
+
+    let mut filter = StdFilter::default();
+    filter.metadata = Some(Metadata::from([("concentration".to_string(), 2.5.into())]));
+
+    let found = db
+        .find_containers_with_metadata(&root_rid, filter.clone())
+        .into_iter()
+        .map(|c| c.resource.rid)
+        .collect::<Vec<ResourceId>>();
+
+    assert_eq!(
+        vec![child_rid.clone()],
+        found,
+        "derived value should be found"
+    );
+
+    // re-evaluate on change
+    db.get_container_mut(&root_rid)
+        .unwrap()
+        .properties
+        .metadata
+        .insert("volume".to_string(), 5.into());
+
+    let found = db.find_containers_with_metadata(&root_rid, filter);
+    assert!(found.is_empty(), "derived value should be re-evaluated");
+
+    let metadata = db
+        .get_container_metadata(&child_rid)
+        .expect("`Container` should exist");
+
+    assert_eq!(
+        Some(&2.into()),
+        metadata.metadata_all().get("concentration"),
+        "derived value should be updated"
+    );
+}
+
+#[test]
+fn search_containers_should_break_ties_by_name() {
+    // setup
+    let mut dir = TempDir::new().expect("new `TempDir` should work");
+    let mut root = LocalContainer::new(dir.path());
+    root.properties.name = "root".to_string();
+    let root_rid = root.rid.clone();
+
+    let mut graph = ResourceTree::new(root);
+    for name in ["sample c", "sample a", "sample b"] {
+        let mut child = LocalContainer::new(dir.mkdir().expect("mkdir should work"));
+        child.properties.name = name.to_string();
+        graph.insert(root_rid.clone(), child).unwrap();
+    }
+
+    let mut db = Datastore::new();
+    db.insert_project_graph(ResourceId::new(), graph);
+
+    // test
+    let names = |found: Vec<WithMetadata<thot_core::project::Container>>| {
+        found
+            .into_iter()
+            .map(|container| container.resource.properties.name)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec!["sample a", "sample b", "sample c"],
+        names(db.search_containers(&root_rid, "sample", None))
+    );
+    assert_eq!(
+        vec!["sample a", "sample b"],
+        names(db.search_containers(&root_rid, "sample", Some(2))),
+        "limited results should be stable"
+    );
+}
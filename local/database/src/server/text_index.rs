@@ -0,0 +1,296 @@
+//! Full-text index over resource properties.
+//!
+//! Words of a resource's name, description, tags, and string metadata values
+//! are indexed so resources can be found by any of them.
+//! Matches are ranked by relevance, allowing for prefix and fuzzy matches.
+use serde_json::Value as JsValue;
+use std::collections::{BTreeMap, HashMap};
+use thot_core::project::{AssetProperties, ContainerProperties, Metadata};
+use thot_core::types::{ResourceId, ResourceMap};
+
+// *****************
+// *** Constants ***
+// *****************
+
+/// Weight of words in a resource's name.
+const NAME_WEIGHT: f64 = 3.0;
+
+/// Weight of words in a resource's tags.
+const TAG_WEIGHT: f64 = 2.0;
+
+/// Weight of words in a resource's description.
+const DESCRIPTION_WEIGHT: f64 = 1.0;
+
+/// Weight of words in a resource's string metadata values.
+const METADATA_WEIGHT: f64 = 1.0;
+
+/// Score of a query word matching an indexed word exactly.
+const EXACT_SCORE: f64 = 1.0;
+
+/// Score of a query word being a prefix of an indexed word.
+const PREFIX_SCORE: f64 = 0.7;
+
+/// Score of a query word being within the edit distance of an indexed word.
+const FUZZY_SCORE: f64 = 0.4;
+
+// *************
+// *** Entry ***
+// *************
+
+/// Indexed words of a single resource, with their weight.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct TextEntry(HashMap<String, f64>);
+
+impl TextEntry {
+    fn new(
+        name: Option<&str>,
+        description: Option<&str>,
+        tags: &Vec<String>,
+        metadata: &Metadata,
+    ) -> Self {
+        let mut entry = Self::default();
+        if let Some(name) = name {
+            entry.insert_text(name, NAME_WEIGHT);
+        }
+
+        if let Some(description) = description {
+            entry.insert_text(description, DESCRIPTION_WEIGHT);
+        }
+
+        for tag in tags {
+            entry.insert_text(tag, TAG_WEIGHT);
+        }
+
+        for value in metadata.values() {
+            entry.insert_value(value);
+        }
+
+        entry
+    }
+
+    /// Adds the words of the text, keeping the highest weight of each.
+    fn insert_text(&mut self, text: &str, weight: f64) {
+        for word in tokenize(text) {
+            let current = self.0.entry(word).or_insert(weight);
+            if *current < weight {
+                *current = weight;
+            }
+        }
+    }
+
+    /// Adds the words of a metadatum's string values.
+    fn insert_value(&mut self, value: &JsValue) {
+        match value {
+            JsValue::String(value) => self.insert_text(value, METADATA_WEIGHT),
+            JsValue::Array(values) => {
+                for value in values {
+                    self.insert_value(value);
+                }
+            }
+
+            JsValue::Object(values) => {
+                for value in values.values() {
+                    self.insert_value(value);
+                }
+            }
+
+            JsValue::Null | JsValue::Bool(_) | JsValue::Number(_) => {}
+        }
+    }
+}
+
+impl From<&ContainerProperties> for TextEntry {
+    fn from(properties: &ContainerProperties) -> Self {
+        Self::new(
+            Some(&properties.name),
+            properties.description.as_deref(),
+            &properties.tags,
+            &properties.metadata,
+        )
+    }
+}
+
+impl From<&AssetProperties> for TextEntry {
+    fn from(properties: &AssetProperties) -> Self {
+        Self::new(
+            properties.name.as_deref(),
+            properties.description.as_deref(),
+            &properties.tags,
+            &properties.metadata,
+        )
+    }
+}
+
+// *************
+// *** Index ***
+// *************
+
+/// Inverted index from words to the resources containing them.
+///
+/// # Notes
+/// + Only owned metadata is indexed.
+#[derive(Default, Debug)]
+pub struct TextIndex {
+    /// Map from a word to the resources containing it, with its weight.
+    /// Ordered so words sharing a prefix are adjacent.
+    words: BTreeMap<String, ResourceMap<f64>>,
+
+    /// Current entry of each indexed resource.
+    entries: ResourceMap<TextEntry>,
+}
+
+impl TextIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indexes a resource, replacing its previous entry.
+    pub fn insert(&mut self, rid: ResourceId, entry: TextEntry) {
+        self.remove(&rid);
+        for (word, weight) in entry.0.iter() {
+            self.words
+                .entry(word.clone())
+                .or_default()
+                .insert(rid.clone(), *weight);
+        }
+
+        self.entries.insert(rid, entry);
+    }
+
+    /// Removes a resource from the index.
+    ///
+    /// # Returns
+    /// The resource's entry, if it was indexed.
+    pub fn remove(&mut self, rid: &ResourceId) -> Option<TextEntry> {
+        let Some(entry) = self.entries.remove(rid) else {
+            return None;
+        };
+
+        for word in entry.0.keys() {
+            let Some(rids) = self.words.get_mut(word) else {
+                continue;
+            };
+
+            rids.remove(rid);
+            if rids.is_empty() {
+                self.words.remove(word);
+            }
+        }
+
+        Some(entry)
+    }
+
+    /// Searches the index.
+    /// A resource matches if it matches any word of the query.
+    ///
+    /// # Returns
+    /// Matching resources with their score, ordered by descending score.
+    pub fn search(&self, query: &str) -> Vec<(ResourceId, f64)> {
+        let mut query = tokenize(query);
+        query.sort();
+        query.dedup();
+
+        let mut scores = ResourceMap::new();
+        for word in query {
+            for (rid, score) in self.search_word(&word) {
+                *scores.entry(rid).or_insert(0.0) += score;
+            }
+        }
+
+        let mut scores = scores.into_iter().collect::<Vec<_>>();
+        scores.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        scores
+    }
+
+    /// Best score of each resource matching a single query word.
+    fn search_word(&self, query: &str) -> ResourceMap<f64> {
+        let mut scores = ResourceMap::new();
+        let mut add_matches = |rids: &ResourceMap<f64>, score: f64| {
+            for (rid, weight) in rids.iter() {
+                let score = score * weight;
+                let best = scores.entry(rid.clone()).or_insert(score);
+                if *best < score {
+                    *best = score;
+                }
+            }
+        };
+
+        for (word, rids) in self.words.range(query.to_string()..) {
+            if !word.starts_with(query) {
+                break;
+            }
+
+            let score = if word == query {
+                EXACT_SCORE
+            } else {
+                PREFIX_SCORE
+            };
+
+            add_matches(rids, score);
+        }
+
+        let max_edits = max_edits(query);
+        if max_edits > 0 {
+            let query_len = query.chars().count();
+            for (word, rids) in self.words.iter() {
+                if word.starts_with(query) {
+                    continue;
+                }
+
+                if word.chars().count().abs_diff(query_len) > max_edits {
+                    continue;
+                }
+
+                if edit_distance(query, word) <= max_edits {
+                    add_matches(rids, FUZZY_SCORE);
+                }
+            }
+        }
+
+        scores
+    }
+}
+
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Splits text into lowercase words.
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect()
+}
+
+/// Maximum edit distance for a word to fuzzy match.
+/// Short words must match exactly to avoid noise.
+fn max_edits(word: &str) -> usize {
+    match word.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Levenshtein distance between two words.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+#[path = "./text_index_test.rs"]
+mod text_index_test;
@@ -0,0 +1,74 @@
+use super::*;
+use thot_core::project::ContainerProperties;
+
+#[test]
+fn text_index_search_should_rank_matches() {
+    // setup
+    let mut index = TextIndex::new();
+
+    let p0 = ContainerProperties::new("Tensile test");
+    let mut p1 = ContainerProperties::new("Sample 1");
+    p1.description = Some("Tensile strength of a steel sample.".to_string());
+    p1.metadata
+        .insert("material".to_string(), "steel".to_string().into());
+
+    let p2 = ContainerProperties::new("Other");
+
+    let r0 = ResourceId::new();
+    let r1 = ResourceId::new();
+    let r2 = ResourceId::new();
+    index.insert(r0.clone(), (&p0).into());
+    index.insert(r1.clone(), (&p1).into());
+    index.insert(r2.clone(), (&p2).into());
+
+    // test
+    let found = index.search("tensile");
+    assert_eq!(2, found.len(), "both resources should be found");
+    assert_eq!(r0, found[0].0, "name matches should rank first");
+
+    let found = index.search("STEEL");
+    assert_eq!(1, found.len(), "search should be case insensitive");
+    assert_eq!(r1, found[0].0, "incorrect resource found");
+
+    let found = index.search("tens");
+    assert_eq!(2, found.len(), "prefixes should match");
+
+    let found = index.search("tensle");
+    assert_eq!(2, found.len(), "misspellings should match");
+
+    let found = index.search("sample steel");
+    assert_eq!(r1, found[0].0, "more matching words should rank higher");
+
+    let found = index.search("xyz");
+    assert!(found.is_empty(), "nothing should be found");
+}
+
+#[test]
+fn text_index_insert_should_replace_entry() {
+    // setup
+    let mut index = TextIndex::new();
+    let rid = ResourceId::new();
+
+    let mut props = ContainerProperties::new("old");
+    index.insert(rid.clone(), (&props).into());
+
+    // test
+    props.name = "new".to_string();
+    index.insert(rid.clone(), (&props).into());
+    assert!(
+        index.search("old").is_empty(),
+        "old name should be unindexed"
+    );
+    assert_eq!(1, index.search("new").len(), "new name should be indexed");
+
+    assert!(index.remove(&rid).is_some(), "entry should be returned");
+    assert!(index.words.is_empty(), "empty words should be removed");
+}
+
+#[test]
+fn edit_distance_should_work() {
+    assert_eq!(0, edit_distance("steel", "steel"));
+    assert_eq!(1, edit_distance("steel", "steal"));
+    assert_eq!(1, edit_distance("steel", "stel"));
+    assert_eq!(2, edit_distance("steel", "stela"));
+}
@@ -1,11 +1,12 @@
 //! Local runner hooks.
+use std::collections::HashSet;
 use std::path::PathBuf;
 use thot_core::error::{ResourceError, Result as CoreResult};
-use thot_core::project::{Project, Script as CoreScript, ScriptLang};
+use thot_core::project::{Container as CoreContainer, Project, Script as CoreScript, ScriptLang};
 use thot_core::runner::RunnerHooks as CoreRunnerHooks;
 use thot_core::types::{ResourceId, ResourcePath};
 use thot_local::system::settings::RunnerSettings;
-use thot_local_database::{Client as DbClient, ProjectCommand, ScriptCommand};
+use thot_local_database::{Client as DbClient, ContainerCommand, ProjectCommand, ScriptCommand};
 
 /// Retrieves a local [`Script`](CoreScript) given its [`ResourceId`].
 #[tracing::instrument]
@@ -86,9 +87,29 @@ pub fn get_script(rid: &ResourceId) -> CoreResult<CoreScript> {
     Ok(script)
 }
 
+/// Retrieves the [`ResourceId`]s of a `Container`'s `Asset`s.
+#[tracing::instrument]
+pub fn get_assets(container: &ResourceId) -> CoreResult<HashSet<ResourceId>> {
+    let db = DbClient::new();
+    let container = db
+        .send(ContainerCommand::Get(container.clone()).into())
+        .expect("could not retrieve `Container`");
+
+    let container: Option<CoreContainer> = serde_json::from_value(container)
+        .expect("could not convert result of `Get` to `Container`");
+
+    let Some(container) = container else {
+        return Err(ResourceError::does_not_exist("`Container` not loaded").into());
+    };
+
+    Ok(container.assets.into_keys().collect())
+}
+
 pub struct RunnerHooks {}
 impl RunnerHooks {
     pub fn new() -> CoreRunnerHooks {
-        CoreRunnerHooks::new(get_script)
+        let mut hooks = CoreRunnerHooks::new(get_script);
+        hooks.get_assets = Some(get_assets);
+        hooks
     }
 }
@@ -1,11 +1,11 @@
 //! Local runner for Thot projects.
-use crate::hooks;
-use thot_core::runner::{Runner as CoreRunner, RunnerHooks};
+use crate::hooks::RunnerHooks;
+use thot_core::runner::Runner as CoreRunner;
 
 pub struct Runner();
 impl Runner {
     pub fn new() -> CoreRunner {
-        let hooks = RunnerHooks::new(hooks::get_script);
+        let hooks = RunnerHooks::new();
         CoreRunner::new(hooks)
     }
 }
@@ -219,6 +219,42 @@ pub fn scripts_file_of(path: &Path) -> PathBuf {
     thot_dir_of(path).join(SCRIPTS_FILE)
 }
 
+// --- metadata history ---
+/// Path to the metadata history file from a base path.
+pub fn metadata_history_file() -> PathBuf {
+    thot_dir().join(METADATA_HISTORY_FILE)
+}
+
+/// Path to the metadata history file for a given path.
+/// thot_dir(path)/\<METADATA_HISTORY_FILE\>
+pub fn metadata_history_file_of(path: &Path) -> PathBuf {
+    thot_dir_of(path).join(METADATA_HISTORY_FILE)
+}
+
+// --- flags ---
+/// Path to the flags file from a base path.
+pub fn flags_file() -> PathBuf {
+    thot_dir().join(FLAGS_FILE)
+}
+
+/// Path to the flags file for a given path.
+/// thot_dir(path)/\<FLAGS_FILE\>
+pub fn flags_file_of(path: &Path) -> PathBuf {
+    thot_dir_of(path).join(FLAGS_FILE)
+}
+
+// --- lineage ---
+/// Path to the lineage file from a base path.
+pub fn lineage_file() -> PathBuf {
+    thot_dir().join(LINEAGE_FILE)
+}
+
+/// Path to the lineage file for a given path.
+/// thot_dir(path)/\<LINEAGE_FILE\>
+pub fn lineage_file_of(path: &Path) -> PathBuf {
+    thot_dir_of(path).join(LINEAGE_FILE)
+}
+
 #[cfg(test)]
 #[path = "./common_test.rs"]
 mod common_test;
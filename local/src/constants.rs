@@ -1,7 +1,7 @@
 //! Crate wide constant values.
 
 /// Current project format version.
-pub static PROJECT_FORMAT_VERSION: &str = "0.10.0";
+pub static PROJECT_FORMAT_VERSION: &str = "0.11.0";
 
 // ************
 // *** thot ***
@@ -14,6 +14,9 @@ pub const CONTAINER_FILE: &str = "container.json";
 pub const CONTAINER_SETTINGS_FILE: &str = "container_settings.json";
 pub const ASSETS_FILE: &str = "assets.json";
 pub const SCRIPTS_FILE: &str = "scripts.json";
+pub const METADATA_HISTORY_FILE: &str = "metadata_history.jsonl";
+pub const FLAGS_FILE: &str = "flags.json";
+pub const LINEAGE_FILE: &str = "lineage.json";
 
 // ************
 // *** misc ***
@@ -48,6 +48,15 @@ pub enum ProjectError {
 
     #[error("`{0}` is not registered")]
     PathNotRegistered(PathBuf),
+
+    #[error("`{0}` is not a valid format version")]
+    InvalidFormatVersion(String),
+
+    /// The Project was written in a newer format than is supported.
+    #[error(
+        "Project format `{0}` is newer than the supported format `{1}`, update Thot to open it"
+    )]
+    FormatTooNew(String, String),
 }
 
 // ***********************
@@ -98,6 +107,23 @@ pub enum AssetError {
     /// [`AssetBuilder`](crate::project::asset::AssetBuilder).
     #[error("builder errored: {0}")]
     BuilderError(String),
+
+    /// The `Asset`'s file could not be moved to the trash.
+    #[error("could not trash `{0}`: {1}")]
+    TrashFailed(PathBuf, String),
+}
+
+// *********************
+// *** Extract Error ***
+// *********************
+
+#[cfg(feature = "fs")]
+#[derive(Serialize, Deserialize, Error, Debug)]
+pub enum ExtractError {
+    /// The file's contents could not be read by the
+    /// [`Extractor`](crate::project::extractors::Extractor).
+    #[error("could not extract properties from `{path}`: {message}")]
+    InvalidFile { path: PathBuf, message: String },
 }
 
 // ********************
@@ -158,6 +184,10 @@ pub enum Error {
     #[error("{0}")]
     ContainerError(ContainerError),
 
+    #[cfg(feature = "fs")]
+    #[error("{0}")]
+    ExtractError(ExtractError),
+
     #[cfg(feature = "fs")]
     #[error("{0}")]
     ProjectError(ProjectError),
@@ -187,6 +217,13 @@ impl From<ContainerError> for Error {
     }
 }
 
+#[cfg(feature = "fs")]
+impl From<ExtractError> for Error {
+    fn from(err: ExtractError) -> Self {
+        Error::ExtractError(err)
+    }
+}
+
 #[cfg(feature = "fs")]
 impl From<ProjectError> for Error {
     fn from(err: ProjectError) -> Self {
@@ -4,6 +4,7 @@ use crate::project::container;
 use crate::project::resources::Container;
 use crate::Result;
 use has_id::HasId;
+use std::cmp::Ordering;
 use std::fs;
 use std::path::Path;
 use thot_core::error::{Error as CoreError, ResourceError};
@@ -66,20 +67,41 @@ impl ContainerTreeTransformer {
 pub struct ContainerTreeLoader;
 impl ContainerTreeLoader {
     /// Load a `Container` tree into a [`ResourceTree`].
+    ///
+    /// # Notes
+    /// + Children are ordered by the `Container`'s saved child order.
+    ///     Children not in the saved order follow, ordered by folder name.
     pub fn load(path: impl AsRef<Path>) -> Result<ContainerTree> {
         let path = path.as_ref();
         let root = Container::load_from(path)?;
         let rid = root.id().clone();
+        let order = root.settings().children.clone();
         let mut graph = ResourceTree::new(root);
 
+        let mut children = Vec::new();
         for entry in fs::read_dir(path)? {
             let dir = entry?;
             if container::path_is_container(&dir.path()) {
                 let c_tree = Self::load(&dir.path())?;
-                graph.insert_tree(&rid, c_tree)?;
+                children.push((dir.file_name(), c_tree));
             }
         }
 
+        children.sort_by(|(a_name, a_tree), (b_name, b_tree)| {
+            let a_pos = order.iter().position(|rid| rid == a_tree.root());
+            let b_pos = order.iter().position(|rid| rid == b_tree.root());
+            match (a_pos, b_pos) {
+                (Some(a_pos), Some(b_pos)) => a_pos.cmp(&b_pos),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a_name.cmp(b_name),
+            }
+        });
+
+        for (_, c_tree) in children {
+            graph.insert_tree(&rid, c_tree)?;
+        }
+
         Ok(graph)
     }
 }
@@ -98,6 +120,7 @@ impl ContainerTreeDuplicator {
     ///
     /// # Notes
     /// + `Asset`s are duplicated.
+    /// + Links are not duplicated.
     #[tracing::instrument(skip(graph))]
     pub fn duplicate(graph: &ContainerTree, root: &ResourceId) -> thot_core::Result<ContainerTree> {
         // ensure root exists
@@ -110,10 +133,12 @@ impl ContainerTreeDuplicator {
         // duplicate container to new location
         let mut container = Container::new(node.base_path());
         container.properties = node.properties.clone();
+        container.properties.links.clear();
         container.scripts = node.scripts.clone();
         for asset_base in node.assets.values() {
             let mut asset = Asset::new(asset_base.path.clone());
             asset.properties = asset_base.properties.clone();
+            asset.properties.links.clear();
             container.insert_asset(asset);
         }
 
@@ -143,6 +168,8 @@ impl ContainerTreeDuplicator {
     ///
     /// # Notes
     /// + `Asset`s are not copied.
+    /// + Links are not copied.
+    /// + Child order is preserved.
     #[tracing::instrument(skip(graph))]
     pub fn duplicate_without_assets_to(
         path: &Path,
@@ -160,6 +187,7 @@ impl ContainerTreeDuplicator {
         // duplicate container to new location
         let mut container = Container::new(path);
         container.properties = node.properties.clone();
+        container.properties.links.clear();
         container.scripts = node.scripts.clone();
         container.save()?;
 
@@ -188,8 +216,88 @@ impl ContainerTreeDuplicator {
             dup_graph.insert_tree(&dup_root, c_tree)?;
         }
 
+        Self::save_child_order(&mut dup_graph)?;
+        Ok(dup_graph)
+    }
+
+    /// Creates a subtree of a Core Container tree at a file path.
+    /// `Container`s are given new ids, and child folders are named after the `Container`s.
+    ///
+    /// # Arguments
+    /// 1. Path to create the tree at.
+    /// 2. Graph.
+    /// 3. Id of the root of the subtree to create.
+    ///
+    /// # Notes
+    /// + `Asset`s are not copied.
+    /// + Links are not copied.
+    /// + Child order is preserved.
+    #[tracing::instrument(skip(graph))]
+    pub fn duplicate_core_without_assets_to(
+        path: &Path,
+        graph: &ResourceTree<CoreContainer>,
+        root: &ResourceId,
+    ) -> Result<ContainerTree> {
+        let Some(node) = graph.get(root) else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist in graph",
+            ))
+            .into());
+        };
+
+        let mut container = Container::new(path);
+        container.properties = node.properties.clone();
+        container.properties.links.clear();
+        container.scripts = node.scripts.clone();
+        container.save()?;
+
+        let dup_root = container.rid.clone();
+        let mut dup_graph = ResourceTree::new(container);
+        let Some(children) = graph.children(&root).cloned() else {
+            return Err(CoreError::ResourceError(ResourceError::does_not_exist(
+                "`Container` does not exist in graph",
+            ))
+            .into());
+        };
+
+        for child in children {
+            let name = &graph
+                .get(&child)
+                .expect("could not get child node")
+                .properties
+                .name;
+
+            let c_path = path.join(common::sanitize_file_path(name.clone()));
+            let c_path = common::unique_file_name(common::normalize_path_separators(c_path))?;
+            let c_tree = Self::duplicate_core_without_assets_to(&c_path, graph, &child)?;
+            dup_graph.insert_tree(&dup_root, c_tree)?;
+        }
+
+        Self::save_child_order(&mut dup_graph)?;
         Ok(dup_graph)
     }
+
+    /// Saves the order of the root's children in the graph to its settings.
+    /// Children are inserted in the order of the source tree,
+    /// so this preserves the source's order with the duplicated ids.
+    fn save_child_order(graph: &mut ContainerTree) -> Result {
+        let root = graph.root().clone();
+        let children = graph
+            .children(&root)
+            .expect("root not in graph")
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if children.is_empty() {
+            return Ok(());
+        }
+
+        let container = graph.get_mut(&root).expect("root not in graph");
+        container.settings_mut().children = children;
+        container.save()?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -47,6 +47,48 @@ fn container_tree_load_should_work() {
     assert!(graph.get(&cid_2).is_some(), "child `Container` not loaded");
 }
 
+#[test]
+fn container_tree_load_should_respect_child_order() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let builder = container::InitOptions::init();
+    builder
+        .build(dir.path())
+        .expect("could not init root `Container`");
+
+    let mut cids = Vec::new();
+    for name in ["a", "b", "c"] {
+        let path = dir.path().join(name);
+        fs::create_dir(&path).expect("could not create child dir");
+        let cid = builder
+            .build(&path)
+            .expect("could not init child `Container`");
+
+        cids.push(cid);
+    }
+
+    // test
+    let graph = ContainerTreeLoader::load(dir.path()).expect("could not load `Container` tree");
+    let children = graph.children(graph.root()).unwrap();
+    assert_eq!(
+        cids,
+        children.iter().cloned().collect::<Vec<_>>(),
+        "unordered children should be sorted by name"
+    );
+
+    let mut root = Container::load_from(dir.path()).expect("could not load root");
+    root.settings_mut().children = vec![cids[2].clone(), cids[0].clone()];
+    root.save().expect("could not save root");
+
+    let graph = ContainerTreeLoader::load(dir.path()).expect("could not load `Container` tree");
+    let children = graph.children(graph.root()).unwrap();
+    assert_eq!(
+        vec![cids[2].clone(), cids[0].clone(), cids[1].clone()],
+        children.iter().cloned().collect::<Vec<_>>(),
+        "children should follow saved order"
+    );
+}
+
 #[test]
 fn container_tree_duplicate_without_assets_to_should_work() {
     // setup
@@ -136,6 +178,59 @@ fn container_tree_duplicate_without_assets_to_should_work() {
     assert_eq!(c_names, cdup_names);
 }
 
+#[test]
+fn container_tree_duplicate_without_assets_to_should_preserve_child_order() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let dup_dir = TempDir::new().expect("could not create temp dir");
+    let builder = container::InitOptions::init();
+    builder
+        .build(dir.path())
+        .expect("could not init root `Container`");
+
+    let mut cids = Vec::new();
+    for name in ["a", "b", "c"] {
+        let path = dir.path().join(name);
+        fs::create_dir(&path).expect("could not create child dir");
+        let cid = builder
+            .build(&path)
+            .expect("could not init child `Container`");
+
+        cids.push(cid);
+    }
+
+    let mut root = Container::load_from(dir.path()).expect("could not load root");
+    root.settings_mut().children = vec![cids[2].clone(), cids[0].clone(), cids[1].clone()];
+    root.save().expect("could not save root");
+
+    let graph = ContainerTreeLoader::load(dir.path()).expect("could not load `Container` tree");
+
+    // test
+    let dup =
+        ContainerTreeDuplicator::duplicate_without_assets_to(dup_dir.path(), &graph, graph.root())
+            .expect("could not duplicate tree");
+
+    let dup_root = dup.get(dup.root()).unwrap();
+    assert_eq!(
+        dup.children(dup.root())
+            .unwrap()
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>(),
+        dup_root.settings().children,
+        "child order should be saved"
+    );
+
+    let reloaded =
+        ContainerTreeLoader::load(dup_dir.path()).expect("could not load duplicated tree");
+
+    assert_eq!(
+        vec!["c", "a", "b"],
+        child_names(reloaded.root(), &reloaded),
+        "child order should survive reload"
+    );
+}
+
 // ***************
 // *** helpers ***
 // ***************
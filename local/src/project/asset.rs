@@ -1,11 +1,13 @@
 //! High level functionality for `Assets` and `Buckets`.
 use super::container::path_is_container;
+use super::extractors::{is_sidecar, Extractors};
 use super::resources::asset::{Asset as LocalAsset, Assets};
 use crate::error::AssetError;
 use crate::types::AssetFileAction;
 use crate::{common, Error, Result};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::{fs, io};
+use thot_core::error::{Error as CoreError, ResourceError};
 use thot_core::project::Asset as CoreAsset;
 use thot_core::types::{ResourceId, ResourcePath};
 
@@ -25,6 +27,10 @@ pub struct AssetBuilder {
 
     /// Path to the moved file, or `None` if the file was not moved.
     moved_asset_path: Option<PathBuf>,
+
+    /// [`Extractor`](super::extractors::Extractor)s run on the file
+    /// to set the [`Asset`](CoreAsset)'s properties.
+    extractors: Extractors,
 }
 
 impl AssetBuilder {
@@ -35,6 +41,7 @@ impl AssetBuilder {
             bucket: None,
             action: None,
             moved_asset_path: None,
+            extractors: Extractors::default(),
         }
     }
 
@@ -87,6 +94,13 @@ impl AssetBuilder {
         self
     }
 
+    /// Sets the [`Extractor`](super::extractors::Extractor)s run on the file.
+    /// Defaults to the built-in extractors.
+    pub fn set_extractors(&mut self, extractors: Extractors) -> &mut Self {
+        self.extractors = extractors;
+        self
+    }
+
     /// Calulates the final path of the [`Asset`](CoreAsset) given the action
     /// and current file system state.
     ///
@@ -177,6 +191,16 @@ impl AssetBuilder {
         Ok(ResourcePath::new(path).expect("could not convert `Asset` path to `ResourcePath`"))
     }
 
+    /// Runs the extractors on the [`Asset`](CoreAsset)'s file,
+    /// setting its properties.
+    /// Extractors that fail are skipped.
+    fn extract_properties(&self, asset: &mut CoreAsset) {
+        let path = self.moved_asset_path.as_ref().unwrap_or(&self.path);
+        self.extractors
+            .extract_lossy(path)
+            .apply(&mut asset.properties);
+    }
+
     /// Initializes an [`Asset`](CoreAsset).
     /// This **does not** register it with its parent Container.
     /// This **does not** move the file.
@@ -232,7 +256,8 @@ impl AssetBuilder {
         // create asset
         self.move_file()?;
         let path = self.resource_path()?;
-        let asset = LocalAsset::new(path).expect("could not create `Asset`");
+        let mut asset = LocalAsset::new(path).expect("could not create `Asset`");
+        self.extract_properties(&mut asset);
 
         Ok(asset)
     }
@@ -261,7 +286,8 @@ impl AssetBuilder {
         // create asset
         self.move_file()?;
         let path = self.resource_path()?;
-        let asset = LocalAsset::new(path).expect("could not create `Asset`");
+        let mut asset = LocalAsset::new(path).expect("could not create `Asset`");
+        self.extract_properties(&mut asset);
 
         // insert asset
         let container = self.container_path()?;
@@ -328,26 +354,327 @@ pub fn init(path: &Path, container: Option<&Path>) -> Result<ResourceId> {
     Ok(rid)
 }
 
-pub fn mv() -> Result {
-    todo!();
+/// Moves an [`Asset`](CoreAsset) to a [`Container`](thot_core::project::Container) or bucket.
+/// The `Asset`'s file is moved with it, unless the `Asset` references its file.
+///
+/// # Arguments
+/// 1. Id of the `Asset`.
+/// 2. Path of the `Container` the `Asset` belongs to.
+/// 3. Path of the destination `Container`. May be the same as the source.
+/// 4. Destination bucket, relative to the destination `Container`,
+///     or `None` to place the file at its root.
+///
+/// # Returns
+/// The moved `Asset`.
+///
+/// # Errors
+/// + [`AssetError::PathNotAContainer`]: If the destination is not a `Container`.
+/// + [`AssetError::IncompatibleAction`]: If a bucket is given for an `Asset` referencing its file.
+/// + [`AssetError::FileAlreadyAsset`]: If the destination already has an `Asset` with the same file.
+pub fn mv(rid: &ResourceId, from: &Path, to: &Path, bucket: Option<&Path>) -> Result<CoreAsset> {
+    if !path_is_container(to) {
+        return Err(AssetError::PathNotAContainer(to.to_path_buf()).into());
+    }
+
+    if let Some(bucket) = bucket {
+        validate_bucket(bucket)?;
+    }
+
+    let mut from_assets = Assets::load_from(from)?;
+    let Some(asset) = from_assets.get(rid).cloned() else {
+        return Err(asset_does_not_exist());
+    };
+
+    let same_container = from == to;
+    let mut to_assets = if same_container {
+        None
+    } else {
+        Some(Assets::load_from(to)?)
+    };
+
+    // move file
+    let mut moved = asset.clone();
+    let mut file_move = None;
+    match &asset.path {
+        ResourcePath::Relative(rel_path) => {
+            let src = from.join(rel_path);
+            let Some(file_name) = rel_path.file_name() else {
+                return Err(AssetError::InvalidPath(
+                    rel_path.clone(),
+                    "could not get file name".to_string(),
+                )
+                .into());
+            };
+
+            let mut dst = to.to_path_buf();
+            if let Some(bucket) = bucket {
+                dst.push(bucket);
+            }
+
+            dst.push(file_name);
+            if dst != src {
+                let dst = common::unique_file_name(dst)?;
+                if let Some(parent) = dst.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+
+                fs::rename(&src, &dst)?;
+                moved.path = ResourcePath::Relative(
+                    dst.strip_prefix(to)
+                        .expect("destination should be in `Container`")
+                        .to_path_buf(),
+                );
+
+                file_move = Some((src, dst));
+            }
+        }
+
+        _ => {
+            if bucket.is_some() {
+                return Err(AssetError::IncompatibleAction(
+                    "`Assets` can not have a `Reference` path and a `bucket`".to_string(),
+                )
+                .into());
+            }
+
+            let to_assets = to_assets.as_ref().unwrap_or(&from_assets);
+            if to_assets
+                .values()
+                .any(|other| other.rid != asset.rid && other.path == asset.path)
+            {
+                return Err(
+                    AssetError::FileAlreadyAsset(asset.path.as_path().to_path_buf()).into(),
+                );
+            }
+        }
+    }
+
+    // register asset
+    let restore_file = |file_move: &Option<(PathBuf, PathBuf)>| {
+        if let Some((src, dst)) = file_move {
+            let _ = fs::rename(dst, src);
+        }
+    };
+
+    from_assets.remove(rid);
+    match to_assets.as_mut() {
+        None => {
+            from_assets.insert(moved.clone());
+            if let Err(err) = from_assets.save() {
+                restore_file(&file_move);
+                return Err(err);
+            }
+        }
+
+        Some(to_assets) => {
+            to_assets.insert(moved.clone());
+            if let Err(err) = to_assets.save() {
+                restore_file(&file_move);
+                return Err(err);
+            }
+
+            if let Err(err) = from_assets.save() {
+                to_assets.remove(rid);
+                let _ = to_assets.save();
+                restore_file(&file_move);
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(moved)
 }
 
-pub fn delete() -> Result {
-    todo!();
+/// Deletes an [`Asset`](CoreAsset), removing it from its [`Container`](thot_core::project::Container).
+/// The `Asset`'s file is moved to the trash, unless the `Asset` references its file.
+///
+/// # Arguments
+/// 1. Id of the `Asset`.
+/// 2. Path of the `Container` the `Asset` belongs to.
+///
+/// # Returns
+/// The deleted `Asset`.
+pub fn delete(rid: &ResourceId, container: &Path) -> Result<CoreAsset> {
+    let mut assets = Assets::load_from(container)?;
+    let Some(asset) = assets.remove(rid) else {
+        return Err(asset_does_not_exist());
+    };
+
+    assets.save()?;
+    if let ResourcePath::Relative(rel_path) = &asset.path {
+        let path = container.join(rel_path);
+        if path.exists() {
+            if let Err(err) = trash::delete(&path) {
+                assets.insert(asset);
+                let _ = assets.save();
+                return Err(AssetError::TrashFailed(path, format!("{err:?}")).into());
+            }
+        }
+    }
+
+    Ok(asset)
 }
 
-pub fn update() -> Result {
-    todo!();
+/// Updates an [`Asset`](CoreAsset).
+/// If the `Asset`'s path changed its file is moved to match,
+/// unless the `Asset` references its file.
+///
+/// # Arguments
+/// 1. The updated `Asset`.
+/// 2. Path of the `Container` the `Asset` belongs to.
+///
+/// # Returns
+/// The `Asset` before the update.
+///
+/// # Errors
+/// + [`AssetError::IncompatibleAction`]: If the update changes whether the `Asset`
+///     references its file. Use [`mv`] or a new `Asset` instead.
+/// + [`AssetError::FileAlreadyAsset`]: If another `Asset` has the same file.
+pub fn update(asset: CoreAsset, container: &Path) -> Result<CoreAsset> {
+    let mut assets = Assets::load_from(container)?;
+    let Some(prev) = assets.get(&asset.rid).cloned() else {
+        return Err(asset_does_not_exist());
+    };
+
+    if assets
+        .values()
+        .any(|other| other.rid != asset.rid && other.path == asset.path)
+    {
+        return Err(AssetError::FileAlreadyAsset(asset.path.as_path().to_path_buf()).into());
+    }
+
+    // move file
+    let mut file_move = None;
+    match (&prev.path, &asset.path) {
+        (ResourcePath::Relative(prev_path), ResourcePath::Relative(path)) => {
+            if prev_path != path {
+                validate_bucket(path)?;
+                let src = container.join(prev_path);
+                let dst = container.join(path);
+                if dst.exists() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        "`Asset` file already exists",
+                    )
+                    .into());
+                }
+
+                if let Some(parent) = dst.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+
+                fs::rename(&src, &dst)?;
+                file_move = Some((src, dst));
+            }
+        }
+
+        (ResourcePath::Relative(_), _) | (_, ResourcePath::Relative(_)) => {
+            return Err(AssetError::IncompatibleAction(
+                "can not change whether an `Asset` references its file".to_string(),
+            )
+            .into());
+        }
+
+        _ => {}
+    }
+
+    assets.insert(asset);
+    if let Err(err) = assets.save() {
+        if let Some((src, dst)) = file_move {
+            let _ = fs::rename(dst, src);
+        }
+
+        return Err(err);
+    }
+
+    Ok(prev)
 }
 
 /// Make a new bucket directory.
-pub fn add_bucket(name: &Path) -> Result {
-    todo!();
+///
+/// # Arguments
+/// 1. Path of the [`Container`](thot_core::project::Container).
+/// 2. Path of the bucket, relative to the `Container`.
+///
+/// # Returns
+/// Path to the bucket.
+///
+/// # Errors
+/// + [`AssetError::PathNotAContainer`]: If `container` is not a `Container`.
+/// + [`AssetError::InvalidPath`]: If `bucket` is not a relative path inside the `Container`.
+pub fn add_bucket(container: &Path, bucket: &Path) -> Result<PathBuf> {
+    if !path_is_container(container) {
+        return Err(AssetError::PathNotAContainer(container.to_path_buf()).into());
+    }
+
+    validate_bucket(bucket)?;
+    let path = container.join(bucket);
+    fs::create_dir_all(&path)?;
+    Ok(path)
 }
 
 /// Make an existing directory a bucket.
-pub fn make_bucket(path: &Path) -> Result {
-    todo!();
+/// Files in the directory, and its subdirectories, are registered as
+/// [`Asset`](CoreAsset)s of the nearest [`Container`](thot_core::project::Container).
+/// Files already registered are skipped.
+/// Properties are set by the default [`Extractors`], as when adding an `Asset`,
+/// so sidecar files are not registered.
+///
+/// # Returns
+/// Ids of the new `Asset`s.
+///
+/// # Errors
+/// + [`AssetError::InvalidPath`]: If the path is not a directory or is a `Container`.
+/// + [`AssetError::ContainerNotFound`]: If the path is not inside a `Container`.
+pub fn make_bucket(path: &Path) -> Result<Vec<ResourceId>> {
+    if !path.is_dir() {
+        return Err(
+            AssetError::InvalidPath(path.to_path_buf(), "not a directory".to_string()).into(),
+        );
+    }
+
+    if path_is_container(path) {
+        return Err(
+            AssetError::InvalidPath(path.to_path_buf(), "is a `Container`".to_string()).into(),
+        );
+    }
+
+    let Some(parent) = path.parent() else {
+        return Err(AssetError::ContainerNotFound(path.to_path_buf()).into());
+    };
+
+    let container = container_from_path_ancestor(parent)?;
+    let mut files = Vec::new();
+    bucket_files(path, &mut files)?;
+
+    let extractors = Extractors::default();
+    let mut assets = Assets::load_from(&container)?;
+    let mut rids = Vec::new();
+    for file in files {
+        // sidecar files are read by their data file's extractors
+        if is_sidecar(&file) {
+            continue;
+        }
+
+        let rel_path = file
+            .strip_prefix(&container)
+            .expect("file should be in `Container`")
+            .to_path_buf();
+
+        let rel_path = ResourcePath::Relative(rel_path);
+        if assets.values().any(|asset| asset.path == rel_path) {
+            continue;
+        }
+
+        let mut asset = LocalAsset::new(rel_path)?;
+        extractors.extract_lossy(&file).apply(&mut asset.properties);
+
+        rids.push(asset.rid.clone());
+        assets.insert(asset);
+    }
+
+    assets.save()?;
+    Ok(rids)
 }
 
 /// Returns whether the path is an Asset registered with the given Container.
@@ -368,6 +695,43 @@ pub fn container_from_path_ancestor(path: &Path) -> Result<PathBuf> {
     }
 }
 
+/// Validates a bucket is a relative path that stays inside its `Container`.
+fn validate_bucket(bucket: &Path) -> Result {
+    if bucket
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Ok(());
+    }
+
+    Err(AssetError::InvalidPath(
+        bucket.to_path_buf(),
+        "must be relative and inside the `Container`".to_string(),
+    )
+    .into())
+}
+
+/// Collects the files of a bucket, excluding those in `Container`s and <THOT_DIR>s.
+fn bucket_files(path: &Path, files: &mut Vec<PathBuf>) -> Result {
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        if path.is_file() {
+            files.push(path);
+        } else if path.is_dir()
+            && !path_is_container(&path)
+            && path.file_name() != Some(common::thot_dir().as_os_str())
+        {
+            bucket_files(&path, files)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn asset_does_not_exist() -> Error {
+    CoreError::ResourceError(ResourceError::does_not_exist("`Asset` does not exist")).into()
+}
+
 /// Calculates the path of an [`Asset`](thot_core::project::Asset)'s file
 /// if it were to be moved into the given [`Container`](thot_core::project::Container) and `bucket`
 /// with the given [action](AssetFileAction).
@@ -5,7 +5,9 @@ use dev_utils::fs::TempDir;
 use fake::faker::filesystem::raw::{FileName, FilePath};
 use fake::locales::EN;
 use fake::Fake;
+use std::fs;
 use std::path::PathBuf;
+use thot_core::project::Asset as CoreAsset;
 
 // ********************
 // *** AssetBuilder ***
@@ -268,3 +270,246 @@ fn container_from_path_ancestor_should_error_if_no_container_found() {
     // test
     let _path = container_from_path_ancestor(&a_path).unwrap();
 }
+
+#[test]
+fn mv_should_move_asset_between_containers_and_buckets() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let from = _dir.mkdir().expect("creating child directory should work");
+    let to = _dir.mkdir().expect("creating child directory should work");
+    let (_, rid) = init_asset_container(&from, "data.csv");
+    let builder = container::InitOptions::init();
+    builder.build(&to).expect("init container should work");
+
+    // test
+    let bucket = PathBuf::from("raw");
+    let asset = mv(&rid, &from, &to, Some(&bucket)).expect("move should work");
+    assert_eq!(
+        ResourcePath::Relative(PathBuf::from("raw").join("data.csv")),
+        asset.path
+    );
+    assert!(!from.join("data.csv").exists(), "file should be moved");
+    assert!(
+        to.join("raw").join("data.csv").exists(),
+        "file should be moved"
+    );
+    assert!(!Assets::load_from(&from).unwrap().contains_key(&rid));
+    assert_eq!(
+        asset,
+        Assets::load_from(&to).unwrap().get(&rid).unwrap().clone()
+    );
+
+    // within container
+    let asset = mv(&rid, &to, &to, None).expect("move should work");
+    assert_eq!(
+        ResourcePath::Relative(PathBuf::from("data.csv")),
+        asset.path
+    );
+    assert!(to.join("data.csv").exists(), "file should be moved");
+    assert_eq!(1, Assets::load_from(&to).unwrap().len());
+}
+
+#[test]
+fn mv_reference_should_not_move_file() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let from = _dir.mkdir().expect("creating child directory should work");
+    let to = _dir.mkdir().expect("creating child directory should work");
+    let builder = container::InitOptions::init();
+    builder.build(&from).expect("init container should work");
+    builder.build(&to).expect("init container should work");
+
+    let file = _dir.path().join("reference.csv");
+    fs::write(&file, "").unwrap();
+    let rid = insert_asset(&from, ResourcePath::Absolute(file.clone()));
+
+    // test
+    let bucket = PathBuf::from("raw");
+    let err = mv(&rid, &from, &to, Some(&bucket));
+    assert!(
+        matches!(
+            err,
+            Err(Error::AssetError(AssetError::IncompatibleAction(_)))
+        ),
+        "reference with bucket should error"
+    );
+
+    let asset = mv(&rid, &from, &to, None).expect("move should work");
+    assert_eq!(ResourcePath::Absolute(file.clone()), asset.path);
+    assert!(file.exists(), "referenced file should not be moved");
+    assert!(!Assets::load_from(&from).unwrap().contains_key(&rid));
+    assert!(Assets::load_from(&to).unwrap().contains_key(&rid));
+}
+
+#[test]
+fn delete_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let c_dir = _dir.mkdir().expect("creating child directory should work");
+    let (file, rid) = init_asset_container(&c_dir, "data.csv");
+
+    let reference = _dir.path().join("reference.csv");
+    fs::write(&reference, "").unwrap();
+    let ref_rid = insert_asset(&c_dir, ResourcePath::Absolute(reference.clone()));
+
+    // test
+    let asset = delete(&rid, &c_dir).expect("delete should work");
+    assert_eq!(rid, asset.rid);
+    assert!(!file.exists(), "file should be deleted");
+
+    delete(&ref_rid, &c_dir).expect("delete should work");
+    assert!(reference.exists(), "referenced file should not be deleted");
+    assert!(Assets::load_from(&c_dir).unwrap().is_empty());
+    let tmp_files = fs::read_dir(common::thot_dir_of(&c_dir))
+        .unwrap()
+        .filter(|entry| {
+            let name = entry.as_ref().unwrap().file_name();
+            name.to_string_lossy().ends_with(".tmp")
+        })
+        .count();
+    assert_eq!(0, tmp_files, "temporary files should be removed on save");
+
+    assert!(
+        delete(&rid, &c_dir).is_err(),
+        "missing `Asset` should error"
+    );
+}
+
+#[test]
+fn update_should_move_file_when_path_changes() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let c_dir = _dir.mkdir().expect("creating child directory should work");
+    let (file, rid) = init_asset_container(&c_dir, "data.csv");
+
+    let mut asset = Assets::load_from(&c_dir)
+        .unwrap()
+        .get(&rid)
+        .unwrap()
+        .clone();
+    asset.properties.name = Some("data".to_string());
+    asset.path = ResourcePath::Relative(PathBuf::from("raw").join("renamed.csv"));
+
+    // test
+    let prev = update(asset.clone(), &c_dir).expect("update should work");
+    assert_eq!(ResourcePath::Relative(PathBuf::from("data.csv")), prev.path);
+    assert!(!file.exists(), "file should be moved");
+    assert!(c_dir.join("raw").join("renamed.csv").exists());
+    assert_eq!(
+        asset,
+        Assets::load_from(&c_dir)
+            .unwrap()
+            .get(&rid)
+            .unwrap()
+            .clone()
+    );
+
+    let mut reference = asset.clone();
+    reference.path = ResourcePath::Absolute(_dir.path().join("reference.csv"));
+    assert!(
+        matches!(
+            update(reference, &c_dir),
+            Err(Error::AssetError(AssetError::IncompatibleAction(_)))
+        ),
+        "changing to a reference should error"
+    );
+}
+
+#[test]
+fn add_bucket_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let c_dir = _dir.mkdir().expect("creating child directory should work");
+    let builder = container::InitOptions::init();
+    builder.build(&c_dir).expect("init container should work");
+
+    // test
+    let bucket = PathBuf::from("raw").join("day_1");
+    let path = add_bucket(&c_dir, &bucket).expect("add bucket should work");
+    assert_eq!(c_dir.join(&bucket), path);
+    assert!(path.is_dir(), "bucket should be created");
+    assert!(
+        add_bucket(&c_dir, &PathBuf::from("..").join("outside")).is_err(),
+        "bucket outside `Container` should error"
+    );
+}
+
+#[test]
+fn make_bucket_should_register_files() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let c_dir = _dir.mkdir().expect("creating child directory should work");
+    let (_, rid) = init_asset_container(&c_dir, "data.csv");
+
+    let bucket = c_dir.join("raw");
+    fs::create_dir_all(bucket.join("day_1")).unwrap();
+    fs::write(bucket.join("a.csv"), "").unwrap();
+    fs::write(bucket.join("day_1").join("b.csv"), "").unwrap();
+
+    // test
+    let mut rids = make_bucket(&bucket).expect("make bucket should work");
+    assert_eq!(2, rids.len());
+    assert!(
+        make_bucket(&bucket).unwrap().is_empty(),
+        "registered files should be skipped"
+    );
+
+    let assets = Assets::load_from(&c_dir).unwrap();
+    assert_eq!(3, assets.len());
+    rids.push(rid);
+    assert!(rids.iter().all(|rid| assets.contains_key(rid)));
+    assert!(make_bucket(&c_dir).is_err(), "`Container` should error");
+}
+
+#[test]
+fn make_bucket_should_extract_properties() {
+    // setup
+    let mut _dir = TempDir::new().expect("setup should work");
+    let c_dir = _dir.mkdir().expect("creating child directory should work");
+    let builder = container::InitOptions::init();
+    builder.build(&c_dir).expect("init container should work");
+
+    let bucket = c_dir.join("raw");
+    fs::create_dir(&bucket).unwrap();
+    fs::write(bucket.join("data.csv"), "time,value\n0,1\n").unwrap();
+    fs::write(bucket.join("data.csv.json"), r#"{"kind": "trial"}"#).unwrap();
+
+    // test
+    let rids = make_bucket(&bucket).expect("make bucket should work");
+    assert_eq!(1, rids.len(), "sidecar files should not be registered");
+
+    let assets = Assets::load_from(&c_dir).unwrap();
+    let asset = assets.get(&rids[0]).unwrap();
+    assert_eq!(Some("trial".to_string()), asset.properties.kind);
+    assert!(
+        asset.properties.metadata.contains_key("columns"),
+        "file extractors should run"
+    );
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+/// Initializes a `Container` with a single `Asset`.
+///
+/// # Returns
+/// Path to the `Asset`'s file and its id.
+fn init_asset_container(path: &Path, file_name: &str) -> (PathBuf, ResourceId) {
+    let builder = container::InitOptions::init();
+    builder.build(path).expect("init container should work");
+
+    let file = path.join(file_name);
+    fs::write(&file, "").unwrap();
+    let rid = insert_asset(path, ResourcePath::Relative(PathBuf::from(file_name)));
+    (file, rid)
+}
+
+fn insert_asset(container: &Path, path: ResourcePath) -> ResourceId {
+    let asset = CoreAsset::new(path);
+    let rid = asset.rid.clone();
+    let mut assets = Assets::load_from(container).unwrap();
+    assets.insert(asset);
+    assets.save().unwrap();
+    rid
+}
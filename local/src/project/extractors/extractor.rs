@@ -0,0 +1,267 @@
+//! Extraction of `Asset` properties from their files.
+use super::image::ImageExtractor;
+use super::sidecar::SidecarExtractor;
+use super::tabular::CsvExtractor;
+use crate::Result;
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+use thot_core::project::{AssetProperties, Metadata};
+
+// *****************
+// *** Extracted ***
+// *****************
+
+/// Properties extracted from a file.
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct Extracted {
+    pub metadata: Metadata,
+    pub kind: Option<String>,
+    pub tags: Vec<String>,
+}
+
+impl Extracted {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether nothing was extracted.
+    pub fn is_empty(&self) -> bool {
+        self.metadata.is_empty() && self.kind.is_none() && self.tags.is_empty()
+    }
+
+    /// Merges other extracted properties into self.
+    /// Values of `other` take precedence.
+    pub fn merge(&mut self, other: Extracted) {
+        self.metadata.extend(other.metadata);
+        if other.kind.is_some() {
+            self.kind = other.kind;
+        }
+
+        for tag in other.tags {
+            if !self.tags.contains(&tag) {
+                self.tags.push(tag);
+            }
+        }
+    }
+
+    /// Applies the extracted properties to an `Asset`'s properties.
+    ///
+    /// # Notes
+    /// + Extracted metadata replaces existing values.
+    /// + `kind` is only set if the `Asset` does not have one.
+    /// + Tags are added to existing tags.
+    pub fn apply(self, properties: &mut AssetProperties) {
+        properties.metadata.extend(self.metadata);
+        if properties.kind.is_none() {
+            properties.kind = self.kind;
+        }
+
+        for tag in self.tags {
+            if !properties.tags.contains(&tag) {
+                properties.tags.push(tag);
+            }
+        }
+    }
+}
+
+// *****************
+// *** Extractor ***
+// *****************
+
+/// Extracts properties from files.
+pub trait Extractor: Send + Sync {
+    /// File extensions handled by the extractor.
+    /// Lowercase, without a leading `.`.
+    /// `*` matches all files.
+    fn extensions(&self) -> &[&str];
+
+    /// MIME types handled by the extractor.
+    /// The subtype may be `*`, e.g. `image/*`.
+    fn mime_types(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Extracts properties from the file.
+    fn extract(&self, path: &Path) -> Result<Extracted>;
+}
+
+// ******************
+// *** Extractors ***
+// ******************
+
+/// Registry of [`Extractor`]s.
+///
+/// # Notes
+/// + The default registry contains the built-in extractors.
+/// Use [`Extractors::new`] for an empty registry.
+pub struct Extractors {
+    extractors: Vec<Box<dyn Extractor>>,
+}
+
+impl Extractors {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            extractors: Vec::new(),
+        }
+    }
+
+    /// Registers an extractor.
+    /// Extractors run in the order they are registered.
+    pub fn register(&mut self, extractor: Box<dyn Extractor>) -> &mut Self {
+        self.extractors.push(extractor);
+        self
+    }
+
+    /// Extractors handling the file.
+    pub fn for_path<'a>(&'a self, path: &Path) -> impl Iterator<Item = &'a dyn Extractor> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+
+        let mime_type = mime_type(path);
+        self.extractors
+            .iter()
+            .map(|extractor| extractor.as_ref())
+            .filter(move |extractor| {
+                let by_extension = extractor.extensions().iter().any(|handled| {
+                    *handled == "*" || extension.as_deref().is_some_and(|ext| ext == *handled)
+                });
+
+                let by_mime_type = mime_type.is_some_and(|mime_type| {
+                    extractor
+                        .mime_types()
+                        .iter()
+                        .any(|handled| mime_type_matches(handled, mime_type))
+                });
+
+                by_extension || by_mime_type
+            })
+    }
+
+    /// Runs all extractors handling the file.
+    ///
+    /// # Returns
+    /// The merged properties of all extractors.
+    /// Later extractors take precedence.
+    ///
+    /// # Errors
+    /// + If any extractor fails.
+    pub fn extract(&self, path: &Path) -> Result<Extracted> {
+        let mut extracted = Extracted::new();
+        for extractor in self.for_path(path) {
+            extracted.merge(extractor.extract(path)?);
+        }
+
+        Ok(extracted)
+    }
+
+    /// Runs all extractors handling the file, skipping those that fail.
+    ///
+    /// # Returns
+    /// The merged properties of all successful extractors.
+    pub fn extract_lossy(&self, path: &Path) -> Extracted {
+        let mut extracted = Extracted::new();
+        for extractor in self.for_path(path) {
+            match extractor.extract(path) {
+                Ok(properties) => extracted.merge(properties),
+                Err(err) => tracing::warn!(?path, ?err, "could not extract properties"),
+            }
+        }
+
+        extracted
+    }
+}
+
+impl Default for Extractors {
+    /// Registry with the built-in extractors.
+    /// Sidecar files are applied last so they take precedence.
+    fn default() -> Self {
+        let mut extractors = Self::new();
+        extractors
+            .register(Box::new(CsvExtractor))
+            .register(Box::new(ImageExtractor))
+            .register(Box::new(SidecarExtractor));
+
+        extractors
+    }
+}
+
+// ******************
+// *** MIME types ***
+// ******************
+
+/// Guesses the MIME type of a file.
+/// Uses the file's extension, falling back to its signature.
+pub fn mime_type(path: &Path) -> Option<&'static str> {
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase());
+
+    let by_extension = extension.and_then(|extension| {
+        MIME_TYPES
+            .iter()
+            .find(|(extensions, _)| extensions.contains(&extension.as_str()))
+            .map(|(_, mime_type)| *mime_type)
+    });
+
+    by_extension.or_else(|| sniff_mime_type(path))
+}
+
+/// Guesses the MIME type of a file from its signature.
+fn sniff_mime_type(path: &Path) -> Option<&'static str> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut header = [0; 12];
+    let read = file.read(&mut header).ok()?;
+    let header = &header[..read];
+
+    SIGNATURES
+        .iter()
+        .find(|(signature, _)| header.starts_with(signature))
+        .map(|(_, mime_type)| *mime_type)
+        .or_else(|| {
+            let is_webp = header.len() == 12 && &header[..4] == b"RIFF" && &header[8..] == b"WEBP";
+            is_webp.then_some("image/webp")
+        })
+}
+
+/// Returns whether a MIME type matches a pattern.
+/// The pattern's subtype may be `*`.
+fn mime_type_matches(pattern: &str, mime_type: &str) -> bool {
+    match pattern.strip_suffix("/*") {
+        Some(kind) => mime_type
+            .split_once('/')
+            .is_some_and(|(mime_kind, _)| mime_kind == kind),
+
+        None => pattern == mime_type,
+    }
+}
+
+const MIME_TYPES: &[(&[&str], &str)] = &[
+    (&["csv"], "text/csv"),
+    (&["tsv"], "text/tab-separated-values"),
+    (&["txt"], "text/plain"),
+    (&["json"], "application/json"),
+    (&["yaml", "yml"], "application/yaml"),
+    (&["png"], "image/png"),
+    (&["jpg", "jpeg"], "image/jpeg"),
+    (&["gif"], "image/gif"),
+    (&["bmp"], "image/bmp"),
+    (&["tif", "tiff"], "image/tiff"),
+    (&["webp"], "image/webp"),
+];
+
+const SIGNATURES: &[(&[u8], &str)] = &[
+    (b"\x89PNG\r\n\x1a\n", "image/png"),
+    (b"\xff\xd8\xff", "image/jpeg"),
+    (b"GIF87a", "image/gif"),
+    (b"GIF89a", "image/gif"),
+    (b"BM", "image/bmp"),
+    (b"II*\x00", "image/tiff"),
+    (b"MM\x00*", "image/tiff"),
+];
+
+#[cfg(test)]
+#[path = "./extractor_test.rs"]
+mod extractor_test;
@@ -0,0 +1,87 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use serde_json::json;
+
+struct KindExtractor(&'static str);
+
+impl Extractor for KindExtractor {
+    fn extensions(&self) -> &[&str] {
+        &["dat"]
+    }
+
+    fn extract(&self, _path: &Path) -> Result<Extracted> {
+        let mut extracted = Extracted::new();
+        extracted.kind = Some(self.0.to_string());
+        extracted.tags.push(self.0.to_string());
+        Ok(extracted)
+    }
+}
+
+#[test]
+fn extractors_extract_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let data = _dir
+        .mkfile_with_extension("DAT")
+        .expect("could not create file");
+
+    let other = _dir
+        .mkfile_with_extension("txt")
+        .expect("could not create file");
+
+    let mut extractors = Extractors::new();
+    extractors
+        .register(Box::new(KindExtractor("first")))
+        .register(Box::new(KindExtractor("second")));
+
+    // test
+    let extracted = extractors.extract(&data).expect("extraction should work");
+    assert_eq!(Some("second".to_string()), extracted.kind);
+    assert_eq!(
+        vec!["first".to_string(), "second".to_string()],
+        extracted.tags
+    );
+
+    let extracted = extractors.extract(&other).expect("extraction should work");
+    assert!(extracted.is_empty(), "no extractor should run");
+}
+
+#[test]
+fn extracted_apply_should_work() {
+    let mut properties = AssetProperties::new();
+    properties.kind = Some("raw".to_string());
+    properties.tags.push("a".to_string());
+    properties.metadata.insert("mass".to_string(), json!(1));
+
+    let mut extracted = Extracted::new();
+    extracted.kind = Some("image".to_string());
+    extracted.tags = vec!["a".to_string(), "b".to_string()];
+    extracted.metadata.insert("mass".to_string(), json!(2));
+    extracted.apply(&mut properties);
+
+    assert_eq!(
+        Some("raw".to_string()),
+        properties.kind,
+        "kind should be kept"
+    );
+    assert_eq!(vec!["a".to_string(), "b".to_string()], properties.tags);
+    assert_eq!(Some(&json!(2)), properties.metadata.get("mass"));
+}
+
+#[test]
+fn mime_type_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let unknown = _dir.mkfile().expect("could not create file");
+    fs::write(&unknown, b"GIF89a\x01\x00\x01\x00").expect("could not write file");
+
+    // test
+    assert_eq!(Some("image/jpeg"), mime_type(Path::new("photo.JPG")));
+    assert_eq!(
+        Some("image/gif"),
+        mime_type(&unknown),
+        "should sniff signature"
+    );
+    assert!(mime_type_matches("image/*", "image/png"));
+    assert!(!mime_type_matches("image/*", "text/csv"));
+}
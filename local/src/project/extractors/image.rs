@@ -0,0 +1,155 @@
+//! Extraction of properties from image files.
+use super::extractor::{Extracted, Extractor};
+use crate::error::{ExtractError, Result};
+use serde_json::{Map as JsMap, Value as JsValue};
+use std::fs;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+/// Extracts the dimensions and EXIF data of images.
+///
+/// # Notes
+/// + Dimensions are stored under the `width` and `height` metadata keys.
+/// Dimensions are read from PNG, JPEG, GIF, and BMP files.
+/// + EXIF fields of the primary image are stored as display strings
+/// under the `exif` metadata key.
+/// + Sets `kind` to `image`.
+pub struct ImageExtractor;
+
+impl Extractor for ImageExtractor {
+    fn extensions(&self) -> &[&str] {
+        &["png", "jpg", "jpeg", "gif", "bmp", "tif", "tiff", "webp"]
+    }
+
+    fn mime_types(&self) -> &[&str] {
+        &["image/*"]
+    }
+
+    fn extract(&self, path: &Path) -> Result<Extracted> {
+        let mut extracted = Extracted::new();
+        extracted.kind = Some("image".to_string());
+
+        let mut header = Vec::new();
+        fs::File::open(path)?
+            .take(HEADER_LEN)
+            .read_to_end(&mut header)?;
+
+        if let Some((width, height)) = dimensions(&header) {
+            extracted
+                .metadata
+                .insert("width".to_string(), JsValue::from(width));
+
+            extracted
+                .metadata
+                .insert("height".to_string(), JsValue::from(height));
+        }
+
+        // GIF and BMP files do not contain EXIF data
+        let has_exif = !(header.starts_with(b"GIF") || header.starts_with(b"BM"));
+        let exif = if has_exif {
+            exif_fields(path)?
+        } else {
+            JsMap::new()
+        };
+
+        if !exif.is_empty() {
+            extracted
+                .metadata
+                .insert("exif".to_string(), JsValue::Object(exif));
+        }
+
+        Ok(extracted)
+    }
+}
+
+/// Number of bytes read to find an image's dimensions.
+/// JPEG files may have large segments before the frame header.
+const HEADER_LEN: u64 = 1 << 16;
+
+/// Reads the dimensions of an image from its header.
+fn dimensions(header: &[u8]) -> Option<(u32, u32)> {
+    let be_u16 = |at: usize| Some(u16::from_be_bytes(header.get(at..at + 2)?.try_into().ok()?));
+    let le_u16 = |at: usize| Some(u16::from_le_bytes(header.get(at..at + 2)?.try_into().ok()?));
+    let be_u32 = |at: usize| Some(u32::from_be_bytes(header.get(at..at + 4)?.try_into().ok()?));
+    let le_i32 = |at: usize| Some(i32::from_le_bytes(header.get(at..at + 4)?.try_into().ok()?));
+
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") {
+        // IHDR is the first chunk
+        return Some((be_u32(16)?, be_u32(20)?));
+    }
+
+    if header.starts_with(b"GIF87a") || header.starts_with(b"GIF89a") {
+        return Some((le_u16(6)? as u32, le_u16(8)? as u32));
+    }
+
+    if header.starts_with(b"BM") {
+        // height is negative for top-down bitmaps
+        return Some((le_i32(18)?.unsigned_abs(), le_i32(22)?.unsigned_abs()));
+    }
+
+    if header.starts_with(b"\xff\xd8") {
+        let mut offset = 2;
+        loop {
+            if *header.get(offset)? != 0xff {
+                return None;
+            }
+
+            let marker = *header.get(offset + 1)?;
+            match marker {
+                // padding
+                0xff => offset += 1,
+
+                // markers without a segment
+                0x01 | 0xd0..=0xd7 => offset += 2,
+
+                // start of frame, excluding DHT, JPG, and DAC
+                0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+                    let height = be_u16(offset + 5)?;
+                    let width = be_u16(offset + 7)?;
+                    return Some((width as u32, height as u32));
+                }
+
+                _ => offset += 2 + be_u16(offset + 2)? as usize,
+            }
+        }
+    }
+
+    None
+}
+
+/// Reads the EXIF fields of the primary image.
+///
+/// # Returns
+/// Map of tag names to display values.
+/// Empty if the image has no EXIF data.
+fn exif_fields(path: &Path) -> Result<JsMap<String, JsValue>> {
+    let mut reader = BufReader::new(fs::File::open(path)?);
+    let exif = match exif::Reader::new().read_from_container(&mut reader) {
+        Ok(exif) => exif,
+        Err(exif::Error::NotFound(_) | exif::Error::NotSupported(_)) => return Ok(JsMap::new()),
+        Err(exif::Error::Io(err)) => return Err(err.into()),
+        Err(err) => {
+            return Err(ExtractError::InvalidFile {
+                path: path.to_path_buf(),
+                message: err.to_string(),
+            }
+            .into())
+        }
+    };
+
+    let fields = exif
+        .fields()
+        .filter(|field| field.ifd_num == exif::In::PRIMARY)
+        .filter(|field| !matches!(field.value, exif::Value::Undefined(..)))
+        .map(|field| {
+            let value = field.display_value().with_unit(&exif).to_string();
+            (field.tag.to_string(), JsValue::String(value))
+        })
+        .collect();
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+#[path = "./image_test.rs"]
+mod image_test;
@@ -0,0 +1,74 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use serde_json::json;
+
+/// Minimal PNG with an `IHDR` and `IEND` chunk.
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    png.extend(13_u32.to_be_bytes());
+    png.extend(b"IHDR");
+    png.extend(width.to_be_bytes());
+    png.extend(height.to_be_bytes());
+    png.extend([8, 6, 0, 0, 0]);
+    png.extend([0; 4]);
+    png.extend(0_u32.to_be_bytes());
+    png.extend(b"IEND");
+    png.extend([0; 4]);
+    png
+}
+
+#[test]
+fn image_extractor_extract_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let png_path = _dir
+        .mkfile_with_extension("png")
+        .expect("could not create file");
+
+    let gif_path = _dir
+        .mkfile_with_extension("gif")
+        .expect("could not create file");
+
+    fs::write(&png_path, png(640, 480)).expect("could not write file");
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(32_u16.to_le_bytes());
+    gif.extend(16_u16.to_le_bytes());
+    gif.extend([0, 0, 0]);
+    fs::write(&gif_path, gif).expect("could not write file");
+
+    // test
+    let extracted = ImageExtractor
+        .extract(&png_path)
+        .expect("extraction should work");
+
+    assert_eq!(Some("image".to_string()), extracted.kind);
+    assert_eq!(Some(&json!(640)), extracted.metadata.get("width"));
+    assert_eq!(Some(&json!(480)), extracted.metadata.get("height"));
+    assert!(
+        !extracted.metadata.contains_key("exif"),
+        "image without EXIF data should not have `exif`"
+    );
+
+    let extracted = ImageExtractor
+        .extract(&gif_path)
+        .expect("extraction should work");
+
+    assert_eq!(Some(&json!(32)), extracted.metadata.get("width"));
+    assert_eq!(Some(&json!(16)), extracted.metadata.get("height"));
+}
+
+#[test]
+fn dimensions_should_read_jpeg_frame_header() {
+    let mut jpeg = b"\xff\xd8".to_vec();
+    // APP0 segment
+    jpeg.extend([0xff, 0xe0, 0x00, 0x04, 0x00, 0x00]);
+    // SOF0 segment
+    jpeg.extend([0xff, 0xc0, 0x00, 0x0b, 0x08]);
+    jpeg.extend(200_u16.to_be_bytes());
+    jpeg.extend(300_u16.to_be_bytes());
+    jpeg.extend([0x01, 0x01, 0x11, 0x00]);
+
+    assert_eq!(Some((300, 200)), dimensions(&jpeg));
+    assert_eq!(None, dimensions(b"not an image"));
+}
@@ -0,0 +1,16 @@
+//! Extraction of `Asset` properties from their files.
+//!
+//! Built-in extractors:
+//! + [`CsvExtractor`]: Column names of CSV and TSV files.
+//! + [`SidecarExtractor`]: JSON and YAML sidecar files.
+//! + [`ImageExtractor`]: Image dimensions and EXIF data.
+pub mod extractor;
+pub mod image;
+pub mod sidecar;
+pub mod tabular;
+
+// Re-exports
+pub use extractor::{Extracted, Extractor, Extractors};
+pub use image::ImageExtractor;
+pub use sidecar::{is_sidecar, SidecarExtractor};
+pub use tabular::CsvExtractor;
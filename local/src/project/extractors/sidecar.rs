@@ -0,0 +1,114 @@
+//! Extraction of properties from sidecar files.
+//!
+//! A sidecar file sits next to a data file and is named after it,
+//! e.g. `data.csv.json` or `data.csv.yaml` for `data.csv`.
+//! It may contain any of the keys
+//! + `metadata`: Object of metadata.
+//! + `kind`: String.
+//! + `tags`: Array of strings.
+use super::extractor::{Extracted, Extractor};
+use crate::error::{ExtractError, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thot_core::project::Metadata;
+
+/// Extensions of sidecar files.
+/// Earlier extensions take precedence if multiple sidecar files exist.
+pub const SIDECAR_EXTENSIONS: &[&str] = &["json", "yaml", "yml"];
+
+/// Contents of a sidecar file.
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct Sidecar {
+    metadata: Metadata,
+    kind: Option<String>,
+    tags: Vec<String>,
+}
+
+/// Extracts properties from a file's sidecar file, if it exists.
+pub struct SidecarExtractor;
+
+impl Extractor for SidecarExtractor {
+    fn extensions(&self) -> &[&str] {
+        &["*"]
+    }
+
+    fn extract(&self, path: &Path) -> Result<Extracted> {
+        let Some(sidecar_path) = sidecar_of(path) else {
+            return Ok(Extracted::new());
+        };
+
+        let invalid_file = |message: String| ExtractError::InvalidFile {
+            path: sidecar_path.clone(),
+            message,
+        };
+
+        let contents = fs::read_to_string(&sidecar_path)?;
+        let is_json = sidecar_extension(&sidecar_path) == Some("json");
+
+        let sidecar: Sidecar = if is_json {
+            serde_json::from_str(&contents).map_err(|err| invalid_file(err.to_string()))?
+        } else if contents.trim().is_empty() {
+            Sidecar::default()
+        } else {
+            serde_yaml::from_str(&contents).map_err(|err| invalid_file(err.to_string()))?
+        };
+
+        Ok(Extracted {
+            metadata: sidecar.metadata,
+            kind: sidecar.kind,
+            tags: sidecar.tags,
+        })
+    }
+}
+
+/// Returns the path of the file's sidecar file, if one exists.
+///
+/// # Notes
+/// + Sidecar extensions are matched case-insensitively.
+pub fn sidecar_of(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?;
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|sidecar_path| sidecar_path.file_stem() == Some(file_name))
+        .filter_map(|sidecar_path| {
+            let extension = sidecar_extension(&sidecar_path)?;
+            let precedence = SIDECAR_EXTENSIONS
+                .iter()
+                .position(|sidecar_extension| *sidecar_extension == extension);
+
+            Some((precedence, sidecar_path))
+        })
+        .filter(|(_, sidecar_path)| sidecar_path.is_file())
+        .min_by_key(|(precedence, _)| *precedence)
+        .map(|(_, sidecar_path)| sidecar_path)
+}
+
+/// Returns whether the path is a sidecar file of an existing file.
+///
+/// # Notes
+/// + Sidecar extensions are matched case-insensitively.
+pub fn is_sidecar(path: &Path) -> bool {
+    sidecar_extension(path).is_some() && path.with_extension("").is_file()
+}
+
+/// Returns the sidecar extension matching the path's extension, if any.
+/// Extensions are compared case-insensitively.
+fn sidecar_extension(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?;
+    SIDECAR_EXTENSIONS
+        .iter()
+        .find(|sidecar_extension| extension.eq_ignore_ascii_case(sidecar_extension))
+        .copied()
+}
+
+#[cfg(test)]
+#[path = "./sidecar_test.rs"]
+mod sidecar_test;
@@ -0,0 +1,88 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use serde_json::json;
+
+#[test]
+fn sidecar_extractor_extract_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let json_data = _dir
+        .mkfile_with_extension("csv")
+        .expect("could not create file");
+
+    let yaml_data = _dir
+        .mkfile_with_extension("txt")
+        .expect("could not create file");
+
+    let bare_data = _dir
+        .mkfile_with_extension("dat")
+        .expect("could not create file");
+
+    let mut json_sidecar = json_data.clone().into_os_string();
+    json_sidecar.push(".json");
+    fs::write(
+        &json_sidecar,
+        r#"{"metadata": {"mass": 1.5}, "kind": "raw", "tags": ["a", "b"]}"#,
+    )
+    .expect("could not write sidecar");
+
+    let mut yaml_sidecar = yaml_data.clone().into_os_string();
+    yaml_sidecar.push(".yml");
+    fs::write(&yaml_sidecar, "metadata:\n  sample: s1\ntags: [c]\n")
+        .expect("could not write sidecar");
+
+    // test
+    let extracted = SidecarExtractor
+        .extract(&json_data)
+        .expect("extraction should work");
+
+    assert_eq!(Some(&json!(1.5)), extracted.metadata.get("mass"));
+    assert_eq!(Some("raw".to_string()), extracted.kind);
+    assert_eq!(vec!["a".to_string(), "b".to_string()], extracted.tags);
+
+    let extracted = SidecarExtractor
+        .extract(&yaml_data)
+        .expect("extraction should work");
+
+    assert_eq!(Some(&json!("s1")), extracted.metadata.get("sample"));
+    assert_eq!(None, extracted.kind);
+    assert_eq!(vec!["c".to_string()], extracted.tags);
+
+    let extracted = SidecarExtractor
+        .extract(&bare_data)
+        .expect("extraction should work");
+
+    assert!(extracted.is_empty(), "no sidecar should extract nothing");
+
+    // is sidecar
+    assert!(is_sidecar(Path::new(&json_sidecar)));
+    assert!(is_sidecar(Path::new(&yaml_sidecar)));
+    assert!(!is_sidecar(&json_data), "data file is not a sidecar");
+}
+
+#[test]
+fn sidecar_extension_should_be_case_insensitive() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let data = _dir
+        .mkfile_with_extension("csv")
+        .expect("could not create file");
+
+    let mut sidecar = data.clone().into_os_string();
+    sidecar.push(".JSON");
+    fs::write(&sidecar, r#"{"kind": "raw"}"#).expect("could not write sidecar");
+
+    // test
+    assert!(is_sidecar(Path::new(&sidecar)));
+    assert_eq!(Some(PathBuf::from(&sidecar)), sidecar_of(&data));
+
+    let extracted = SidecarExtractor
+        .extract(&data)
+        .expect("extraction should work");
+
+    assert_eq!(
+        Some("raw".to_string()),
+        extracted.kind,
+        "upper case sidecar should be parsed as JSON"
+    );
+}
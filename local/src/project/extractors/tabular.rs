@@ -0,0 +1,60 @@
+//! Extraction of properties from tabular data files.
+use super::extractor::{Extracted, Extractor};
+use crate::error::{ExtractError, Result};
+use serde_json::Value as JsValue;
+use std::path::Path;
+
+/// Extracts the column names of CSV and TSV files.
+///
+/// # Notes
+/// + Column names are stored under the `columns` metadata key.
+/// + The first row is assumed to be the header.
+pub struct CsvExtractor;
+
+impl Extractor for CsvExtractor {
+    fn extensions(&self) -> &[&str] {
+        &["csv", "tsv"]
+    }
+
+    fn mime_types(&self) -> &[&str] {
+        &["text/csv", "text/tab-separated-values"]
+    }
+
+    fn extract(&self, path: &Path) -> Result<Extracted> {
+        let is_tsv = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("tsv"));
+
+        let delimiter = if is_tsv { b'\t' } else { b',' };
+        let invalid_file = |err: csv::Error| ExtractError::InvalidFile {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        };
+
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .flexible(true)
+            .from_path(path)
+            .map_err(invalid_file)?;
+
+        let columns = reader
+            .headers()
+            .map_err(invalid_file)?
+            .iter()
+            .map(|column| JsValue::String(column.trim().to_string()))
+            .collect::<Vec<_>>();
+
+        let mut extracted = Extracted::new();
+        if !columns.is_empty() {
+            extracted
+                .metadata
+                .insert("columns".to_string(), JsValue::Array(columns));
+        }
+
+        Ok(extracted)
+    }
+}
+
+#[cfg(test)]
+#[path = "./tabular_test.rs"]
+mod tabular_test;
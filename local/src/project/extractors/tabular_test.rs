@@ -0,0 +1,35 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn csv_extractor_extract_should_work() {
+    // setup
+    let mut _dir = TempDir::new().expect("could not create `TempDir`");
+    let csv = _dir
+        .mkfile_with_extension("csv")
+        .expect("could not create file");
+
+    let tsv = _dir
+        .mkfile_with_extension("tsv")
+        .expect("could not create file");
+
+    fs::write(&csv, "time, mass,temperature\n0,1.2,20\n1,1.3,21\n").expect("could not write file");
+    fs::write(&tsv, "time\tmass\n0\t1.2\n").expect("could not write file");
+
+    // test
+    let extracted = CsvExtractor.extract(&csv).expect("extraction should work");
+    assert_eq!(
+        Some(&json!(["time", "mass", "temperature"])),
+        extracted.metadata.get("columns"),
+        "incorrect columns"
+    );
+
+    let extracted = CsvExtractor.extract(&tsv).expect("extraction should work");
+    assert_eq!(
+        Some(&json!(["time", "mass"])),
+        extracted.metadata.get("columns"),
+        "incorrect columns"
+    );
+}
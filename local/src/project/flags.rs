@@ -0,0 +1,44 @@
+//! Analysis flags.
+//!
+//! The flags of a `Container` and its `Asset`s are stored
+//! in the `Container`'s `.thot` folder.
+use crate::common;
+use crate::Result;
+use std::fs;
+use std::path::Path;
+use thot_core::project::Flag;
+
+/// Loads the flags of a `Container` and its `Asset`s.
+///
+/// # Notes
+/// + If the `Container` has no flags, an empty list is returned.
+pub fn load(container: &Path) -> Result<Vec<Flag>> {
+    let flags = match fs::read_to_string(common::flags_file_of(container)) {
+        Ok(flags) => flags,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(serde_json::from_str(&flags)?)
+}
+
+/// Saves the flags of a `Container` and its `Asset`s.
+///
+/// # Notes
+/// + If there are no flags, the flags file is removed.
+pub fn save(container: &Path, flags: &[Flag]) -> Result {
+    let path = common::flags_file_of(container);
+    if flags.is_empty() {
+        return match fs::remove_file(path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        };
+    }
+
+    fs::write(path, serde_json::to_string_pretty(flags)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./flags_test.rs"]
+mod flags_test;
@@ -0,0 +1,33 @@
+use super::*;
+use crate::project::container;
+use dev_utils::fs::TempDir;
+use thot_core::types::ResourceId;
+
+#[test]
+fn save_and_load_should_work() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let builder = container::InitOptions::init();
+    let rid = builder
+        .build(dir.path())
+        .expect("could not init `Container`");
+
+    // test
+    assert!(
+        load(dir.path()).expect("load should work").is_empty(),
+        "missing flags should be empty"
+    );
+
+    let flags = vec![
+        Flag::new(rid, "check"),
+        Flag::new(ResourceId::new(), "asset"),
+    ];
+    save(dir.path(), &flags).expect("save should work");
+    assert_eq!(flags, load(dir.path()).expect("load should work"));
+
+    save(dir.path(), &[]).expect("save should work");
+    assert!(
+        !common::flags_file_of(dir.path()).exists(),
+        "empty flags should remove file"
+    );
+}
@@ -0,0 +1,52 @@
+//! `Asset` lineage.
+//!
+//! The lineage records of a project are stored
+//! in the project's `.thot` folder.
+use crate::common;
+use crate::Result;
+use std::fs;
+use std::path::Path;
+use thot_core::project::Lineage;
+
+/// Loads the lineage records of a project.
+///
+/// # Notes
+/// + If the project has no lineage, an empty list is returned.
+pub fn load(project: &Path) -> Result<Vec<Lineage>> {
+    let records = match fs::read_to_string(common::lineage_file_of(project)) {
+        Ok(records) => records,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(serde_json::from_str(&records)?)
+}
+
+/// Saves the lineage records of a project.
+pub fn save(project: &Path, records: &[Lineage]) -> Result {
+    fs::write(
+        common::lineage_file_of(project),
+        serde_json::to_string_pretty(records)?,
+    )?;
+
+    Ok(())
+}
+
+/// Hash of a file's contents, used to identify versions of a `Script`.
+///
+/// # Notes
+/// + Uses 64-bit FNV-1a, so is only suitable for detecting changes.
+pub fn file_hash(path: &Path) -> Result<String> {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let hash = fs::read(path)?.iter().fold(OFFSET, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    });
+
+    Ok(format!("{hash:016x}"))
+}
+
+#[cfg(test)]
+#[path = "./lineage_test.rs"]
+mod lineage_test;
@@ -0,0 +1,34 @@
+use super::*;
+use dev_utils::fs::TempDir;
+use thot_core::types::ResourceId;
+
+#[test]
+fn save_and_load_should_work() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    fs::create_dir(common::thot_dir_of(dir.path())).expect("could not create thot dir");
+
+    // test
+    assert!(
+        load(dir.path()).expect("load should work").is_empty(),
+        "missing lineage should be empty"
+    );
+
+    let mut record = Lineage::new(ResourceId::new(), ResourceId::new(), ResourceId::new());
+    record.inputs.push(ResourceId::new());
+    save(dir.path(), &[record.clone()]).expect("save should work");
+    assert_eq!(vec![record], load(dir.path()).expect("load should work"));
+}
+
+#[test]
+fn file_hash_should_change_with_contents() {
+    let dir = TempDir::new().expect("could not create temp dir");
+    let path = dir.path().join("script.py");
+
+    fs::write(&path, "print('a')").unwrap();
+    let hash = file_hash(&path).expect("could not hash file");
+    assert_eq!(hash, file_hash(&path).unwrap());
+
+    fs::write(&path, "print('b')").unwrap();
+    assert_ne!(hash, file_hash(&path).unwrap());
+}
@@ -0,0 +1,79 @@
+//! Metadata change history.
+//!
+//! The history of a `Container` and its `Asset`s is stored as an append-only log
+//! in the `Container`'s `.thot` folder, one [`MetadataChange`] per line.
+use crate::common;
+use crate::Result;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use thot_core::project::MetadataChange;
+use thot_core::types::ResourceId;
+
+/// Appends changes to a `Container`'s history.
+///
+/// # Arguments
+/// 1. Path to the `Container`.
+/// 2. Changes to append.
+pub fn append(container: &Path, changes: &[MetadataChange]) -> Result {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(common::metadata_history_file_of(container))?;
+
+    let mut lines = String::new();
+    for change in changes {
+        lines.push_str(&serde_json::to_string(change)?);
+        lines.push('\n');
+    }
+
+    file.write_all(lines.as_bytes())?;
+    Ok(())
+}
+
+/// Loads the history of a `Container` and its `Asset`s, oldest first.
+///
+/// # Notes
+/// + If the `Container` has no history, an empty list is returned.
+/// + Lines that can not be parsed are skipped.
+pub fn load(container: &Path) -> Result<Vec<MetadataChange>> {
+    let file = match fs::File::open(common::metadata_history_file_of(container)) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut changes = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        match serde_json::from_str(&line) {
+            Ok(change) => changes.push(change),
+            Err(err) => tracing::warn!(?err, "could not parse metadata history entry"),
+        }
+    }
+
+    Ok(changes)
+}
+
+/// Loads the history of a single resource, oldest first.
+///
+/// # Arguments
+/// 1. Path to the `Container` holding the resource's history.
+/// 2. Resource.
+pub fn load_resource(container: &Path, resource: &ResourceId) -> Result<Vec<MetadataChange>> {
+    let mut changes = load(container)?;
+    changes.retain(|change| &change.resource == resource);
+    Ok(changes)
+}
+
+#[cfg(test)]
+#[path = "./metadata_history_test.rs"]
+mod metadata_history_test;
@@ -0,0 +1,41 @@
+use super::*;
+use crate::project::container;
+use dev_utils::fs::TempDir;
+use serde_json::json;
+use thot_core::project::Metadata;
+use thot_core::types::Creator;
+
+#[test]
+fn append_and_load_should_work() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let builder = container::InitOptions::init();
+    let rid = builder
+        .build(dir.path())
+        .expect("could not init `Container`");
+
+    let asset = ResourceId::new();
+    let old = Metadata::new();
+    let new = Metadata::from([("temp".to_string(), json!(21.5))]);
+    let creator = Creator::User(None);
+
+    // test
+    assert!(
+        load(dir.path()).expect("load should work").is_empty(),
+        "missing history should be empty"
+    );
+
+    let changes = MetadataChange::diff(&rid, &old, &new, &creator, "update");
+    append(dir.path(), &changes).expect("append should work");
+
+    let changes = MetadataChange::diff(&asset, &new, &old, &creator, "update");
+    append(dir.path(), &changes).expect("append should work");
+
+    let history = load(dir.path()).expect("load should work");
+    assert_eq!(2, history.len(), "history should be appended");
+
+    let history = load_resource(dir.path(), &asset).expect("load should work");
+    assert_eq!(1, history.len(), "history should be filtered by resource");
+    assert_eq!(Some(json!(21.5)), history[0].old);
+    assert_eq!(None, history[0].new);
+}
@@ -0,0 +1,371 @@
+//! Project format versioning and migrations.
+//!
+//! A Project records the format it was written in as
+//! [`ProjectSettings::local_format_version`](crate::types::ProjectSettings::local_format_version).
+//! Projects in an older format are migrated to the current format,
+//! applying each step between the two versions in order.
+//! Resource files are backed up before they are migrated.
+use crate::common;
+use crate::constants::PROJECT_FORMAT_VERSION;
+use crate::error::{ProjectError, Result};
+use serde_json::{Map as JsMap, Value as JsValue};
+use std::cmp::Ordering;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+
+/// Directory in the Project's <THOT_DIR> backups are placed in.
+const BACKUPS_DIR: &str = "backups";
+
+/// Migration steps, ordered by version.
+/// Each step migrates files from the previous version to its version.
+static MIGRATIONS: &[(&str, MigrationFn)] = &[("0.11.0", v0_11_0)];
+
+type MigrationFn = fn(FormatFile, &mut JsValue);
+
+// **********************
+// *** Format Version ***
+// **********************
+
+/// Version of the Project format, as `major.minor.patch`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+pub struct FormatVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl FormatVersion {
+    /// The current format version.
+    pub fn current() -> Self {
+        PROJECT_FORMAT_VERSION
+            .parse()
+            .expect("invalid `PROJECT_FORMAT_VERSION`")
+    }
+
+    /// Version of Projects that predate format versioning.
+    pub fn unversioned() -> Self {
+        Self {
+            major: 0,
+            minor: 0,
+            patch: 0,
+        }
+    }
+}
+
+impl FromStr for FormatVersion {
+    type Err = ProjectError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let parts = s
+            .split('.')
+            .map(|part| part.parse::<u64>())
+            .collect::<std::result::Result<Vec<_>, _>>();
+
+        match parts.as_deref() {
+            Ok([major, minor, patch]) => Ok(Self {
+                major: *major,
+                minor: *minor,
+                patch: *patch,
+            }),
+
+            _ => Err(ProjectError::InvalidFormatVersion(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for FormatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+// *********************
+// *** Format Status ***
+// *********************
+
+/// How a Project's format relates to the current format.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum FormatStatus {
+    Current,
+
+    /// The Project must be migrated before it is opened.
+    Outdated(FormatVersion),
+
+    /// The Project was written by a newer version of Thot and can not be opened.
+    Newer(FormatVersion),
+}
+
+/// Gets the format version of a Project from its settings file.
+/// Projects without a recorded version are [unversioned](FormatVersion::unversioned).
+///
+/// # Arguments
+/// 1. Path to the Project root.
+pub fn project_format_version(project: &Path) -> Result<FormatVersion> {
+    let settings = read_json(&common::project_settings_file_of(project))?;
+    match settings.get("local_format_version") {
+        None => Ok(FormatVersion::unversioned()),
+        Some(JsValue::String(version)) => Ok(version.parse()?),
+        Some(version) => Err(ProjectError::InvalidFormatVersion(version.to_string()).into()),
+    }
+}
+
+/// Gets the format status of a Project.
+///
+/// # Arguments
+/// 1. Path to the Project root.
+pub fn format_status(project: &Path) -> Result<FormatStatus> {
+    let version = project_format_version(project)?;
+    Ok(match version.cmp(&FormatVersion::current()) {
+        Ordering::Equal => FormatStatus::Current,
+        Ordering::Less => FormatStatus::Outdated(version),
+        Ordering::Greater => FormatStatus::Newer(version),
+    })
+}
+
+// *****************
+// *** Migration ***
+// *****************
+
+/// A resource file that is migrated.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum FormatFile {
+    Project,
+    ProjectSettings,
+    Scripts,
+    Container,
+    ContainerSettings,
+    Assets,
+}
+
+impl FormatFile {
+    fn path_of(&self, resource: &Path) -> PathBuf {
+        match self {
+            Self::Project => common::project_file_of(resource),
+            Self::ProjectSettings => common::project_settings_file_of(resource),
+            Self::Scripts => common::scripts_file_of(resource),
+            Self::Container => common::container_file_of(resource),
+            Self::ContainerSettings => common::container_settings_file_of(resource),
+            Self::Assets => common::assets_file_of(resource),
+        }
+    }
+}
+
+/// Migrates a Project to the current format.
+/// The <THOT_DIR> of the Project and each of its `Container`s is backed up first,
+/// and restored if the migration fails.
+///
+/// # Arguments
+/// 1. Path to the Project root.
+///
+/// # Returns
+/// Path to the backup, or `None` if the Project is already in the current format.
+///
+/// # Errors
+/// + [`ProjectError::FormatTooNew`]: If the Project is in a newer format.
+pub fn migrate(project: &Path) -> Result<Option<PathBuf>> {
+    let version = match format_status(project)? {
+        FormatStatus::Current => return Ok(None),
+        FormatStatus::Outdated(version) => version,
+        FormatStatus::Newer(version) => {
+            return Err(ProjectError::FormatTooNew(
+                version.to_string(),
+                PROJECT_FORMAT_VERSION.to_string(),
+            )
+            .into());
+        }
+    };
+
+    // load files
+    let mut files = Vec::new();
+    for file in [
+        FormatFile::Project,
+        FormatFile::ProjectSettings,
+        FormatFile::Scripts,
+    ] {
+        let path = file.path_of(project);
+        if path.exists() {
+            files.push((file, read_json(&path)?, path));
+        }
+    }
+
+    let mut containers = Vec::new();
+    container_paths(project, &mut containers)?;
+    for container in containers.iter() {
+        for file in [
+            FormatFile::Container,
+            FormatFile::ContainerSettings,
+            FormatFile::Assets,
+        ] {
+            let path = file.path_of(container);
+            if path.exists() {
+                files.push((file, read_json(&path)?, path));
+            }
+        }
+    }
+
+    // migrate
+    for (file, value, _) in files.iter_mut() {
+        migrate_file(*file, value, version);
+        if *file == FormatFile::ProjectSettings {
+            if let JsValue::Object(settings) = value {
+                settings.insert(
+                    "local_format_version".to_string(),
+                    JsValue::String(PROJECT_FORMAT_VERSION.to_string()),
+                );
+            }
+        }
+    }
+
+    // save
+    let resources = [project.to_path_buf()]
+        .into_iter()
+        .chain(containers.into_iter())
+        .collect::<Vec<_>>();
+
+    let backup = backup(project, &resources, version)?;
+    for (_, value, path) in files.iter() {
+        if let Err(err) = fs::write(path, serde_json::to_string_pretty(value)?) {
+            restore(project, &backup, &resources)?;
+            return Err(err.into());
+        }
+    }
+
+    Ok(Some(backup))
+}
+
+/// Applies the migration steps after `version` to a file.
+pub fn migrate_file(file: FormatFile, value: &mut JsValue, version: FormatVersion) {
+    for (step, migration) in MIGRATIONS {
+        let step: FormatVersion = step.parse().expect("invalid migration version");
+        if version < step {
+            migration(file, value);
+        }
+    }
+}
+
+// *************
+// *** Steps ***
+// *************
+
+/// Adds the resource links and `Container` child ordering introduced in `0.11.0`.
+fn v0_11_0(file: FormatFile, value: &mut JsValue) {
+    match file {
+        FormatFile::Container => {
+            if let Some(properties) = value.get_mut("properties") {
+                insert_default(properties, "links", JsValue::Object(JsMap::new()));
+            }
+        }
+
+        FormatFile::Assets => {
+            let JsValue::Object(assets) = value else {
+                return;
+            };
+
+            for asset in assets.values_mut() {
+                if let Some(properties) = asset.get_mut("properties") {
+                    insert_default(properties, "links", JsValue::Object(JsMap::new()));
+                }
+            }
+        }
+
+        FormatFile::ContainerSettings => {
+            insert_default(value, "children", JsValue::Array(Vec::new()));
+        }
+
+        FormatFile::Project | FormatFile::ProjectSettings | FormatFile::Scripts => {}
+    }
+}
+
+// ***************
+// *** Helpers ***
+// ***************
+
+/// Inserts a key into an object if it is not present.
+fn insert_default(value: &mut JsValue, key: &str, default: JsValue) {
+    if let JsValue::Object(map) = value {
+        map.entry(key).or_insert(default);
+    }
+}
+
+fn read_json(path: &Path) -> Result<JsValue> {
+    let contents = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Collects the paths of `Container`s at or below a path.
+/// Symlinks are not followed.
+fn container_paths(path: &Path, containers: &mut Vec<PathBuf>) -> Result {
+    if common::container_file_of(path).exists() {
+        containers.push(path.to_path_buf());
+    }
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() && entry.file_name() != common::thot_dir().as_os_str() {
+            container_paths(&entry.path(), containers)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Copies the <THOT_DIR> of each resource into a new backup directory,
+/// mirroring the Project's layout.
+///
+/// # Returns
+/// Path to the backup.
+fn backup(project: &Path, resources: &[PathBuf], version: FormatVersion) -> Result<PathBuf> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default();
+
+    let backup = common::thot_dir_of(project)
+        .join(BACKUPS_DIR)
+        .join(format!("{version}_{timestamp}"));
+
+    for resource in resources {
+        let to = common::thot_dir_of(&backup.join(backup_rel_path(project, resource)));
+        fs::create_dir_all(&to)?;
+        copy_files(&common::thot_dir_of(resource), &to)?;
+    }
+
+    Ok(backup)
+}
+
+/// Restores the files of each resource from a backup.
+fn restore(project: &Path, backup: &Path, resources: &[PathBuf]) -> Result {
+    for resource in resources {
+        let from = common::thot_dir_of(&backup.join(backup_rel_path(project, resource)));
+        copy_files(&from, &common::thot_dir_of(resource))?;
+    }
+
+    Ok(())
+}
+
+/// Path of a resource's backup relative to the backup root.
+fn backup_rel_path(project: &Path, resource: &Path) -> PathBuf {
+    resource
+        .strip_prefix(project)
+        .expect("resource should be in Project")
+        .to_path_buf()
+}
+
+/// Copies the files, but not directories, of a directory.
+fn copy_files(from: &Path, to: &Path) -> io::Result<()> {
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        if entry.path().is_file() {
+            fs::copy(entry.path(), to.join(entry.file_name()))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./migration_test.rs"]
+mod migration_test;
@@ -0,0 +1,242 @@
+use super::*;
+use crate::error::Error;
+use crate::project::container;
+use crate::project::resources::{Container, Project};
+use dev_utils::fs::TempDir;
+use serde_json::json;
+
+#[test]
+fn format_version_should_parse_and_order() {
+    let v0_10: FormatVersion = "0.10.0".parse().expect("could not parse version");
+    let v0_11: FormatVersion = "0.11.0".parse().expect("could not parse version");
+    let v1: FormatVersion = "1.0.0".parse().expect("could not parse version");
+
+    assert!(
+        v0_10 < v0_11,
+        "minor versions should be compared numerically"
+    );
+    assert!(v0_11 < v1);
+    assert!(FormatVersion::unversioned() < v0_10);
+    assert_eq!("0.10.0", v0_10.to_string());
+    assert!("0.10".parse::<FormatVersion>().is_err());
+    assert!("0.x.0".parse::<FormatVersion>().is_err());
+}
+
+#[test]
+fn migrate_file_should_apply_steps_after_version() {
+    let asset = json!({
+        "rid": "asset",
+        "properties": {"name": "data"},
+        "path": {"Relative": "data.csv"}
+    });
+
+    let mut assets = json!({ "asset": asset.clone() });
+    migrate_file(FormatFile::Assets, &mut assets, "0.10.0".parse().unwrap());
+    assert_eq!(json!({}), assets["asset"]["properties"]["links"]);
+
+    let mut assets = json!({ "asset": asset.clone() });
+    migrate_file(FormatFile::Assets, &mut assets, FormatVersion::current());
+    assert_eq!(
+        json!({ "asset": asset }),
+        assets,
+        "current files should not change"
+    );
+
+    let mut settings = json!({"permissions": [], "children": ["child"]});
+    migrate_file(
+        FormatFile::ContainerSettings,
+        &mut settings,
+        "0.10.0".parse().unwrap(),
+    );
+    assert_eq!(
+        json!(["child"]),
+        settings["children"],
+        "existing values should be kept"
+    );
+}
+
+#[test]
+fn migrate_should_update_files_and_back_up() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create temp dir");
+    let data_dir = dir.mkdir().unwrap();
+    let project = Project::new(dir.path().to_path_buf()).unwrap();
+    project.save().unwrap();
+
+    let builder = container::InitOptions::init();
+    builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    set_format_version(dir.path(), json!("0.10.0"));
+    let container_path = common::container_file_of(&data_dir);
+    let mut stored = read_json(&container_path).unwrap();
+    stored["properties"]
+        .as_object_mut()
+        .unwrap()
+        .remove("links");
+    fs::write(&container_path, stored.to_string()).unwrap();
+
+    let settings_path = common::container_settings_file_of(&data_dir);
+    fs::write(&settings_path, r#"{"permissions": []}"#).unwrap();
+
+    // test
+    assert_eq!(
+        FormatStatus::Outdated("0.10.0".parse().unwrap()),
+        format_status(dir.path()).unwrap()
+    );
+
+    let backup = migrate(dir.path())
+        .expect("migrate should work")
+        .expect("`Project` should be migrated");
+
+    assert_eq!(FormatStatus::Current, format_status(dir.path()).unwrap());
+    assert_eq!(
+        json!({}),
+        read_json(&container_path).unwrap()["properties"]["links"]
+    );
+    assert_eq!(json!([]), read_json(&settings_path).unwrap()["children"]);
+    Container::load_from(&data_dir).expect("migrated `Container` should load");
+
+    let backup_settings = read_json(&common::project_settings_file_of(&backup)).unwrap();
+    assert_eq!(json!("0.10.0"), backup_settings["local_format_version"]);
+
+    let data_rel_path = data_dir.strip_prefix(dir.path()).unwrap();
+    let backup_settings = common::container_settings_file_of(&backup.join(data_rel_path));
+    assert_eq!(
+        r#"{"permissions": []}"#,
+        fs::read_to_string(backup_settings).unwrap()
+    );
+
+    assert!(
+        migrate(dir.path()).unwrap().is_none(),
+        "current `Project` should not be migrated"
+    );
+}
+
+#[test]
+fn migrate_should_skip_missing_files() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create temp dir");
+    let data_dir = dir.mkdir().unwrap();
+    let project = Project::new(dir.path().to_path_buf()).unwrap();
+    project.save().unwrap();
+
+    let builder = container::InitOptions::init();
+    builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let assets_path = common::assets_file_of(&data_dir);
+    fs::remove_file(&assets_path).unwrap();
+    set_format_version(dir.path(), json!("0.10.0"));
+
+    // test
+    migrate(dir.path())
+        .expect("migrate should work")
+        .expect("`Project` should be migrated");
+
+    assert!(!assets_path.exists(), "missing files should not be created");
+}
+
+#[cfg(unix)]
+#[test]
+fn migrate_should_not_follow_symlinks() {
+    // setup
+    let mut dir = TempDir::new().expect("could not create temp dir");
+    let data_dir = dir.mkdir().unwrap();
+    let project = Project::new(dir.path().to_path_buf()).unwrap();
+    project.save().unwrap();
+
+    let builder = container::InitOptions::init();
+    builder
+        .build(&data_dir)
+        .expect("could not init `Container`");
+
+    let outside = TempDir::new().expect("could not create temp dir");
+    builder
+        .build(outside.path())
+        .expect("could not init `Container`");
+
+    let outside_settings = common::container_settings_file_of(outside.path());
+    fs::write(&outside_settings, r#"{"permissions": []}"#).unwrap();
+    std::os::unix::fs::symlink(outside.path(), data_dir.join("link")).unwrap();
+    set_format_version(dir.path(), json!("0.10.0"));
+
+    // test
+    migrate(dir.path())
+        .expect("migrate should work")
+        .expect("`Project` should be migrated");
+
+    assert_eq!(
+        r#"{"permissions": []}"#,
+        fs::read_to_string(&outside_settings).unwrap(),
+        "`Container`s behind symlinks should not be migrated"
+    );
+}
+
+#[test]
+fn unversioned_project_should_be_migrated_on_load() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let project = Project::new(dir.path().to_path_buf()).unwrap();
+    project.save().unwrap();
+
+    let settings_path = common::project_settings_file_of(dir.path());
+    let mut settings = read_json(&settings_path).unwrap();
+    settings
+        .as_object_mut()
+        .unwrap()
+        .remove("local_format_version");
+    fs::write(&settings_path, settings.to_string()).unwrap();
+
+    // test
+    assert_eq!(
+        FormatStatus::Outdated(FormatVersion::unversioned()),
+        format_status(dir.path()).unwrap()
+    );
+
+    let project = Project::load_from(dir.path()).expect("`Project` should load");
+    assert_eq!(
+        PROJECT_FORMAT_VERSION,
+        project.settings().local_format_version
+    );
+}
+
+#[test]
+fn newer_project_should_not_be_opened() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let project = Project::new(dir.path().to_path_buf()).unwrap();
+    project.save().unwrap();
+    set_format_version(dir.path(), json!("99.0.0"));
+
+    // test
+    assert!(matches!(
+        migrate(dir.path()),
+        Err(Error::ProjectError(ProjectError::FormatTooNew(_, _)))
+    ));
+
+    assert!(matches!(
+        Project::load_from(dir.path()),
+        Err(Error::ProjectError(ProjectError::FormatTooNew(_, _)))
+    ));
+
+    let settings = read_json(&common::project_settings_file_of(dir.path())).unwrap();
+    assert_eq!(
+        json!("99.0.0"),
+        settings["local_format_version"],
+        "newer `Project` should not be modified"
+    );
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+fn set_format_version(project: &Path, version: JsValue) {
+    let path = common::project_settings_file_of(project);
+    let mut settings = read_json(&path).unwrap();
+    settings["local_format_version"] = version;
+    fs::write(path, settings.to_string()).unwrap();
+}
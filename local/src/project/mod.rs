@@ -7,12 +7,16 @@
 //! + Script Associations
 pub mod asset;
 pub mod container;
+pub mod extractors;
+pub mod flags;
+pub mod lineage;
+pub mod metadata_history;
+pub mod migration;
 pub mod project;
 pub mod resources;
 pub mod script;
 
-/// Current project format version.
-pub static PROJECT_FORMAT_VERSION: &str = "0.10.0";
+pub use crate::constants::PROJECT_FORMAT_VERSION;
 
 // *****************
 // *** functions ***
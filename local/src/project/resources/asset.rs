@@ -78,9 +78,27 @@ impl Assets {
         Ok(Self { base_path, assets })
     }
 
+    /// Saves the `Asset`s.
+    /// The file is written to a temporary file first, then moved over the original,
+    /// so an interrupted save does not corrupt the file.
     pub fn save(&self) -> Result {
-        let file = fs::OpenOptions::new().write(true).open(self.path())?;
-        Ok(serde_json::to_writer_pretty(file, &self.assets)?)
+        let path = self.path();
+        let mut tmp_name = path.file_name().expect("invalid file path").to_os_string();
+        tmp_name.push(".tmp");
+        let tmp_path = path.with_file_name(tmp_name);
+
+        let file = fs::File::create(&tmp_path)?;
+        if let Err(err) = serde_json::to_writer_pretty(file, &self.assets) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        if let Err(err) = fs::rename(&tmp_path, &path) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        Ok(())
     }
 
     pub fn insert(&mut self, asset: CoreAsset) -> Option<CoreAsset> {
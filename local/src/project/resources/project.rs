@@ -1,6 +1,7 @@
 //! Project and project settings.
 use crate::common::{project_file, project_settings_file};
 use crate::file_resource::LocalResource;
+use crate::project::migration;
 use crate::types::ProjectSettings;
 use crate::Result;
 use std::fs;
@@ -44,8 +45,15 @@ impl Project {
         })
     }
 
+    /// Loads a `Project`, migrating it to the current format if needed.
+    ///
+    /// # Errors
+    /// + [`ProjectError::FormatTooNew`](crate::error::ProjectError::FormatTooNew):
+    ///     If the `Project` is in a newer format.
     pub fn load_from(base_path: impl Into<PathBuf>) -> Result<Self> {
         let base_path = fs::canonicalize(base_path.into())?;
+        migration::migrate(&base_path)?;
+
         let project_path = base_path.join(<Project as LocalResource<CoreProject>>::rel_path());
         let settings_path = base_path.join(<Project as LocalResource<ProjectSettings>>::rel_path());
 
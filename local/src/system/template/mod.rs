@@ -1,5 +1,7 @@
 /// Templates.
 pub mod project;
+pub mod subtree;
 
 // Re-exports
 pub use project::Project;
+pub use subtree::Subtree;
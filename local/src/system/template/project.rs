@@ -1,14 +1,32 @@
 //! Project template.
+//!
+//! Templates are stored in the user's config directory as
+//! `templates/<template id>/template.json`.
+//! Script files are copied into the template's `analysis` folder,
+//! and Asset files into `assets/<container id>`.
 use crate::file_resource::UserResource;
+use crate::graph::{ContainerTreeLoader, ContainerTreeTransformer};
+use crate::project::project;
+use crate::project::resources::{
+    Container as LocalContainer, Project as LocalProject, Scripts as LocalScripts,
+};
 use crate::system::common::config_dir_path;
 use crate::Result;
-use has_id::{HasId, HasIdSerde};
+use has_id::HasId;
+use std::collections::HashMap;
+use std::fs;
+use std::io::BufReader;
 use std::ops::{Deref, DerefMut};
 use std::path::{Path, PathBuf};
-use thot_core::graph::ResourceTree;
-use thot_core::project::Project as CoreProject;
+use thot_core::error::{Error as CoreError, ProjectError, ResourceError};
+use thot_core::project::{Asset, Project as CoreProject};
+use thot_core::system::template::project::ProjectInfo;
 use thot_core::system::template::Project as ProjectTemplate;
-use thot_core::types::ResourceId;
+use thot_core::types::{ResourceId, ResourcePath};
+
+const TEMPLATE_FILE: &str = "template.json";
+const ANALYSIS_DIR: &str = "analysis";
+const ASSETS_DIR: &str = "assets";
 
 pub struct Project {
     rel_path: PathBuf,
@@ -16,21 +34,218 @@ pub struct Project {
 }
 
 impl Project {
-    /// Creates a new [`Project`](crate::project::Project) from the template.
-    pub fn create_project<T>(&self, path: PathBuf) -> Result<(CoreProject, ResourceTree<T>)>
-    where
-        T: HasId<Id = ResourceId> + HasIdSerde<'static, Id = ResourceId>,
-    {
-        todo!();
-        // let mut project = CoreProject::new(&self.name);
-        // project.description = self.project.description.clone();
-        // project.data_root = self.project.data_root.clone();
-        // project.universal_root = self.universal_root.clone();
-        // project.analysis_root = self.project.analysis_root.clone();
-
-        // let graph = ResourceTree::to_tree(graph);
-
-        // Ok((project, graph))
+    pub fn new(project: ProjectTemplate) -> Self {
+        Self {
+            rel_path: Self::rel_path_of(&project.rid),
+            project,
+        }
+    }
+
+    /// Creates a template from an existing [`Project`](crate::project::resources::Project).
+    /// Script and, if included, Asset files are copied into the template.
+    ///
+    /// # Arguments
+    /// 1. Path to the `Project`'s root.
+    /// 2. Name of the template.
+    /// 3. Whether to include `Asset`s.
+    ///
+    /// # Errors
+    /// + [`ProjectError::Misconfigured`] if the `Project` does not have a data root.
+    ///
+    /// # Notes
+    /// + The template is not saved.
+    pub fn from_project(
+        path: impl AsRef<Path>,
+        name: impl Into<String>,
+        assets: bool,
+    ) -> Result<Self> {
+        let prj = LocalProject::load_from(path.as_ref())?;
+        let Some(data_root) = prj.data_root_path() else {
+            return Err(CoreError::ProjectError(ProjectError::misconfigured(
+                "`Project` does not have a data root",
+            ))
+            .into());
+        };
+
+        let local_graph = ContainerTreeLoader::load(&data_root)?;
+        let mut graph = ContainerTreeTransformer::local_to_core(&local_graph);
+        if !assets {
+            let root = graph.root().clone();
+            graph.visit_mut(&root, |node| {
+                node.assets.clear();
+                thot_core::graph::Visit::Continue
+            })?;
+        }
+
+        let info: CoreProject = (*prj).clone();
+        let mut template = Self::new(ProjectTemplate::new(name, ProjectInfo::from(info), graph));
+        template.scripts = LocalScripts::load_from(prj.base_path())?.into();
+        let dir = template.dir();
+
+        // copy files
+        if let Some(analysis_root) = prj.analysis_root_path() {
+            for script in template.scripts.values() {
+                let ResourcePath::Relative(rel_path) = &script.path else {
+                    continue;
+                };
+
+                copy_file(
+                    &analysis_root.join(rel_path),
+                    &dir.join(ANALYSIS_DIR).join(rel_path),
+                )?;
+            }
+        }
+
+        if assets {
+            for (cid, container) in local_graph.nodes() {
+                for asset in container.assets.values() {
+                    let ResourcePath::Relative(rel_path) = &asset.path else {
+                        continue;
+                    };
+
+                    copy_file(
+                        &container.base_path().join(rel_path),
+                        &dir.join(ASSETS_DIR).join(cid.to_string()).join(rel_path),
+                    )?;
+                }
+            }
+        }
+
+        Ok(template)
+    }
+
+    pub fn load(rid: &ResourceId) -> Result<Self> {
+        let rel_path = Self::rel_path_of(rid);
+        let file = fs::File::open(Self::base_path().join(&rel_path))?;
+        let reader = BufReader::new(file);
+        let project = serde_json::from_reader(reader)?;
+
+        Ok(Self { rel_path, project })
+    }
+
+    pub fn save(&self) -> Result {
+        let path = self.path();
+        fs::create_dir_all(path.parent().expect("invalid template path"))?;
+        fs::write(path, serde_json::to_string_pretty(&self.project)?)?;
+        Ok(())
+    }
+
+    /// Returns the template's folder.
+    pub fn dir(&self) -> PathBuf {
+        Self::base_path().join(self.rid.to_string())
+    }
+
+    /// Creates a new [`Project`](crate::project::resources::Project) from the template.
+    /// `Container`s, `Asset`s, and `Script`s are given new ids,
+    /// and the `Project` is registered as a child of the template.
+    ///
+    /// # Arguments
+    /// 1. Path to the new `Project`'s root. Must not exist.
+    ///
+    /// # Returns
+    /// Id of the new `Project`.
+    pub fn create_project(&mut self, path: impl AsRef<Path>) -> Result<ResourceId> {
+        let path = path.as_ref();
+        if self.project.project.data_root.is_none() {
+            return Err(CoreError::ProjectError(ProjectError::misconfigured(
+                "template does not have a data root",
+            ))
+            .into());
+        }
+
+        let dir = self.dir();
+        project::new(path)?;
+
+        let mut prj = LocalProject::load_from(path)?;
+        prj.description = self.project.project.description.clone();
+        prj.data_root = self.project.project.data_root.clone();
+        prj.universal_root = self.project.project.universal_root.clone();
+        prj.analysis_root = self.project.project.analysis_root.clone();
+        prj.save()?;
+
+        // scripts
+        let mut scripts = LocalScripts::load_from(prj.base_path())?;
+        let mut script_ids = HashMap::new();
+        for script in self.scripts.values() {
+            let mut dup = script.clone();
+            dup.rid = ResourceId::new();
+            script_ids.insert(script.rid.clone(), dup.rid.clone());
+
+            if let (Some(analysis_root), ResourcePath::Relative(rel_path)) =
+                (prj.analysis_root_path(), &script.path)
+            {
+                let from = dir.join(ANALYSIS_DIR).join(rel_path);
+                if from.exists() {
+                    copy_file(&from, &analysis_root.join(rel_path))?;
+                }
+            }
+
+            scripts.insert_script(dup)?;
+        }
+        scripts.save()?;
+
+        // containers
+        let data_root = prj.data_root_path().expect("data root not set");
+        let graph = &self.project.graph;
+        let mut paths = HashMap::new();
+        for node in graph
+            .iter_pre_order(graph.root())
+            .expect("root not in graph")
+        {
+            let c_path = match graph.parent(node.id())? {
+                None => data_root.clone(),
+                Some(parent) => {
+                    let parent: &PathBuf = paths.get(parent).expect("parent not created");
+                    parent.join(&node.properties.name)
+                }
+            };
+
+            if c_path.exists() && c_path != data_root {
+                return Err(CoreError::ResourceError(ResourceError::already_exists(
+                    "`Container` path already exists",
+                ))
+                .into());
+            }
+
+            // links refer to resources of the source project
+            let mut container = LocalContainer::new(&c_path);
+            container.properties = node.properties.clone();
+            container.properties.links.clear();
+            for (script, params) in node.scripts.iter() {
+                let rid = script_ids.get(script).unwrap_or(script);
+                container.scripts.insert(rid.clone(), params.clone());
+            }
+
+            for asset in node.assets.values() {
+                let mut dup = Asset::new(asset.path.clone());
+                dup.properties = asset.properties.clone();
+                dup.properties.links.clear();
+                if let ResourcePath::Relative(rel_path) = &asset.path {
+                    let from = dir
+                        .join(ASSETS_DIR)
+                        .join(node.id().to_string())
+                        .join(rel_path);
+
+                    if from.exists() {
+                        copy_file(&from, &c_path.join(rel_path))?;
+                    }
+                }
+
+                container.insert_asset(dup);
+            }
+
+            container.save()?;
+            paths.insert(node.id().clone(), c_path);
+        }
+
+        let rid = prj.rid.clone();
+        self.children.insert(rid.clone());
+        self.save()?;
+        Ok(rid)
+    }
+
+    fn rel_path_of(rid: &ResourceId) -> PathBuf {
+        PathBuf::from(rid.to_string()).join(TEMPLATE_FILE)
     }
 }
 
@@ -62,3 +277,14 @@ impl UserResource<ProjectTemplate> for Project {
         &self.rel_path
     }
 }
+
+/// Copies a file, creating the parent folders of the destination if needed.
+fn copy_file(from: &Path, to: &Path) -> Result {
+    fs::create_dir_all(to.parent().expect("invalid file path"))?;
+    fs::copy(from, to)?;
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "./project_test.rs"]
+mod project_test;
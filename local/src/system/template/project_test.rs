@@ -0,0 +1,114 @@
+use super::*;
+use crate::system::projects;
+use dev_utils::fs::TempDir;
+use thot_core::project::{RunParameters, Script as CoreScript};
+
+#[test]
+fn template_should_create_project_with_new_ids() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let src = dir.path().join("src");
+    let (src_id, script_id) = create_source_project(&src);
+
+    // test
+    let template =
+        Project::from_project(&src, "template", false).expect("could not create template");
+    template.save().expect("could not save template");
+
+    let mut template = Project::load(&template.rid).expect("could not load template");
+    assert_eq!(2, template.graph.nodes().len());
+    assert!(
+        template.graph.nodes().values().all(|c| c.assets.is_empty()),
+        "assets should be excluded"
+    );
+
+    let dst = dir.path().join("dst");
+    let dst_id = template
+        .create_project(&dst)
+        .expect("could not create project");
+
+    assert_ne!(src_id, dst_id);
+    assert!(template.children.contains(&dst_id));
+    let saved = Project::load(&template.rid).expect("could not load template");
+    assert!(saved.children.contains(&dst_id), "children should be saved");
+
+    let dst_prj = LocalProject::load_from(&dst).expect("could not load project");
+    let data_root = dst_prj.data_root_path().expect("data root not set");
+    let graph = ContainerTreeLoader::load(&data_root).expect("could not load graph");
+    assert_eq!(2, graph.nodes().len());
+    for rid in graph.nodes().keys() {
+        assert!(
+            template.graph.get(rid).is_none(),
+            "`Container` ids should be new"
+        );
+    }
+
+    let child = graph
+        .get(graph.children(graph.root()).unwrap().iter().next().unwrap())
+        .unwrap();
+    assert_eq!("child", child.properties.name);
+
+    let scripts = LocalScripts::load_from(&dst).expect("could not load scripts");
+    assert_eq!(1, scripts.len());
+    let dst_script = scripts.values().next().unwrap();
+    assert_ne!(script_id, dst_script.rid, "`Script` ids should be new");
+    assert!(child.scripts.contains_key(&dst_script.rid));
+    assert!(dst_prj
+        .analysis_root_path()
+        .unwrap()
+        .join("analysis.py")
+        .exists());
+
+    // clean up
+    fs::remove_dir_all(template.dir()).expect("could not remove template");
+    projects::deregister_project(&src_id).expect("could not deregister project");
+    projects::deregister_project(&dst_id).expect("could not deregister project");
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+/// Creates a `Project` at the given path with a data root containing a single child
+/// `Container` with an `Asset` and a `Script` association.
+///
+/// # Returns
+/// Ids of the `Project` and `Script`.
+fn create_source_project(path: &Path) -> (ResourceId, ResourceId) {
+    let rid = project::new(path).expect("could not create project");
+    let mut prj = LocalProject::load_from(path).expect("could not load project");
+    prj.data_root = Some(PathBuf::from("data"));
+    prj.analysis_root = Some(PathBuf::from("analysis"));
+    prj.save().expect("could not save project");
+
+    let analysis_root = prj.analysis_root_path().unwrap();
+    fs::create_dir_all(&analysis_root).expect("could not create analysis root");
+    fs::write(analysis_root.join("analysis.py"), "").expect("could not write script");
+
+    let script = CoreScript::new(ResourcePath::Relative(PathBuf::from("analysis.py")))
+        .expect("could not create script");
+    let script_id = script.rid.clone();
+    let mut scripts = LocalScripts::load_from(path).expect("could not load scripts");
+    scripts.insert_script(script).expect("could not add script");
+    scripts.save().expect("could not save scripts");
+
+    let data_root = prj.data_root_path().unwrap();
+    LocalContainer::new(&data_root)
+        .save()
+        .expect("could not save root");
+
+    let child_path = data_root.join("child");
+    let mut child = LocalContainer::new(&child_path);
+    child
+        .scripts
+        .insert(script_id.clone(), RunParameters::new());
+
+    child.insert_asset(Asset::new(ResourcePath::Relative(PathBuf::from(
+        "data.csv",
+    ))));
+
+    child.save().expect("could not save child");
+    fs::write(child_path.join("data.csv"), "").expect("could not write asset");
+
+    (rid, script_id)
+}
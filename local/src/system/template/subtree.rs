@@ -0,0 +1,206 @@
+//! `Container` subtree template.
+//!
+//! Templates are stored in the user's config directory as
+//! `templates/subtrees/<template id>.json`.
+use crate::common::{sanitize_file_path, unique_file_name};
+use crate::file_resource::UserResource;
+use crate::graph::{ContainerTreeDuplicator, ContainerTreeTransformer};
+use crate::project::resources::Container;
+use crate::system::common::config_dir_path;
+use crate::Result;
+use std::fs;
+use std::io::BufReader;
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use thot_core::graph::ResourceTree;
+use thot_core::system::template::{InstanceOptions, Subtree as SubtreeTemplate};
+use thot_core::types::ResourceId;
+
+type ContainerTree = ResourceTree<Container>;
+
+pub struct Subtree {
+    rel_path: PathBuf,
+    template: SubtreeTemplate,
+}
+
+impl Subtree {
+    pub fn new(template: SubtreeTemplate) -> Self {
+        Self {
+            rel_path: Self::rel_path_of(&template.rid),
+            template,
+        }
+    }
+
+    /// Creates a template from a subtree.
+    ///
+    /// # Arguments
+    /// 1. Graph.
+    /// 2. Id of the root of the subtree.
+    /// 3. Name of the template.
+    ///
+    /// # Notes
+    /// + The template is not saved.
+    /// + `Asset`s are not included.
+    /// + Links are not included.
+    pub fn from_graph(
+        graph: &ContainerTree,
+        root: &ResourceId,
+        name: impl Into<String>,
+    ) -> Result<Self> {
+        let dup = ContainerTreeDuplicator::duplicate(graph, root)?;
+        let dup = ContainerTreeTransformer::local_to_core(&dup);
+        Ok(Self::new(SubtreeTemplate::new(name, dup)))
+    }
+
+    pub fn load(rid: &ResourceId) -> Result<Self> {
+        let rel_path = Self::rel_path_of(rid);
+        let file = fs::File::open(Self::base_path().join(&rel_path))?;
+        let reader = BufReader::new(file);
+        let template = serde_json::from_reader(reader)?;
+
+        Ok(Self { rel_path, template })
+    }
+
+    /// Loads all saved subtree templates.
+    pub fn load_all() -> Result<Vec<SubtreeTemplate>> {
+        let base_path = Self::base_path();
+        if !base_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut templates = Vec::new();
+        for entry in fs::read_dir(base_path)? {
+            let path = entry?.path();
+            if path.extension().map_or(true, |ext| ext != "json") {
+                continue;
+            }
+
+            let file = fs::File::open(path)?;
+            let reader = BufReader::new(file);
+            templates.push(serde_json::from_reader(reader)?);
+        }
+
+        Ok(templates)
+    }
+
+    pub fn save(&self) -> Result {
+        let path = self.path();
+        fs::create_dir_all(path.parent().expect("invalid template path"))?;
+        fs::write(path, serde_json::to_string_pretty(&self.template)?)?;
+        Ok(())
+    }
+
+    /// Creates instances of the template.
+    ///
+    /// # Arguments
+    /// 1. Path to the parent `Container`.
+    /// 2. Instance options.
+    ///
+    /// # Returns
+    /// Created `Container` trees, in order.
+    pub fn instantiate(
+        &self,
+        parent: impl AsRef<Path>,
+        opts: &InstanceOptions,
+    ) -> Result<Vec<ContainerTree>> {
+        let parent = parent.as_ref();
+        let mut paths = Vec::with_capacity(opts.count);
+        let mut instances = Vec::with_capacity(opts.count);
+        for instance in 0..opts.count {
+            let name = opts.instance_name(instance);
+            let path = match unique_file_name(parent.join(sanitize_file_path(name.clone()))) {
+                Ok(path) => path,
+                Err(err) => {
+                    Self::remove_instances(&paths);
+                    return Err(err);
+                }
+            };
+
+            paths.push(path.clone());
+            match self.instantiate_to(&path, name, opts, instance) {
+                Ok(tree) => instances.push(tree),
+                Err(err) => {
+                    Self::remove_instances(&paths);
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(instances)
+    }
+
+    /// Creates a single instance of the template.
+    fn instantiate_to(
+        &self,
+        path: &Path,
+        name: String,
+        opts: &InstanceOptions,
+        instance: usize,
+    ) -> Result<ContainerTree> {
+        let mut tree = ContainerTreeDuplicator::duplicate_core_without_assets_to(
+            path,
+            &self.graph,
+            self.graph.root(),
+        )?;
+
+        let root = tree.root().clone();
+        let root = tree.get_mut(&root).expect("root not in graph");
+        root.properties.name = name;
+        if let Some(metadata) = opts.instance_metadata(instance) {
+            root.properties.metadata.extend(metadata.clone());
+        }
+
+        root.save()?;
+        Ok(tree)
+    }
+
+    /// Removes the folders of created instances.
+    /// Used to roll back a failed instantiation.
+    fn remove_instances(paths: &[PathBuf]) {
+        for path in paths {
+            if path.exists() {
+                if let Err(err) = fs::remove_dir_all(path) {
+                    tracing::error!(?err, ?path, "could not remove template instance");
+                }
+            }
+        }
+    }
+
+    fn rel_path_of(rid: &ResourceId) -> PathBuf {
+        PathBuf::from(format!("{rid}.json"))
+    }
+}
+
+impl Deref for Subtree {
+    type Target = SubtreeTemplate;
+
+    fn deref(&self) -> &Self::Target {
+        &self.template
+    }
+}
+
+impl DerefMut for Subtree {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.template
+    }
+}
+
+impl UserResource<SubtreeTemplate> for Subtree {
+    /// Returns the base path to the templates folder.
+    fn base_path() -> PathBuf {
+        let mut path = config_dir_path().expect("could not get config path");
+        path.push("templates");
+        path.push("subtrees");
+
+        path
+    }
+
+    /// Returns the relative path for the template.
+    fn rel_path(&self) -> &Path {
+        &self.rel_path
+    }
+}
+
+#[cfg(test)]
+#[path = "./subtree_test.rs"]
+mod subtree_test;
@@ -0,0 +1,155 @@
+use super::*;
+use crate::graph::ContainerTreeLoader;
+use dev_utils::fs::TempDir;
+use thot_core::project::RunParameters;
+
+#[test]
+fn instantiate_should_work() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let script = ResourceId::new();
+    let (graph, subject) = create_tree(dir.path(), &script);
+    let template =
+        Subtree::from_graph(&graph, &subject, "subject").expect("could not create template");
+
+    let parent = dir.path().join("parent");
+    Container::new(&parent)
+        .save()
+        .expect("could not save parent");
+
+    let mut opts = InstanceOptions::new("Subject {i:02}", 2);
+    let mut metadata = thot_core::project::Metadata::new();
+    metadata.insert("age".to_string(), 30.into());
+    opts.metadata.push(metadata);
+
+    // test
+    for node in template.graph.nodes().values() {
+        assert!(
+            node.properties.links.is_empty(),
+            "links should not be saved"
+        );
+    }
+
+    let instances = template
+        .instantiate(&parent, &opts)
+        .expect("could not instantiate template");
+
+    assert_eq!(2, instances.len());
+    for (instance, name) in instances.iter().zip(["Subject 01", "Subject 02"]) {
+        let root = instance.get(instance.root()).unwrap();
+        assert_eq!(name, root.properties.name);
+        assert_eq!(parent.join(name), root.base_path());
+        assert_eq!(2, instance.nodes().len());
+        for rid in instance.nodes().keys() {
+            assert!(graph.get(rid).is_none(), "ids should be new");
+            assert!(template.graph.get(rid).is_none(), "ids should be new");
+        }
+
+        let loaded = ContainerTreeLoader::load(root.base_path()).expect("could not load instance");
+        assert_eq!(2, loaded.nodes().len());
+
+        let session = loaded
+            .get(
+                loaded
+                    .children(loaded.root())
+                    .unwrap()
+                    .iter()
+                    .next()
+                    .unwrap(),
+            )
+            .unwrap();
+
+        assert_eq!("Session", session.properties.name);
+        assert!(session.properties.links.is_empty());
+        assert!(session.scripts.contains_key(&script));
+    }
+
+    let first = instances[0].get(instances[0].root()).unwrap();
+    let second = instances[1].get(instances[1].root()).unwrap();
+    assert_eq!(Some(&30.into()), first.properties.metadata.get("age"));
+    assert!(second.properties.metadata.get("age").is_none());
+}
+
+#[test]
+fn instantiate_should_give_children_unique_folders() {
+    // setup
+    let dir = TempDir::new().expect("could not create temp dir");
+    let script = ResourceId::new();
+    let (graph, subject) = create_tree(dir.path(), &script);
+    let mut template =
+        Subtree::from_graph(&graph, &subject, "subject").expect("could not create template");
+
+    let root = template.graph.root().clone();
+    let children = template.graph.children(&root).unwrap().clone();
+    let session = children.iter().next().unwrap();
+    let mut sibling = template.graph.get(session).unwrap().data().clone();
+    sibling.rid = ResourceId::new();
+    let sibling_id = sibling.rid.clone();
+    template.graph.insert(root, sibling).unwrap();
+    for rid in children.iter().chain([&sibling_id]) {
+        template.graph.get_mut(rid).unwrap().properties.name = "Session/1".to_string();
+    }
+
+    let parent = dir.path().join("parent");
+    Container::new(&parent)
+        .save()
+        .expect("could not save parent");
+
+    // test
+    let instances = template
+        .instantiate(&parent, &InstanceOptions::new("Subject", 1))
+        .expect("could not instantiate template");
+
+    let instance = &instances[0];
+    let mut paths = instance
+        .children(instance.root())
+        .unwrap()
+        .iter()
+        .map(|child| instance.get(child).unwrap().base_path().to_path_buf())
+        .collect::<Vec<_>>();
+
+    paths.sort();
+    paths.dedup();
+    assert_eq!(2, paths.len(), "children should have unique folders");
+    for path in paths {
+        assert_eq!(parent.join("Subject"), path.parent().unwrap());
+        assert!(path.exists());
+    }
+}
+
+// ***************
+// *** helpers ***
+// ***************
+
+/// Creates the tree
+/// root
+/// + Subject (with an `Asset`)
+///   + Session (with a `Script` association)
+///
+/// # Returns
+/// The tree and the id of `Subject`.
+fn create_tree(path: &Path, script: &ResourceId) -> (ContainerTree, ResourceId) {
+    let root = Container::new(path.join("root"));
+    let root_id = root.rid.clone();
+    root.save().expect("could not save root");
+
+    let mut subject = Container::new(path.join("root").join("Subject"));
+    subject.insert_asset(thot_core::project::Asset::new(
+        thot_core::types::ResourcePath::Relative(PathBuf::from("data.csv")),
+    ));
+    subject.save().expect("could not save subject");
+    let subject_id = subject.rid.clone();
+
+    let mut session = Container::new(path.join("root").join("Subject").join("Session"));
+    session.scripts.insert(script.clone(), RunParameters::new());
+    session
+        .properties
+        .links
+        .insert("subject", subject_id.clone());
+    session.save().expect("could not save session");
+
+    let mut graph = ResourceTree::new(root);
+    graph.insert(root_id, subject).unwrap();
+    graph.insert(subject_id.clone(), session).unwrap();
+    (graph, subject_id)
+}
@@ -112,6 +112,12 @@ pub fn get_active_user() -> Result<Option<User>> {
     user_by_id(&active_user)
 }
 
+/// Gets the id of the active user.
+pub fn get_active_user_id() -> Result<Option<ResourceId>> {
+    let user_settings = UserSettings::load_or_default()?;
+    Ok(user_settings.active_user)
+}
+
 /// Sets the active user in the system settings.
 ///
 /// # Errors
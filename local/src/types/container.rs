@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use thot_core::types::UserPermissions;
+use thot_core::types::{ResourceId, UserPermissions};
 
 // *************************
 // *** ContainerSettings ***
@@ -11,4 +11,9 @@ use thot_core::types::UserPermissions;
 pub struct ContainerSettings {
     #[serde(default)]
     pub permissions: Vec<UserPermissions>,
+
+    /// Order of the child `Container`s.
+    /// Children not listed are placed after the listed ones, ordered by folder name.
+    #[serde(default)]
+    pub children: Vec<ResourceId>,
 }
@@ -5,4 +5,4 @@ pub mod project_settings;
 // Re-exports
 pub use asset::AssetFileAction;
 pub use container::ContainerSettings;
-pub use project_settings::ProjectSettings;
+pub use project_settings::{ProjectSettings, SavedQuery};
@@ -1,6 +1,8 @@
 use crate::constants::PROJECT_FORMAT_VERSION;
 use serde::{Deserialize, Serialize};
-use thot_core::types::{ResourceMap, UserPermissions};
+use thot_core::db::StandardSearchFilter;
+use thot_core::project::{MetadataSchemas, Vocabularies};
+use thot_core::types::{ResourceId, ResourceMap, UserPermissions};
 
 /// Settings for a local Project.
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
@@ -8,6 +10,48 @@ pub struct ProjectSettings {
     /// Format standard for the Project.
     pub local_format_version: String,
     pub permissions: ResourceMap<UserPermissions>,
+
+    /// Saved queries.
+    #[serde(default)]
+    pub queries: Vec<SavedQuery>,
+
+    /// Metadata schemas of resource kinds.
+    #[serde(default)]
+    pub schemas: MetadataSchemas,
+
+    /// Controlled vocabularies of tags and kinds.
+    #[serde(default)]
+    pub vocabularies: Vocabularies,
+}
+
+impl ProjectSettings {
+    /// Gets a saved query by name.
+    pub fn query(&self, name: &str) -> Option<&SavedQuery> {
+        self.queries.iter().find(|query| query.name == name)
+    }
+
+    /// Saves a query, replacing any with the same name.
+    ///
+    /// # Returns
+    /// The replaced query.
+    pub fn insert_query(&mut self, query: SavedQuery) -> Option<SavedQuery> {
+        match self.queries.iter_mut().find(|q| q.name == query.name) {
+            Some(current) => Some(std::mem::replace(current, query)),
+            None => {
+                self.queries.push(query);
+                None
+            }
+        }
+    }
+
+    /// Removes a saved query by name.
+    ///
+    /// # Returns
+    /// The removed query.
+    pub fn remove_query(&mut self, name: &str) -> Option<SavedQuery> {
+        let index = self.queries.iter().position(|query| query.name == name)?;
+        Some(self.queries.remove(index))
+    }
 }
 
 impl Default for ProjectSettings {
@@ -15,6 +59,37 @@ impl Default for ProjectSettings {
         Self {
             local_format_version: PROJECT_FORMAT_VERSION.to_string(),
             permissions: ResourceMap::default(),
+            queries: Vec::new(),
+            schemas: MetadataSchemas::default(),
+            vocabularies: Vocabularies::default(),
         }
     }
 }
+
+/// A named search filter.
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
+pub struct SavedQuery {
+    /// Name of the query.
+    /// Unique within a Project.
+    pub name: String,
+
+    pub filter: StandardSearchFilter,
+
+    /// Root `Container` to search from.
+    /// If `None`, the caller's root is used.
+    pub root: Option<ResourceId>,
+}
+
+impl SavedQuery {
+    pub fn new(name: impl Into<String>, filter: StandardSearchFilter) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+            root: None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "./project_settings_test.rs"]
+mod project_settings_test;
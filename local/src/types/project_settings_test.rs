@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn project_settings_insert_query_should_replace_by_name() {
+    // setup
+    let mut settings = ProjectSettings::default();
+    let mut filter = StandardSearchFilter::default();
+    filter.kind = Some(Some("run".to_string()));
+
+    // test
+    let old = settings.insert_query(SavedQuery::new("good_runs", filter.clone()));
+    assert!(old.is_none(), "no query should be replaced");
+
+    filter.kind = Some(Some("trial".to_string()));
+    let old = settings.insert_query(SavedQuery::new("good_runs", filter.clone()));
+    let old = old.expect("query should be replaced");
+    assert_eq!(Some(Some("run".to_string())), old.filter.kind);
+    assert_eq!(
+        1,
+        settings.queries.len(),
+        "queries should be unique by name"
+    );
+    assert_eq!(
+        filter,
+        settings.query("good_runs").unwrap().filter,
+        "query should be updated"
+    );
+
+    let removed = settings.remove_query("good_runs");
+    assert!(removed.is_some(), "query should be removed");
+    assert!(settings.query("good_runs").is_none());
+}
+
+#[test]
+fn project_settings_should_deserialize_without_queries() {
+    let settings = r#"{"local_format_version": "0.0.0", "permissions": {}}"#;
+    let settings: ProjectSettings =
+        serde_json::from_str(settings).expect("could not deserialize settings");
+
+    assert!(settings.queries.is_empty());
+}
@@ -1,8 +1,12 @@
 //! Properties editor for [`Contaier`](thot_core::project::Container)s.
+use super::super::common::creator::creator_display_name;
+use super::super::metadata::DerivedMetadataEditor;
 use super::super::{MetadataEditor, TagsEditor};
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
-use thot_core::project::{AssetProperties, Metadata};
+use thot_core::project::{
+    AssetProperties, DerivedMetadata, Metadata, MetadataChange, MetadataSchema,
+};
 use yew::prelude::*;
 
 // ************************
@@ -17,6 +21,7 @@ enum AssetPropertiesStateAction {
     ClearDescription,
     SetTags(Vec<String>),
     SetMetadata(Metadata),
+    SetDerived(DerivedMetadata),
     Update(AssetProperties),
 }
 
@@ -87,6 +92,10 @@ impl Reducible for AssetPropertiesState {
                 current.metadata = metadata;
             }
 
+            AssetPropertiesStateAction::SetDerived(derived) => {
+                current.derived = derived;
+            }
+
             AssetPropertiesStateAction::Update(properties) => {
                 return Self(properties).into();
             }
@@ -110,6 +119,19 @@ pub struct AssetPropertiesEditorProps {
     #[prop_or_else(AssetProperties::new)]
     pub properties: AssetProperties,
 
+    /// Tags suggested while editing,
+    /// e.g. the terms of the `Project`'s tag vocabulary.
+    #[prop_or_default]
+    pub tag_suggestions: Vec<String>,
+
+    /// History of changes to the metadata, oldest first.
+    #[prop_or_default]
+    pub metadata_history: Vec<MetadataChange>,
+
+    /// Schema of the resource's kind.
+    #[prop_or_default]
+    pub schema: Option<MetadataSchema>,
+
     /// Callback when value changes.
     #[prop_or_default]
     pub onchange: Callback<AssetProperties>,
@@ -227,6 +249,16 @@ pub fn asset_properties_editor(props: &AssetPropertiesEditorProps) -> Html {
         })
     };
 
+    let onchange_derived = {
+        let properties_state = properties_state.clone();
+        let dirty_state = dirty_state.clone();
+
+        Callback::from(move |value: DerivedMetadata| {
+            properties_state.dispatch(AssetPropertiesStateAction::SetDerived(value));
+            dirty_state.set(true);
+        })
+    };
+
     {
         let properties_state = properties_state.clone();
         let dirty_state = dirty_state.clone();
@@ -280,6 +312,7 @@ pub fn asset_properties_editor(props: &AssetPropertiesEditorProps) -> Html {
                     <h3> { "Tags" } </h3>
                     <TagsEditor
                         value={properties_state.tags.clone()}
+                        suggestions={props.tag_suggestions.clone()}
                         onchange={onchange_tags} />
                 </label>
             </div>
@@ -287,8 +320,31 @@ pub fn asset_properties_editor(props: &AssetPropertiesEditorProps) -> Html {
             <div class={classes!("form-field", "metadata")}>
                 <MetadataEditor
                     value={properties_state.metadata.clone()}
+                    history={props.metadata_history.clone()}
+                    schema={props.schema.clone()}
                     onchange={onchange_metadata} />
             </div>
+
+            <div class={classes!("form-field", "derived")}>
+                <h3>{ "Derived metadata" }</h3>
+                <DerivedMetadataEditor
+                    value={properties_state.derived.clone()}
+                    onchange={onchange_derived} />
+            </div>
+
+            if let Some(modified) = properties_state.modified() {
+                <div class={classes!("form-field", "modified")}>
+                    <h3>{ "Last modified" }</h3>
+                    <span class={classes!("modified-at")}>
+                        { modified.format("%Y-%m-%d %H:%M UTC").to_string() }
+                    </span>
+                    if let Some(modified_by) = properties_state.modified_by.as_ref() {
+                        <span class={classes!("modified-by")}>
+                            { format!(" by {}", creator_display_name(modified_by)) }
+                        </span>
+                    }
+                </div>
+            }
         </form>
     }
 }
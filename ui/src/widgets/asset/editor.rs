@@ -1,6 +1,6 @@
 //! [`Asset`](thot_core::project::Asset) editor.
 use super::AssetPropertiesEditor;
-use thot_core::project::{Asset, AssetProperties};
+use thot_core::project::{Asset, AssetProperties, MetadataChange, MetadataSchema};
 use yew::prelude::*;
 
 /// Properties for [`AssetEditor`].
@@ -11,6 +11,18 @@ pub struct AssetEditorProps {
 
     pub asset: Asset,
 
+    /// Tags suggested while editing.
+    #[prop_or_default]
+    pub tag_suggestions: Vec<String>,
+
+    /// History of changes to the `Asset`'s metadata.
+    #[prop_or_default]
+    pub metadata_history: Vec<MetadataChange>,
+
+    /// Schema of the `Asset`'s kind.
+    #[prop_or_default]
+    pub schema: Option<MetadataSchema>,
+
     pub onchange_properties: Callback<AssetProperties>,
 }
 
@@ -23,6 +35,9 @@ pub fn asset_editor(props: &AssetEditorProps) -> Html {
         <div key={props.asset.rid.clone()} {class}>
             <AssetPropertiesEditor
                 properties={props.asset.properties.clone()}
+                tag_suggestions={props.tag_suggestions.clone()}
+                metadata_history={props.metadata_history.clone()}
+                schema={props.schema.clone()}
                 onchange={props.onchange_properties.clone()}/>
 
             <div class={classes!("thot-ui-asset-file_name")}>
@@ -78,3 +78,18 @@ pub mod asset {
         format!("color: {}", color)
     }
 }
+
+pub mod creator {
+    //! Common Creator functionality.
+    use thot_core::types::{Creator, UserId};
+
+    /// Gets the name to display for a [`Creator`].
+    pub fn creator_display_name(creator: &Creator) -> String {
+        match creator {
+            Creator::User(None) => "(unknown user)".to_string(),
+            Creator::User(Some(UserId::Email(email))) => email.clone(),
+            Creator::User(Some(UserId::Id(user))) => user.to_string(),
+            Creator::Script(script) => format!("script {script}"),
+        }
+    }
+}
@@ -1,8 +1,13 @@
 //! Properties editor for [`Contaier`](thot_core::project::Container)s.
+use super::super::common::creator::creator_display_name;
+use super::super::metadata::{DerivedMetadataEditor, MetadataInheritanceEditor};
 use super::super::{MetadataEditor, TagsEditor};
 use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
-use thot_core::project::{ContainerProperties, Metadata};
+use thot_core::project::{
+    ContainerProperties, DerivedMetadata, Metadata, MetadataChange, MetadataInheritance,
+    MetadataSchema,
+};
 use yew::prelude::*;
 
 // ************************
@@ -16,6 +21,8 @@ enum ContainerPropertiesStateAction {
     ClearDescription,
     SetTags(Vec<String>),
     SetMetadata(Metadata),
+    SetInheritance(MetadataInheritance),
+    SetDerived(DerivedMetadata),
     Update(ContainerProperties),
 }
 
@@ -79,9 +86,18 @@ impl Reducible for ContainerPropertiesState {
             }
 
             ContainerPropertiesStateAction::SetMetadata(metadata) => {
+                current.inheritance.retain_keys(&metadata);
                 current.metadata = metadata;
             }
 
+            ContainerPropertiesStateAction::SetInheritance(inheritance) => {
+                current.inheritance = inheritance;
+            }
+
+            ContainerPropertiesStateAction::SetDerived(derived) => {
+                current.derived = derived;
+            }
+
             ContainerPropertiesStateAction::Update(properties) => {
                 return Self(properties).into();
             }
@@ -104,6 +120,19 @@ pub struct ContainerPropertiesEditorProps {
     /// Initial value.
     pub properties: ContainerProperties,
 
+    /// Tags suggested while editing,
+    /// e.g. the terms of the `Project`'s tag vocabulary.
+    #[prop_or_default]
+    pub tag_suggestions: Vec<String>,
+
+    /// History of changes to the metadata, oldest first.
+    #[prop_or_default]
+    pub metadata_history: Vec<MetadataChange>,
+
+    /// Schema of the resource's kind.
+    #[prop_or_default]
+    pub schema: Option<MetadataSchema>,
+
     /// Callback when value changes.
     #[prop_or_default]
     pub onchange: Callback<ContainerProperties>,
@@ -214,6 +243,26 @@ pub fn container_properties_editor(props: &ContainerPropertiesEditorProps) -> Ht
         })
     };
 
+    let onchange_inheritance = {
+        let properties_state = properties_state.clone();
+        let dirty_state = dirty_state.clone();
+
+        Callback::from(move |value: MetadataInheritance| {
+            properties_state.dispatch(ContainerPropertiesStateAction::SetInheritance(value));
+            dirty_state.set(true);
+        })
+    };
+
+    let onchange_derived = {
+        let properties_state = properties_state.clone();
+        let dirty_state = dirty_state.clone();
+
+        Callback::from(move |value: DerivedMetadata| {
+            properties_state.dispatch(ContainerPropertiesStateAction::SetDerived(value));
+            dirty_state.set(true);
+        })
+    };
+
     {
         let properties_state = properties_state.clone();
         let dirty_state = dirty_state.clone();
@@ -268,6 +317,7 @@ pub fn container_properties_editor(props: &ContainerPropertiesEditorProps) -> Ht
                     <h3>{ "Tags" }</h3>
                     <TagsEditor
                         value={properties_state.tags.clone()}
+                        suggestions={props.tag_suggestions.clone()}
                         onchange={onchange_tags} />
                 </label>
             </div>
@@ -275,8 +325,41 @@ pub fn container_properties_editor(props: &ContainerPropertiesEditorProps) -> Ht
             <div class={classes!("form-field", "metadata")}>
                 <MetadataEditor
                     value={properties_state.metadata.clone()}
+                    history={props.metadata_history.clone()}
+                    schema={props.schema.clone()}
                     onchange={onchange_metadata} />
             </div>
+
+            if !properties_state.metadata.is_empty() {
+                <div class={classes!("form-field", "inheritance")}>
+                    <h3>{ "Inheritance" }</h3>
+                    <MetadataInheritanceEditor
+                        metadata={properties_state.metadata.clone()}
+                        value={properties_state.inheritance.clone()}
+                        onchange={onchange_inheritance} />
+                </div>
+            }
+
+            <div class={classes!("form-field", "derived")}>
+                <h3>{ "Derived metadata" }</h3>
+                <DerivedMetadataEditor
+                    value={properties_state.derived.clone()}
+                    onchange={onchange_derived} />
+            </div>
+
+            if let Some(modified) = properties_state.modified() {
+                <div class={classes!("form-field", "modified")}>
+                    <h3>{ "Last modified" }</h3>
+                    <span class={classes!("modified-at")}>
+                        { modified.format("%Y-%m-%d %H:%M UTC").to_string() }
+                    </span>
+                    if let Some(modified_by) = properties_state.modified_by.as_ref() {
+                        <span class={classes!("modified-by")}>
+                            { format!(" by {}", creator_display_name(modified_by)) }
+                        </span>
+                    }
+                </div>
+            }
         </form>
     }
 }
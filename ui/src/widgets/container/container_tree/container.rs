@@ -28,6 +28,12 @@ pub enum ContainerMenuEvent {
     /// Duplicate the `Contiainer` tree.
     DuplicateTree,
 
+    /// Save the `Container` tree as a template.
+    SaveTemplate,
+
+    /// Add `Container` trees from a template.
+    AddFromTemplate,
+
     /// Remove the `Contiainer` tree.
     Remove,
 }
@@ -78,6 +84,14 @@ fn container_menu(props: &ContainerMenuProps) -> Html {
                 { "Add data" }
             </li>
 
+            <li onclick={onclick(ContainerMenuEvent::AddFromTemplate)}>
+                { "Add from template" }
+            </li>
+
+            <li onclick={onclick(ContainerMenuEvent::SaveTemplate)}>
+                { "Save as template" }
+            </li>
+
             { if props.is_root { html!{} } else { html!{
                 <>
                 <li onclick={onclick(ContainerMenuEvent::DuplicateTree)}>
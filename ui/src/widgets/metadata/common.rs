@@ -3,6 +3,8 @@ use super::MetadatumType;
 use serde_json::{Result as JsResult, Value as JsValue};
 use std::result::Result as StdResult;
 use std::str::FromStr;
+use thot_core::project::{DateTimeValue, Link, Quantity};
+use thot_core::types::ResourceId;
 use yew::prelude::NodeRef;
 
 /// Converts a string to a number.
@@ -111,6 +113,39 @@ pub fn value_from_input(value_ref: NodeRef, kind: &MetadatumType) -> JsResult<Js
                 false => serde_json::from_str(&val)?,
             }
         }
+
+        MetadatumType::Quantity => {
+            let v_in = value_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not convert value node ref into input");
+
+            match v_in.value().parse::<Quantity>() {
+                Ok(val) => val.into(),
+                Err(_) => JsValue::Null,
+            }
+        }
+
+        MetadatumType::DateTime => {
+            let v_in = value_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not convert value node ref into input");
+
+            match v_in.value().parse::<DateTimeValue>() {
+                Ok(val) => val.into(),
+                Err(_) => JsValue::Null,
+            }
+        }
+
+        MetadatumType::Link => {
+            let v_in = value_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not convert value node ref into input");
+
+            match v_in.value().trim().parse::<ResourceId>() {
+                Ok(rid) => Link(rid).into(),
+                Err(_) => JsValue::Null,
+            }
+        }
     };
 
     Ok(value)
@@ -121,6 +156,29 @@ pub fn value_from_input(value_ref: NodeRef, kind: &MetadatumType) -> JsResult<Js
 #[tracing::instrument]
 pub fn convert_value(value: JsValue, target: &MetadatumType) -> JsValue {
     match (value.clone(), target.clone()) {
+        (_, MetadatumType::Quantity) if Quantity::is_quantity(&value) => value,
+        (_, MetadatumType::DateTime) if DateTimeValue::is_datetime(&value) => value,
+        (_, MetadatumType::Link) if Link::is_link(&value) => value,
+        (JsValue::Object(_), MetadatumType::String)
+            if DateTimeValue::is_datetime(&value) || Link::is_link(&value) =>
+        {
+            value_display(&value).into()
+        }
+
+        (JsValue::String(value), MetadatumType::DateTime) => value
+            .parse::<DateTimeValue>()
+            .unwrap_or_else(|_| DateTimeValue::now())
+            .into(),
+
+        (JsValue::String(value), MetadatumType::Link) => match value.trim().parse::<ResourceId>() {
+            Ok(rid) => Link(rid).into(),
+            Err(_) => empty_link(),
+        },
+        (JsValue::Object(_), MetadatumType::Number) if Quantity::is_quantity(&value) => {
+            let value = Quantity::from_value(&value).expect("value should be a quantity");
+            value.magnitude.into()
+        }
+
         (JsValue::String(_), MetadatumType::String)
         | (JsValue::Number(_), MetadatumType::Number)
         | (JsValue::Bool(_), MetadatumType::Bool)
@@ -133,6 +191,19 @@ pub fn convert_value(value: JsValue, target: &MetadatumType) -> JsValue {
         },
 
         (JsValue::Number(value), MetadatumType::String) => value.to_string().into(),
+        (JsValue::Number(value), MetadatumType::Quantity) => {
+            Quantity::new(value.as_f64().unwrap_or_default(), "").into()
+        }
+
+        (JsValue::String(value), MetadatumType::Quantity) => match value.parse::<Quantity>() {
+            Ok(val) => val.into(),
+            Err(_) => Quantity::new(0.0, "").into(),
+        },
+
+        (JsValue::Object(_), MetadatumType::String) if Quantity::is_quantity(&value) => {
+            let value = Quantity::from_value(&value).expect("value should be a quantity");
+            value.to_string().into()
+        }
 
         (JsValue::Array(value), MetadatumType::String) => serde_json::to_string_pretty(&value)
             .unwrap_or(String::default())
@@ -165,5 +236,34 @@ pub fn convert_value(value: JsValue, target: &MetadatumType) -> JsValue {
         (_, MetadatumType::Bool) => JsValue::Bool(false),
         (_, MetadatumType::Array) => JsValue::Array(Vec::default()),
         (_, MetadatumType::Object) => JsValue::Object(serde_json::Map::default()),
+        (_, MetadatumType::Quantity) => Quantity::new(0.0, "").into(),
+        (_, MetadatumType::DateTime) => DateTimeValue::now().into(),
+        (_, MetadatumType::Link) => empty_link(),
     }
 }
+
+/// A [`Link`] to no resource, used until a target is set.
+fn empty_link() -> JsValue {
+    Link(ResourceId::from(uuid::Uuid::nil())).into()
+}
+
+/// Text to display for a metadatum value.
+pub fn value_display(value: &JsValue) -> String {
+    if value.is_null() {
+        return "(no value)".to_string();
+    }
+
+    if let Some(quantity) = Quantity::from_value(value) {
+        return quantity.to_string();
+    }
+
+    if let Some(datetime) = DateTimeValue::from_value(value) {
+        return datetime.0.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+    }
+
+    if let Some(link) = Link::from_value(value) {
+        return link.to_string();
+    }
+
+    value.to_string()
+}
@@ -0,0 +1,158 @@
+//! Editor for the expressions of derived metadata.
+use thot_core::project::expression::Expression;
+use thot_core::project::DerivedMetadata;
+use yew::prelude::*;
+
+#[derive(PartialEq, Properties)]
+pub struct DerivedMetadataEditorProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    pub value: DerivedMetadata,
+
+    #[prop_or_default]
+    pub onchange: Callback<DerivedMetadata>,
+}
+
+/// Adds, edits, and removes derived metadata.
+/// Expressions are checked for syntax errors as they are edited.
+#[function_component(DerivedMetadataEditor)]
+pub fn derived_metadata_editor(props: &DerivedMetadataEditorProps) -> Html {
+    let key_ref = use_node_ref();
+    let expression_ref = use_node_ref();
+    let error = use_state(|| None::<String>);
+
+    let onchange_expression = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+
+        move |key: String| {
+            let value = value.clone();
+            let onchange = onchange.clone();
+
+            Callback::from(move |e: Event| {
+                let elm = e
+                    .target_dyn_into::<web_sys::HtmlInputElement>()
+                    .expect("could not cast target into input");
+
+                let mut value = value.clone();
+                value.insert(key.clone(), elm.value().trim().to_string());
+                onchange.emit(value);
+            })
+        }
+    };
+
+    let onremove = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+
+        move |key: String| {
+            let value = value.clone();
+            let onchange = onchange.clone();
+
+            Callback::from(move |_: MouseEvent| {
+                let mut value = value.clone();
+                value.remove(&key);
+                onchange.emit(value);
+            })
+        }
+    };
+
+    let onadd = {
+        let value = props.value.clone();
+        let onchange = props.onchange.clone();
+        let key_ref = key_ref.clone();
+        let expression_ref = expression_ref.clone();
+        let error = error.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            let key_input = key_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not cast key node ref into input");
+
+            let expression_input = expression_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not cast expression node ref into input");
+
+            let key = key_input.value().trim().to_string();
+            let expression = expression_input.value().trim().to_string();
+            if key.is_empty() {
+                error.set(Some("Key can not be empty".to_string()));
+                return;
+            }
+
+            if value.contains_key(&key) {
+                error.set(Some("Key already exists".to_string()));
+                return;
+            }
+
+            if let Err(err) = Expression::parse(&expression) {
+                error.set(Some(err.to_string()));
+                return;
+            }
+
+            error.set(None);
+            key_input.set_value("");
+            expression_input.set_value("");
+
+            let mut value = value.clone();
+            value.insert(key, expression);
+            onchange.emit(value);
+        })
+    };
+
+    let mut keys = props.value.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let class = classes!("thot-ui-derived-metadata-editor", props.class.clone());
+    html! {
+        <div {class}>
+            <ol>
+                { keys.into_iter().map(|key| {
+                    let expression = props.value.get(key).expect("key should exist");
+                    let parse_error = Expression::parse(expression).err();
+
+                    html! {
+                        <li key={key.clone()}
+                            class={classes!(parse_error.is_some().then_some("invalid"))}>
+
+                            <span class={classes!("metadatum-key")} title={key.clone()}>
+                                { key }
+                            </span>
+
+                            <input
+                                class={classes!("expression")}
+                                value={expression.clone()}
+                                onchange={onchange_expression(key.clone())} />
+
+                            <button
+                                type={"button"}
+                                title={"Remove"}
+                                onclick={onremove(key.clone())}>
+                                { "-" }
+                            </button>
+
+                            if let Some(err) = parse_error {
+                                <div class={classes!("error")}>{ err.to_string() }</div>
+                            }
+                        </li>
+                    }
+                }).collect::<Html>() }
+            </ol>
+
+            <div class={classes!("add-derived-metadatum")}>
+                <input ref={key_ref} class={classes!("key")} placeholder={"Key"} />
+                <input
+                    ref={expression_ref}
+                    class={classes!("expression")}
+                    placeholder={"Expression, e.g. mass / volume"} />
+
+                <button type={"button"} title={"Add"} onclick={onadd}>{ "+" }</button>
+
+                if let Some(err) = (*error).clone() {
+                    <div class={classes!("error")}>{ err }</div>
+                }
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,69 @@
+//! Preview of inherited metadata.
+use super::common;
+use std::collections::HashMap;
+use thot_core::project::{InheritMetadata, ResolvedMetadata};
+use thot_core::types::ResourceId;
+use yew::prelude::*;
+
+#[derive(PartialEq, Properties)]
+pub struct InheritedMetadataPreviewProps {
+    pub value: ResolvedMetadata,
+
+    /// Names of the `Container`s metadata is inherited from.
+    /// `Container`s without a name are displayed by id.
+    #[prop_or_default]
+    pub sources: HashMap<ResourceId, String>,
+}
+
+/// Displays effective inherited metadata and where it came from.
+#[function_component(InheritedMetadataPreview)]
+pub fn inherited_metadata_preview(props: &InheritedMetadataPreviewProps) -> Html {
+    let mut keys = props
+        .value
+        .metadata_all()
+        .keys()
+        .filter(|key| props.value.source(key).is_some())
+        .collect::<Vec<_>>();
+
+    keys.sort();
+
+    html! {
+        <ol class={classes!("thot-ui-inherited-metadata-preview")}>
+            { keys.into_iter().map(|key| {
+                let value = props.value.metadata_all().get(key).expect("key should exist");
+                let source = props.value.source(key).expect("key should be inherited");
+                let source = props
+                    .sources
+                    .get(source)
+                    .cloned()
+                    .unwrap_or_else(|| source.to_string());
+
+                let sealed = props.value.is_sealed(key);
+                let overridden = props.value.is_overridden(key);
+
+                html! {
+                    <li key={key.clone()}
+                        class={classes!(sealed.then_some("sealed"))}>
+
+                        <span class={classes!("metadatum-key")}
+                            title={key.clone()}>
+                            { key }
+                        </span>
+
+                        <span class={classes!("metadatum-value")}>
+                            { common::value_display(value) }
+                        </span>
+
+                        <span class={classes!("metadatum-source")}
+                            title={if overridden { "Sealed, overrides the owned value" } else if sealed { "Sealed" } else { "" }}>
+                            { "from " }{ source }
+                            if sealed {
+                                { " (sealed)" }
+                            }
+                        </span>
+                    </li>
+                }
+            }).collect::<Html>() }
+        </ol>
+    }
+}
@@ -1,7 +1,7 @@
 //! Inline metadata editor.
-use super::{MetadatumBuilder, MetadatumEditor};
+use super::{MetadatumBuilder, MetadatumEditor, MetadatumHistory};
 use std::collections::HashSet;
-use thot_core::project::Metadata;
+use thot_core::project::{Metadata, MetadataChange, MetadataSchema};
 use yew::prelude::*;
 use yew_icons::{Icon, IconId};
 
@@ -17,6 +17,16 @@ pub struct MetadataEditorProps {
     #[prop_or(Metadata::new())]
     pub value: Metadata,
 
+    /// History of changes to the metadata, oldest first.
+    /// Displayed per `Metadatum` if not empty.
+    #[prop_or_default]
+    pub history: Vec<MetadataChange>,
+
+    /// Schema of the metadata.
+    /// Values of keys with an `enum` are selected from its options.
+    #[prop_or_default]
+    pub schema: Option<MetadataSchema>,
+
     /// Callback triggered when the value of a single `Metadatum` is changed
     /// or a new `Metadatum` is added.
     ///
@@ -87,6 +97,23 @@ pub fn metadata_editor(props: &MetadataEditorProps) -> Html {
         }
     };
 
+    let history = |key: &String| {
+        props
+            .history
+            .iter()
+            .filter(|change| &change.key == key)
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    let options = |key: &String| {
+        props
+            .schema
+            .as_ref()
+            .and_then(|schema| schema.metadata.get(key))
+            .and_then(|schema| schema.options.clone())
+    };
+
     let name_filter = props.value.clone().into_keys().collect::<HashSet<String>>();
     let class = classes!("thot-ui-metadata-editor", props.class.clone());
 
@@ -107,17 +134,25 @@ pub fn metadata_editor(props: &MetadataEditorProps) -> Html {
                 }
             </div>
             <ol class={classes!("metadata-editor")}>
-                { props.value.clone().into_iter().map(|(name, value)| html! {
-                    <li key={name.clone()}>
-                        <MetadatumEditor
-                            name={name.clone()}
-                            {value}
-                            onchange={onchange(name.clone())}/>
-
-                        <button class={classes!("remove-button")} type="button" onclick={remove_metadatum(name)}>
-                            <Icon class={classes!("thot-ui-add-remove-icon")} icon_id={IconId::HeroiconsSolidMinus}/>
-                        </button>
-                    </li>
+                { props.value.clone().into_iter().map(|(name, value)| {
+                    let changes = history(&name);
+                    html! {
+                        <li key={name.clone()}>
+                            <MetadatumEditor
+                                name={name.clone()}
+                                {value}
+                                options={options(&name)}
+                                onchange={onchange(name.clone())}/>
+
+                            if !changes.is_empty() {
+                                <MetadatumHistory {changes} />
+                            }
+
+                            <button class={classes!("remove-button")} type="button" onclick={remove_metadatum(name)}>
+                                <Icon class={classes!("thot-ui-add-remove-icon")} icon_id={IconId::HeroiconsSolidMinus}/>
+                            </button>
+                        </li>
+                    }
                 }).collect::<Html>() }
             </ol>
         </div>
@@ -0,0 +1,91 @@
+//! Editor for how a `Container`'s metadata is inherited.
+use thot_core::project::{Metadata, MetadataInheritance};
+use yew::prelude::*;
+
+const INHERIT: &'static str = "inherit";
+const NON_INHERITABLE: &'static str = "non-inheritable";
+const SEALED: &'static str = "sealed";
+
+#[derive(PartialEq, Properties)]
+pub struct MetadataInheritanceEditorProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Metadata whose inheritance is edited.
+    pub metadata: Metadata,
+
+    pub value: MetadataInheritance,
+
+    #[prop_or_default]
+    pub onchange: Callback<MetadataInheritance>,
+}
+
+/// Sets whether each metadatum is inherited, not inherited, or sealed.
+#[function_component(MetadataInheritanceEditor)]
+pub fn metadata_inheritance_editor(props: &MetadataInheritanceEditorProps) -> Html {
+    let onchange = {
+        let value = props.value.clone();
+        let metadata = props.metadata.clone();
+        let onchange = props.onchange.clone();
+
+        move |key: String| {
+            let value = value.clone();
+            let metadata = metadata.clone();
+            let onchange = onchange.clone();
+
+            Callback::from(move |e: Event| {
+                let elm = e
+                    .target_dyn_into::<web_sys::HtmlSelectElement>()
+                    .expect("could not cast target into select");
+
+                let mut value = value.clone();
+                match elm.value().as_str() {
+                    NON_INHERITABLE => value.set_non_inheritable(key.clone()),
+                    SEALED => value.seal(key.clone()),
+                    _ => value.reset(&key),
+                }
+
+                value.retain_keys(&metadata);
+                onchange.emit(value);
+            })
+        }
+    };
+
+    let mut keys = props.metadata.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let class = classes!("thot-ui-metadata-inheritance-editor", props.class.clone());
+    html! {
+        <ol {class}>
+            { keys.into_iter().map(|key| {
+                let state = if props.value.is_sealed(key) {
+                    SEALED
+                } else if !props.value.is_inheritable(key) {
+                    NON_INHERITABLE
+                } else {
+                    INHERIT
+                };
+
+                html! {
+                    <li key={key.clone()}>
+                        <span class={classes!("metadatum-key")} title={key.clone()}>
+                            { key }
+                        </span>
+
+                        <select onchange={onchange(key.clone())}>
+                            <option value={INHERIT} selected={state == INHERIT}>
+                                { "Inherited" }
+                            </option>
+                            <option value={NON_INHERITABLE} selected={state == NON_INHERITABLE}>
+                                { "Not inherited" }
+                            </option>
+                            <option value={SEALED} selected={state == SEALED}>
+                                { "Sealed" }
+                            </option>
+                        </select>
+                    </li>
+                }
+            }).collect::<Html>() }
+        </ol>
+    }
+}
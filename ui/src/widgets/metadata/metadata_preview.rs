@@ -1,4 +1,5 @@
 //! Metadata preview.
+use super::common;
 use thot_core::project::Metadata;
 use yew::prelude::*;
 
@@ -19,11 +20,7 @@ pub fn metadata_preview(props: &MetadataPreviewProps) -> Html {
                     </span>
 
                     <span class={classes!("metadatum-value")}>
-                        if value.is_null() {
-                            { "(no value)" }
-                        } else {
-                            { value.to_string() }
-                        }
+                        { common::value_display(value) }
                     </span>
                 </li>
             }).collect::<Html>() }
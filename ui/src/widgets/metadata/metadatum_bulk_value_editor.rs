@@ -1,8 +1,9 @@
 //! Editor for a `Metadatum` value.
 use super::common;
-use super::{type_from_string, type_of_value, MetadatumType};
+use super::{type_from_string, type_of_value, MetadatumType, QuantityInput};
 use serde_json::Value as JsValue;
 use std::rc::Rc;
+use thot_core::project::{DateTimeValue, Link, Quantity};
 use yew::prelude::*;
 
 const PLACEHOLDER: &'static str = "(mixed)";
@@ -197,10 +198,22 @@ pub fn metadatum_bulk_value_editor(props: &MetadatumBulkValueEditorProps) -> Htm
         })
     };
 
+    let onchange_quantity = {
+        let state = state.clone();
+        Callback::from(move |quantity: Quantity| {
+            state.dispatch(MetadatumStateAction::Set(BulkValue::EqualValue(
+                quantity.into(),
+            )));
+        })
+    };
+
     // create <options> for `kind` <select>
     let kind_opts = [
         MetadatumType::String,
         MetadatumType::Number,
+        MetadatumType::Quantity,
+        MetadatumType::DateTime,
+        MetadatumType::Link,
         MetadatumType::Bool,
         MetadatumType::Array,
         MetadatumType::Object,
@@ -258,6 +271,35 @@ pub fn metadatum_bulk_value_editor(props: &MetadatumBulkValueEditorProps) -> Htm
                             onchange={onchange_value.clone()} />
                     },
 
+                    MetadatumType::Quantity => html! {
+                        <input
+                            ref={value_ref.clone()}
+                            value={""}
+                            {PLACEHOLDER}
+                            onchange={onchange_value.clone()} />
+                    },
+
+                    MetadatumType::DateTime => html! {
+                        <>
+                        <input
+                            ref={value_ref.clone()}
+                            type={"datetime-local"}
+                            step={"1"}
+                            value={""}
+                            onchange={onchange_value.clone()} />
+
+                        <span class={classes!("unit")}>{ "UTC" }</span>
+                        </>
+                    },
+
+                    MetadatumType::Link => html! {
+                        <input
+                            ref={value_ref.clone()}
+                            value={""}
+                            {PLACEHOLDER}
+                            onchange={onchange_value.clone()} />
+                    },
+
                     MetadatumType::Bool => html! {
                         <input
                             ref={value_ref.clone()}
@@ -311,6 +353,38 @@ pub fn metadatum_bulk_value_editor(props: &MetadatumBulkValueEditorProps) -> Htm
                             onchange={onchange_value.clone()} />
                     },
 
+                    value @ JsValue::Object(_) if Quantity::is_quantity(value) => html! {
+                        <QuantityInput
+                            value={Quantity::from_value(value).expect("value should be a quantity")}
+                            onchange={onchange_quantity.clone()}
+                            onerror={props.onerror.clone()} />
+                    },
+
+                    value @ JsValue::Object(_) if DateTimeValue::is_datetime(value) => html! {
+                        <>
+                        <input
+                            ref={value_ref}
+                            type={"datetime-local"}
+                            step={"1"}
+                            value={DateTimeValue::from_value(value)
+                                .expect("value should be a date time")
+                                .0
+                                .format("%Y-%m-%dT%H:%M:%S")
+                                .to_string()}
+                            onchange={onchange_value.clone()} />
+
+                        <span class={classes!("unit")}>{ "UTC" }</span>
+                        </>
+                    },
+
+                    value @ JsValue::Object(_) if Link::is_link(value) => html! {
+                        <input
+                            ref={value_ref}
+                            value={Link::from_value(value).expect("value should be a link").to_string()}
+                            placeholder={"Resource id"}
+                            onchange={onchange_value.clone()} />
+                    },
+
                     JsValue::Array(value) => html! {
                         <textarea
                             ref={value_ref}
@@ -10,6 +10,10 @@ pub struct MetadatumEditorProps {
     #[prop_or(JsValue::Null)]
     pub value: JsValue,
 
+    /// Allowed values.
+    #[prop_or_default]
+    pub options: Option<Vec<JsValue>>,
+
     #[prop_or_default]
     pub onchange: Callback<JsValue>,
 }
@@ -47,6 +51,7 @@ pub fn metadatum_editor(props: &MetadatumEditorProps) -> Html {
                 <MetadatumValueEditor
                     class={classes!("metadatum-value")}
                     value={props.value.clone()}
+                    options={props.options.clone()}
                     {onchange}
                     {onerror} />
             </div>
@@ -0,0 +1,76 @@
+//! History of a `Metadatum`'s changes.
+use super::common;
+use crate::widgets::common::creator::creator_display_name;
+use serde_json::Value as JsValue;
+use thot_core::project::MetadataChange;
+use yew::prelude::*;
+use yew_icons::{Icon, IconId};
+
+#[derive(Properties, PartialEq)]
+pub struct MetadatumHistoryProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// Changes to the `Metadatum`, oldest first.
+    pub changes: Vec<MetadataChange>,
+}
+
+/// Button toggling a popover with the history of a `Metadatum`.
+#[function_component(MetadatumHistory)]
+pub fn metadatum_history(props: &MetadatumHistoryProps) -> Html {
+    let open = use_state(|| false);
+
+    let toggle = {
+        let open = open.clone();
+
+        Callback::from(move |_: MouseEvent| {
+            open.set(!*open);
+        })
+    };
+
+    let class = classes!("thot-ui-metadatum-history", props.class.clone());
+    html! {
+        <div {class}>
+            <button class={classes!("history-button")}
+                type="button"
+                title="History"
+                onclick={toggle}>
+
+                <Icon icon_id={IconId::HeroiconsOutlineClock} />
+            </button>
+
+            if *open {
+                <ol class={classes!("metadatum-history-popover")}>
+                    { props.changes.iter().rev().map(|change| html! {
+                        <li>
+                            <div class={classes!("change-values")}>
+                                <span class={classes!("old-value")}>
+                                    { value_display(change.old.as_ref()) }
+                                </span>
+                                { " → " }
+                                <span class={classes!("new-value")}>
+                                    { value_display(change.new.as_ref()) }
+                                </span>
+                            </div>
+
+                            <div class={classes!("change-details")}>
+                                { change.modified.format("%Y-%m-%d %H:%M UTC").to_string() }
+                                { " by " }
+                                { creator_display_name(&change.modified_by) }
+                                { " (" }{ &change.command }{ ")" }
+                            </div>
+                        </li>
+                    }).collect::<Html>() }
+                </ol>
+            }
+        </div>
+    }
+}
+
+/// Text to display for a value in the history.
+fn value_display(value: Option<&JsValue>) -> String {
+    match value {
+        None => "(none)".to_string(),
+        Some(value) => common::value_display(value),
+    }
+}
@@ -1,7 +1,8 @@
 //! Editor for a `Metadatum` value.
 use super::common;
-use super::{type_from_string, type_of_value, MetadatumType};
+use super::{type_from_string, type_of_value, MetadatumType, QuantityInput};
 use serde_json::Value as JsValue;
+use thot_core::project::{DateTimeValue, Link, Quantity};
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq, Debug)]
@@ -12,6 +13,11 @@ pub struct MetadatumValueEditorProps {
     #[prop_or(JsValue::Null)]
     pub value: JsValue,
 
+    /// Allowed values, e.g. the `enum` of the `Metadatum`'s schema.
+    /// If set, the value is selected from them.
+    #[prop_or_default]
+    pub options: Option<Vec<JsValue>>,
+
     #[prop_or_default]
     pub onchange: Callback<JsValue>,
 
@@ -35,6 +41,7 @@ pub fn metadatum_value_editor(props: &MetadatumValueEditorProps) -> Html {
     });
     let kind_ref = use_node_ref();
     let value_ref = use_node_ref();
+    let option_ref = use_node_ref();
 
     {
         // update states if prop value changes
@@ -125,6 +132,21 @@ pub fn metadatum_value_editor(props: &MetadatumValueEditorProps) -> Html {
                     return;
                 }
 
+                if kind == MetadatumType::Quantity && val == JsValue::Null {
+                    onerror.emit("Invalid quantity".to_string());
+                    return;
+                }
+
+                if kind == MetadatumType::DateTime && val == JsValue::Null {
+                    onerror.emit("Invalid date time".to_string());
+                    return;
+                }
+
+                if kind == MetadatumType::Link && val == JsValue::Null {
+                    onerror.emit("Invalid resource id".to_string());
+                    return;
+                }
+
                 value.set(common::convert_value(val, &kind));
             } else {
                 // invalid input for type
@@ -133,10 +155,43 @@ pub fn metadatum_value_editor(props: &MetadatumValueEditorProps) -> Html {
         })
     };
 
+    let onchange_quantity = {
+        let value = value.clone();
+        Callback::from(move |quantity: Quantity| {
+            value.set(quantity.into());
+        })
+    };
+
+    let onchange_option = {
+        let value = value.clone();
+        let option_ref = option_ref.clone();
+        let options = props.options.clone().unwrap_or_default();
+
+        Callback::from(move |_: Event| {
+            let option = option_ref
+                .cast::<web_sys::HtmlSelectElement>()
+                .expect("could not cast option node ref into select");
+
+            let Some(option) = option
+                .value()
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| options.get(index))
+            else {
+                return;
+            };
+
+            value.set(option.clone());
+        })
+    };
+
     // create <options> for `kind` <select>
     let kind_opts = [
         MetadatumType::String,
         MetadatumType::Number,
+        MetadatumType::Quantity,
+        MetadatumType::DateTime,
+        MetadatumType::Link,
         MetadatumType::Bool,
         MetadatumType::Array,
         MetadatumType::Object,
@@ -157,6 +212,32 @@ pub fn metadatum_value_editor(props: &MetadatumValueEditorProps) -> Html {
 
     // ui
     let class = classes!("thot-ui-metadatum-value-editor", props.class.clone());
+    if let Some(options) = props.options.as_ref() {
+        return html! {
+            <span {class}>
+                <select ref={option_ref} onchange={onchange_option}>
+                    if !options.contains(&*value) {
+                        <option selected={true} disabled={true}>
+                            { common::value_display(&*value) }
+                        </option>
+                    }
+
+                    { options.iter().enumerate().map(|(index, option)| html! {
+                        <option
+                            value={index.to_string()}
+                            selected={option == &*value}>
+
+                            { match option {
+                                JsValue::String(option) => option.clone(),
+                                option => common::value_display(option),
+                            }}
+                        </option>
+                    }).collect::<Html>() }
+                </select>
+            </span>
+        };
+    }
+
     html! {
         <span {class}>
             <select ref={kind_ref} onchange={onchange_kind.clone()}>
@@ -190,6 +271,38 @@ pub fn metadatum_value_editor(props: &MetadatumValueEditorProps) -> Html {
                         onchange={onchange_value.clone()} />
                 },
 
+                value @ JsValue::Object(_) if Quantity::is_quantity(&value) => html! {
+                    <QuantityInput
+                        value={Quantity::from_value(&value).expect("value should be a quantity")}
+                        onchange={onchange_quantity}
+                        onerror={props.onerror.clone()} />
+                },
+
+                value @ JsValue::Object(_) if DateTimeValue::is_datetime(&value) => html! {
+                    <>
+                    <input
+                        ref={value_ref}
+                        type={"datetime-local"}
+                        step={"1"}
+                        value={DateTimeValue::from_value(&value)
+                            .expect("value should be a date time")
+                            .0
+                            .format("%Y-%m-%dT%H:%M:%S")
+                            .to_string()}
+                        onchange={onchange_value.clone()} />
+
+                    <span class={classes!("unit")}>{ "UTC" }</span>
+                    </>
+                },
+
+                value @ JsValue::Object(_) if Link::is_link(&value) => html! {
+                    <input
+                        ref={value_ref}
+                        value={Link::from_value(&value).expect("value should be a link").to_string()}
+                        placeholder={"Resource id"}
+                        onchange={onchange_value.clone()} />
+                },
+
                 JsValue::Array(value) => html! {
                     <textarea
                         ref={value_ref}
@@ -1,22 +1,32 @@
 //! Metadata widgets.
 pub mod common;
+pub mod derived_metadata_editor;
+pub mod inherited_metadata_preview;
 pub mod metadata_bulk_editor;
 pub mod metadata_editor;
+pub mod metadata_inheritance_editor;
 pub mod metadata_preview;
 pub mod metadatum_builder;
 pub mod metadatum_bulk_editor;
 pub mod metadatum_bulk_value_editor;
 pub mod metadatum_editor;
+pub mod metadatum_history;
 pub mod metadatum_value_editor;
+pub mod quantity_input;
 pub mod types;
 
 // Re-exports
+pub use derived_metadata_editor::DerivedMetadataEditor;
+pub use inherited_metadata_preview::InheritedMetadataPreview;
 pub use metadata_bulk_editor::MetadataBulkEditor;
 pub use metadata_editor::MetadataEditor;
+pub use metadata_inheritance_editor::MetadataInheritanceEditor;
 pub use metadata_preview::MetadataPreview;
 pub use metadatum_builder::MetadatumBuilder;
 pub use metadatum_bulk_editor::MetadatumBulkEditor;
 pub use metadatum_bulk_value_editor::MetadatumBulkValueEditor;
 pub use metadatum_editor::MetadatumEditor;
+pub use metadatum_history::MetadatumHistory;
 pub use metadatum_value_editor::MetadatumValueEditor;
+pub use quantity_input::QuantityInput;
 pub use types::{type_from_string, type_of_value, MetadataBulk, Metadatum, MetadatumType};
@@ -0,0 +1,74 @@
+//! Input for a [`Quantity`].
+use thot_core::project::Quantity;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq, Debug)]
+pub struct QuantityInputProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    pub value: Quantity,
+
+    /// Placeholder for the magnitude.
+    #[prop_or_default]
+    pub placeholder: Option<AttrValue>,
+
+    #[prop_or_default]
+    pub onchange: Callback<Quantity>,
+
+    #[prop_or_default]
+    pub onerror: Callback<String>,
+}
+
+#[tracing::instrument]
+#[function_component(QuantityInput)]
+pub fn quantity_input(props: &QuantityInputProps) -> Html {
+    let magnitude_ref = use_node_ref();
+    let unit_ref = use_node_ref();
+
+    let onchange = {
+        let magnitude_ref = magnitude_ref.clone();
+        let unit_ref = unit_ref.clone();
+        let onchange = props.onchange.clone();
+        let onerror = props.onerror.clone();
+
+        Callback::from(move |_: Event| {
+            let magnitude = magnitude_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not cast magnitude node ref into input");
+
+            let unit = unit_ref
+                .cast::<web_sys::HtmlInputElement>()
+                .expect("could not cast unit node ref into input");
+
+            let magnitude = magnitude.value_as_number();
+            if !magnitude.is_finite() {
+                onerror.emit("Invalid magnitude".to_string());
+                return;
+            }
+
+            onchange.emit(Quantity::new(magnitude, unit.value().trim()));
+        })
+    };
+
+    let class = classes!("thot-ui-quantity-input", props.class.clone());
+    html! {
+        <span {class}>
+            <input
+                ref={magnitude_ref}
+                class={"magnitude"}
+                type={"number"}
+                step={"any"}
+                value={props.value.magnitude.to_string()}
+                placeholder={props.placeholder.clone()}
+                onchange={onchange.clone()} />
+
+            <input
+                ref={unit_ref}
+                class={"unit"}
+                value={props.value.unit.clone()}
+                placeholder={"Unit"}
+                {onchange} />
+        </span>
+    }
+}
@@ -1,6 +1,7 @@
 //! Common types for metadata.
 use serde_json::Value as JsValue;
 use std::collections::HashMap;
+use thot_core::project::{DateTimeValue, Link, Quantity};
 use yew::html::IntoPropValue;
 use yew::virtual_dom::AttrValue;
 
@@ -15,6 +16,9 @@ pub enum MetadatumType {
     Number,
     Array,
     Object,
+    Quantity,
+    DateTime,
+    Link,
 }
 
 impl Default for MetadatumType {
@@ -31,6 +35,9 @@ impl Into<String> for MetadatumType {
             MetadatumType::Bool => "Boolean".to_string(),
             MetadatumType::Array => "Array".to_string(),
             MetadatumType::Object => "Object".to_string(),
+            MetadatumType::Quantity => "Quantity".to_string(),
+            MetadatumType::DateTime => "Date time".to_string(),
+            MetadatumType::Link => "Link".to_string(),
         }
     }
 }
@@ -55,12 +62,29 @@ pub fn type_from_string(s: &str) -> Option<MetadatumType> {
         "Boolean" => Some(MetadatumType::Bool),
         "Array" => Some(MetadatumType::Array),
         "Object" => Some(MetadatumType::Object),
+        "Quantity" => Some(MetadatumType::Quantity),
+        "Date time" => Some(MetadatumType::DateTime),
+        "Link" => Some(MetadatumType::Link),
         _ => None,
     }
 }
 
 /// Returns the type of the value.
+/// Objects with the shape of a [`Quantity`], [`DateTimeValue`], or [`Link`]
+/// are of that type.
 pub fn type_of_value(value: &JsValue) -> Option<MetadatumType> {
+    if Quantity::is_quantity(value) {
+        return Some(MetadatumType::Quantity);
+    }
+
+    if DateTimeValue::is_datetime(value) {
+        return Some(MetadatumType::DateTime);
+    }
+
+    if Link::is_link(value) {
+        return Some(MetadatumType::Link);
+    }
+
     match value {
         JsValue::Null => None,
         JsValue::String(_) => Some(MetadatumType::String),
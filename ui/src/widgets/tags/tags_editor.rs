@@ -7,6 +7,11 @@ pub struct TagsEditorProps {
     #[prop_or(Vec::new())]
     pub value: Vec<String>,
 
+    /// Tags suggested while typing,
+    /// e.g. the terms of a vocabulary.
+    #[prop_or_default]
+    pub suggestions: Vec<String>,
+
     /// Callback when value is changed.
     #[prop_or_default]
     pub onchange: Callback<Vec<String>>,
@@ -15,6 +20,26 @@ pub struct TagsEditorProps {
 #[function_component(TagsEditor)]
 pub fn tags_editor(props: &TagsEditorProps) -> Html {
     let input_ref = use_node_ref();
+    let input_value = use_state(|| props.value.join(", "));
+    let list_id = use_state(|| format!("thot-ui-tags-{}", uuid::Uuid::new_v4()));
+
+    {
+        let input_value = input_value.setter();
+        use_effect_with(props.value.clone(), move |value| {
+            input_value.set(value.join(", "));
+        });
+    }
+
+    let oninput = {
+        let input_value = input_value.setter();
+        Callback::from(move |e: InputEvent| {
+            let input = e
+                .target_dyn_into::<web_sys::HtmlInputElement>()
+                .expect("could not cast target into input");
+
+            input_value.set(input.value());
+        })
+    };
 
     let onchange = {
         let input_ref = input_ref.clone();
@@ -43,11 +68,43 @@ pub fn tags_editor(props: &TagsEditorProps) -> Html {
         })
     };
 
+    // suggestions complete the last tag being typed
+    let (prefix, current) = match input_value.rsplit_once(',') {
+        Some((prefix, current)) => (format!("{prefix}, "), current.trim()),
+        None => (String::new(), input_value.trim()),
+    };
+
+    let current = current.to_lowercase();
+    let entered = input_value
+        .split(',')
+        .map(|tag| tag.trim().to_lowercase())
+        .collect::<Vec<_>>();
+
+    let suggestions = props
+        .suggestions
+        .iter()
+        .filter(|tag| tag.to_lowercase().starts_with(&current))
+        .filter(|tag| !entered.contains(&tag.to_lowercase()))
+        .map(|tag| format!("{prefix}{tag}"))
+        .collect::<Vec<_>>();
+
     html! {
+        <>
         <input
             ref={input_ref}
+            list={(!props.suggestions.is_empty()).then(|| (*list_id).clone())}
             placeholder={"(no tags)"}
-            value={props.value.clone().join(", ")}
+            value={(*input_value).clone()}
+            {oninput}
             {onchange} />
+
+        if !props.suggestions.is_empty() {
+            <datalist id={(*list_id).clone()}>
+                { suggestions.into_iter().map(|suggestion| html! {
+                    <option value={suggestion} />
+                }).collect::<Html>() }
+            </datalist>
+        }
+        </>
     }
 }